@@ -24,6 +24,7 @@ use crate::{
 use snarkvm_curves::{
     templates::twisted_edwards_extended::{Affine as TEAffine, Projective},
     AffineCurve,
+    Group,
     ProjectiveCurve,
     TwistedEdwardsParameters,
 };
@@ -47,6 +48,12 @@ use itertools::Itertools;
 use rand::{CryptoRng, Rng};
 use std::sync::Arc;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The window size of the wNAF form of the private key, used to compute symmetric keys in batch.
+const SYMMETRIC_KEY_WNAF_WINDOW_SIZE: usize = 5;
+
 #[derive(Derivative, CanonicalSerialize, CanonicalDeserialize)]
 #[derivative(
     Copy(bound = "TE: TwistedEdwardsParameters"),
@@ -111,6 +118,76 @@ where
     symmetric_encryption_domain: TE::BaseField,
 }
 
+impl<TE: TwistedEdwardsParameters> ECIESPoseidonEncryption<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    /// Returns the ciphertext randomizer group element in the prime-order subgroup, for the given x-coordinate.
+    fn recover_ciphertext_randomizer(ciphertext_randomizer: TE::BaseField) -> Option<TEAffine<TE>> {
+        // Note for twisted Edwards curves, only one of (x, y) or (x, -y) is in the prime-order subgroup.
+        [true, false].iter().find_map(|greatest| {
+            TEAffine::<TE>::from_x_coordinate(ciphertext_randomizer, *greatest)
+                .filter(|element| element.is_in_correct_subgroup_assuming_on_curve())
+        })
+    }
+
+    /// Returns the wNAF form of the given scalar for the given window size, in little-endian order.
+    fn wnaf_form(scalar: &TE::ScalarField, window_size: usize) -> Vec<i64> {
+        let mut bits = BitIteratorBE::new(scalar.to_repr()).collect::<Vec<_>>();
+        bits.reverse();
+
+        let width = 1i64 << window_size;
+        let mut wnaf = Vec::with_capacity(bits.len() + 1);
+        let mut carry = 0i64;
+        let mut position = 0;
+
+        // Iterate one window past the last bit, to absorb the final carry.
+        while position < bits.len() + window_size {
+            // Read the next window of bits, in little-endian order.
+            let window = (0..window_size)
+                .filter(|i| bits.get(position + i).copied().unwrap_or(false))
+                .fold(0i64, |window, i| window | (1 << i));
+            let value = carry + window;
+
+            if value & 1 == 0 {
+                wnaf.push(0);
+                position += 1;
+            } else {
+                let digit = if value < width / 2 { value } else { value - width };
+                carry = (digit < 0) as i64;
+                wnaf.push(digit);
+                wnaf.resize(wnaf.len() + window_size - 1, 0);
+                position += window_size;
+            }
+        }
+        wnaf
+    }
+
+    /// Returns the product of the given base and the scalar in the given wNAF form.
+    fn wnaf_mul(base: &TEAffine<TE>, wnaf: &[i64], window_size: usize) -> Projective<TE> {
+        // Precompute the odd multiples of the base, i.e. [base, 3 * base, 5 * base, ...].
+        let base = base.into_projective();
+        let double = base.double();
+        let mut table = Vec::with_capacity(1 << (window_size - 2));
+        table.push(base);
+        for i in 1..(1 << (window_size - 2)) {
+            let next = table[i - 1] + double;
+            table.push(next);
+        }
+
+        let mut result = Projective::<TE>::zero();
+        for digit in wnaf.iter().rev() {
+            result.double_in_place();
+            match *digit {
+                digit if digit > 0 => result += &table[(digit / 2) as usize],
+                digit if digit < 0 => result -= &table[(-digit / 2) as usize],
+                _ => (),
+            }
+        }
+        result
+    }
+}
+
 impl<TE: TwistedEdwardsParameters> EncryptionScheme for ECIESPoseidonEncryption<TE>
 where
     TE::BaseField: PoseidonDefaultParametersField,
@@ -184,23 +261,7 @@ where
         private_key: &<Self as EncryptionScheme>::PrivateKey,
         ciphertext_randomizer: Self::CiphertextRandomizer,
     ) -> Option<Self::SymmetricKey> {
-        // Recover the ciphertext randomizer group element.
-        let mut randomizer = None;
-
-        if let Some(element) = TEAffine::<TE>::from_x_coordinate(ciphertext_randomizer, true) {
-            if element.is_in_correct_subgroup_assuming_on_curve() {
-                randomizer = Some(element);
-            }
-        }
-        if randomizer.is_none() {
-            if let Some(element) = TEAffine::<TE>::from_x_coordinate(ciphertext_randomizer, false) {
-                if element.is_in_correct_subgroup_assuming_on_curve() {
-                    randomizer = Some(element);
-                }
-            }
-        }
-
-        randomizer.map(|randomizer| {
+        Self::recover_ciphertext_randomizer(ciphertext_randomizer).map(|randomizer| {
             randomizer
                 .mul_bits(BitIteratorBE::new_without_leading_zeros(private_key.to_repr()))
                 .into_affine()
@@ -208,6 +269,43 @@ where
         })
     }
 
+    ///
+    /// Given the private key and ciphertext randomizers, return the symmetric keys.
+    ///
+    /// The wNAF form of the private key is computed once, and shared across the ciphertext randomizers.
+    /// The scalar multiplications are performed in parallel, and normalized together in one batch inversion.
+    ///
+    fn generate_symmetric_keys(
+        &self,
+        private_key: &Self::PrivateKey,
+        ciphertext_randomizers: &[Self::CiphertextRandomizer],
+    ) -> Vec<Option<Self::SymmetricKey>> {
+        // Compute the wNAF form of the private key.
+        let wnaf = Self::wnaf_form(private_key, SYMMETRIC_KEY_WNAF_WINDOW_SIZE);
+
+        // Compute the symmetric keys in projective form.
+        let symmetric_keys = cfg_iter!(ciphertext_randomizers)
+            .map(|ciphertext_randomizer| {
+                Self::recover_ciphertext_randomizer(*ciphertext_randomizer)
+                    .map(|randomizer| Self::wnaf_mul(&randomizer, &wnaf, SYMMETRIC_KEY_WNAF_WINDOW_SIZE))
+            })
+            .collect::<Vec<_>>();
+
+        // Normalize the symmetric keys in one batch.
+        let mut projective_keys = symmetric_keys.iter().flatten().copied().collect::<Vec<_>>();
+        Projective::<TE>::batch_normalization(&mut projective_keys);
+
+        let mut projective_keys = projective_keys.into_iter();
+        symmetric_keys
+            .into_iter()
+            .map(|symmetric_key| {
+                symmetric_key
+                    .and_then(|_| projective_keys.next())
+                    .map(|symmetric_key| symmetric_key.into_affine().to_x_coordinate())
+            })
+            .collect()
+    }
+
     ///
     /// Given the symmetric key, return the following:
     ///
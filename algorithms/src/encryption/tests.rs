@@ -17,6 +17,7 @@
 mod ecies {
     use crate::{encryption::ECIESPoseidonEncryption, EncryptionScheme};
     use snarkvm_curves::edwards_bls12::EdwardsParameters;
+    use snarkvm_fields::{One, Zero};
     use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
 
     use rand::{thread_rng, Rng};
@@ -132,4 +133,43 @@ mod ecies {
             assert_ne!(message, candidate_message);
         }
     }
+
+    #[test]
+    fn test_generate_symmetric_keys_in_batch() {
+        let rng = &mut thread_rng();
+        let encryption = TestEncryptionScheme::setup("encryption_symmetric_keys_in_batch");
+
+        let private_keys = vec![
+            <TestEncryptionScheme as EncryptionScheme>::PrivateKey::zero(),
+            <TestEncryptionScheme as EncryptionScheme>::PrivateKey::one(),
+            encryption.generate_private_key(rng),
+            encryption.generate_private_key(rng),
+        ];
+
+        for private_key in &private_keys {
+            // Sample ciphertext randomizers for the private key, for other private keys, and malformed ones.
+            let public_key = encryption.generate_public_key(private_key);
+            let mut ciphertext_randomizers = Vec::with_capacity(ITERATIONS);
+            for i in 0..ITERATIONS {
+                let ciphertext_randomizer = match i % 3 {
+                    0 => encryption.generate_asymmetric_key(&public_key, rng).1,
+                    1 => {
+                        let alternate_private_key = encryption.generate_private_key(rng);
+                        let alternate_public_key = encryption.generate_public_key(&alternate_private_key);
+                        encryption.generate_asymmetric_key(&alternate_public_key, rng).1
+                    }
+                    _ => UniformRand::rand(rng),
+                };
+                ciphertext_randomizers.push(ciphertext_randomizer);
+            }
+
+            // Ensure the batch results match the single-item results.
+            let expected = ciphertext_randomizers
+                .iter()
+                .map(|ciphertext_randomizer| encryption.generate_symmetric_key(private_key, *ciphertext_randomizer))
+                .collect::<Vec<_>>();
+            let candidate = encryption.generate_symmetric_keys(private_key, &ciphertext_randomizers);
+            assert_eq!(expected, candidate);
+        }
+    }
 }
@@ -62,6 +62,21 @@ pub struct AleoSignature<TE: TwistedEdwardsParameters> {
 }
 
 impl<TE: TwistedEdwardsParameters> AleoSignature<TE> {
+    /// Initializes a new signature from (s, c, G^sk_sig, G^r_sig).
+    pub(crate) fn new(
+        prover_response: TE::ScalarField,
+        verifier_challenge: TE::ScalarField,
+        root_public_key: TEAffine<TE>,
+        root_randomizer: TEAffine<TE>,
+    ) -> Self {
+        Self {
+            prover_response,
+            verifier_challenge,
+            root_public_key: root_public_key.x,
+            root_randomizer: root_randomizer.x,
+        }
+    }
+
     #[inline]
     pub fn size() -> usize {
         2 * TE::ScalarField::SERIALIZED_SIZE + 2 * TE::BaseField::SERIALIZED_SIZE
@@ -236,37 +251,21 @@ where
         // Compute G^r_sig.
         let g_r_sig = self.g_scalar_multiply(r_sig);
 
-        // Compute sk_prf := RO(G^sk_sig || G^r_sig).
-        let sk_prf = self.hash_to_scalar_field(&[g_sk_sig.x, g_r_sig.x]);
-
-        // Compute G^sk_prf.
-        let g_sk_prf = self.g_scalar_multiply(&sk_prf);
-
         // Compute G^sk_sig G^r_sig G^sk_prf.
-        let public_key = g_sk_sig + g_r_sig + g_sk_prf;
+        let public_key = self.compute_public_key(&g_sk_sig, &g_r_sig);
 
         // Compute the verifier challenge.
-        let verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
-            let mut preimage = vec![];
-            preimage.extend_from_slice(&public_key.x.to_field_elements()?);
-            preimage.extend_from_slice(&g_r.x.to_field_elements()?);
-            preimage.push(TE::BaseField::from(message.len() as u128));
-            preimage.extend_from_slice(&message.to_field_elements()?);
-
-            // Hash to derive the verifier challenge.
-            self.hash_to_scalar_field(&preimage)
-        };
+        let verifier_challenge = self.compute_verifier_challenge(&public_key, &g_r, message)?;
 
         // Compute the prover response.
         let prover_response = r - (verifier_challenge * sk_sig);
 
-        Ok(AleoSignature {
+        Ok(AleoSignature::new(
             prover_response,
             verifier_challenge,
-            root_public_key: g_sk_sig.x,
-            root_randomizer: g_r_sig.x,
-        })
+            g_sk_sig,
+            g_r_sig,
+        ))
     }
 
     ///
@@ -292,32 +291,13 @@ where
         let g_r = self.g_scalar_multiply(prover_response) + g_sk_sig_c;
 
         // Compute the candidate verifier challenge.
-        let candidate_verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
-            let mut preimage = vec![];
-            preimage.extend_from_slice(&public_key.x.to_field_elements()?);
-            preimage.extend_from_slice(&g_r.x.to_field_elements()?);
-            preimage.push(TE::BaseField::from(message.len() as u128));
-            preimage.extend_from_slice(&message.to_field_elements()?);
-
-            // Hash to derive the verifier challenge.
-            self.hash_to_scalar_field(&preimage)
-        };
+        let candidate_verifier_challenge = self.compute_verifier_challenge(public_key, &g_r, message)?;
 
         // Recover G^r_sig.
         let g_r_sig = Self::recover_from_x_coordinate(root_randomizer)?;
 
         // Compute the candidate public key as (G^sk_sig G^r_sig G^sk_prf).
-        let candidate_public_key = {
-            // Compute sk_prf := RO(G^sk_sig || G^r_sig).
-            let sk_prf = self.hash_to_scalar_field(&[g_sk_sig.x, g_r_sig.x]);
-
-            // Compute G^sk_prf.
-            let g_sk_prf = self.g_scalar_multiply(&sk_prf);
-
-            // Compute G^sk_sig G^r_sig G^sk_prf.
-            g_sk_sig + g_r_sig + g_sk_prf
-        };
+        let candidate_public_key = self.compute_public_key(&g_sk_sig, &g_r_sig);
 
         Ok(*verifier_challenge == candidate_verifier_challenge && *public_key == candidate_public_key)
     }
@@ -378,6 +358,41 @@ where
         base.mul(*scalar).into_affine()
     }
 
+    ///
+    /// Returns the public key as (G^sk_sig G^r_sig G^sk_prf), where:
+    ///     sk_prf := RO(G^sk_sig || G^r_sig)
+    ///
+    pub(crate) fn compute_public_key(&self, g_sk_sig: &TEAffine<TE>, g_r_sig: &TEAffine<TE>) -> TEAffine<TE> {
+        // Compute sk_prf := RO(G^sk_sig || G^r_sig).
+        let sk_prf = self.hash_to_scalar_field(&[g_sk_sig.x, g_r_sig.x]);
+
+        // Compute G^sk_prf.
+        let g_sk_prf = self.g_scalar_multiply(&sk_prf);
+
+        // Compute G^sk_sig G^r_sig G^sk_prf.
+        *g_sk_sig + *g_r_sig + g_sk_prf
+    }
+
+    ///
+    /// Returns the verifier challenge as Hash(G^sk_sig G^r_sig G^sk_prf, G^r, message).
+    ///
+    pub(crate) fn compute_verifier_challenge(
+        &self,
+        public_key: &TEAffine<TE>,
+        g_r: &TEAffine<TE>,
+        message: &[u8],
+    ) -> Result<TE::ScalarField> {
+        // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
+        let mut preimage = vec![];
+        preimage.extend_from_slice(&public_key.x.to_field_elements()?);
+        preimage.extend_from_slice(&g_r.x.to_field_elements()?);
+        preimage.push(TE::BaseField::from(message.len() as u128));
+        preimage.extend_from_slice(&message.to_field_elements()?);
+
+        // Hash to derive the verifier challenge.
+        Ok(self.hash_to_scalar_field(&preimage))
+    }
+
     fn recover_from_x_coordinate(x_coordinate: &TE::BaseField) -> Result<TEAffine<TE>> {
         if let Some(element) = TEAffine::<TE>::from_x_coordinate(*x_coordinate, true) {
             if element.is_in_correct_subgroup_assuming_on_curve() {
@@ -17,5 +17,8 @@
 pub mod aleo;
 pub use aleo::*;
 
+pub mod threshold;
+pub use threshold::*;
+
 #[cfg(test)]
 mod tests;
@@ -77,3 +77,132 @@ mod aleo {
         signature_scheme_serialization::<AleoSignatureScheme<EdwardsBW6>>();
     }
 }
+
+mod threshold {
+    use crate::{
+        signature::{AleoSignatureScheme, KeyGenerationParticipant, ThresholdKeyShare},
+        SignatureScheme,
+    };
+    use snarkvm_curves::edwards_bls12::EdwardsParameters as EdwardsBls12;
+    use snarkvm_utilities::{FromBytes, ToBytes};
+
+    use rand::thread_rng;
+
+    type TestSignature = AleoSignatureScheme<EdwardsBls12>;
+
+    /// Runs the distributed key generation, returning the key share of each participant.
+    fn key_generation(
+        signature_scheme: &TestSignature,
+        threshold: u16,
+        num_participants: u16,
+    ) -> Vec<ThresholdKeyShare<EdwardsBls12>> {
+        let rng = &mut thread_rng();
+
+        let (participants, commitments): (Vec<_>, Vec<_>) = (1..=num_participants)
+            .map(|index| {
+                KeyGenerationParticipant::new(signature_scheme, index, threshold, num_participants, rng).unwrap()
+            })
+            .unzip();
+
+        participants
+            .iter()
+            .map(|participant| {
+                let shares: Vec<_> = participants
+                    .iter()
+                    .map(|sender| sender.share(participant.index()).unwrap())
+                    .collect();
+                participant.finalize(signature_scheme, &commitments, &shares).unwrap()
+            })
+            .collect()
+    }
+
+    /// Runs the signing rounds with the given signers, returning the aggregate signature.
+    fn threshold_sign(
+        signature_scheme: &TestSignature,
+        signers: &[&ThresholdKeyShare<EdwardsBls12>],
+        message: &[u8],
+    ) -> anyhow::Result<<TestSignature as SignatureScheme>::Signature> {
+        let rng = &mut thread_rng();
+
+        let (nonces, commitments): (Vec<_>, Vec<_>) = signers
+            .iter()
+            .map(|signer| signer.commit(signature_scheme, rng))
+            .unzip();
+
+        let partial_signatures = signers
+            .iter()
+            .zip(nonces)
+            .map(|(signer, nonces)| signer.sign(signature_scheme, message, nonces, &commitments))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        signers[0]
+            .public_key()
+            .aggregate(signature_scheme, message, &commitments, &partial_signatures)
+    }
+
+    #[test]
+    fn test_threshold_sign_and_verify() {
+        let signature_scheme = TestSignature::setup("test_threshold_sign_and_verify");
+        let key_shares = key_generation(&signature_scheme, 2, 3);
+        let public_key = *key_shares[0].public_key().public_key();
+
+        // Ensure all participants agree on the group public key.
+        assert!(key_shares
+            .iter()
+            .all(|key_share| key_share.public_key() == key_shares[0].public_key()));
+
+        // Ensure any 2 (or all 3) participants produce an ordinary signature.
+        let message = b"Hi, I am an Aleo threshold signature!";
+        for signers in [vec![0, 1], vec![0, 2], vec![2, 1], vec![0, 1, 2]] {
+            let signers: Vec<_> = signers.into_iter().map(|i| &key_shares[i]).collect();
+            let signature = threshold_sign(&signature_scheme, &signers, message).unwrap();
+            assert!(signature_scheme.verify(&public_key, message, &signature).unwrap());
+            assert!(!signature_scheme
+                .verify(&public_key, b"Bad message", &signature)
+                .unwrap());
+        }
+
+        // Ensure a single participant cannot sign.
+        assert!(threshold_sign(&signature_scheme, &[&key_shares[1]], message).is_err());
+
+        // Ensure the reconstructed account secrets derive the group public key.
+        let private_key = ThresholdKeyShare::reconstruct(&key_shares[1..]).unwrap();
+        assert_eq!(public_key, signature_scheme.generate_public_key(&private_key));
+        assert!(ThresholdKeyShare::reconstruct(&key_shares[..1]).is_err());
+    }
+
+    #[test]
+    fn test_threshold_rejects_invalid_partial_signature() {
+        let rng = &mut thread_rng();
+        let signature_scheme = TestSignature::setup("test_threshold_rejects_invalid_partial_signature");
+        let key_shares = key_generation(&signature_scheme, 2, 2);
+
+        let message = b"Hi, I am an Aleo threshold signature!";
+        let (first_nonces, first_commitment) = key_shares[0].commit(&signature_scheme, rng);
+        let (second_nonces, second_commitment) = key_shares[1].commit(&signature_scheme, rng);
+        let commitments = vec![first_commitment, second_commitment];
+
+        // Sign a different message with the second signer.
+        let partial_signatures = vec![
+            key_shares[0]
+                .sign(&signature_scheme, message, first_nonces, &commitments)
+                .unwrap(),
+            key_shares[1]
+                .sign(&signature_scheme, b"Bad message", second_nonces, &commitments)
+                .unwrap(),
+        ];
+        assert!(key_shares[0]
+            .public_key()
+            .aggregate(&signature_scheme, message, &commitments, &partial_signatures)
+            .is_err());
+    }
+
+    #[test]
+    fn test_threshold_key_share_serialization() {
+        let signature_scheme = TestSignature::setup("test_threshold_key_share_serialization");
+        for key_share in key_generation(&signature_scheme, 2, 3) {
+            let bytes = key_share.to_bytes_le().unwrap();
+            assert_eq!(key_share, ThresholdKeyShare::read_le(&bytes[..]).unwrap());
+        }
+    }
+}
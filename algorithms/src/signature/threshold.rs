@@ -0,0 +1,874 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Threshold signing for the Aleo signature scheme.
+//!
+//! A `t`-of-`n` group runs a distributed key generation for the account secrets (sk_sig, r_sig),
+//! after which any `t` participants can jointly produce an ordinary `AleoSignature` in two rounds:
+//! each signer first publishes a nonce commitment, then a partial signature, and the partial
+//! signatures are aggregated into a signature that verifies under the group public key.
+
+use crate::{
+    crypto_hash::PoseidonDefaultParametersField,
+    signature::{AleoSignature, AleoSignatureScheme},
+    SignatureError,
+    SignatureScheme,
+    SignatureSchemeOperations,
+};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::{Affine as TEAffine, Projective as TEProjective},
+    AffineCurve,
+    ProjectiveCurve,
+    TwistedEdwardsParameters,
+};
+use snarkvm_fields::{Field, One, PrimeField, ToConstraintField, Zero};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    ops::Mul,
+    rand::UniformRand,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+use std::collections::HashSet;
+
+pub static THRESHOLD_KEY_GENERATION_DOMAIN: &str = "AleoThresholdKeyGeneration0";
+pub static THRESHOLD_SIGNING_BINDING_DOMAIN: &str = "AleoThresholdSigningBinding0";
+
+///
+/// The broadcast message of a participant in the distributed key generation,
+/// committing to its secret polynomials for (sk_sig, r_sig).
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct KeyGenerationCommitment<TE: TwistedEdwardsParameters> {
+    index: u16,
+    sk_sig_commitments: Vec<TEAffine<TE>>,
+    r_sig_commitments: Vec<TEAffine<TE>>,
+    /// A proof of knowledge of the constant terms, as (c, s_sk_sig, s_r_sig).
+    proof: (TE::ScalarField, TE::ScalarField, TE::ScalarField),
+}
+
+impl<TE: TwistedEdwardsParameters> KeyGenerationCommitment<TE> {
+    /// Returns the index of the participant.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+}
+
+///
+/// The private message from one participant to another in the distributed key generation,
+/// containing the evaluations of the sender's secret polynomials at the receiver's index.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct KeyGenerationShare<TE: TwistedEdwardsParameters> {
+    sender: u16,
+    receiver: u16,
+    sk_sig_share: TE::ScalarField,
+    r_sig_share: TE::ScalarField,
+}
+
+impl<TE: TwistedEdwardsParameters> KeyGenerationShare<TE> {
+    /// Returns the index of the sending participant.
+    pub fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    /// Returns the index of the receiving participant.
+    pub fn receiver(&self) -> u16 {
+        self.receiver
+    }
+}
+
+///
+/// A participant in the distributed key generation, holding its secret polynomials.
+///
+pub struct KeyGenerationParticipant<TE: TwistedEdwardsParameters> {
+    index: u16,
+    threshold: u16,
+    num_participants: u16,
+    sk_sig_coefficients: Vec<TE::ScalarField>,
+    r_sig_coefficients: Vec<TE::ScalarField>,
+}
+
+impl<TE: TwistedEdwardsParameters> KeyGenerationParticipant<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    ///
+    /// Samples the secret polynomials of the participant at the given index (from 1 to `num_participants`),
+    /// returning the participant and its commitment to broadcast to all other participants.
+    ///
+    pub fn new<R: Rng + CryptoRng>(
+        signature_scheme: &AleoSignatureScheme<TE>,
+        index: u16,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<(Self, KeyGenerationCommitment<TE>)> {
+        if threshold == 0 || threshold > num_participants {
+            return Err(
+                SignatureError::Message(format!("Invalid threshold {} of {}", threshold, num_participants)).into(),
+            );
+        }
+        if index == 0 || index > num_participants {
+            return Err(SignatureError::Message(format!("Invalid participant index {}", index)).into());
+        }
+
+        // Sample the secret polynomials of degree (threshold - 1).
+        let sk_sig_coefficients: Vec<_> = (0..threshold).map(|_| TE::ScalarField::rand(rng)).collect();
+        let r_sig_coefficients: Vec<_> = (0..threshold).map(|_| TE::ScalarField::rand(rng)).collect();
+
+        // Commit to the coefficients.
+        let commit = |coefficients: &[TE::ScalarField]| -> Vec<_> {
+            coefficients
+                .iter()
+                .map(|coefficient| signature_scheme.g_scalar_multiply(coefficient))
+                .collect()
+        };
+        let sk_sig_commitments = commit(&sk_sig_coefficients);
+        let r_sig_commitments = commit(&r_sig_coefficients);
+
+        // Prove knowledge of the constant terms, to prevent rogue-key attacks.
+        let k_sk_sig = TE::ScalarField::rand(rng);
+        let k_r_sig = TE::ScalarField::rand(rng);
+        let challenge = key_generation_challenge(
+            signature_scheme,
+            index,
+            &sk_sig_commitments[0],
+            &r_sig_commitments[0],
+            &signature_scheme.g_scalar_multiply(&k_sk_sig),
+            &signature_scheme.g_scalar_multiply(&k_r_sig),
+        )?;
+        let proof = (
+            challenge,
+            k_sk_sig + challenge * sk_sig_coefficients[0],
+            k_r_sig + challenge * r_sig_coefficients[0],
+        );
+
+        let commitment = KeyGenerationCommitment {
+            index,
+            sk_sig_commitments,
+            r_sig_commitments,
+            proof,
+        };
+
+        Ok((
+            Self {
+                index,
+                threshold,
+                num_participants,
+                sk_sig_coefficients,
+                r_sig_coefficients,
+            },
+            commitment,
+        ))
+    }
+
+    /// Returns the index of the participant.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the share to privately send to the participant at the given index.
+    pub fn share(&self, receiver: u16) -> Result<KeyGenerationShare<TE>> {
+        if receiver == 0 || receiver > self.num_participants {
+            return Err(SignatureError::Message(format!("Invalid participant index {}", receiver)).into());
+        }
+
+        let x = TE::ScalarField::from(receiver as u64);
+        Ok(KeyGenerationShare {
+            sender: self.index,
+            receiver,
+            sk_sig_share: evaluate_polynomial(&self.sk_sig_coefficients, x),
+            r_sig_share: evaluate_polynomial(&self.r_sig_coefficients, x),
+        })
+    }
+
+    ///
+    /// Verifies the commitments of all participants and the shares sent to this participant
+    /// (including its own share), returning the key share of this participant.
+    ///
+    pub fn finalize(
+        &self,
+        signature_scheme: &AleoSignatureScheme<TE>,
+        commitments: &[KeyGenerationCommitment<TE>],
+        shares: &[KeyGenerationShare<TE>],
+    ) -> Result<ThresholdKeyShare<TE>> {
+        // Ensure there is exactly one commitment from each participant.
+        let mut commitments = commitments.to_vec();
+        commitments.sort_by_key(|commitment| commitment.index);
+        if commitments.len() != self.num_participants as usize
+            || commitments
+                .iter()
+                .zip(1..)
+                .any(|(commitment, index)| commitment.index != index)
+        {
+            return Err(SignatureError::Message("Missing or duplicate key generation commitments".into()).into());
+        }
+
+        // Ensure each commitment is well-formed, with a valid proof of knowledge.
+        for commitment in &commitments {
+            if commitment.sk_sig_commitments.len() != self.threshold as usize
+                || commitment.r_sig_commitments.len() != self.threshold as usize
+            {
+                return Err(SignatureError::Message(format!(
+                    "Participant {} committed to a polynomial of the wrong degree",
+                    commitment.index
+                ))
+                .into());
+            }
+
+            let (challenge, s_sk_sig, s_r_sig) = commitment.proof;
+            let candidate_challenge = key_generation_challenge(
+                signature_scheme,
+                commitment.index,
+                &commitment.sk_sig_commitments[0],
+                &commitment.r_sig_commitments[0],
+                &(signature_scheme.g_scalar_multiply(&s_sk_sig).into_projective()
+                    - commitment.sk_sig_commitments[0].into_projective().mul(challenge))
+                .into_affine(),
+                &(signature_scheme.g_scalar_multiply(&s_r_sig).into_projective()
+                    - commitment.r_sig_commitments[0].into_projective().mul(challenge))
+                .into_affine(),
+            )?;
+            if challenge != candidate_challenge {
+                return Err(SignatureError::Message(format!(
+                    "Participant {} has an invalid proof of knowledge",
+                    commitment.index
+                ))
+                .into());
+            }
+        }
+
+        // Ensure there is exactly one share from each participant, and that each share matches its commitment.
+        let mut shares = shares.to_vec();
+        shares.sort_by_key(|share| share.sender);
+        if shares.len() != self.num_participants as usize
+            || shares.iter().zip(1..).any(|(share, index)| share.sender != index)
+        {
+            return Err(SignatureError::Message("Missing or duplicate key generation shares".into()).into());
+        }
+        let x = TE::ScalarField::from(self.index as u64);
+        for (share, commitment) in shares.iter().zip(&commitments) {
+            if share.receiver != self.index
+                || signature_scheme.g_scalar_multiply(&share.sk_sig_share)
+                    != evaluate_commitments(&commitment.sk_sig_commitments, x)
+                || signature_scheme.g_scalar_multiply(&share.r_sig_share)
+                    != evaluate_commitments(&commitment.r_sig_commitments, x)
+            {
+                return Err(
+                    SignatureError::Message(format!("Participant {} sent an invalid share", share.sender)).into(),
+                );
+            }
+        }
+
+        // Compute the group commitments to (sk_sig, r_sig).
+        let g_sk_sig = sum_points(commitments.iter().map(|commitment| commitment.sk_sig_commitments[0]));
+        let g_r_sig = sum_points(commitments.iter().map(|commitment| commitment.r_sig_commitments[0]));
+
+        // Compute the verification shares G^sk_sig_j of all participants.
+        let verification_shares = (1..=self.num_participants)
+            .map(|index| {
+                let x = TE::ScalarField::from(index as u64);
+                sum_points(
+                    commitments
+                        .iter()
+                        .map(|commitment| evaluate_commitments(&commitment.sk_sig_commitments, x)),
+                )
+            })
+            .collect();
+
+        Ok(ThresholdKeyShare {
+            index: self.index,
+            sk_sig_share: shares.iter().map(|share| share.sk_sig_share).sum(),
+            r_sig_share: shares.iter().map(|share| share.r_sig_share).sum(),
+            public_key: ThresholdPublicKey {
+                threshold: self.threshold,
+                public_key: signature_scheme.compute_public_key(&g_sk_sig, &g_r_sig),
+                g_sk_sig,
+                g_r_sig,
+                verification_shares,
+            },
+        })
+    }
+}
+
+///
+/// The public key of a threshold group, along with the verification shares of its participants.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct ThresholdPublicKey<TE: TwistedEdwardsParameters> {
+    threshold: u16,
+    public_key: TEAffine<TE>,
+    g_sk_sig: TEAffine<TE>,
+    g_r_sig: TEAffine<TE>,
+    verification_shares: Vec<TEAffine<TE>>,
+}
+
+impl<TE: TwistedEdwardsParameters> ThresholdPublicKey<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    /// Returns the public key of the group, as (G^sk_sig G^r_sig G^sk_prf).
+    pub fn public_key(&self) -> &TEAffine<TE> {
+        &self.public_key
+    }
+
+    /// Returns the number of signers required to sign.
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the number of participants in the group.
+    pub fn num_participants(&self) -> u16 {
+        self.verification_shares.len() as u16
+    }
+
+    ///
+    /// Verifies the partial signatures of the signers, and aggregates them into a signature
+    /// on the message that verifies under the group public key.
+    ///
+    pub fn aggregate(
+        &self,
+        signature_scheme: &AleoSignatureScheme<TE>,
+        message: &[u8],
+        commitments: &[SigningCommitment<TE>],
+        partial_signatures: &[PartialSignature<TE>],
+    ) -> Result<AleoSignature<TE>> {
+        let commitments = self.signing_commitments(commitments)?;
+        let binding_factors = self.binding_factors(signature_scheme, message, &commitments)?;
+        let g_r = group_commitment(&commitments, &binding_factors);
+        let challenge = signature_scheme.compute_verifier_challenge(&self.public_key, &g_r, message)?;
+
+        // Ensure there is exactly one partial signature from each signer.
+        let mut partial_signatures = partial_signatures.to_vec();
+        partial_signatures.sort_by_key(|partial_signature| partial_signature.index);
+        if partial_signatures.len() != commitments.len()
+            || partial_signatures
+                .iter()
+                .zip(&commitments)
+                .any(|(partial_signature, commitment)| partial_signature.index != commitment.index)
+        {
+            return Err(
+                SignatureError::Message("Partial signatures do not match the signing commitments".into()).into(),
+            );
+        }
+
+        // Ensure each partial signature is valid, as G^s_i == D_i E_i^rho_i (G^sk_sig_i)^(-lambda_i c).
+        let signers: Vec<_> = commitments.iter().map(|commitment| commitment.index).collect();
+        for ((partial_signature, commitment), binding_factor) in
+            partial_signatures.iter().zip(&commitments).zip(&binding_factors)
+        {
+            let lambda = lagrange_coefficient::<TE>(partial_signature.index, &signers)?;
+            let verification_share = self.verification_shares[partial_signature.index as usize - 1];
+
+            let candidate = commitment.hiding.into_projective()
+                + commitment.binding.into_projective().mul(*binding_factor)
+                - verification_share.into_projective().mul(lambda * challenge);
+            if signature_scheme.g_scalar_multiply(&partial_signature.response) != candidate.into_affine() {
+                return Err(SignatureError::Message(format!(
+                    "Signer {} produced an invalid partial signature",
+                    partial_signature.index
+                ))
+                .into());
+            }
+        }
+
+        // Aggregate the partial signatures.
+        let prover_response = partial_signatures
+            .iter()
+            .map(|partial_signature| partial_signature.response)
+            .sum();
+        let signature = AleoSignature::new(prover_response, challenge, self.g_sk_sig, self.g_r_sig);
+
+        match signature_scheme.verify(&self.public_key, message, &signature)? {
+            true => Ok(signature),
+            false => Err(SignatureError::Message("Failed to aggregate a valid signature".into()).into()),
+        }
+    }
+
+    /// Returns the signing commitments sorted by index, ensuring they form a valid signing set.
+    fn signing_commitments(&self, commitments: &[SigningCommitment<TE>]) -> Result<Vec<SigningCommitment<TE>>> {
+        let mut commitments = commitments.to_vec();
+        commitments.sort_by_key(|commitment| commitment.index);
+
+        let mut signers = HashSet::with_capacity(commitments.len());
+        for commitment in &commitments {
+            if commitment.index == 0 || commitment.index > self.num_participants() || !signers.insert(commitment.index)
+            {
+                return Err(SignatureError::Message(format!("Invalid signer index {}", commitment.index)).into());
+            }
+        }
+
+        match commitments.len() >= self.threshold as usize {
+            true => Ok(commitments),
+            false => Err(SignatureError::Message(format!(
+                "Found {} signers, but the threshold is {}",
+                commitments.len(),
+                self.threshold
+            ))
+            .into()),
+        }
+    }
+
+    ///
+    /// Returns the binding factor of each signer, as
+    ///     rho_i := Hash(public_key, message, commitments, i)
+    ///
+    fn binding_factors(
+        &self,
+        signature_scheme: &AleoSignatureScheme<TE>,
+        message: &[u8],
+        commitments: &[SigningCommitment<TE>],
+    ) -> Result<Vec<TE::ScalarField>> {
+        let mut preimage = vec![TE::BaseField::from_bytes_le_mod_order(
+            THRESHOLD_SIGNING_BINDING_DOMAIN.as_bytes(),
+        )];
+        preimage.extend_from_slice(&self.public_key.x.to_field_elements()?);
+        preimage.push(TE::BaseField::from(message.len() as u128));
+        preimage.extend_from_slice(&message.to_field_elements()?);
+        for commitment in commitments {
+            preimage.push(TE::BaseField::from(commitment.index as u128));
+            preimage.extend_from_slice(&[commitment.hiding.x, commitment.hiding.y]);
+            preimage.extend_from_slice(&[commitment.binding.x, commitment.binding.y]);
+        }
+
+        Ok(commitments
+            .iter()
+            .map(|commitment| {
+                let mut preimage = preimage.clone();
+                preimage.push(TE::BaseField::from(commitment.index as u128));
+                signature_scheme.hash_to_scalar_field(&preimage)
+            })
+            .collect())
+    }
+}
+
+///
+/// The key share of a participant in a threshold group.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct ThresholdKeyShare<TE: TwistedEdwardsParameters> {
+    index: u16,
+    sk_sig_share: TE::ScalarField,
+    r_sig_share: TE::ScalarField,
+    public_key: ThresholdPublicKey<TE>,
+}
+
+impl<TE: TwistedEdwardsParameters> ThresholdKeyShare<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    /// Returns the index of the participant.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the public key of the threshold group.
+    pub fn public_key(&self) -> &ThresholdPublicKey<TE> {
+        &self.public_key
+    }
+
+    ///
+    /// Samples the signing nonces for one signature, returning the nonces to keep secret
+    /// and the commitment to send to the other signers.
+    ///
+    pub fn commit<R: Rng + CryptoRng>(
+        &self,
+        signature_scheme: &AleoSignatureScheme<TE>,
+        rng: &mut R,
+    ) -> (SigningNonces<TE>, SigningCommitment<TE>) {
+        let hiding = TE::ScalarField::rand(rng);
+        let binding = TE::ScalarField::rand(rng);
+
+        let commitment = SigningCommitment {
+            index: self.index,
+            hiding: signature_scheme.g_scalar_multiply(&hiding),
+            binding: signature_scheme.g_scalar_multiply(&binding),
+        };
+
+        (
+            SigningNonces {
+                hiding,
+                binding,
+                commitment: commitment.clone(),
+            },
+            commitment,
+        )
+    }
+
+    ///
+    /// Returns the partial signature of this signer on the message, given the commitments of all signers.
+    /// The signing nonces are consumed, as reusing them for another signature reveals the key share.
+    ///
+    pub fn sign(
+        &self,
+        signature_scheme: &AleoSignatureScheme<TE>,
+        message: &[u8],
+        nonces: SigningNonces<TE>,
+        commitments: &[SigningCommitment<TE>],
+    ) -> Result<PartialSignature<TE>> {
+        let commitments = self.public_key.signing_commitments(commitments)?;
+
+        // Ensure the commitment of this signer is in the signing set.
+        let position = commitments
+            .iter()
+            .position(|commitment| *commitment == nonces.commitment && commitment.index == self.index)
+            .ok_or_else(|| SignatureError::Message("Signing nonces do not match the signing commitments".into()))?;
+
+        let binding_factors = self
+            .public_key
+            .binding_factors(signature_scheme, message, &commitments)?;
+        let g_r = group_commitment(&commitments, &binding_factors);
+        let challenge = signature_scheme.compute_verifier_challenge(&self.public_key.public_key, &g_r, message)?;
+
+        let signers: Vec<_> = commitments.iter().map(|commitment| commitment.index).collect();
+        let lambda = lagrange_coefficient::<TE>(self.index, &signers)?;
+
+        // Compute s_i := d_i + e_i * rho_i - lambda_i * c * sk_sig_i.
+        Ok(PartialSignature {
+            index: self.index,
+            response: nonces.hiding + nonces.binding * binding_factors[position]
+                - lambda * challenge * self.sk_sig_share,
+        })
+    }
+
+    ///
+    /// Returns the account secrets (sk_sig, r_sig), reconstructed from at least `threshold` key shares.
+    ///
+    pub fn reconstruct(key_shares: &[ThresholdKeyShare<TE>]) -> Result<(TE::ScalarField, TE::ScalarField)> {
+        let signers: Vec<_> = key_shares.iter().map(|key_share| key_share.index).collect();
+        if let Some(key_share) = key_shares.first() {
+            if signers.iter().collect::<HashSet<_>>().len() == signers.len()
+                && signers.len() >= key_share.public_key.threshold as usize
+            {
+                let (mut sk_sig, mut r_sig) = (TE::ScalarField::zero(), TE::ScalarField::zero());
+                for key_share in key_shares {
+                    let lambda = lagrange_coefficient::<TE>(key_share.index, &signers)?;
+                    sk_sig += lambda * key_share.sk_sig_share;
+                    r_sig += lambda * key_share.r_sig_share;
+                }
+                return Ok((sk_sig, r_sig));
+            }
+        }
+        Err(SignatureError::Message("Insufficient key shares to reconstruct the account secrets".into()).into())
+    }
+}
+
+/// The secret nonces of a signer for one signature.
+pub struct SigningNonces<TE: TwistedEdwardsParameters> {
+    hiding: TE::ScalarField,
+    binding: TE::ScalarField,
+    commitment: SigningCommitment<TE>,
+}
+
+/// The commitment of a signer to its nonces, as (index, G^d_i, G^e_i).
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct SigningCommitment<TE: TwistedEdwardsParameters> {
+    index: u16,
+    hiding: TEAffine<TE>,
+    binding: TEAffine<TE>,
+}
+
+impl<TE: TwistedEdwardsParameters> SigningCommitment<TE> {
+    /// Returns the index of the signer.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+}
+
+/// The partial signature of a signer.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct PartialSignature<TE: TwistedEdwardsParameters> {
+    index: u16,
+    response: TE::ScalarField,
+}
+
+impl<TE: TwistedEdwardsParameters> PartialSignature<TE> {
+    /// Returns the index of the signer.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+}
+
+/// Returns the challenge of the proof of knowledge in a key generation commitment.
+fn key_generation_challenge<TE: TwistedEdwardsParameters>(
+    signature_scheme: &AleoSignatureScheme<TE>,
+    index: u16,
+    g_sk_sig: &TEAffine<TE>,
+    g_r_sig: &TEAffine<TE>,
+    k_sk_sig: &TEAffine<TE>,
+    k_r_sig: &TEAffine<TE>,
+) -> Result<TE::ScalarField>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    let preimage = [
+        TE::BaseField::from_bytes_le_mod_order(THRESHOLD_KEY_GENERATION_DOMAIN.as_bytes()),
+        TE::BaseField::from(index as u128),
+        g_sk_sig.x,
+        g_r_sig.x,
+        k_sk_sig.x,
+        k_r_sig.x,
+    ];
+    Ok(signature_scheme.hash_to_scalar_field(&preimage))
+}
+
+/// Returns the group commitment G^r := prod_i D_i E_i^rho_i.
+fn group_commitment<TE: TwistedEdwardsParameters>(
+    commitments: &[SigningCommitment<TE>],
+    binding_factors: &[TE::ScalarField],
+) -> TEAffine<TE> {
+    commitments
+        .iter()
+        .zip(binding_factors)
+        .map(|(commitment, binding_factor)| {
+            commitment.hiding.into_projective() + commitment.binding.into_projective().mul(*binding_factor)
+        })
+        .sum::<TEProjective<TE>>()
+        .into_affine()
+}
+
+/// Returns the Lagrange coefficient at zero of the given index, over the given set of indices.
+fn lagrange_coefficient<TE: TwistedEdwardsParameters>(index: u16, indices: &[u16]) -> Result<TE::ScalarField> {
+    let x_i = TE::ScalarField::from(index as u64);
+
+    let (mut numerator, mut denominator) = (TE::ScalarField::one(), TE::ScalarField::one());
+    for j in indices.iter().filter(|j| **j != index) {
+        let x_j = TE::ScalarField::from(*j as u64);
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+
+    match denominator.inverse() {
+        Some(inverse) => Ok(numerator * inverse),
+        None => Err(SignatureError::Message("Failed to compute a Lagrange coefficient".into()).into()),
+    }
+}
+
+/// Returns the evaluation of the polynomial with the given coefficients at x.
+fn evaluate_polynomial<F: Field>(coefficients: &[F], x: F) -> F {
+    coefficients
+        .iter()
+        .rev()
+        .fold(F::zero(), |result, coefficient| result * x + coefficient)
+}
+
+/// Returns the evaluation in the exponent of the committed polynomial at x.
+fn evaluate_commitments<TE: TwistedEdwardsParameters>(
+    commitments: &[TEAffine<TE>],
+    x: TE::ScalarField,
+) -> TEAffine<TE> {
+    commitments
+        .iter()
+        .rev()
+        .fold(TEProjective::<TE>::zero(), |result, commitment| {
+            result.mul(x) + commitment.into_projective()
+        })
+        .into_affine()
+}
+
+/// Returns the sum of the given points.
+fn sum_points<TE: TwistedEdwardsParameters>(points: impl Iterator<Item = TEAffine<TE>>) -> TEAffine<TE> {
+    points
+        .map(|point| point.into_projective())
+        .sum::<TEProjective<TE>>()
+        .into_affine()
+}
+
+/// Writes the given points, prefixed by their number.
+fn write_points<TE: TwistedEdwardsParameters, W: Write>(points: &[TEAffine<TE>], mut writer: W) -> IoResult<()> {
+    (points.len() as u16).write_le(&mut writer)?;
+    points.iter().try_for_each(|point| point.write_le(&mut writer))
+}
+
+/// Reads points, prefixed by their number.
+fn read_points<TE: TwistedEdwardsParameters, R: Read>(mut reader: R) -> IoResult<Vec<TEAffine<TE>>> {
+    let num_points: u16 = FromBytes::read_le(&mut reader)?;
+    (0..num_points).map(|_| FromBytes::read_le(&mut reader)).collect()
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for KeyGenerationCommitment<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        write_points(&self.sk_sig_commitments, &mut writer)?;
+        write_points(&self.r_sig_commitments, &mut writer)?;
+        self.proof.0.write_le(&mut writer)?;
+        self.proof.1.write_le(&mut writer)?;
+        self.proof.2.write_le(&mut writer)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for KeyGenerationCommitment<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            index: FromBytes::read_le(&mut reader)?,
+            sk_sig_commitments: read_points(&mut reader)?,
+            r_sig_commitments: read_points(&mut reader)?,
+            proof: (
+                FromBytes::read_le(&mut reader)?,
+                FromBytes::read_le(&mut reader)?,
+                FromBytes::read_le(&mut reader)?,
+            ),
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for KeyGenerationShare<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.sender.write_le(&mut writer)?;
+        self.receiver.write_le(&mut writer)?;
+        self.sk_sig_share.write_le(&mut writer)?;
+        self.r_sig_share.write_le(&mut writer)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for KeyGenerationShare<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            sender: FromBytes::read_le(&mut reader)?,
+            receiver: FromBytes::read_le(&mut reader)?,
+            sk_sig_share: FromBytes::read_le(&mut reader)?,
+            r_sig_share: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for ThresholdPublicKey<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.threshold.write_le(&mut writer)?;
+        self.public_key.write_le(&mut writer)?;
+        self.g_sk_sig.write_le(&mut writer)?;
+        self.g_r_sig.write_le(&mut writer)?;
+        write_points(&self.verification_shares, &mut writer)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for ThresholdPublicKey<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            threshold: FromBytes::read_le(&mut reader)?,
+            public_key: FromBytes::read_le(&mut reader)?,
+            g_sk_sig: FromBytes::read_le(&mut reader)?,
+            g_r_sig: FromBytes::read_le(&mut reader)?,
+            verification_shares: read_points(&mut reader)?,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for ThresholdKeyShare<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        self.sk_sig_share.write_le(&mut writer)?;
+        self.r_sig_share.write_le(&mut writer)?;
+        self.public_key.write_le(&mut writer)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for ThresholdKeyShare<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            index: FromBytes::read_le(&mut reader)?,
+            sk_sig_share: FromBytes::read_le(&mut reader)?,
+            r_sig_share: FromBytes::read_le(&mut reader)?,
+            public_key: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for SigningCommitment<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        self.hiding.write_le(&mut writer)?;
+        self.binding.write_le(&mut writer)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for SigningCommitment<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            index: FromBytes::read_le(&mut reader)?,
+            hiding: FromBytes::read_le(&mut reader)?,
+            binding: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for PartialSignature<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for PartialSignature<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            index: FromBytes::read_le(&mut reader)?,
+            response: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{
+    batch_verify_proofs,
     create_random_proof,
     generate_random_parameters,
     verify_proof,
@@ -91,4 +92,21 @@ impl<E: PairingEngine, V: ToConstraintField<E::Fr> + Clone> SNARK for Groth16<E,
         end_timer!(verify_time);
         Ok(result)
     }
+
+    fn batch_verify_prepared<R: Rng + CryptoRng>(
+        prepared_verifying_key: &Self::PreparedVerifyingKey,
+        inputs_and_proofs: &[(&Self::VerifierInput, &Self::Proof)],
+        rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        let verify_time = start_timer!(|| format!("{{Groth 2016}}::BatchVerify ({} proofs)", inputs_and_proofs.len()));
+        let conversion_time = start_timer!(|| "Convert inputs to E::Fr");
+        let proofs_and_inputs = inputs_and_proofs
+            .iter()
+            .map(|(input, proof)| Ok((*proof, input.to_field_elements()?)))
+            .collect::<Result<Vec<_>, SNARKError>>()?;
+        end_timer!(conversion_time);
+        let result = batch_verify_proofs(prepared_verifying_key, &proofs_and_inputs, rng)?;
+        end_timer!(verify_time);
+        Ok(result)
+    }
 }
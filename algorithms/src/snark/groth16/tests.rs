@@ -51,6 +51,7 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<C
 mod bls12_377 {
     use super::*;
     use crate::snark::groth16::{
+        batch_verify_proofs,
         create_random_proof,
         generate_random_parameters,
         prepare_verifying_key,
@@ -80,6 +81,32 @@ mod bls12_377 {
         }
     }
 
+    #[test]
+    fn batch_prove_and_verify() {
+        let rng = &mut thread_rng();
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(parameters.vk.clone());
+
+        let mut proofs_and_inputs = Vec::new();
+        for _ in 0..10 {
+            let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+            let proof = create_random_proof(&MySillyCircuit { a: Some(a), b: Some(b) }, &parameters, rng).unwrap();
+            proofs_and_inputs.push((proof, vec![a * b]));
+        }
+        let mut batch = proofs_and_inputs
+            .iter()
+            .map(|(proof, inputs)| (proof, inputs.clone()))
+            .collect::<Vec<_>>();
+
+        assert!(batch_verify_proofs(&pvk, &[], rng).unwrap());
+        assert!(batch_verify_proofs(&pvk, &batch, rng).unwrap());
+
+        // Ensure a single invalid proof fails the batch.
+        batch[3].1 = vec![Fr::rand(rng)];
+        assert!(!batch_verify_proofs(&pvk, &batch, rng).unwrap());
+    }
+
     #[test]
     fn test_serde_json() {
         let expected_proof = {
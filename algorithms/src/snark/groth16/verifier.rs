@@ -15,10 +15,13 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{PreparedVerifyingKey, Proof, VerifyingKey};
-use snarkvm_curves::traits::{PairingCurve, PairingEngine};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, PrimeField, Zero};
 use snarkvm_r1cs::errors::SynthesisError;
+use snarkvm_utilities::UniformRand;
 
 use core::ops::{AddAssign, Mul, Neg};
+use rand::Rng;
 
 pub fn prepare_verifying_key<E: PairingEngine>(vk: VerifyingKey<E>) -> PreparedVerifyingKey<E> {
     let alpha_g1_beta_g2 = E::pairing(vk.alpha_g1, vk.beta_g2);
@@ -64,3 +67,54 @@ pub fn verify_proof<E: PairingEngine>(
 
     Ok(test == pvk.alpha_g1_beta_g2)
 }
+
+/// Verifies a batch of proofs under the same verifying key with a single multi-pairing,
+/// by checking a random linear combination of their verification equations.
+pub fn batch_verify_proofs<E: PairingEngine, R: Rng>(
+    pvk: &PreparedVerifyingKey<E>,
+    proofs_and_inputs: &[(&Proof<E>, Vec<E::Fr>)],
+    rng: &mut R,
+) -> Result<bool, SynthesisError> {
+    if proofs_and_inputs.is_empty() {
+        return Ok(true);
+    }
+
+    // For each proof, the randomizer `r` scales `e(A, B) = e(alpha, beta) * e(IC, gamma) * e(C, delta)`.
+    let mut prepared_pairs = Vec::with_capacity(proofs_and_inputs.len());
+    let mut combined_ic = E::G1Projective::zero();
+    let mut combined_c = E::G1Projective::zero();
+    let mut combined_randomizer = E::Fr::zero();
+    for (proof, public_inputs) in proofs_and_inputs {
+        if (public_inputs.len() + 1) != pvk.gamma_abc_g1().len() {
+            return Err(SynthesisError::MalformedVerifyingKey(
+                public_inputs.len() + 1,
+                pvk.gamma_abc_g1().len(),
+            ));
+        }
+
+        let mut g_ic = pvk.gamma_abc_g1()[0].into_projective();
+        for (i, b) in public_inputs.iter().zip(pvk.gamma_abc_g1().iter().skip(1)) {
+            g_ic += b.into_projective().mul(*i);
+        }
+
+        let randomizer = E::Fr::rand(rng);
+        combined_ic += g_ic.mul(randomizer);
+        combined_c += proof.c.into_projective().mul(randomizer);
+        combined_randomizer += randomizer;
+
+        let a = proof.a.into_projective().mul(randomizer).into_affine();
+        prepared_pairs.push((a.prepare(), proof.b.prepare()));
+    }
+
+    let combined_ic = combined_ic.into_affine().prepare();
+    let combined_c = combined_c.into_affine().prepare();
+
+    let qap = E::miller_loop(prepared_pairs.iter().map(|(a, b)| (a, b)).chain([
+        (&combined_ic, &pvk.gamma_g2_neg_pc),
+        (&combined_c, &pvk.delta_g2_neg_pc),
+    ]));
+
+    let test = E::final_exponentiation(&qap).ok_or(SynthesisError::UnexpectedIdentity)?;
+
+    Ok(test == pvk.alpha_g1_beta_g2.pow(combined_randomizer.to_repr()))
+}
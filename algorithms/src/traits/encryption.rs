@@ -49,6 +49,19 @@ pub trait EncryptionScheme:
         ciphertext_randomizer: Self::CiphertextRandomizer,
     ) -> Option<Self::SymmetricKey>;
 
+    /// Returns the symmetric keys for the given ciphertext randomizers, under the same private key.
+    /// The result for each ciphertext randomizer matches the result of `generate_symmetric_key`.
+    fn generate_symmetric_keys(
+        &self,
+        private_key: &Self::PrivateKey,
+        ciphertext_randomizers: &[Self::CiphertextRandomizer],
+    ) -> Vec<Option<Self::SymmetricKey>> {
+        ciphertext_randomizers
+            .iter()
+            .map(|ciphertext_randomizer| self.generate_symmetric_key(private_key, ciphertext_randomizer.clone()))
+            .collect()
+    }
+
     fn generate_symmetric_key_commitment(&self, symmetric_key: &Self::SymmetricKey) -> Self::SymmetricKeyCommitment;
 
     fn encrypt(&self, symmetric_key: &Self::SymmetricKey, message: &[u8]) -> Result<Vec<u8>, EncryptionError>;
@@ -99,4 +99,29 @@ pub trait SNARK: Clone + Debug {
         let processed_verifying_key = verifying_key.prepare();
         Self::verify_prepared(&processed_verifying_key, input, proof)
     }
+
+    /// Verifies a batch of proofs under the same verifying key, returning `true` if every proof is valid.
+    /// By default, each proof is verified separately.
+    fn batch_verify_prepared<R: Rng + CryptoRng>(
+        prepared_verifying_key: &Self::PreparedVerifyingKey,
+        inputs_and_proofs: &[(&Self::VerifierInput, &Self::Proof)],
+        _rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        for (input, proof) in inputs_and_proofs {
+            if !Self::verify_prepared(prepared_verifying_key, input, proof)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Verifies a batch of proofs under the same verifying key, returning `true` if every proof is valid.
+    fn batch_verify<R: Rng + CryptoRng>(
+        verifying_key: &Self::VerifyingKey,
+        inputs_and_proofs: &[(&Self::VerifierInput, &Self::Proof)],
+        rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        let processed_verifying_key = verifying_key.prepare();
+        Self::batch_verify_prepared(&processed_verifying_key, inputs_and_proofs, rng)
+    }
 }
@@ -45,6 +45,8 @@ fn marlin_posw(c: &mut Criterion) {
         b.iter(|| {
             Testnet2::posw()
                 .mine(&block_template, &AtomicBool::new(false), rng)
+                .unwrap()
+                .block_header()
                 .unwrap();
         });
     });
@@ -0,0 +1,299 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountError, Network, PrivateKey, ViewKey};
+
+use chacha20poly1305::{
+    aead::{Aead, NewAead, Payload},
+    ChaCha20Poly1305,
+    Key,
+    Nonce,
+};
+use rand::{CryptoRng, Rng};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+    str::FromStr,
+};
+
+/// The version of the keystore format.
+pub const KEYSTORE_VERSION: u8 = 1;
+
+/// The key derivation function of the keystore format.
+pub const KEYSTORE_KDF: &str = "scrypt";
+/// The authenticated encryption scheme of the keystore format.
+pub const KEYSTORE_CIPHER: &str = "chacha20poly1305";
+
+/// The default scrypt cost parameters, as (log_n, r, p).
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// The maximum scrypt cost parameters accepted from a keystore, as (log_n, r, p).
+/// These bound the memory of the key derivation to 1 GiB, and its time to 128 times the default.
+const MAXIMUM_SCRYPT_LOG_N: u8 = 20;
+const MAXIMUM_SCRYPT_R: u32 = 8;
+const MAXIMUM_SCRYPT_P: u32 = 4;
+
+/// The number of bytes in the scrypt salt.
+const SALT_SIZE_IN_BYTES: usize = 32;
+/// The number of bytes in the ChaCha20-Poly1305 nonce.
+const NONCE_SIZE_IN_BYTES: usize = 12;
+/// The number of bytes in the ChaCha20-Poly1305 key.
+const KEY_SIZE_IN_BYTES: usize = 32;
+
+/// The type of account key stored in a keystore.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeystoreKind {
+    PrivateKey,
+    ViewKey,
+}
+
+/// The key derivation parameters of a keystore.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreKdf {
+    name: String,
+    log_n: u8,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+///
+/// A password-encrypted account key.
+///
+/// The encryption key is derived from the password with scrypt, and the account key
+/// is encrypted with ChaCha20-Poly1305, authenticating all keystore parameters.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    version: u8,
+    network: u16,
+    kind: KeystoreKind,
+    kdf: KeystoreKdf,
+    cipher: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl Keystore {
+    /// Returns a new keystore of the account private key, encrypted under the given password.
+    pub fn from_private_key<N: Network, R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        password: &str,
+        rng: &mut R,
+    ) -> Result<Self, AccountError> {
+        Self::encrypt::<N, R>(KeystoreKind::PrivateKey, &private_key.to_string(), password, rng)
+    }
+
+    /// Returns a new keystore of the account view key, encrypted under the given password.
+    pub fn from_view_key<N: Network, R: Rng + CryptoRng>(
+        view_key: &ViewKey<N>,
+        password: &str,
+        rng: &mut R,
+    ) -> Result<Self, AccountError> {
+        Self::encrypt::<N, R>(KeystoreKind::ViewKey, &view_key.to_string(), password, rng)
+    }
+
+    /// Returns the account private key in the keystore, decrypted with the given password.
+    pub fn to_private_key<N: Network>(&self, password: &str) -> Result<PrivateKey<N>, AccountError> {
+        match self.kind {
+            KeystoreKind::PrivateKey => PrivateKey::from_str(&self.decrypt::<N>(password)?),
+            KeystoreKind::ViewKey => Err(AccountError::InvalidKeystore(
+                "keystore does not contain a private key".into(),
+            )),
+        }
+    }
+
+    /// Returns the account view key in the keystore, decrypted with the given password.
+    /// If the keystore contains a private key, its view key is returned.
+    /// To retrieve both keys from a private key keystore, decrypt its private key once, and derive the view key.
+    pub fn to_view_key<N: Network>(&self, password: &str) -> Result<ViewKey<N>, AccountError> {
+        match self.kind {
+            KeystoreKind::PrivateKey => Ok(ViewKey::from_private_key(&self.to_private_key::<N>(password)?)),
+            KeystoreKind::ViewKey => ViewKey::from_str(&self.decrypt::<N>(password)?),
+        }
+    }
+
+    /// Returns the network ID of the keystore.
+    pub fn network(&self) -> u16 {
+        self.network
+    }
+
+    /// Returns the type of account key in the keystore.
+    pub fn kind(&self) -> KeystoreKind {
+        self.kind
+    }
+
+    /// Loads the keystore from the given path.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, AccountError> {
+        let keystore: Self = serde_json::from_slice(&fs::read(path)?)
+            .map_err(|error| AccountError::InvalidKeystore(error.to_string()))?;
+        keystore.validate()?;
+        Ok(keystore)
+    }
+
+    ///
+    /// Saves the keystore to a new file at the given path, readable only by the owner where supported.
+    /// Returns an error if a file already exists at the given path.
+    ///
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), AccountError> {
+        let keystore = serde_json::to_string_pretty(self).map_err(|error| AccountError::Message(error.to_string()))?;
+
+        // Create the file with owner-only permissions, so the keystore is never readable by others.
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options.open(path.as_ref())?;
+        file.write_all(keystore.as_bytes())?;
+        Ok(())
+    }
+
+    /// Ensures the keystore is of a supported version and format, with bounded key derivation costs.
+    fn validate(&self) -> Result<(), AccountError> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(AccountError::InvalidKeystore(format!(
+                "unsupported version {}",
+                self.version
+            )));
+        }
+        if self.kdf.name != KEYSTORE_KDF || self.cipher != KEYSTORE_CIPHER {
+            return Err(AccountError::InvalidKeystore(format!(
+                "unsupported scheme {} with {}",
+                self.kdf.name, self.cipher
+            )));
+        }
+        if self.kdf.log_n > MAXIMUM_SCRYPT_LOG_N || self.kdf.r > MAXIMUM_SCRYPT_R || self.kdf.p > MAXIMUM_SCRYPT_P {
+            return Err(AccountError::InvalidKeystore(format!(
+                "scrypt parameters (log_n = {}, r = {}, p = {}) exceed the maximum of ({}, {}, {})",
+                self.kdf.log_n, self.kdf.r, self.kdf.p, MAXIMUM_SCRYPT_LOG_N, MAXIMUM_SCRYPT_R, MAXIMUM_SCRYPT_P
+            )));
+        }
+        Ok(())
+    }
+
+    /// Encrypts the given account key string under the given password.
+    fn encrypt<N: Network, R: Rng + CryptoRng>(
+        kind: KeystoreKind,
+        plaintext: &str,
+        password: &str,
+        rng: &mut R,
+    ) -> Result<Self, AccountError> {
+        let salt: [u8; SALT_SIZE_IN_BYTES] = rng.gen();
+        let nonce: [u8; NONCE_SIZE_IN_BYTES] = rng.gen();
+
+        let mut keystore = Self {
+            version: KEYSTORE_VERSION,
+            network: N::NETWORK_ID,
+            kind,
+            kdf: KeystoreKdf {
+                name: KEYSTORE_KDF.to_string(),
+                log_n: SCRYPT_LOG_N,
+                r: SCRYPT_R,
+                p: SCRYPT_P,
+                salt: hex::encode(salt),
+            },
+            cipher: KEYSTORE_CIPHER.to_string(),
+            nonce: hex::encode(nonce),
+            ciphertext: String::new(),
+        };
+
+        let payload = Payload {
+            msg: plaintext.as_bytes(),
+            aad: &keystore.associated_data()?,
+        };
+        let ciphertext = keystore
+            .cipher(password)?
+            .encrypt(Nonce::from_slice(&nonce), payload)
+            .map_err(|_| AccountError::Message("Failed to encrypt the keystore".into()))?;
+
+        keystore.ciphertext = hex::encode(ciphertext);
+        Ok(keystore)
+    }
+
+    /// Decrypts the account key string with the given password.
+    fn decrypt<N: Network>(&self, password: &str) -> Result<String, AccountError> {
+        // Ensure the keystore is supported, before deriving a key with its parameters.
+        self.validate()?;
+
+        // Ensure the keystore is for the given network.
+        if self.network != N::NETWORK_ID {
+            return Err(AccountError::InvalidKeystore(format!(
+                "expected network {}, found network {}",
+                N::NETWORK_ID,
+                self.network
+            )));
+        }
+
+        let nonce = Self::decode(&self.nonce, NONCE_SIZE_IN_BYTES)?;
+        let ciphertext =
+            hex::decode(&self.ciphertext).map_err(|error| AccountError::InvalidKeystore(error.to_string()))?;
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: &self.associated_data()?,
+        };
+        let plaintext = self
+            .cipher(password)?
+            .decrypt(Nonce::from_slice(&nonce), payload)
+            .map_err(|_| AccountError::InvalidPassword)?;
+
+        String::from_utf8(plaintext).map_err(|error| AccountError::InvalidKeystore(error.to_string()))
+    }
+
+    /// Returns the cipher, keyed with the scrypt derivation of the given password.
+    fn cipher(&self, password: &str) -> Result<ChaCha20Poly1305, AccountError> {
+        let salt = Self::decode(&self.kdf.salt, SALT_SIZE_IN_BYTES)?;
+        let params = scrypt::Params::new(self.kdf.log_n, self.kdf.r, self.kdf.p)
+            .map_err(|error| AccountError::InvalidKeystore(error.to_string()))?;
+
+        let mut key = [0u8; KEY_SIZE_IN_BYTES];
+        scrypt::scrypt(password.as_bytes(), &salt, &params, &mut key)
+            .map_err(|error| AccountError::Message(error.to_string()))?;
+
+        Ok(ChaCha20Poly1305::new(Key::from_slice(&key)))
+    }
+
+    /// Returns the keystore parameters, which are authenticated by the cipher.
+    fn associated_data(&self) -> Result<Vec<u8>, AccountError> {
+        serde_json::to_vec(&(
+            self.version,
+            self.network,
+            self.kind,
+            &self.kdf,
+            &self.cipher,
+            &self.nonce,
+        ))
+        .map_err(|error| AccountError::Message(error.to_string()))
+    }
+
+    /// Decodes the given hex string, ensuring it has the expected number of bytes.
+    fn decode(input: &str, size_in_bytes: usize) -> Result<Vec<u8>, AccountError> {
+        let bytes = hex::decode(input).map_err(|error| AccountError::InvalidKeystore(error.to_string()))?;
+        match bytes.len() == size_in_bytes {
+            true => Ok(bytes),
+            false => Err(AccountError::InvalidByteLength(bytes.len())),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountError, Network, PrivateKey, ACCOUNT_SEED_CHILD_DOMAIN, ACCOUNT_SEED_MASTER_DOMAIN};
+use snarkvm_algorithms::traits::PRF;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use bip39::Mnemonic;
+use rand::{CryptoRng, Rng};
+use std::fmt;
+
+/// The number of bytes of entropy in a master seed, encoded as a 24-word mnemonic.
+pub const MASTER_SEED_ENTROPY_SIZE_IN_BYTES: usize = 32;
+
+///
+/// A master seed for hierarchical account derivation.
+///
+/// The master account seed is derived from the entropy with `N::AccountSeedPRF`,
+/// and each child account seed is derived from its parent seed and an index.
+/// A path of indices thereby identifies a unique account, which can be restored
+/// from the mnemonic phrase of the master seed.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct MasterSeed<N: Network> {
+    entropy: [u8; MASTER_SEED_ENTROPY_SIZE_IN_BYTES],
+    seed: N::AccountSeed,
+}
+
+impl<N: Network> MasterSeed<N> {
+    /// Creates a new master seed.
+    pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        // Sample random entropy.
+        Self::from_entropy(rng.gen())
+    }
+
+    /// Returns the master seed from the given entropy.
+    pub fn from_entropy(entropy: [u8; MASTER_SEED_ENTROPY_SIZE_IN_BYTES]) -> Self {
+        // Construct the master domain separator.
+        let master_domain = N::ProgramScalarField::from_bytes_le_mod_order(ACCOUNT_SEED_MASTER_DOMAIN.as_bytes());
+
+        // Derive the master account seed as PRF(entropy, master_domain).
+        let entropy_seed = Self::to_account_seed(N::ProgramScalarField::from_bytes_le_mod_order(&entropy));
+        let seed = N::AccountSeedPRF::evaluate(&entropy_seed, &vec![master_domain])
+            .expect("Failed to derive the master seed for PRF(entropy, master_domain)");
+
+        Self {
+            entropy,
+            seed: Self::to_account_seed(seed),
+        }
+    }
+
+    /// Returns the master seed from the given mnemonic phrase.
+    pub fn from_mnemonic(phrase: &str) -> Result<Self, AccountError> {
+        let mnemonic = Mnemonic::parse_normalized(&phrase.trim().to_lowercase())?;
+
+        let (entropy, length) = mnemonic.to_entropy_array();
+        if length != MASTER_SEED_ENTROPY_SIZE_IN_BYTES {
+            return Err(AccountError::InvalidByteLength(length));
+        }
+
+        let mut master_entropy = [0u8; MASTER_SEED_ENTROPY_SIZE_IN_BYTES];
+        master_entropy.copy_from_slice(&entropy[..length]);
+        Ok(Self::from_entropy(master_entropy))
+    }
+
+    /// Returns the mnemonic phrase of the master seed.
+    pub fn to_mnemonic(&self) -> String {
+        Mnemonic::from_entropy(&self.entropy)
+            .expect("Failed to encode the master seed as a mnemonic")
+            .to_string()
+    }
+
+    /// Returns the account seed at the given path of child indices.
+    /// The empty path returns the master account seed.
+    pub fn derive_seed(&self, path: &[u32]) -> N::AccountSeed {
+        // Construct the child domain separator.
+        let child_domain = N::ProgramScalarField::from_bytes_le_mod_order(ACCOUNT_SEED_CHILD_DOMAIN.as_bytes());
+
+        // Derive each child account seed as PRF(parent_seed, child_domain || index).
+        path.iter().fold(self.seed.clone(), |parent_seed, index| {
+            let input = vec![child_domain, N::ProgramScalarField::from(*index as u64)];
+            let child_seed = N::AccountSeedPRF::evaluate(&parent_seed, &input)
+                .expect("Failed to derive the child seed for PRF(parent_seed, child_domain || index)");
+            Self::to_account_seed(child_seed)
+        })
+    }
+
+    /// Returns the account private key at the given path of child indices.
+    pub fn derive_private_key(&self, path: &[u32]) -> PrivateKey<N> {
+        PrivateKey::from(&self.derive_seed(path))
+    }
+
+    /// Returns the given scalar field element as an account seed.
+    fn to_account_seed(element: N::ProgramScalarField) -> N::AccountSeed {
+        let bytes = element
+            .to_bytes_le()
+            .expect("Failed to serialize a scalar field element");
+        FromBytes::read_le(&bytes[..]).expect("Failed to deserialize a scalar field element into an account seed")
+    }
+}
+
+impl<N: Network> fmt::Debug for MasterSeed<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MasterSeed {{ seed: {:?} }}", self.seed)
+    }
+}
@@ -28,6 +28,12 @@ pub use address::*;
 pub mod compute_key;
 pub use compute_key::*;
 
+pub mod keystore;
+pub use keystore::*;
+
+pub mod master_seed;
+pub use master_seed::*;
+
 pub mod private_key;
 pub use private_key::*;
 
@@ -290,8 +290,21 @@ mod testnet1 {
 
 #[cfg(test)]
 mod testnet2 {
-    use crate::{testnet2::Testnet2, Account, AccountScheme, Address, Network, PrivateKey, ViewKey};
-    use snarkvm_algorithms::prelude::*;
+    use crate::{
+        testnet1::Testnet1,
+        testnet2::Testnet2,
+        Account,
+        AccountError,
+        AccountScheme,
+        Address,
+        Keystore,
+        KeystoreKind,
+        MasterSeed,
+        Network,
+        PrivateKey,
+        ViewKey,
+    };
+    use snarkvm_algorithms::{prelude::*, signature::KeyGenerationParticipant};
     use snarkvm_curves::AffineCurve;
     use snarkvm_utilities::{FromBytes, ToBytes};
 
@@ -561,4 +574,180 @@ mod testnet2 {
             );
         }
     }
+
+    #[test]
+    fn test_master_seed_mnemonic() {
+        for _ in 0..25 {
+            let master_seed = MasterSeed::<Testnet2>::new(&mut thread_rng());
+
+            // Check the mnemonic restores the same master seed.
+            let mnemonic = master_seed.to_mnemonic();
+            assert_eq!(24, mnemonic.split_whitespace().count());
+            let candidate = MasterSeed::<Testnet2>::from_mnemonic(&mnemonic).unwrap();
+            assert_eq!(master_seed, candidate);
+            assert_eq!(
+                master_seed.derive_private_key(&[0, 1]),
+                candidate.derive_private_key(&[0, 1])
+            );
+        }
+
+        // Check that a mnemonic with too little entropy is rejected.
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert!(MasterSeed::<Testnet2>::from_mnemonic(mnemonic).is_err());
+
+        // Check that a mnemonic with an invalid checksum is rejected.
+        let mnemonic = MasterSeed::<Testnet2>::from_entropy([0u8; 32])
+            .to_mnemonic()
+            .replace("art", "zoo");
+        assert!(MasterSeed::<Testnet2>::from_mnemonic(&mnemonic).is_err());
+    }
+
+    #[test]
+    fn test_master_seed_derivation() {
+        let master_seed = MasterSeed::<Testnet2>::from_entropy(thread_rng().gen());
+
+        // Check the derivation is deterministic.
+        let private_key = master_seed.derive_private_key(&[0, 7]);
+        assert!(private_key.is_valid());
+        assert_eq!(private_key, master_seed.derive_private_key(&[0, 7]));
+
+        // Check the derivation depends on each index of the path.
+        assert_ne!(private_key, master_seed.derive_private_key(&[0]));
+        assert_ne!(private_key, master_seed.derive_private_key(&[0, 8]));
+        assert_ne!(private_key, master_seed.derive_private_key(&[7, 0]));
+        assert_ne!(master_seed.derive_seed(&[]), master_seed.derive_seed(&[0]));
+
+        // Check the derivation depends on the entropy.
+        let other_seed = MasterSeed::<Testnet2>::from_entropy(thread_rng().gen());
+        assert_ne!(private_key, other_seed.derive_private_key(&[0, 7]));
+    }
+
+    #[test]
+    fn test_keystore_private_key() {
+        let rng = &mut thread_rng();
+        let private_key = PrivateKey::<Testnet2>::new(rng);
+
+        let keystore = Keystore::from_private_key(&private_key, "password", rng).unwrap();
+        assert_eq!(KeystoreKind::PrivateKey, keystore.kind());
+        assert_eq!(Testnet2::NETWORK_ID, keystore.network());
+
+        // Check the keystore decrypts with the correct password.
+        assert_eq!(private_key, keystore.to_private_key::<Testnet2>("password").unwrap());
+        assert_eq!(
+            ViewKey::from_private_key(&private_key),
+            keystore.to_view_key::<Testnet2>("password").unwrap()
+        );
+
+        // Check the keystore rejects an incorrect password, or another network.
+        assert!(matches!(
+            keystore.to_private_key::<Testnet2>("passw0rd"),
+            Err(AccountError::InvalidPassword)
+        ));
+        assert!(keystore.to_private_key::<Testnet1>("password").is_err());
+    }
+
+    #[test]
+    fn test_keystore_view_key() {
+        let rng = &mut thread_rng();
+        let view_key = ViewKey::from_private_key(&PrivateKey::<Testnet2>::new(rng));
+
+        let keystore = Keystore::from_view_key(&view_key, "password", rng).unwrap();
+        assert_eq!(KeystoreKind::ViewKey, keystore.kind());
+        assert_eq!(view_key, keystore.to_view_key::<Testnet2>("password").unwrap());
+        assert!(keystore.to_private_key::<Testnet2>("password").is_err());
+    }
+
+    #[test]
+    fn test_keystore_save_and_load() {
+        let rng = &mut thread_rng();
+        let private_key = PrivateKey::<Testnet2>::new(rng);
+        let keystore = Keystore::from_private_key(&private_key, "password", rng).unwrap();
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("account.keystore");
+        keystore.save(&path).unwrap();
+
+        let candidate = Keystore::load(&path).unwrap();
+        assert_eq!(keystore, candidate);
+        assert_eq!(private_key, candidate.to_private_key::<Testnet2>("password").unwrap());
+
+        // Check the keystore is readable only by the owner, and is never overwritten.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(0o600, std::fs::metadata(&path).unwrap().permissions().mode() & 0o777);
+        }
+        assert!(keystore.save(&path).is_err());
+
+        // Check the keystore parameters are authenticated, by tampering with a parameter used only as associated data.
+        let tampered = std::fs::read_to_string(&path).unwrap().replace("\"private_key\"", "\"view_key\"");
+        std::fs::write(&path, tampered).unwrap();
+        assert!(matches!(
+            Keystore::load(&path).unwrap().to_view_key::<Testnet2>("password"),
+            Err(AccountError::InvalidPassword)
+        ));
+
+        // Check excessive scrypt parameters are rejected before deriving a key.
+        let expensive = serde_json::to_string(&keystore).unwrap().replace("\"log_n\":15", "\"log_n\":30");
+        std::fs::write(&path, expensive).unwrap();
+        assert!(Keystore::load(&path).is_err());
+
+        // Check an unsupported version is rejected.
+        let unsupported = serde_json::to_string(&keystore).unwrap().replace("\"version\":1", "\"version\":2");
+        std::fs::write(&path, unsupported).unwrap();
+        assert!(Keystore::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_threshold_signature() {
+        let rng = &mut thread_rng();
+        let signature_scheme = Testnet2::account_signature_scheme();
+
+        // Run a 2-of-3 distributed key generation for the account.
+        let (participants, commitments): (Vec<_>, Vec<_>) = (1..=3)
+            .map(|index| KeyGenerationParticipant::new(signature_scheme, index, 2, 3, rng).unwrap())
+            .unzip();
+        let key_shares: Vec<_> = participants
+            .iter()
+            .map(|participant| {
+                let shares: Vec<_> = participants
+                    .iter()
+                    .map(|sender| sender.share(participant.index()).unwrap())
+                    .collect();
+                participant.finalize(signature_scheme, &commitments, &shares).unwrap()
+            })
+            .collect();
+        let public_key = key_shares[0].public_key();
+        let address =
+            Address::<Testnet2>::read_le(&public_key.public_key().to_x_coordinate().to_bytes_le().unwrap()[..])
+                .unwrap();
+
+        // Sign with the first and third participants.
+        let message: Vec<u8> = (0..32).map(|_| rand::random::<u8>()).collect();
+        let signers = [&key_shares[0], &key_shares[2]];
+        let (nonces, signing_commitments): (Vec<_>, Vec<_>) = signers
+            .iter()
+            .map(|signer| signer.commit(signature_scheme, rng))
+            .unzip();
+        let partial_signatures: Vec<_> = signers
+            .iter()
+            .zip(nonces)
+            .map(|(signer, nonces)| {
+                signer
+                    .sign(signature_scheme, &message, nonces, &signing_commitments)
+                    .unwrap()
+            })
+            .collect();
+        let signature: <Testnet2 as Network>::AccountSignature = public_key
+            .aggregate(signature_scheme, &message, &signing_commitments, &partial_signatures)
+            .unwrap()
+            .into();
+
+        // Ensure the aggregate signature is an ordinary account signature.
+        assert!(address.verify_signature(&message, &signature).unwrap());
+        assert_eq!(
+            signature,
+            <Testnet2 as Network>::AccountSignature::read_le(&signature.to_bytes_le().unwrap()[..]).unwrap()
+        );
+    }
 }
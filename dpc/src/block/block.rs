@@ -20,6 +20,7 @@ use crate::{
     BlockError,
     BlockHeader,
     BlockTemplate,
+    BlockValidationError,
     LedgerProof,
     LedgerTree,
     LedgerTreeScheme,
@@ -27,8 +28,7 @@ use crate::{
     Transaction,
     Transactions,
 };
-use snarkvm_algorithms::CRH;
-use snarkvm_utilities::{to_bytes_le, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use anyhow::{anyhow, Result};
 use rand::{CryptoRng, Rng};
@@ -114,67 +114,83 @@ impl<N: Network> Block<N> {
     ) -> Result<Self, BlockError> {
         assert!(!(*transactions).is_empty(), "Cannot create block with no transactions");
 
+        let block = Self::from_unchecked(previous_block_hash, header, transactions)?;
+
+        // Ensure the block is valid.
+        block.validate()?;
+        Ok(block)
+    }
+
+    ///
+    /// Initializes a new block from a given previous hash, header, and transactions list,
+    /// without checking the block is well-formed. This is only used for blocks read from
+    /// trusted storage, which were checked before they were stored.
+    ///
+    pub(crate) fn from_unchecked(
+        previous_block_hash: N::BlockHash,
+        header: BlockHeader<N>,
+        transactions: Transactions<N>,
+    ) -> Result<Self, BlockError> {
         // Compute the block hash.
-        let block_hash = N::block_hash_crh()
-            .hash(&to_bytes_le![previous_block_hash, header.to_header_root()?]?)?
-            .into();
+        let block_hash = header.to_block_hash(previous_block_hash)?;
 
         // Construct the block.
-        let block = Self {
+        Ok(Self {
             block_hash,
             previous_block_hash,
             header,
             transactions,
-        };
-
-        // Ensure the block is valid.
-        match block.is_valid() {
-            true => Ok(block),
-            false => Err(anyhow!("Failed to initialize a block from given inputs").into()),
-        }
+        })
     }
 
     /// Returns `true` if the block is well-formed.
     pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    ///
+    /// Ensures the block is well-formed, returning the consensus rule it violates otherwise.
+    /// This check is independent of the state of the ledger.
+    ///
+    pub fn validate(&self) -> Result<(), BlockValidationError<N>> {
         // Ensure the previous block hash is well-formed.
         let genesis_previous_block_hash = LedgerProof::<N>::default().block_hash();
-        if self.height() == 0u32 {
-            if self.previous_block_hash != genesis_previous_block_hash {
-                eprintln!("Genesis block must have the default ledger proof block hash");
-                return false;
+        let is_valid_previous_block_hash = match self.height() == 0u32 {
+            // Ensure the genesis block has the default ledger proof block hash.
+            true => self.previous_block_hash == genesis_previous_block_hash,
+            // Ensure the block has a non-empty, non-genesis previous block hash.
+            false => {
+                self.previous_block_hash != genesis_previous_block_hash
+                    && self.previous_block_hash != Default::default()
             }
-        } else if self.previous_block_hash == genesis_previous_block_hash {
-            eprintln!("Block cannot have genesis previous block hash");
-            return false;
-        } else if self.previous_block_hash == Default::default() {
-            eprintln!("Block must have a non-empty previous block hash");
-            return false;
+        };
+        if !is_valid_previous_block_hash {
+            return Err(BlockValidationError::InvalidPreviousBlockHash(
+                self.height(),
+                self.previous_block_hash,
+            ));
         }
 
         // Ensure the header are valid.
-        if !self.header.is_valid() {
-            eprintln!("Invalid block header");
-            return false;
-        }
+        self.header.validate()?;
 
-        // Ensure the transactions are valid.
-        if !self.transactions.is_valid() {
-            eprintln!("Invalid block transactions");
-            return false;
-        }
+        // Ensure the transactions are valid, allowing a genesis block coinbase only at height 0.
+        self.transactions.validate_for(self.height() == 0u32)?;
 
         // Ensure the transactions root matches the computed root from the transactions list.
         if self.header.transactions_root() != self.transactions.transactions_root() {
-            eprintln!("Invalid block transactions does not match transactions root in header");
-            return false;
+            return Err(BlockValidationError::TransactionsRootMismatch {
+                expected: self.header.transactions_root(),
+                found: self.transactions.transactions_root(),
+            });
         }
 
         // Retrieve the coinbase transaction.
         let coinbase_transaction = match self.to_coinbase_transaction() {
             Ok(coinbase_transaction) => coinbase_transaction,
-            Err(error) => {
-                eprintln!("{}", error);
-                return false;
+            Err(_) => {
+                let num_coinbase = self.transactions.iter().filter(|t| t.value_balance().is_negative()).count();
+                return Err(BlockValidationError::InvalidNumberOfCoinbaseTransactions(num_coinbase));
             }
         };
 
@@ -182,21 +198,22 @@ impl<N: Network> Block<N> {
         let coinbase_reward = AleoAmount::ZERO.sub(coinbase_transaction.value_balance()); // Make it a positive number.
         let block_reward = Self::block_reward(self.height());
         if coinbase_reward < block_reward {
-            eprintln!("Coinbase reward must be >= {}, found {}", block_reward, coinbase_reward);
-            return false;
+            return Err(BlockValidationError::InsufficientCoinbaseReward {
+                expected: block_reward,
+                found: coinbase_reward,
+            });
         }
 
         // Ensure the coinbase reward less transaction fees is less than or equal to the block reward.
         let candidate_block_reward = AleoAmount::ZERO.sub(self.transactions.net_value_balance()); // Make it a positive number.
         if candidate_block_reward > block_reward {
-            eprintln!(
-                "Block reward must be <= {}, found {}",
-                block_reward, candidate_block_reward
-            );
-            return false;
+            return Err(BlockValidationError::ExcessiveBlockReward {
+                expected: block_reward,
+                found: candidate_block_reward,
+            });
         }
 
-        true
+        Ok(())
     }
 
     /// Returns `true` if the block is a genesis block.
@@ -311,8 +328,8 @@ impl<N: Network> FromBytes for Block<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let block_hash: N::BlockHash = FromBytes::read_le(&mut reader)?;
         let previous_block_hash = FromBytes::read_le(&mut reader)?;
-        let header = FromBytes::read_le(&mut reader)?;
-        let transactions = FromBytes::read_le(&mut reader)?;
+        let header: BlockHeader<N> = FromBytes::read_le(&mut reader)?;
+        let transactions = Transactions::read_le_in_block(&mut reader, header.height())?;
         let block = Self::from(previous_block_hash, header, transactions)?;
 
         match block_hash == block.hash() {
@@ -328,7 +345,8 @@ impl<N: Network> ToBytes for Block<N> {
         self.block_hash.write_le(&mut writer)?;
         self.previous_block_hash.write_le(&mut writer)?;
         self.header.write_le(&mut writer)?;
-        self.transactions.write_le(&mut writer)
+        // The transactions were checked for the block height when the block was initialized.
+        self.transactions.write_le_unchecked(&mut writer)
     }
 }
 
@@ -374,11 +392,15 @@ impl<'de, N: Network> Deserialize<'de> for Block<N> {
                 let block_hash: N::BlockHash =
                     serde_json::from_value(block["block_hash"].clone()).map_err(de::Error::custom)?;
 
-                // Recover the block.
+                // Recover the block, checking the transactions for the block height.
+                let header: BlockHeader<N> =
+                    serde_json::from_value(block["header"].clone()).map_err(de::Error::custom)?;
+                let transactions = Transactions::from_json_in_block(&block["transactions"], header.height())
+                    .map_err(de::Error::custom)?;
                 let block = Self::from(
                     serde_json::from_value(block["previous_block_hash"].clone()).map_err(de::Error::custom)?,
-                    serde_json::from_value(block["header"].clone()).map_err(de::Error::custom)?,
-                    serde_json::from_value(block["transactions"].clone()).map_err(de::Error::custom)?,
+                    header,
+                    transactions,
                 )
                 .map_err(de::Error::custom)?;
 
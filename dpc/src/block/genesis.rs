@@ -0,0 +1,442 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_algorithms::crh::sha256::sha256;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use core::sync::atomic::AtomicBool;
+use once_cell::sync::OnceCell;
+use rand::{CryptoRng, Rng};
+use serde_json::json;
+use std::{fs, path::Path};
+
+///
+/// The genesis block of a network. It is initialized with the default genesis block of the network
+/// when first used, unless a genesis block is set before then.
+///
+pub struct GenesisBlockCell<N: Network>(OnceCell<Block<N>>);
+
+impl<N: Network> GenesisBlockCell<N> {
+    /// Initializes a new, unset instance of `GenesisBlockCell`.
+    pub fn new() -> Self {
+        Self(OnceCell::new())
+    }
+
+    /// Returns the genesis block, initializing it with the given default genesis block if it is unset.
+    pub fn get_or_init<F: FnOnce() -> Block<N>>(&self, default: F) -> &Block<N> {
+        self.0.get_or_init(default)
+    }
+
+    /// Sets the genesis block, if the network has a configurable genesis block,
+    /// and the genesis block has not been set or used yet.
+    pub fn set(&self, block: Block<N>) -> Result<()> {
+        if !N::ALEO_CONFIGURABLE_GENESIS {
+            return Err(anyhow!("The {} genesis block is not configurable", N::NETWORK_NAME));
+        }
+        if !block.is_genesis() {
+            return Err(anyhow!("Block {} is not a genesis block", block.hash()));
+        }
+        self.0
+            .set(block)
+            .map_err(|_| anyhow!("The {} genesis block is already set", N::NETWORK_NAME))
+    }
+}
+
+impl<N: Network> Default for GenesisBlockCell<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///
+/// A builder for genesis blocks, which mints the starting supply to a list of premine allocations.
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct GenesisBuilder<N: Network> {
+    /// The recipients and amounts of the starting supply.
+    allocations: Vec<(Address<N>, AleoAmount)>,
+    /// The timestamp of the genesis block.
+    timestamp: i64,
+    /// The difficulty target of the genesis block.
+    difficulty_target: u64,
+    /// The visibility of the coinbase records.
+    is_public: bool,
+    /// A list of errors accumulated from calling the builder.
+    errors: Vec<String>,
+}
+
+impl<N: Network> GenesisBuilder<N> {
+    ///
+    /// Initializes a new instance of `GenesisBuilder`.
+    ///
+    pub fn new() -> Self {
+        Self {
+            allocations: Vec::new(),
+            timestamp: 0i64,
+            difficulty_target: u64::MAX,
+            is_public: true,
+            errors: Vec::new(),
+        }
+    }
+
+    ///
+    /// Returns the maximum number of premine allocations. The coinbase transaction supports
+    /// `N::NUM_OUTPUT_RECORDS` allocations in each of its transitions, and only mints over
+    /// multiple transitions on networks with a configurable genesis block.
+    ///
+    pub fn maximum_allocations() -> usize {
+        match N::ALEO_CONFIGURABLE_GENESIS {
+            true => N::NUM_OUTPUT_RECORDS * N::NUM_TRANSITIONS as usize,
+            false => N::NUM_OUTPUT_RECORDS,
+        }
+    }
+
+    ///
+    /// Adds the given recipient and amount as a premine allocation into the builder.
+    ///
+    pub fn add_allocation(mut self, recipient: Address<N>, amount: AleoAmount) -> Self {
+        if amount <= AleoAmount::ZERO {
+            self.errors.push("Builder requires allocations to be positive".into());
+        } else if self.allocations.len() >= Self::maximum_allocations() {
+            self.errors.push("Builder exceeded maximum allocations".into());
+        } else {
+            self.allocations.push((recipient, amount));
+        }
+        self
+    }
+
+    ///
+    /// Adds the given premine allocations into the builder.
+    ///
+    pub fn add_allocations(mut self, allocations: Vec<(Address<N>, AleoAmount)>) -> Self {
+        for (recipient, amount) in allocations {
+            self = self.add_allocation(recipient, amount);
+        }
+        self
+    }
+
+    ///
+    /// Sets the timestamp of the genesis block.
+    ///
+    pub fn set_timestamp(mut self, timestamp: i64) -> Self {
+        match timestamp >= 0 {
+            true => self.timestamp = timestamp,
+            false => self.errors.push("Builder requires a non-negative timestamp".into()),
+        }
+        self
+    }
+
+    ///
+    /// Sets the initial difficulty target of the genesis block.
+    ///
+    pub fn set_difficulty_target(mut self, difficulty_target: u64) -> Self {
+        match difficulty_target > 0 {
+            true => self.difficulty_target = difficulty_target,
+            false => self.errors.push("Builder requires a nonzero difficulty target".into()),
+        }
+        self
+    }
+
+    ///
+    /// Sets the visibility of the coinbase records.
+    ///
+    pub fn set_public(mut self, is_public: bool) -> Self {
+        self.is_public = is_public;
+        self
+    }
+
+    ///
+    /// Finalizes the builder and returns a new genesis block.
+    ///
+    pub fn build<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Block<N>> {
+        // Ensure there are no errors in the build process yet.
+        if !self.errors.is_empty() {
+            for error in &self.errors {
+                eprintln!("{}", error);
+            }
+            return Err(anyhow!("Genesis builder encountered build errors: {:?}", self.errors));
+        }
+
+        // Ensure the timestamp and difficulty target are the defaults, if the network requires them.
+        if !N::ALEO_CONFIGURABLE_GENESIS && (self.timestamp != 0i64 || self.difficulty_target != u64::MAX) {
+            return Err(anyhow!(
+                "The {} genesis block requires a timestamp of 0 and a difficulty target of u64::MAX",
+                N::NETWORK_NAME
+            ));
+        }
+
+        // Ensure the allocations mint exactly the starting supply.
+        let mut total_amount = 0i64;
+        for (_, amount) in &self.allocations {
+            total_amount = total_amount
+                .checked_add(amount.as_i64())
+                .ok_or(VMError::BalanceOverflow)?;
+        }
+        let total_amount = AleoAmount::from_i64(total_amount);
+        let starting_supply = Block::<N>::block_reward(0);
+        if total_amount != starting_supply {
+            return Err(anyhow!(
+                "Genesis allocations total {}, but must equal the starting supply of {}",
+                total_amount,
+                starting_supply
+            ));
+        }
+
+        // Compute the coinbase transaction, with one coinbase transition for every `N::NUM_OUTPUT_RECORDS` allocations,
+        // and one output per allocation.
+        let mut vm = VirtualMachine::<N>::new(LedgerTree::<N>::new()?.root())?;
+        let mut coinbase_records = Vec::with_capacity(self.allocations.len());
+        for allocations in self.allocations.chunks(N::NUM_OUTPUT_RECORDS) {
+            let amount = allocations
+                .iter()
+                .fold(AleoAmount::ZERO, |total, (_, amount)| total.add(*amount));
+            let request = Request::new_coinbase(allocations[0].0, amount, self.is_public, rng)?;
+            let response = allocations
+                .iter()
+                .try_fold(
+                    ResponseBuilder::new().add_request(request.clone()),
+                    |builder, (recipient, amount)| {
+                        Ok::<_, anyhow::Error>(builder.add_output(Output::new(
+                            *recipient,
+                            *amount,
+                            Default::default(),
+                            None,
+                        )?))
+                    },
+                )?
+                .build(rng)?;
+            let (next_vm, response) = vm.execute_response(&request, response, rng)?;
+            vm = next_vm;
+            coinbase_records.extend(response.records().iter().cloned());
+        }
+        let transactions = Transactions::from_transactions(&[vm.finalize_genesis()?], true)?;
+
+        // Construct the block template.
+        let template = BlockTemplate::new(
+            LedgerProof::<N>::default().block_hash(),
+            0u32,
+            self.timestamp,
+            self.difficulty_target,
+            0u128,
+            LedgerTree::<N>::new()?.root(),
+            transactions,
+            coinbase_records[0].clone(),
+        );
+
+        // Construct the genesis block.
+        let block = Block::mine(&template, &AtomicBool::new(false), rng)?;
+
+        // Ensure the block is valid genesis block.
+        match block.is_genesis() {
+            true => Ok(block),
+            false => Err(anyhow!("Failed to initialize a genesis block")),
+        }
+    }
+}
+
+impl<N: Network> Default for GenesisBuilder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> Block<N> {
+    ///
+    /// Writes the genesis block to the given path (for example, `block.genesis`),
+    /// along with a `.metadata` file of its block hash, checksum, and size.
+    ///
+    pub fn write_genesis<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        if !self.is_genesis() {
+            return Err(anyhow!("Block {} is not a genesis block", self.hash()));
+        }
+
+        let bytes = self.to_bytes_le()?;
+        let metadata = json!({
+            "block_hash": self.hash().to_string(),
+            "genesis_checksum": hex::encode(sha256(&bytes)),
+            "genesis_size": bytes.len(),
+        });
+
+        fs::write(path.as_ref(), &bytes)?;
+        fs::write(
+            path.as_ref().with_extension("metadata"),
+            serde_json::to_vec_pretty(&metadata)?,
+        )?;
+        Ok(())
+    }
+
+    ///
+    /// Reads a genesis block from the given path, and ensures it matches the checksum and size
+    /// in its `.metadata` file, and is a valid genesis block.
+    ///
+    pub fn read_genesis<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let metadata_path = path.with_extension("metadata");
+
+        let bytes = fs::read(path).map_err(|error| anyhow!("Failed to read {:?}: {}", path, error))?;
+        let metadata: serde_json::Value = serde_json::from_slice(
+            &fs::read(&metadata_path).map_err(|error| anyhow!("Failed to read {:?}: {}", metadata_path, error))?,
+        )?;
+
+        // Ensure the size matches.
+        let expected_size = metadata["genesis_size"].as_u64().unwrap_or_default();
+        if expected_size != bytes.len() as u64 {
+            return Err(anyhow!(
+                "Expected a genesis block of size {}, found size {}",
+                expected_size,
+                bytes.len()
+            ));
+        }
+
+        // Ensure the checksum matches.
+        let expected_checksum = metadata["genesis_checksum"].as_str().unwrap_or_default();
+        let candidate_checksum = hex::encode(sha256(&bytes));
+        if expected_checksum != candidate_checksum {
+            return Err(anyhow!(
+                "Expected a genesis block checksum of {}, found checksum of {}",
+                expected_checksum,
+                candidate_checksum
+            ));
+        }
+
+        // Ensure the block is a valid genesis block.
+        let block = Self::read_le(&bytes[..])?;
+        if !block.is_genesis() || !block.is_valid() {
+            return Err(anyhow!("The block in {:?} is not a valid genesis block", path));
+        }
+
+        Ok(block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+    #[cfg(feature = "devnet")]
+    use crate::devnet::Devnet;
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_build_rejects_partial_allocations() {
+        let rng = &mut thread_rng();
+
+        let recipient = Address::from_private_key(&PrivateKey::<Testnet2>::new(rng));
+        let builder = GenesisBuilder::<Testnet2>::new().add_allocation(recipient, AleoAmount::from_i64(1));
+        assert!(builder.build(rng).is_err());
+    }
+
+    #[test]
+    fn test_builder_errors() {
+        let recipient = Address::from_private_key(&PrivateKey::<Testnet2>::new(&mut thread_rng()));
+
+        let builder = GenesisBuilder::<Testnet2>::new()
+            .add_allocation(recipient, AleoAmount::ZERO)
+            .set_timestamp(-1)
+            .set_difficulty_target(0);
+        assert_eq!(builder.errors.len(), 3);
+
+        let maximum_allocations = GenesisBuilder::<Testnet2>::maximum_allocations();
+        assert_eq!(maximum_allocations, Testnet2::NUM_OUTPUT_RECORDS);
+        let builder = (0..=maximum_allocations).fold(GenesisBuilder::<Testnet2>::new(), |builder, _| {
+            builder.add_allocation(recipient, AleoAmount::from_i64(1))
+        });
+        assert_eq!(builder.allocations.len(), maximum_allocations);
+        assert_eq!(builder.errors.len(), 1);
+    }
+
+    #[test]
+    fn test_build_rejects_configured_genesis_on_testnet() {
+        let rng = &mut thread_rng();
+
+        let recipient = Address::from_private_key(&PrivateKey::<Testnet2>::new(rng));
+        let builder = GenesisBuilder::<Testnet2>::new().add_allocation(recipient, Block::<Testnet2>::block_reward(0));
+
+        // Ensure a custom timestamp or difficulty target is rejected on a network with a fixed genesis block.
+        assert!(builder.clone().set_timestamp(1_600_000_000).build(rng).is_err());
+        assert!(builder.set_difficulty_target(u64::MAX / 2).build(rng).is_err());
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_genesis_file_round_trip() {
+        let rng = &mut thread_rng();
+
+        // Split the starting supply between three recipients.
+        let first_recipient = Address::from_private_key(&PrivateKey::<Devnet>::new(rng));
+        let second_recipient = Address::from_private_key(&PrivateKey::<Devnet>::new(rng));
+        let third_recipient = Address::from_private_key(&PrivateKey::<Devnet>::new(rng));
+        let starting_supply = Block::<Devnet>::block_reward(0);
+        let amount = AleoAmount::from_i64(starting_supply.as_i64() / 4);
+
+        let block = GenesisBuilder::<Devnet>::new()
+            .add_allocation(first_recipient, amount)
+            .add_allocation(second_recipient, amount)
+            .add_allocation(third_recipient, starting_supply.sub(amount).sub(amount))
+            .set_timestamp(1_600_000_000)
+            .set_difficulty_target(u64::MAX / 2)
+            .build(rng)
+            .unwrap();
+        assert!(block.is_genesis());
+        assert!(block.is_valid());
+        assert_eq!(block.timestamp(), 1_600_000_000);
+        assert_eq!(block.difficulty_target(), u64::MAX / 2);
+
+        // Ensure the starting supply is minted in one coinbase transaction, with two coinbase transitions.
+        let coinbase_transaction = block.to_coinbase_transaction().unwrap();
+        assert_eq!(coinbase_transaction.transitions().len(), 2);
+        assert_eq!(
+            AleoAmount::ZERO.sub(coinbase_transaction.value_balance()),
+            starting_supply
+        );
+
+        // Ensure the multi-transition coinbase is rejected outside of the genesis block.
+        assert!(!coinbase_transaction.is_valid());
+        assert!(matches!(
+            block.transactions().validate(),
+            Err(BlockValidationError::InvalidTransaction(transaction_id))
+                if transaction_id == coinbase_transaction.transaction_id()
+        ));
+        let template = BlockTemplate::new(
+            block.hash(),
+            1u32,
+            block.timestamp() + 1,
+            u64::MAX,
+            block.cumulative_weight(),
+            LedgerTree::<Devnet>::new().unwrap().root(),
+            block.transactions().clone(),
+            coinbase_transaction.to_records().next().unwrap(),
+        );
+        assert!(Block::mine(&template, &AtomicBool::new(false), rng).is_err());
+
+        // Write the genesis block and read it back.
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("block.genesis");
+        block.write_genesis(&path).unwrap();
+        assert!(directory.path().join("block.metadata").exists());
+        assert_eq!(Block::<Devnet>::read_genesis(&path).unwrap(), block);
+
+        // Ensure a corrupted genesis block is rejected.
+        let mut bytes = fs::read(&path).unwrap();
+        bytes[0] ^= 1;
+        fs::write(&path, &bytes).unwrap();
+        assert!(Block::<Devnet>::read_genesis(&path).is_err());
+    }
+}
@@ -14,17 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{BlockError, BlockTemplate, Network, PoSWCircuit, PoSWProof, PoSWScheme};
-use snarkvm_algorithms::merkle_tree::{MerklePath, MerkleTree};
+use crate::{BlockError, BlockTemplate, BlockValidationError, MiningOutcome, Network, PoSWProof, PoSWScheme, WorkUnit};
+use snarkvm_algorithms::{
+    merkle_tree::{MerklePath, MerkleTree},
+    CRH,
+};
 use snarkvm_utilities::{
     fmt,
     io::{Read, Result as IoResult, Write},
     str::FromStr,
+    to_bytes_le,
     FromBytes,
     FromBytesDeserializer,
     ToBytes,
     ToBytesSerializer,
-    UniformRand,
 };
 
 use anyhow::{anyhow, Result};
@@ -48,14 +51,11 @@ pub struct BlockHeaderMetadata {
 impl BlockHeaderMetadata {
     /// Initializes a new instance of a block header metadata.
     pub fn new<N: Network>(template: &BlockTemplate<N>) -> Self {
-        match template.block_height() == 0 {
-            true => Self::genesis(),
-            false => Self {
-                height: template.block_height(),
-                timestamp: template.block_timestamp(),
-                difficulty_target: template.difficulty_target(),
-                cumulative_weight: template.cumulative_weight(),
-            },
+        Self {
+            height: template.block_height(),
+            timestamp: template.block_timestamp(),
+            difficulty_target: template.difficulty_target(),
+            cumulative_weight: template.cumulative_weight(),
         }
     }
 
@@ -73,6 +73,42 @@ impl BlockHeaderMetadata {
     pub fn size() -> usize {
         size_of::<u32>() + size_of::<i64>() + size_of::<u64>() + size_of::<u128>()
     }
+
+    /// Returns the block height.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the block timestamp.
+    pub fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Returns the difficulty target.
+    pub fn difficulty_target(&self) -> u64 {
+        self.difficulty_target
+    }
+
+    /// Returns the cumulative weight.
+    pub fn cumulative_weight(&self) -> u128 {
+        self.cumulative_weight
+    }
+}
+
+impl FromBytes for BlockHeaderMetadata {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let height = <[u8; 4]>::read_le(&mut reader)?;
+        let timestamp = <[u8; 8]>::read_le(&mut reader)?;
+        let difficulty_target = <[u8; 8]>::read_le(&mut reader)?;
+        let cumulative_weight = <[u8; 16]>::read_le(&mut reader)?;
+
+        Ok(Self {
+            height: u32::from_le_bytes(height),
+            timestamp: i64::from_le_bytes(timestamp),
+            difficulty_target: u64::from_le_bytes(difficulty_target),
+            cumulative_weight: u128::from_le_bytes(cumulative_weight),
+        })
+    }
 }
 
 impl ToBytes for BlockHeaderMetadata {
@@ -131,7 +167,11 @@ impl<N: Network> BlockHeader<N> {
         rng: &mut R,
     ) -> Result<Self> {
         // Mine the block.
-        let block_header = N::posw().mine(block_template, terminator, rng)?;
+        let block_header = match N::posw().mine(block_template, terminator, rng)? {
+            MiningOutcome::Found(block_header) => block_header,
+            MiningOutcome::Cancelled(_) => return Err(anyhow!("Mining the block header was cancelled")),
+            MiningOutcome::Expired(_) => return Err(anyhow!("Failed to mine a block header in the allowed duration")),
+        };
 
         // Ensure the block header is valid.
         match block_header.is_valid() {
@@ -149,51 +189,66 @@ impl<N: Network> BlockHeader<N> {
         terminator: &AtomicBool,
         rng: &mut R,
     ) -> Result<Self> {
-        // Instantiate the circuit.
-        let mut circuit = PoSWCircuit::<N>::new(block_template, UniformRand::rand(rng))?;
-
         // Run one iteration of PoSW.
         // Warning: this operation is unchecked.
-        let proof = N::posw().prove_once_unchecked(&mut circuit, block_template, terminator, rng)?;
+        let work_unit = WorkUnit::new(block_template);
+        let solution = work_unit.mine_once_unchecked(terminator, rng)?;
 
         // Construct a block header.
         Ok(Self {
-            previous_ledger_root: block_template.previous_ledger_root(),
-            transactions_root: block_template.transactions().transactions_root(),
-            metadata: BlockHeaderMetadata::new(block_template),
-            nonce: circuit.nonce(),
-            proof,
+            previous_ledger_root: work_unit.previous_ledger_root(),
+            transactions_root: work_unit.transactions_root(),
+            metadata: work_unit.metadata().clone(),
+            nonce: solution.nonce(),
+            proof: solution.proof().clone(),
         })
     }
 
     /// Returns `true` if the block header is well-formed.
     pub fn is_valid(&self) -> bool {
+        match self.validate() {
+            Ok(()) => true,
+            Err(error) => {
+                eprintln!("{}", error);
+                false
+            }
+        }
+    }
+
+    /// Ensures the block header is well-formed, returning the consensus rule it violates otherwise.
+    pub fn validate(&self) -> Result<(), BlockValidationError<N>> {
         // Ensure the ledger root is nonzero.
         if self.previous_ledger_root == Default::default() {
-            eprintln!("Invalid ledger root in block header");
-            return false;
+            return Err(BlockValidationError::EmptyPreviousLedgerRoot);
         }
 
         // Ensure the transactions root is nonzero.
         if self.transactions_root == Default::default() {
-            eprintln!("Invalid transactions root in block header");
-            return false;
+            return Err(BlockValidationError::EmptyTransactionsRoot);
         }
 
         // Ensure the nonce is nonzero.
         if self.nonce == Default::default() {
-            eprintln!("Invalid nonce in block header");
-            return false;
+            return Err(BlockValidationError::EmptyNonce);
         }
 
         // Ensure the metadata and proof are valid.
         match self.metadata.height == 0u32 {
-            true => self.is_genesis(),
+            true => match self.is_genesis() {
+                true => Ok(()),
+                false => Err(BlockValidationError::InvalidGenesisBlockHeader),
+            },
             false => {
                 // Ensure the timestamp in the block is greater than 0.
-                self.metadata.timestamp > 0i64
-                    // Ensure the PoSW proof is valid.
-                    && N::posw().verify_from_block_header(self)
+                if self.metadata.timestamp <= 0i64 {
+                    return Err(BlockValidationError::InvalidTimestamp(self.metadata.timestamp));
+                }
+
+                // Ensure the PoSW proof is valid.
+                match N::posw().verify_from_block_header(self) {
+                    true => Ok(()),
+                    false => Err(BlockValidationError::InvalidPoSWProof(self.metadata.height)),
+                }
             }
         }
     }
@@ -202,10 +257,12 @@ impl<N: Network> BlockHeader<N> {
     pub fn is_genesis(&self) -> bool {
         // Ensure the height in the genesis block is 0.
         self.metadata.height == 0u32
-            // Ensure the timestamp in the genesis block is 0.
-            && self.metadata.timestamp == 0i64
-            // Ensure the difficulty target in the genesis block is u64::MAX.
-            && self.metadata.difficulty_target == u64::MAX
+            // Ensure the timestamp in the genesis block is 0, and the difficulty target is u64::MAX,
+            // or, if the network has a configurable genesis block, are non-negative and nonzero.
+            && match N::ALEO_CONFIGURABLE_GENESIS {
+                true => self.metadata.timestamp >= 0i64 && self.metadata.difficulty_target > 0u64,
+                false => self.metadata.timestamp == 0i64 && self.metadata.difficulty_target == u64::MAX,
+            }
             // Ensure the cumulative weight in the genesis block is 0u128.
             && self.metadata.cumulative_weight == 0u128
             // Ensure the PoSW proof is valid.
@@ -278,6 +335,13 @@ impl<N: Network> BlockHeader<N> {
     pub fn to_header_root(&self) -> Result<N::BlockHeaderRoot> {
         Ok((*self.to_header_tree()?.root()).into())
     }
+
+    /// Returns the block hash of this block header, given the block hash of its previous block.
+    pub fn to_block_hash(&self, previous_block_hash: N::BlockHash) -> Result<N::BlockHash> {
+        Ok(N::block_hash_crh()
+            .hash(&to_bytes_le![previous_block_hash, self.to_header_root()?]?)?
+            .into())
+    }
 }
 
 impl<N: Network> FromBytes for BlockHeader<N> {
@@ -288,16 +352,7 @@ impl<N: Network> FromBytes for BlockHeader<N> {
         let transactions_root = FromBytes::read_le(&mut reader)?;
 
         // Read the header metadata.
-        let height = <[u8; 4]>::read_le(&mut reader)?;
-        let timestamp = <[u8; 8]>::read_le(&mut reader)?;
-        let difficulty_target = <[u8; 8]>::read_le(&mut reader)?;
-        let cumulative_weight = <[u8; 16]>::read_le(&mut reader)?;
-        let metadata = BlockHeaderMetadata {
-            height: u32::from_le_bytes(height),
-            timestamp: i64::from_le_bytes(timestamp),
-            difficulty_target: u64::from_le_bytes(difficulty_target),
-            cumulative_weight: u128::from_le_bytes(cumulative_weight),
-        };
+        let metadata = FromBytes::read_le(&mut reader)?;
 
         // Read the header nonce.
         let nonce = FromBytes::read_le(&mut reader)?;
@@ -501,6 +556,8 @@ mod tests {
         // Construct a PoSW proof.
         let mut block_header = Testnet2::posw()
             .mine(&block_template, &AtomicBool::new(false), &mut thread_rng())
+            .unwrap()
+            .block_header()
             .unwrap();
 
         // Check that the difficulty target is satisfied.
@@ -17,11 +17,20 @@
 pub mod block;
 pub use block::*;
 
+pub mod genesis;
+pub use genesis::*;
+
 pub mod header;
 pub use header::*;
 
 pub mod template;
 pub use template::*;
 
+#[cfg(test)]
+pub(crate) mod test_helpers;
+
 pub mod transactions;
 pub use transactions::*;
+
+pub mod work;
+pub use work::*;
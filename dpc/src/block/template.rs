@@ -164,7 +164,7 @@ impl<N: Network> FromBytes for BlockTemplate<N> {
         let difficulty_target: u64 = FromBytes::read_le(&mut reader)?;
         let cumulative_weight: u128 = FromBytes::read_le(&mut reader)?;
         let previous_ledger_root: N::LedgerRoot = FromBytes::read_le(&mut reader)?;
-        let transactions: Transactions<N> = FromBytes::read_le(&mut reader)?;
+        let transactions = Transactions::read_le_in_block(&mut reader, block_height)?;
         let coinbase_record: Record<N> = FromBytes::read_le(&mut reader)?;
 
         Ok(Self::new(
@@ -188,7 +188,8 @@ impl<N: Network> ToBytes for BlockTemplate<N> {
         self.difficulty_target.write_le(&mut writer)?;
         self.cumulative_weight.write_le(&mut writer)?;
         self.previous_ledger_root.write_le(&mut writer)?;
-        self.transactions.write_le(&mut writer)?;
+        // The transactions were checked for the block height when they were initialized.
+        self.transactions.write_le_unchecked(&mut writer)?;
         self.coinbase_record.write_le(&mut writer)
     }
 }
@@ -249,8 +250,8 @@ impl<'de, N: Network> Deserialize<'de> for BlockTemplate<N> {
                 let previous_ledger_root: N::LedgerRoot =
                     serde_json::from_value(block_template["previous_ledger_root"].clone())
                         .map_err(de::Error::custom)?;
-                let transactions: Transactions<N> =
-                    serde_json::from_value(block_template["transactions"].clone()).map_err(de::Error::custom)?;
+                let transactions = Transactions::from_json_in_block(&block_template["transactions"], block_height)
+                    .map_err(de::Error::custom)?;
                 let coinbase_record: Record<N> =
                     serde_json::from_value(block_template["coinbase_record"].clone()).map_err(de::Error::custom)?;
                 Ok(Self::new(
@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AleoAmount, BlockError, DecryptionKey, Network, Record, Transaction};
+use crate::{AleoAmount, BlockError, BlockValidationError, DecryptionKey, Network, Record, Transaction};
 use snarkvm_algorithms::merkle_tree::*;
-use snarkvm_utilities::{has_duplicates, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use anyhow::{anyhow, Result};
 use rayon::prelude::*;
@@ -29,6 +29,7 @@ use serde::{
     Serializer,
 };
 use std::{
+    collections::HashSet,
     fmt,
     io::{Read, Result as IoResult, Write},
     ops::Deref,
@@ -54,6 +55,23 @@ pub struct Transactions<N: Network> {
 impl<N: Network> Transactions<N> {
     /// Initializes from a given transactions list.
     pub fn from(transactions: &[Transaction<N>]) -> Result<Self, BlockError> {
+        Self::from_transactions(transactions, false)
+    }
+
+    /// Initializes from a given transactions list, allowing a genesis block coinbase if `is_genesis` is set.
+    pub(crate) fn from_transactions(transactions: &[Transaction<N>], is_genesis: bool) -> Result<Self, BlockError> {
+        let transactions = Self::from_unchecked(transactions)?;
+
+        // Ensure the list of transactions are valid.
+        transactions.validate_for(is_genesis)?;
+        Ok(transactions)
+    }
+
+    ///
+    /// Initializes from a given transactions list, without checking the transactions are well-formed.
+    /// This is only used for transactions read from trusted storage, which were checked before they were stored.
+    ///
+    pub(crate) fn from_unchecked(transactions: &[Transaction<N>]) -> Result<Self, BlockError> {
         // Compute the transactions tree.
         let tree = MerkleTree::<N::TransactionsRootParameters>::new(
             Arc::new(N::transactions_root_parameters().clone()),
@@ -61,47 +79,88 @@ impl<N: Network> Transactions<N> {
         )?;
 
         // Construct the transactions struct.
-        let transactions = Self {
+        Ok(Self {
             transactions: transactions.to_vec(),
             tree: Arc::new(tree),
-        };
-
-        // Ensure the list of transactions are valid.
-        match transactions.is_valid() {
-            true => Ok(transactions),
-            false => Err(anyhow!("Failed to initialize the transactions list").into()),
-        }
+        })
     }
 
     /// Returns `true` if the transactions are well-formed.
     pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Ensures the transactions are well-formed, returning the consensus rule they violate otherwise.
+    pub fn validate(&self) -> Result<(), BlockValidationError<N>> {
+        self.validate_for(false)
+    }
+
+    ///
+    /// Ensures the transactions are well-formed, returning the consensus rule they violate otherwise.
+    /// If `is_genesis` is set, the coinbase transaction may mint over multiple transitions
+    /// on networks with a configurable genesis block.
+    ///
+    pub(crate) fn validate_for(&self, is_genesis: bool) -> Result<(), BlockValidationError<N>> {
         // Ensure the transactions list is not empty.
         if self.transactions.is_empty() {
-            eprintln!("Cannot process validity checks on an empty transactions list");
-            return false;
+            return Err(BlockValidationError::EmptyTransactions);
         }
 
         // Ensure each transaction is well-formed.
-        if !self
+        let outer_proofs = self
             .transactions
             .as_parallel_slice()
             .par_iter()
-            .all(Transaction::is_valid)
+            .map(|transaction| transaction.to_outer_proofs(is_genesis))
+            .collect::<Vec<_>>();
+        if let Some((transaction, _)) = self
+            .transactions
+            .iter()
+            .zip(&outer_proofs)
+            .find(|(_, proofs)| proofs.is_none())
         {
-            eprintln!("Invalid transaction found in the transactions list");
-            return false;
+            return Err(BlockValidationError::InvalidTransaction(transaction.transaction_id()));
+        }
+
+        // Ensure the transition proofs of all transactions are valid, verifying them as one batch.
+        let outer_proofs = outer_proofs.into_iter().flatten().flatten().collect::<Vec<_>>();
+        if !Transaction::verify_outer_proofs(&outer_proofs) {
+            // Find the first transaction with an invalid transition proof.
+            return match self
+                .transactions
+                .as_parallel_slice()
+                .par_iter()
+                .find_first(|transaction| !transaction.is_valid_for(is_genesis))
+            {
+                Some(transaction) => Err(BlockValidationError::InvalidTransaction(transaction.transaction_id())),
+                None => Err(BlockValidationError::InvalidTransitionProofs),
+            };
         }
 
         // Ensure there are no duplicate serial numbers.
-        if has_duplicates(self.transactions.iter().flat_map(Transaction::serial_numbers)) {
-            eprintln!("Found duplicate serial numbers in the transactions list");
-            return false;
+        let mut serial_numbers = HashSet::new();
+        for transaction in self.transactions.iter() {
+            for serial_number in transaction.serial_numbers() {
+                if !serial_numbers.insert(serial_number) {
+                    return Err(BlockValidationError::DuplicateSerialNumber(
+                        transaction.transaction_id(),
+                        *serial_number,
+                    ));
+                }
+            }
         }
 
         // Ensure there are no duplicate commitments.
-        if has_duplicates(self.transactions.iter().flat_map(Transaction::commitments)) {
-            eprintln!("Found duplicate commitments in the transactions list");
-            return false;
+        let mut commitments = HashSet::new();
+        for transaction in self.transactions.iter() {
+            for commitment in transaction.commitments() {
+                if !commitments.insert(commitment) {
+                    return Err(BlockValidationError::DuplicateCommitment(
+                        transaction.transaction_id(),
+                        *commitment,
+                    ));
+                }
+            }
         }
 
         // Ensure there is 1 coinbase transaction.
@@ -111,11 +170,10 @@ impl<N: Network> Transactions<N> {
             .filter(|t| t.value_balance().is_negative())
             .count();
         if num_coinbase != 1 {
-            eprintln!("Block must have exactly 1 coinbase transaction, found {}", num_coinbase);
-            return false;
+            return Err(BlockValidationError::InvalidNumberOfCoinbaseTransactions(num_coinbase));
         }
 
-        true
+        Ok(())
     }
 
     /// Returns the transaction IDs, by constructing a flattened list of transaction IDs from all transactions.
@@ -204,6 +262,50 @@ impl<N: Network> Transactions<N> {
     }
 }
 
+impl<N: Network> Transactions<N> {
+    /// Reads the transactions of the block at the given height, allowing a genesis block coinbase only at height 0.
+    pub(crate) fn read_le_in_block<R: Read>(mut reader: R, block_height: u32) -> IoResult<Self> {
+        let num_transactions: u16 = FromBytes::read_le(&mut reader)?;
+        let mut transactions = Vec::with_capacity(num_transactions as usize);
+        for _ in 0..num_transactions {
+            transactions.push(Transaction::read_le_in_block(&mut reader, block_height)?);
+        }
+        Ok(Self::from_transactions(&transactions, block_height == 0)?)
+    }
+
+    /// Recovers the transactions of the block at the given height from JSON,
+    /// allowing a genesis block coinbase only at height 0.
+    pub(crate) fn from_json_in_block(transactions: &serde_json::Value, block_height: u32) -> Result<Self> {
+        let transactions = match transactions["transactions"].as_array() {
+            Some(transactions) => transactions
+                .iter()
+                .map(|transaction| Transaction::from_json_in_block(transaction, block_height))
+                .collect::<Result<Vec<_>>>()?,
+            None => return Err(anyhow!("Missing the transactions list")),
+        };
+        Ok(Self::from_transactions(&transactions, block_height == 0)?)
+    }
+
+    /// Reads the transactions list from trusted storage, without checking the transactions are well-formed.
+    pub(crate) fn read_le_unchecked<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_transactions: u16 = FromBytes::read_le(&mut reader)?;
+        let mut transactions = Vec::with_capacity(num_transactions as usize);
+        for _ in 0..num_transactions {
+            transactions.push(Transaction::read_le_unchecked(&mut reader)?);
+        }
+        Ok(Self::from_unchecked(&transactions)?)
+    }
+
+    /// Writes the transactions list for trusted storage, without checking the transactions are well-formed.
+    pub(crate) fn write_le_unchecked<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.transactions.len() as u16).write_le(&mut writer)?;
+        for transaction in &self.transactions {
+            transaction.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
 impl<N: Network> FromBytes for Transactions<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
@@ -220,13 +322,7 @@ impl<N: Network> ToBytes for Transactions<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         match self.is_valid() {
-            true => {
-                (self.transactions.len() as u16).write_le(&mut writer)?;
-                for transaction in &self.transactions {
-                    transaction.write_le(&mut writer)?;
-                }
-                Ok(())
-            }
+            true => self.write_le_unchecked(&mut writer),
             false => Err(BlockError::Message("Invalid transactions list".to_string()).into()),
         }
     }
@@ -319,8 +415,13 @@ mod tests {
     fn test_duplicate_transactions() {
         // Fetch any transaction.
         let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
-        // Duplicate the transaction, and ensure it errors.
-        assert!(Transactions::from(&[transaction.clone(), transaction]).is_err());
+        // Duplicate the transaction, and ensure it errors with the violated consensus rule.
+        let error = Transactions::from(&[transaction.clone(), transaction.clone()]).unwrap_err();
+        assert!(matches!(
+            error.validation_error::<Testnet2>(),
+            Some(BlockValidationError::DuplicateSerialNumber(transaction_id, _))
+                if *transaction_id == transaction.transaction_id()
+        ));
     }
 
     #[test]
@@ -0,0 +1,407 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{BlockHeader, BlockHeaderMetadata, BlockTemplate, Network, PoSWCircuit, PoSWProof, PoSWScheme};
+use snarkvm_algorithms::{merkle_tree::MerkleTree, traits::MerkleParameters};
+use snarkvm_utilities::{
+    error,
+    fmt,
+    io::{Read, Result as IoResult, Write},
+    str::FromStr,
+    FromBytes,
+    FromBytesDeserializer,
+    ToBytes,
+    ToBytesSerializer,
+    UniformRand,
+};
+
+use anyhow::{anyhow, Result};
+use rand::{CryptoRng, Rng};
+use serde::{de, ser, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use std::sync::atomic::AtomicBool;
+
+///
+/// A unit of PoSW work, holding everything a miner needs to search for a block header
+/// without the block transactions or the ledger.
+///
+/// A work unit contains the leaves of the block header tree, which include the difficulty target
+/// in the block header metadata. The masked Merkle parameters of the block header tree are identified
+/// by their setup message, and a work unit is only read if they match the parameters of the network.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkUnit<N: Network> {
+    previous_ledger_root: N::LedgerRoot,
+    transactions_root: N::TransactionsRoot,
+    metadata: BlockHeaderMetadata,
+}
+
+impl<N: Network> WorkUnit<N> {
+    /// Initializes a new work unit from the given block template.
+    pub fn new(block_template: &BlockTemplate<N>) -> Self {
+        Self {
+            previous_ledger_root: block_template.previous_ledger_root(),
+            transactions_root: block_template.transactions().transactions_root(),
+            metadata: BlockHeaderMetadata::new(block_template),
+        }
+    }
+
+    /// Returns the previous ledger root.
+    pub fn previous_ledger_root(&self) -> N::LedgerRoot {
+        self.previous_ledger_root
+    }
+
+    /// Returns the transactions root.
+    pub fn transactions_root(&self) -> N::TransactionsRoot {
+        self.transactions_root
+    }
+
+    /// Returns a reference to the block header metadata.
+    pub fn metadata(&self) -> &BlockHeaderMetadata {
+        &self.metadata
+    }
+
+    /// Returns the block height.
+    pub fn block_height(&self) -> u32 {
+        self.metadata.height()
+    }
+
+    /// Returns the difficulty target.
+    pub fn difficulty_target(&self) -> u64 {
+        self.metadata.difficulty_target()
+    }
+
+    /// Returns the masked Merkle parameters of the block header tree.
+    pub fn header_root_parameters(&self) -> &'static N::BlockHeaderRootParameters {
+        N::block_header_root_parameters()
+    }
+
+    /// Returns an instance of the block header tree.
+    pub fn to_header_tree(&self) -> Result<MerkleTree<N::BlockHeaderRootParameters>> {
+        BlockTemplate::<N>::compute_block_header_tree(self.previous_ledger_root, self.transactions_root, &self.metadata)
+    }
+
+    /// Returns the block header root.
+    pub fn to_header_root(&self) -> Result<N::BlockHeaderRoot> {
+        Ok((*self.to_header_tree()?.root()).into())
+    }
+
+    ///
+    /// Mines a new unchecked solution for the work unit.
+    /// WARNING - This method does *not* ensure the solution satisfies the difficulty target.
+    ///
+    pub fn mine_once_unchecked<R: Rng + CryptoRng>(
+        &self,
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<WorkSolution<N>> {
+        // Instantiate the circuit.
+        let mut circuit = PoSWCircuit::<N>::from_work_unit(self, UniformRand::rand(rng))?;
+
+        // Run one iteration of PoSW.
+        let proof = N::posw().prove_once_unchecked_at_height(&mut circuit, self.block_height(), terminator, rng)?;
+
+        Ok(WorkSolution::new(circuit.nonce(), proof))
+    }
+
+    /// Returns `true` if the given solution satisfies the difficulty target of the work unit.
+    pub fn is_solution(&self, solution: &WorkSolution<N>) -> bool {
+        match self.to_header_root() {
+            Ok(block_header_root) => N::posw().verify(
+                self.block_height(),
+                self.difficulty_target(),
+                &[*block_header_root, *solution.nonce],
+                &solution.proof,
+            ),
+            Err(error) => {
+                eprintln!("{}", error);
+                false
+            }
+        }
+    }
+
+    /// Returns the block header for the given solution, if the solution is valid.
+    pub fn to_block_header(&self, solution: &WorkSolution<N>) -> Result<BlockHeader<N>> {
+        Ok(BlockHeader::from(
+            self.previous_ledger_root,
+            self.transactions_root,
+            self.metadata.clone(),
+            solution.nonce,
+            solution.proof.clone(),
+        )?)
+    }
+}
+
+impl<N: Network> FromBytes for WorkUnit<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let previous_ledger_root = FromBytes::read_le(&mut reader)?;
+        let transactions_root = FromBytes::read_le(&mut reader)?;
+        let metadata = FromBytes::read_le(&mut reader)?;
+
+        // Ensure the masked Merkle parameters match the parameters of the network.
+        // The length is checked before allocating, as it is read from an untrusted source.
+        let expected_setup_message = N::block_header_root_parameters().setup_message().as_bytes();
+        let setup_message_length: u64 = FromBytes::read_le(&mut reader)?;
+        if setup_message_length != expected_setup_message.len() as u64 {
+            return Err(error("Mismatching block header root parameters in the work unit"));
+        }
+        let mut setup_message_bytes = vec![0u8; expected_setup_message.len()];
+        reader.read_exact(&mut setup_message_bytes)?;
+        if setup_message_bytes != expected_setup_message {
+            return Err(error("Mismatching block header root parameters in the work unit"));
+        }
+
+        Ok(Self {
+            previous_ledger_root,
+            transactions_root,
+            metadata,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for WorkUnit<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.previous_ledger_root.write_le(&mut writer)?;
+        self.transactions_root.write_le(&mut writer)?;
+        self.metadata.write_le(&mut writer)?;
+
+        let setup_message_bytes: &[u8] = self.header_root_parameters().setup_message().as_bytes();
+        (setup_message_bytes.len() as u64).write_le(&mut writer)?;
+        setup_message_bytes.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromStr for WorkUnit<N> {
+    type Err = anyhow::Error;
+
+    fn from_str(work_unit: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(work_unit)?)
+    }
+}
+
+impl<N: Network> fmt::Display for WorkUnit<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?
+        )
+    }
+}
+
+impl<N: Network> Serialize for WorkUnit<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut work_unit = serializer.serialize_struct("WorkUnit", 4)?;
+                work_unit.serialize_field("previous_ledger_root", &self.previous_ledger_root)?;
+                work_unit.serialize_field("transactions_root", &self.transactions_root)?;
+                work_unit.serialize_field("metadata", &self.metadata)?;
+                work_unit.serialize_field("header_root_parameters", self.header_root_parameters().setup_message())?;
+                work_unit.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for WorkUnit<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let work_unit = serde_json::Value::deserialize(deserializer)?;
+
+                // Ensure the masked Merkle parameters match the parameters of the network.
+                let setup_message: String =
+                    serde_json::from_value(work_unit["header_root_parameters"].clone()).map_err(de::Error::custom)?;
+                if setup_message != N::block_header_root_parameters().setup_message() {
+                    return Err(de::Error::custom(anyhow!(
+                        "Mismatching block header root parameters in the work unit"
+                    )));
+                }
+
+                Ok(Self {
+                    previous_ledger_root: serde_json::from_value(work_unit["previous_ledger_root"].clone())
+                        .map_err(de::Error::custom)?,
+                    transactions_root: serde_json::from_value(work_unit["transactions_root"].clone())
+                        .map_err(de::Error::custom)?,
+                    metadata: serde_json::from_value(work_unit["metadata"].clone()).map_err(de::Error::custom)?,
+                })
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "work unit"),
+        }
+    }
+}
+
+///
+/// A solution to a unit of PoSW work, returned by a miner to assemble the block header.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkSolution<N: Network> {
+    nonce: N::PoSWNonce,
+    proof: PoSWProof<N>,
+}
+
+impl<N: Network> WorkSolution<N> {
+    /// Initializes a new work solution.
+    pub fn new(nonce: N::PoSWNonce, proof: PoSWProof<N>) -> Self {
+        Self { nonce, proof }
+    }
+
+    /// Returns the nonce.
+    pub fn nonce(&self) -> N::PoSWNonce {
+        self.nonce
+    }
+
+    /// Returns a reference to the PoSW proof.
+    pub fn proof(&self) -> &PoSWProof<N> {
+        &self.proof
+    }
+}
+
+impl<N: Network> FromBytes for WorkSolution<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let nonce = FromBytes::read_le(&mut reader)?;
+        let proof = FromBytes::read_le(&mut reader)?;
+        Ok(Self::new(nonce, proof))
+    }
+}
+
+impl<N: Network> ToBytes for WorkSolution<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.nonce.write_le(&mut writer)?;
+        self.proof.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromStr for WorkSolution<N> {
+    type Err = anyhow::Error;
+
+    fn from_str(work_solution: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(work_solution)?)
+    }
+}
+
+impl<N: Network> fmt::Display for WorkSolution<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?
+        )
+    }
+}
+
+impl<N: Network> Serialize for WorkSolution<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut work_solution = serializer.serialize_struct("WorkSolution", 2)?;
+                work_solution.serialize_field("nonce", &self.nonce)?;
+                work_solution.serialize_field("proof", &self.proof)?;
+                work_solution.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for WorkSolution<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let work_solution = serde_json::Value::deserialize(deserializer)?;
+                Ok(Self::new(
+                    serde_json::from_value(work_solution["nonce"].clone()).map_err(de::Error::custom)?,
+                    serde_json::from_value(work_solution["proof"].clone()).map_err(de::Error::custom)?,
+                ))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "work solution"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "devnet"))]
+mod tests {
+    use super::*;
+    use crate::{block::test_helpers::genesis_template, devnet::Devnet};
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_work_unit_serialization() {
+        let block_template = genesis_template::<Devnet>(u64::MAX);
+        let work_unit = WorkUnit::new(&block_template);
+        assert_eq!(
+            work_unit.to_header_root().unwrap(),
+            block_template.to_header_root().unwrap()
+        );
+
+        // Serialize and deserialize.
+        assert_eq!(work_unit, WorkUnit::from_str(&work_unit.to_string()).unwrap());
+        assert_eq!(
+            work_unit,
+            WorkUnit::read_le(&work_unit.to_bytes_le().unwrap()[..]).unwrap()
+        );
+        assert_eq!(
+            work_unit,
+            bincode::deserialize(&bincode::serialize(&work_unit).unwrap()).unwrap()
+        );
+
+        // Ensure a work unit for different masked Merkle parameters is rejected.
+        let mut json: serde_json::Value = serde_json::from_str(&work_unit.to_string()).unwrap();
+        json["header_root_parameters"] = "AleoBlockHeaderRootCRH1".into();
+        assert!(WorkUnit::<Devnet>::from_str(&json.to_string()).is_err());
+
+        // Ensure a work unit with a malformed setup message length is rejected before allocating.
+        let mut bytes = work_unit.to_bytes_le().unwrap();
+        let setup_message_length = work_unit.header_root_parameters().setup_message().len();
+        let offset = bytes.len() - setup_message_length - 8;
+        bytes[offset..offset + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(WorkUnit::<Devnet>::read_le(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_remote_mining() {
+        let rng = &mut thread_rng();
+        let block_template = genesis_template::<Devnet>(u64::MAX);
+
+        // The ledger exports the work unit, and the miner receives it.
+        let work_unit = WorkUnit::<Devnet>::from_str(&WorkUnit::new(&block_template).to_string()).unwrap();
+
+        // The miner returns a solution, and the ledger receives it.
+        let solution = work_unit.mine_once_unchecked(&AtomicBool::new(false), rng).unwrap();
+        assert!(work_unit.is_solution(&solution));
+        let solution = WorkSolution::<Devnet>::from_str(&solution.to_string()).unwrap();
+
+        // The ledger assembles the block header.
+        let block_header = WorkUnit::new(&block_template).to_block_header(&solution).unwrap();
+        assert!(block_header.is_valid());
+        assert_eq!(block_header.nonce(), solution.nonce());
+        assert_eq!(
+            block_header.to_header_root().unwrap(),
+            block_template.to_header_root().unwrap()
+        );
+
+        // Ensure a solution for a different work unit is rejected.
+        let other_template = genesis_template::<Devnet>(u64::MAX - 1);
+        assert!(!WorkUnit::new(&other_template).is_solution(&solution));
+        assert!(WorkUnit::new(&other_template).to_block_header(&solution).is_err());
+    }
+}
@@ -42,18 +42,29 @@ use snarkvm_gadgets::algorithms::merkle_tree::compute_root;
 pub struct InnerCircuit<N: Network> {
     public: InnerPublicVariables<N>,
     private: InnerPrivateVariables<N>,
+    noop_program_id: N::ProgramID,
 }
 
 impl<N: Network> InnerCircuit<N> {
     pub fn blank() -> Self {
+        Self::blank_with_noop_program_id(*N::noop_program_id())
+    }
+
+    /// Returns a blank inner circuit for the given noop program ID, as used to set up new parameters.
+    pub fn blank_with_noop_program_id(noop_program_id: N::ProgramID) -> Self {
         Self {
             public: InnerPublicVariables::blank(),
             private: InnerPrivateVariables::blank(),
+            noop_program_id,
         }
     }
 
     pub fn new(public: InnerPublicVariables<N>, private: InnerPrivateVariables<N>) -> Self {
-        Self { public, private }
+        Self {
+            public,
+            private,
+            noop_program_id: *N::noop_program_id(),
+        }
     }
 }
 
@@ -144,7 +155,7 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
         // Declares a constant for an empty payload in a record.
         let empty_payload = UInt8::constant_vec(&Payload::<N>::default().to_bytes_le()?);
         // Declare the noop program ID as bytes.
-        let noop_program_id_bytes = UInt8::constant_vec(&N::noop_program_id().to_bytes_le()?);
+        let noop_program_id_bytes = UInt8::constant_vec(&self.noop_program_id.to_bytes_le()?);
 
         // TODO: directly allocate these as the appropriate number of constant zero field elements
         // (i.e., no constraints)
@@ -36,6 +36,12 @@ pub enum AccountError {
     #[error("invalid character length: {}", _0)]
     InvalidCharacterLength(usize),
 
+    #[error("invalid keystore: {}", _0)]
+    InvalidKeystore(String),
+
+    #[error("invalid keystore password")]
+    InvalidPassword,
+
     #[error("invalid prefix: {:?}", _0)]
     InvalidPrefix(String),
 
@@ -61,6 +67,12 @@ impl From<base58::FromBase58Error> for AccountError {
     }
 }
 
+impl From<bip39::Error> for AccountError {
+    fn from(error: bip39::Error) -> Self {
+        AccountError::Crate("bip39", format!("{:?}", error))
+    }
+}
+
 impl From<std::io::Error> for AccountError {
     fn from(error: std::io::Error) -> Self {
         AccountError::Crate("std::io", format!("{:?}", error))
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{AleoAmount, Network};
+
 use std::fmt::Debug;
 
 #[derive(Debug, Error)]
@@ -35,6 +37,25 @@ pub enum BlockError {
 
     #[error("{}", _0)]
     Message(String),
+
+    #[error("{}", _0)]
+    ValidationError(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl BlockError {
+    /// Returns the consensus rule violated by the block, if this error is a validation error.
+    pub fn validation_error<N: Network>(&self) -> Option<&BlockValidationError<N>> {
+        match self {
+            BlockError::ValidationError(error) => error.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl<N: Network> From<BlockValidationError<N>> for BlockError {
+    fn from(error: BlockValidationError<N>) -> Self {
+        BlockError::ValidationError(Box::new(error))
+    }
 }
 
 impl From<std::io::Error> for BlockError {
@@ -48,3 +69,121 @@ impl From<BlockError> for std::io::Error {
         std::io::Error::new(std::io::ErrorKind::Other, format!("{}", error))
     }
 }
+
+/// An error when a block violates a consensus rule, with the offending transaction or field.
+#[derive(Debug, Error)]
+pub enum BlockValidationError<N: Network> {
+    #[error("Block height {} already exists in the ledger", _0)]
+    BlockHeightExists(u32),
+
+    #[error("Commitment {} in transaction {} already exists in the ledger", _1, _0)]
+    CommitmentExists(N::TransactionID, N::Commitment),
+
+    #[error("Transaction {} contains duplicate commitment {} in the block", _0, _1)]
+    DuplicateCommitment(N::TransactionID, N::Commitment),
+
+    #[error("Block hash {} already exists in the ledger", _0)]
+    DuplicateBlockHash(N::BlockHash),
+
+    #[error("Transaction {} contains duplicate serial number {} in the block", _0, _1)]
+    DuplicateSerialNumber(N::TransactionID, N::SerialNumber),
+
+    #[error("Transaction {} already exists in the ledger", _0)]
+    DuplicateTransaction(N::TransactionID),
+
+    #[error("Block header has an empty nonce")]
+    EmptyNonce,
+
+    #[error("Block header has an empty previous ledger root")]
+    EmptyPreviousLedgerRoot,
+
+    #[error("Block has no transactions")]
+    EmptyTransactions,
+
+    #[error("Block header has an empty transactions root")]
+    EmptyTransactionsRoot,
+
+    #[error("Block reward must be <= {}, found {}", expected, found)]
+    ExcessiveBlockReward { expected: AleoAmount, found: AleoAmount },
+
+    #[error("Block timestamp {} exceeds the time limit {}", timestamp, limit)]
+    FutureTimestamp { timestamp: i64, limit: i64 },
+
+    #[error("Block height is incorrect. Found {}, but expected {}", found, expected)]
+    IncorrectBlockHeight { expected: u32, found: u32 },
+
+    #[error("Block cumulative weight is incorrect. Found {}, but expected {}", found, expected)]
+    IncorrectCumulativeWeight { expected: u128, found: u128 },
+
+    #[error("Block difficulty target is incorrect. Found {}, but expected {}", found, expected)]
+    IncorrectDifficultyTarget { expected: u64, found: u64 },
+
+    #[error("Block previous block hash is incorrect. Found {}, but expected {}", found, expected)]
+    IncorrectPreviousBlockHash {
+        expected: N::BlockHash,
+        found: N::BlockHash,
+    },
+
+    #[error(
+        "Block previous ledger root is incorrect. Found {}, but expected {}",
+        found,
+        expected
+    )]
+    IncorrectPreviousLedgerRoot {
+        expected: N::LedgerRoot,
+        found: N::LedgerRoot,
+    },
+
+    #[error("Coinbase reward must be >= {}, found {}", expected, found)]
+    InsufficientCoinbaseReward { expected: AleoAmount, found: AleoAmount },
+
+    #[error("Invalid genesis block header")]
+    InvalidGenesisBlockHeader,
+
+    #[error("Block must have exactly 1 coinbase transaction, found {}", _0)]
+    InvalidNumberOfCoinbaseTransactions(usize),
+
+    #[error("Invalid PoSW proof in block header at height {}", _0)]
+    InvalidPoSWProof(u32),
+
+    #[error("Invalid previous block hash {} for block height {}", _1, _0)]
+    InvalidPreviousBlockHash(u32, N::BlockHash),
+
+    #[error("Invalid block timestamp {}", _0)]
+    InvalidTimestamp(i64),
+
+    #[error("Invalid transaction {}", _0)]
+    InvalidTransaction(N::TransactionID),
+
+    #[error("Block transition proofs failed batch verification")]
+    InvalidTransitionProofs,
+
+    #[error("Missing block header for block height {}", _0)]
+    MissingBlockHeader(u32),
+
+    #[error(
+        "Block timestamp {} is not after the previous block timestamp {}",
+        timestamp,
+        previous_timestamp
+    )]
+    NonIncreasingTimestamp { timestamp: i64, previous_timestamp: i64 },
+
+    #[error("Serial number {} in transaction {} already exists in the ledger", _1, _0)]
+    SerialNumberExists(N::TransactionID, N::SerialNumber),
+
+    #[error("Failed to read the ledger storage: {}", _0)]
+    StorageError(anyhow::Error),
+
+    #[error(
+        "Block transactions root {} does not match transactions root {} in the block header",
+        found,
+        expected
+    )]
+    TransactionsRootMismatch {
+        expected: N::TransactionsRoot,
+        found: N::TransactionsRoot,
+    },
+
+    #[error("Transaction {} references a non-existent ledger root {}", _0, _1)]
+    UnknownLedgerRoot(N::TransactionID, N::LedgerRoot),
+}
@@ -19,48 +19,64 @@ use snarkvm_algorithms::merkle_tree::*;
 
 use anyhow::{anyhow, Result};
 use chrono::Utc;
-use itertools::Itertools;
-use std::collections::HashMap;
 
 #[derive(Clone, Debug)]
-pub struct Blocks<N: Network> {
+pub struct Blocks<N: Network, S: LedgerStorage<N> = MemoryStorage<N>> {
     /// The current block height.
     current_height: u32,
     /// The current block hash.
     current_hash: N::BlockHash,
     /// The current ledger tree.
     ledger_tree: LedgerTree<N>,
-    /// The chain of previous block hashes.
-    previous_hashes: HashMap<u32, N::BlockHash>,
-    /// The chain of block headers.
-    headers: HashMap<u32, BlockHeader<N>>,
-    /// The chain of block transactions.
-    transactions: HashMap<u32, Transactions<N>>,
+    /// The storage backend for the chain of blocks.
+    storage: S,
 }
 
 impl<N: Network> Blocks<N> {
-    /// Initializes a new instance of `Blocks` with the genesis block.
+    /// Initializes a new in-memory instance of `Blocks` with the genesis block.
     pub fn new() -> Result<Self> {
+        Self::open(MemoryStorage::new())
+    }
+}
+
+impl<N: Network, S: LedgerStorage<N>> Blocks<N, S> {
+    /// Initializes an instance of `Blocks` from the given storage.
+    /// If the storage is empty, the genesis block is inserted into it.
+    pub fn open(mut storage: S) -> Result<Self> {
         let genesis_block = N::genesis_block();
-        let height = genesis_block.height();
-
-        let mut blocks = Self {
-            current_height: height,
-            current_hash: genesis_block.hash(),
-            ledger_tree: LedgerTree::<N>::new()?,
-            previous_hashes: Default::default(),
-            headers: Default::default(),
-            transactions: Default::default(),
+
+        // Retrieve the latest block height, initializing the storage with the genesis block if it is empty.
+        let latest_height = match storage.latest_block_height()? {
+            Some(height) => height,
+            None => {
+                storage.insert_block(genesis_block)?;
+                genesis_block.height()
+            }
         };
 
-        blocks.ledger_tree.add(&genesis_block.hash())?;
-        blocks
-            .previous_hashes
-            .insert(height, genesis_block.previous_block_hash());
-        blocks.headers.insert(height, genesis_block.header().clone());
-        blocks.transactions.insert(height, genesis_block.transactions().clone());
+        // Ensure the storage contains the expected genesis block.
+        if storage.get_block_hash(genesis_block.height())? != Some(genesis_block.hash()) {
+            return Err(anyhow!("The given storage contains a mismatching genesis block"));
+        }
 
-        Ok(blocks)
+        // Retrieve the block hashes, in order of block height.
+        let block_hashes = (0..=latest_height)
+            .map(|height| match storage.get_block_hash(height)? {
+                Some(block_hash) => Ok(block_hash),
+                None => Err(anyhow!("Missing block hash for height {} in storage", height)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Reconstruct the ledger tree from the block hashes.
+        let mut ledger_tree = LedgerTree::<N>::new()?;
+        ledger_tree.add_all(&block_hashes)?;
+
+        Ok(Self {
+            current_height: latest_height,
+            current_hash: block_hashes[latest_height as usize],
+            ledger_tree,
+            storage,
+        })
     }
 
     /// Returns the latest block height.
@@ -94,7 +110,7 @@ impl<N: Network> Blocks<N> {
     }
 
     /// Returns the latest block transactions.
-    pub fn latest_block_transactions(&self) -> Result<&Transactions<N>> {
+    pub fn latest_block_transactions(&self) -> Result<Transactions<N>> {
         self.get_block_transactions(self.current_height)
     }
 
@@ -105,23 +121,23 @@ impl<N: Network> Blocks<N> {
 
     /// Returns the previous block hash given the block height.
     pub fn get_previous_block_hash(&self, height: u32) -> Result<N::BlockHash> {
-        match self.previous_hashes.get(&height) {
-            Some(previous_hash) => Ok(*previous_hash),
+        match self.storage.get_previous_block_hash(height)? {
+            Some(previous_hash) => Ok(previous_hash),
             None => Err(anyhow!("Missing previous block hash for height {}", height)),
         }
     }
 
     /// Returns the block header given the block height.
-    pub fn get_block_header(&self, height: u32) -> Result<&BlockHeader<N>> {
-        match self.headers.get(&height) {
+    pub fn get_block_header(&self, height: u32) -> Result<BlockHeader<N>> {
+        match self.storage.get_block_header(height)? {
             Some(header) => Ok(header),
             None => Err(anyhow!("Missing block header for height {}", height)),
         }
     }
 
     /// Returns the block transactions given the block height.
-    pub fn get_block_transactions(&self, height: u32) -> Result<&Transactions<N>> {
-        match self.transactions.get(&height) {
+    pub fn get_block_transactions(&self, height: u32) -> Result<Transactions<N>> {
+        match self.storage.get_block_transactions(height)? {
             Some(transactions) => Ok(transactions),
             None => Err(anyhow!("Missing block transactions for height {}", height)),
         }
@@ -131,10 +147,11 @@ impl<N: Network> Blocks<N> {
     pub fn get_block(&self, height: u32) -> Result<Block<N>> {
         match height == 0 {
             true => Ok(N::genesis_block().clone()),
-            false => Ok(Block::from(
+            // The block was checked before it was added to storage, so it is not re-verified here.
+            false => Ok(Block::from_unchecked(
                 self.get_previous_block_hash(height)?,
-                self.get_block_header(height)?.clone(),
-                self.get_block_transactions(height)?.clone(),
+                self.get_block_header(height)?,
+                self.get_block_transactions(height)?,
             )?),
         }
     }
@@ -145,177 +162,206 @@ impl<N: Network> Blocks<N> {
             return Err(anyhow!("Given block height {} is greater than current height", height));
         }
 
-        match height == self.current_height {
-            true => Ok(self.current_hash),
-            false => match self.previous_hashes.get(&(height + 1)) {
-                Some(block_hash) => Ok(*block_hash),
-                None => Err(anyhow!("Missing block hash for height {}", height)),
-            },
+        match self.storage.get_block_hash(height)? {
+            Some(block_hash) => Ok(block_hash),
+            None => Err(anyhow!("Missing block hash for height {}", height)),
+        }
+    }
+
+    /// Returns the block height given the block hash.
+    pub fn get_block_height(&self, block_hash: &N::BlockHash) -> Result<u32> {
+        match self.storage.get_block_height(block_hash)? {
+            Some(height) => Ok(height),
+            None => Err(anyhow!("Block {} does not exist in the ledger", block_hash)),
+        }
+    }
+
+    /// Returns the block height at which the given ledger root was the latest ledger root.
+    pub fn get_ledger_root_height(&self, ledger_root: &N::LedgerRoot) -> Result<u32> {
+        if *ledger_root == self.latest_ledger_root() {
+            return Ok(self.current_height);
+        }
+
+        match self.storage.get_ledger_root_height(ledger_root)? {
+            Some(height) => Ok(height.saturating_sub(1)),
+            None => Err(anyhow!("Ledger root {} does not exist in the ledger", ledger_root)),
         }
     }
 
     /// Returns `true` if the given block height exists.
-    pub fn contains_height(&self, height: u32) -> bool {
-        self.previous_hashes.contains_key(&height)
-            || self.headers.contains_key(&height)
-            || self.transactions.contains_key(&height)
+    pub fn contains_height(&self, height: u32) -> Result<bool> {
+        Ok(self.storage.get_block_hash(height)?.is_some())
     }
 
     /// Returns `true` if the given ledger root exists.
-    pub fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> bool {
-        *ledger_root == self.latest_ledger_root()
-            || self
-                .headers
-                .values()
-                .map(BlockHeader::previous_ledger_root)
-                .any(|root| root == *ledger_root)
+    pub fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> Result<bool> {
+        Ok(*ledger_root == self.latest_ledger_root() || self.storage.contains_ledger_root(ledger_root)?)
     }
 
     /// Returns `true` if the given block hash exists.
-    pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> bool {
-        self.current_hash == *block_hash || self.previous_hashes.values().any(|hash| *hash == *block_hash)
+    pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> Result<bool> {
+        Ok(self.storage.get_block_height(block_hash)?.is_some())
     }
 
     /// Returns `true` if the given transaction exists.
-    pub fn contains_transaction(&self, transaction: &Transaction<N>) -> bool {
-        self.transactions
-            .values()
-            .flat_map(|transactions| &**transactions)
-            .any(|tx| *tx == *transaction)
+    pub fn contains_transaction(&self, transaction: &Transaction<N>) -> Result<bool> {
+        self.storage.contains_transaction_id(&transaction.transaction_id())
     }
 
     /// Returns `true` if the given serial number exists.
-    pub fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> bool {
-        self.transactions
-            .values()
-            .flat_map(|transactions| (**transactions).iter().map(Transaction::serial_numbers))
-            .any(|mut serial_numbers| serial_numbers.contains(serial_number))
+    pub fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> Result<bool> {
+        self.storage.contains_serial_number(serial_number)
     }
 
     /// Returns `true` if the given commitment exists.
-    pub fn contains_commitment(&self, commitment: &N::Commitment) -> bool {
-        self.transactions
-            .values()
-            .flat_map(|transactions| (**transactions).iter().map(Transaction::commitments))
-            .any(|mut commitments| commitments.contains(commitment))
+    pub fn contains_commitment(&self, commitment: &N::Commitment) -> Result<bool> {
+        self.storage.contains_commitment(commitment)
     }
 
-    /// Adds the given block as the next block in the chain.
-    pub fn add_next(&mut self, block: &Block<N>) -> Result<()> {
+    ///
+    /// Ensures the given block is valid as the next block in the chain,
+    /// returning the consensus rule it violates otherwise. This check does not modify the chain.
+    ///
+    pub fn validate_next(&self, block: &Block<N>) -> Result<(), BlockValidationError<N>> {
         // Ensure the block itself is valid.
-        if !block.is_valid() {
-            return Err(anyhow!("The given block is invalid"));
-        }
-
-        // Ensure the next block height is correct.
-        let height = block.height();
-        if self.current_height + 1 != height {
-            return Err(anyhow!("The given block has an incorrect block height"));
-        }
+        block.validate()?;
+
+        // Ensure the block header is valid as the next block header.
+        let current_block_header = self
+            .storage
+            .get_block_header(self.current_height)
+            .map_err(BlockValidationError::StorageError)?
+            .ok_or(BlockValidationError::MissingBlockHeader(self.current_height))?;
+        Blocks::<N>::validate_next_header(
+            &current_block_header,
+            self.latest_ledger_root(),
+            block.header(),
+            |height| self.storage.get_block_header(height),
+        )?;
 
         // Ensure the block height does not already exist.
-        if self.contains_height(height) {
-            return Err(anyhow!("The given block height already exists in the ledger"));
+        let height = block.height();
+        if self.contains_height(height).map_err(BlockValidationError::StorageError)? {
+            return Err(BlockValidationError::BlockHeightExists(height));
         }
 
         // Ensure the previous block hash is correct.
         if self.current_hash != block.previous_block_hash() {
-            return Err(anyhow!("The given block has an incorrect previous block hash"));
+            return Err(BlockValidationError::IncorrectPreviousBlockHash {
+                expected: self.current_hash,
+                found: block.previous_block_hash(),
+            });
         }
 
         // Ensure the block hash does not already exist.
         let block_hash = block.hash();
-        if self.contains_block_hash(&block_hash) {
-            return Err(anyhow!("The given block hash already exists in the ledger"));
-        }
-
-        // Ensure the next block timestamp is within the declared time limit.
-        let now = Utc::now().timestamp();
-        if block.timestamp() > (now + N::ALEO_FUTURE_TIME_LIMIT_IN_SECS) {
-            return Err(anyhow!("The given block timestamp exceeds the time limit"));
-        }
-
-        // Ensure the next block timestamp is after the current block timestamp.
-        let current_block = self.latest_block()?;
-        if block.timestamp() <= current_block.timestamp() {
-            return Err(anyhow!("The given block timestamp is before the current timestamp"));
-        }
-
-        // Ensure the expected difficulty target is met.
-        let expected_difficulty_target =
-            if N::NETWORK_ID == 2 && block.height() <= crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT {
-                Blocks::<N>::compute_difficulty_target(current_block.header(), block.timestamp(), block.height())
-            } else if N::NETWORK_ID == 2 {
-                let anchor_block_header = self.get_block_header(crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT)?;
-                Blocks::<N>::compute_difficulty_target(anchor_block_header, block.timestamp(), block.height())
-            } else {
-                Blocks::<N>::compute_difficulty_target(N::genesis_block().header(), block.timestamp(), block.height())
-            };
-        if block.difficulty_target() != expected_difficulty_target {
-            return Err(anyhow!(
-                "The given block difficulty target is incorrect. Found {}, but expected {}",
-                block.difficulty_target(),
-                expected_difficulty_target
-            ));
-        }
-
-        // Ensure the expected cumulative weight is computed correctly.
-        let expected_cumulative_weight = current_block
-            .cumulative_weight()
-            .saturating_add((u64::MAX / expected_difficulty_target) as u128);
-        if block.cumulative_weight() != expected_cumulative_weight {
-            return Err(anyhow!(
-                "The given cumulative weight is incorrect. Found {}, but expected {}",
-                block.cumulative_weight(),
-                expected_cumulative_weight
-            ));
+        if self.contains_block_hash(&block_hash).map_err(BlockValidationError::StorageError)? {
+            return Err(BlockValidationError::DuplicateBlockHash(block_hash));
         }
 
         for transaction in block.transactions().iter() {
+            let transaction_id = transaction.transaction_id();
+
             // Ensure the transaction in the block do not already exist.
-            if self.contains_transaction(transaction) {
-                return Err(anyhow!("The given block has a duplicate transaction in the ledger"));
+            if self.contains_transaction(transaction).map_err(BlockValidationError::StorageError)? {
+                return Err(BlockValidationError::DuplicateTransaction(transaction_id));
             }
+
             // Ensure the transaction in the block references a valid past or current ledger root.
-            if !self.contains_ledger_root(&transaction.ledger_root()) {
-                return Err(anyhow!(
-                    "The given transaction references a non-existent ledger root {}",
-                    &transaction.ledger_root()
+            if !self.contains_ledger_root(&transaction.ledger_root()).map_err(BlockValidationError::StorageError)? {
+                return Err(BlockValidationError::UnknownLedgerRoot(
+                    transaction_id,
+                    transaction.ledger_root(),
                 ));
             }
-        }
 
-        // Ensure the ledger does not already contain a given serial numbers.
-        for serial_number in block.serial_numbers() {
-            if self.contains_serial_number(serial_number) {
-                return Err(anyhow!("Serial number already exists in the ledger"));
+            // Ensure the ledger does not already contain a given serial numbers.
+            for serial_number in transaction.serial_numbers() {
+                if self.contains_serial_number(serial_number).map_err(BlockValidationError::StorageError)? {
+                    return Err(BlockValidationError::SerialNumberExists(transaction_id, *serial_number));
+                }
             }
-        }
 
-        // Ensure the ledger does not already contain a given commitments.
-        for commitment in block.commitments() {
-            if self.contains_commitment(commitment) {
-                return Err(anyhow!("Commitment already exists in the ledger"));
+            // Ensure the ledger does not already contain a given commitments.
+            for commitment in transaction.commitments() {
+                if self.contains_commitment(commitment).map_err(BlockValidationError::StorageError)? {
+                    return Err(BlockValidationError::CommitmentExists(transaction_id, *commitment));
+                }
             }
         }
 
+        Ok(())
+    }
+
+    /// Adds the given block as the next block in the chain.
+    pub fn add_next(&mut self, block: &Block<N>) -> Result<()> {
+        // Ensure the block is valid as the next block in the chain.
+        self.validate_next(block)?;
+
+        self.add_next_unchecked(block)
+    }
+
+    /// Adds the given block as the next block in the chain, without validating it.
+    /// This is only used to restore blocks that were previously validated and removed from the chain.
+    pub(crate) fn add_next_unchecked(&mut self, block: &Block<N>) -> Result<()> {
+        let height = block.height();
+        let block_hash = block.hash();
+
         // Add the block to the ledger. This code section executes atomically.
         {
-            let mut blocks = self.clone();
+            let mut ledger_tree = self.ledger_tree.clone();
+            ledger_tree.add(&block_hash)?;
 
-            blocks.current_height = height;
-            blocks.current_hash = block_hash;
-            blocks.ledger_tree.add(&block.hash())?;
-            blocks.previous_hashes.insert(height, block.previous_block_hash());
-            blocks.headers.insert(height, block.header().clone());
-            blocks.transactions.insert(height, block.transactions().clone());
+            self.storage.insert_block(block)?;
 
-            *self = blocks;
+            self.current_height = height;
+            self.current_hash = block_hash;
+            self.ledger_tree = ledger_tree;
         }
 
         Ok(())
     }
 
+    /// Removes the latest given number of blocks from the chain,
+    /// returning the removed blocks in ascending order of block height.
+    pub fn remove_last(&mut self, num_blocks: u32) -> Result<Vec<Block<N>>> {
+        // Ensure the genesis block is not removed.
+        if num_blocks > self.current_height {
+            return Err(anyhow!("Cannot remove {} blocks from a chain of height {}", num_blocks, self.current_height));
+        }
+
+        // Ensure the number of blocks to remove is within the maximum fork depth.
+        if num_blocks > N::ALEO_MAXIMUM_FORK_DEPTH {
+            return Err(anyhow!("Cannot remove {} blocks beyond the maximum fork depth", num_blocks));
+        }
+
+        // Retrieve the blocks to remove.
+        let height = self.current_height - num_blocks;
+        let blocks = (height + 1..=self.current_height)
+            .map(|height| self.get_block(height))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Reconstruct the ledger tree from the remaining block hashes.
+        let block_hashes = (0..=height)
+            .map(|height| self.get_block_hash(height))
+            .collect::<Result<Vec<_>>>()?;
+        let mut ledger_tree = LedgerTree::<N>::new()?;
+        ledger_tree.add_all(&block_hashes)?;
+
+        // Remove the blocks from the ledger. This code section executes atomically.
+        {
+            if num_blocks > 0 {
+                self.storage.remove_blocks(height + 1)?;
+            }
+
+            self.current_height = height;
+            self.current_hash = block_hashes[height as usize];
+            self.ledger_tree = ledger_tree;
+        }
+
+        Ok(blocks)
+    }
+
     /// Returns the ledger tree.
     pub fn to_ledger_tree(&self) -> &LedgerTree<N> {
         &self.ledger_tree
@@ -332,66 +378,43 @@ impl<N: Network> Blocks<N> {
     ///
     /// Returns a ledger proof for the given commitment.
     ///
+    /// The record proof is anchored to the block containing the commitment, rather than the latest block,
+    /// so that records created before the latest block can also be proven.
+    ///
     pub fn to_ledger_proof(&self, commitment: N::Commitment) -> Result<LedgerProof<N>> {
-        // TODO (howardwu): Optimize this operation.
-        let transaction = self
-            .transactions
-            .values()
-            .flat_map(|transactions| &**transactions)
-            .filter(|transaction| transaction.commitments().contains(&commitment))
-            .collect::<Vec<_>>();
-        assert_eq!(1, transaction.len()); // TODO (howardwu): Clean this up with a proper error handler.
-        let transaction = transaction[0];
-        let local_proof = {
-            // Initialize a transitions tree.
-            let mut transitions_tree = Transitions::<N>::new()?;
-            // Add all given transition IDs to the tree.
-            transitions_tree.add_all(transaction.transitions())?;
-            // Return the local proof for the transitions tree.
-            transitions_tree.to_local_proof(commitment)?
+        // Retrieve the transaction ID and block height containing the commitment.
+        let transaction_id = match self.storage.get_commitment_transaction_id(&commitment)? {
+            Some(transaction_id) => transaction_id,
+            None => return Err(anyhow!("Commitment {} does not exist in the ledger", commitment)),
+        };
+        let block_height = match self.storage.get_transaction_height(&transaction_id)? {
+            Some(block_height) => block_height,
+            None => return Err(anyhow!("Transaction {} does not exist in the ledger", transaction_id)),
         };
-        let transaction_id = local_proof.transaction_id();
-
-        // TODO (howardwu): Optimize this operation.
-        let block_height = self
-            .transactions
-            .iter()
-            .filter_map(
-                |(block_height, transactions)| match transactions.transaction_ids().contains(&transaction_id) {
-                    true => Some(block_height),
-                    false => None,
-                },
-            )
-            .collect::<Vec<_>>();
-        assert_eq!(1, block_height.len()); // TODO (howardwu): Clean this up with a proper error handler.
-        let block_height = *block_height[0];
         let transactions = self.get_block_transactions(block_height)?;
         let block_header = self.get_block_header(block_height)?;
 
-        // Compute the transactions inclusion proof.
-        let transactions_inclusion_proof = {
-            // TODO (howardwu): Optimize this operation.
-            let index = transactions
-                .transaction_ids()
-                .enumerate()
-                .filter_map(|(index, id)| match id == transaction_id {
-                    true => Some(index),
-                    false => None,
-                })
-                .collect::<Vec<_>>();
-            assert_eq!(1, index.len()); // TODO (howardwu): Clean this up with a proper error handler.
-            transactions.to_transactions_inclusion_proof(index[0], transaction_id)?
+        // Retrieve the index of the transaction in the block.
+        let index = match transactions.transaction_ids().position(|id| id == transaction_id) {
+            Some(index) => index,
+            None => return Err(anyhow!("Transaction {} is missing from block {}", transaction_id, block_height)),
         };
 
+        // Compute the local proof.
+        let local_proof = transactions[index].to_local_proof(commitment)?;
+
+        // Compute the transactions inclusion proof.
+        let transactions_inclusion_proof = transactions.to_transactions_inclusion_proof(index, transaction_id)?;
+
         // Compute the block header inclusion proof.
         let transactions_root = transactions.transactions_root();
         let block_header_inclusion_proof = block_header.to_header_inclusion_proof(1, transactions_root)?;
         let block_header_root = block_header.to_header_root()?;
-        let previous_block_hash = self.get_previous_block_hash(self.current_height)?;
-        let current_block_hash = self.current_hash;
+        let previous_block_hash = self.get_previous_block_hash(block_height)?;
+        let block_hash = self.get_block_hash(block_height)?;
 
         let record_proof = RecordProof::new(
-            current_block_hash,
+            block_hash,
             previous_block_hash,
             block_header_root,
             block_header_inclusion_proof,
@@ -401,10 +424,93 @@ impl<N: Network> Blocks<N> {
         )?;
 
         let ledger_root = self.latest_ledger_root();
-        let ledger_root_inclusion_proof = self.to_ledger_root_inclusion_proof(&current_block_hash)?;
+        let ledger_root_inclusion_proof = self.to_ledger_root_inclusion_proof(&block_hash)?;
 
         LedgerProof::new(ledger_root, ledger_root_inclusion_proof, record_proof)
     }
+}
+
+impl<N: Network> Blocks<N> {
+    ///
+    /// Ensures the given block header is valid as the next block header after the given
+    /// current block header and ledger root, returning the consensus rule it violates otherwise.
+    /// The block header at a given height is retrieved with `get_block_header`,
+    /// which returns `None` if the header is not found.
+    ///
+    /// This check is shared by `Blocks` and `HeaderChain`, and does not verify the PoSW proof.
+    ///
+    pub fn validate_next_header<F: Fn(u32) -> Result<Option<BlockHeader<N>>>>(
+        current_block_header: &BlockHeader<N>,
+        current_ledger_root: N::LedgerRoot,
+        header: &BlockHeader<N>,
+        get_block_header: F,
+    ) -> Result<(), BlockValidationError<N>> {
+        // Ensure the next block height is correct.
+        if current_block_header.height() + 1 != header.height() {
+            return Err(BlockValidationError::IncorrectBlockHeight {
+                expected: current_block_header.height() + 1,
+                found: header.height(),
+            });
+        }
+
+        // Ensure the previous ledger root is the current ledger root.
+        if current_ledger_root != header.previous_ledger_root() {
+            return Err(BlockValidationError::IncorrectPreviousLedgerRoot {
+                expected: current_ledger_root,
+                found: header.previous_ledger_root(),
+            });
+        }
+
+        // Ensure the next block timestamp is within the declared time limit.
+        let limit = Utc::now().timestamp() + N::ALEO_FUTURE_TIME_LIMIT_IN_SECS;
+        if header.timestamp() > limit {
+            return Err(BlockValidationError::FutureTimestamp {
+                timestamp: header.timestamp(),
+                limit,
+            });
+        }
+
+        // Ensure the next block timestamp is after the current block timestamp.
+        if header.timestamp() <= current_block_header.timestamp() {
+            return Err(BlockValidationError::NonIncreasingTimestamp {
+                timestamp: header.timestamp(),
+                previous_timestamp: current_block_header.timestamp(),
+            });
+        }
+
+        // Ensure the expected difficulty target is met.
+        let expected_difficulty_target =
+            if N::NETWORK_ID == 2 && header.height() <= crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT {
+                Self::compute_difficulty_target(current_block_header, header.timestamp(), header.height())
+            } else if N::NETWORK_ID == 2 {
+                let anchor_height = crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT;
+                let anchor_block_header = get_block_header(anchor_height)
+                    .map_err(BlockValidationError::StorageError)?
+                    .ok_or(BlockValidationError::MissingBlockHeader(anchor_height))?;
+                Self::compute_difficulty_target(&anchor_block_header, header.timestamp(), header.height())
+            } else {
+                Self::compute_difficulty_target(N::genesis_block().header(), header.timestamp(), header.height())
+            };
+        if header.difficulty_target() != expected_difficulty_target {
+            return Err(BlockValidationError::IncorrectDifficultyTarget {
+                expected: expected_difficulty_target,
+                found: header.difficulty_target(),
+            });
+        }
+
+        // Ensure the expected cumulative weight is computed correctly.
+        let expected_cumulative_weight = current_block_header
+            .cumulative_weight()
+            .saturating_add((u64::MAX / expected_difficulty_target) as u128);
+        if header.cumulative_weight() != expected_cumulative_weight {
+            return Err(BlockValidationError::IncorrectCumulativeWeight {
+                expected: expected_cumulative_weight,
+                found: header.cumulative_weight(),
+            });
+        }
+
+        Ok(())
+    }
 
     /// Returns the expected difficulty target given the previous block and expected next block details.
     pub fn compute_difficulty_target(
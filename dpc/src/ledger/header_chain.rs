@@ -0,0 +1,356 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+/// A header-only chain of blocks, which validates the PoSW proof and difficulty of each
+/// block header, and verifies ledger proofs without the transactions of each block.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct HeaderChain<N: Network> {
+    /// The block headers, in order of block height.
+    headers: Vec<BlockHeader<N>>,
+    /// The block hashes, in order of block height.
+    block_hashes: Vec<N::BlockHash>,
+    /// The map of block hashes to block heights.
+    block_heights: HashMap<N::BlockHash, u32>,
+    /// The map of ledger roots to the block height they were computed at.
+    ledger_roots: HashMap<N::LedgerRoot, u32>,
+    /// The current ledger tree.
+    ledger_tree: LedgerTree<N>,
+}
+
+impl<N: Network> HeaderChain<N> {
+    /// Initializes a new instance of `HeaderChain` with the genesis block header.
+    pub fn new() -> Result<Self> {
+        let genesis_block = N::genesis_block();
+
+        // Ensure the genesis block header is valid.
+        if !genesis_block.header().is_genesis() {
+            return Err(anyhow!("Invalid genesis block header"));
+        }
+
+        let mut ledger_tree = LedgerTree::<N>::new()?;
+        ledger_tree.add(&genesis_block.hash())?;
+
+        Ok(Self {
+            headers: vec![genesis_block.header().clone()],
+            block_hashes: vec![genesis_block.hash()],
+            block_heights: [(genesis_block.hash(), genesis_block.height())]
+                .iter()
+                .cloned()
+                .collect(),
+            ledger_roots: [(ledger_tree.root(), genesis_block.height())].iter().cloned().collect(),
+            ledger_tree,
+        })
+    }
+
+    /// Returns the latest block height.
+    pub fn latest_block_height(&self) -> u32 {
+        (self.headers.len() - 1) as u32
+    }
+
+    /// Returns the latest block hash.
+    pub fn latest_block_hash(&self) -> N::BlockHash {
+        self.block_hashes[self.block_hashes.len() - 1]
+    }
+
+    /// Returns the latest block header.
+    pub fn latest_block_header(&self) -> &BlockHeader<N> {
+        &self.headers[self.headers.len() - 1]
+    }
+
+    /// Returns the latest ledger root.
+    pub fn latest_ledger_root(&self) -> N::LedgerRoot {
+        self.ledger_tree.root()
+    }
+
+    /// Returns the block header given the block height.
+    pub fn get_block_header(&self, height: u32) -> Result<&BlockHeader<N>> {
+        match self.headers.get(height as usize) {
+            Some(header) => Ok(header),
+            None => Err(anyhow!("Missing block header for height {}", height)),
+        }
+    }
+
+    /// Returns the block hash given the block height.
+    pub fn get_block_hash(&self, height: u32) -> Result<N::BlockHash> {
+        match self.block_hashes.get(height as usize) {
+            Some(block_hash) => Ok(*block_hash),
+            None => Err(anyhow!("Missing block hash for height {}", height)),
+        }
+    }
+
+    /// Returns the block height given the block hash.
+    pub fn get_block_height(&self, block_hash: &N::BlockHash) -> Result<u32> {
+        match self.block_heights.get(block_hash) {
+            Some(height) => Ok(*height),
+            None => Err(anyhow!("Missing block height for block hash {}", block_hash)),
+        }
+    }
+
+    /// Returns `true` if the given block hash exists.
+    pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> bool {
+        self.block_heights.contains_key(block_hash)
+    }
+
+    /// Returns `true` if the given ledger root exists.
+    pub fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> bool {
+        self.ledger_roots.contains_key(ledger_root)
+    }
+
+    /// Adds the given block header as the next block in the chain.
+    pub fn add_next(&mut self, header: &BlockHeader<N>) -> Result<()> {
+        // Ensure the block header itself is valid, including its PoSW proof.
+        if !header.is_valid() {
+            return Err(anyhow!("The given block header is invalid"));
+        }
+
+        // Ensure the block header is valid as the next block header.
+        Blocks::<N>::validate_next_header(
+            self.latest_block_header(),
+            self.latest_ledger_root(),
+            header,
+            |height| Ok(self.headers.get(height as usize).cloned()),
+        )?;
+
+        // Ensure the block hash does not already exist.
+        let height = header.height();
+        let block_hash = header.to_block_hash(self.latest_block_hash())?;
+        if self.contains_block_hash(&block_hash) {
+            return Err(anyhow!("The given block hash already exists in the header chain"));
+        }
+
+        // Add the block header to the chain. This code section executes atomically.
+        {
+            let mut ledger_tree = self.ledger_tree.clone();
+            ledger_tree.add(&block_hash)?;
+
+            self.headers.push(header.clone());
+            self.block_hashes.push(block_hash);
+            self.block_heights.insert(block_hash, height);
+            self.ledger_roots.insert(ledger_tree.root(), height);
+            self.ledger_tree = ledger_tree;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the latest given number of block headers from the chain,
+    /// returning the removed block headers in ascending order of block height.
+    pub fn remove_last(&mut self, num_headers: u32) -> Result<Vec<BlockHeader<N>>> {
+        // Ensure the genesis block header is not removed.
+        if num_headers > self.latest_block_height() {
+            return Err(anyhow!("Cannot remove the genesis block header"));
+        }
+
+        // Reconstruct the ledger tree from the remaining block hashes.
+        let start_height = self.latest_block_height() + 1 - num_headers;
+        let mut ledger_tree = LedgerTree::<N>::new()?;
+        ledger_tree.add_all(&self.block_hashes[..start_height as usize])?;
+
+        // Remove the block headers from the chain. This code section executes atomically.
+        let removed_headers = {
+            for block_hash in self.block_hashes.drain(start_height as usize..) {
+                self.block_heights.remove(&block_hash);
+            }
+            self.ledger_roots.retain(|_, height| *height < start_height);
+            self.ledger_tree = ledger_tree;
+            self.headers.split_off(start_height as usize)
+        };
+
+        Ok(removed_headers)
+    }
+
+    ///
+    /// Verifies the given ledger proof for the given commitment against the header chain.
+    ///
+    pub fn verify_ledger_proof(&self, commitment: N::Commitment, ledger_proof: &LedgerProof<N>) -> Result<()> {
+        // Ensure the ledger proof is for the given commitment.
+        if ledger_proof.commitment() != commitment {
+            return Err(anyhow!(
+                "Ledger proof is for commitment {}, expected {}",
+                ledger_proof.commitment(),
+                commitment
+            ));
+        }
+
+        // Ensure the ledger root exists in the header chain.
+        let ledger_root = ledger_proof.ledger_root();
+        if !self.contains_ledger_root(&ledger_root) {
+            return Err(anyhow!(
+                "Ledger root {} does not exist in the header chain",
+                ledger_root
+            ));
+        }
+
+        // Ensure the block hash belongs to the ledger root.
+        let block_hash = ledger_proof.block_hash();
+        if !ledger_proof
+            .ledger_root_inclusion_proof()
+            .verify(&ledger_root, &block_hash)?
+        {
+            return Err(anyhow!(
+                "Block hash {} does not belong to ledger root {}",
+                block_hash,
+                ledger_root
+            ));
+        }
+
+        // Ensure the block hash and previous block hash exist in the header chain.
+        let height = self.get_block_height(&block_hash)?;
+        let expected_previous_block_hash = match height == 0 {
+            true => LedgerProof::<N>::default().block_hash(),
+            false => self.get_block_hash(height - 1)?,
+        };
+        if ledger_proof.previous_block_hash() != expected_previous_block_hash {
+            return Err(anyhow!(
+                "Previous block hash {} does not match block {} in the header chain",
+                ledger_proof.previous_block_hash(),
+                height
+            ));
+        }
+
+        // Ensure the block header root matches the block header in the header chain.
+        let header = self.get_block_header(height)?;
+        if ledger_proof.block_header_root() != header.to_header_root()? {
+            return Err(anyhow!(
+                "Block header root {} does not match block {} in the header chain",
+                ledger_proof.block_header_root(),
+                height
+            ));
+        }
+
+        // Ensure the transactions root matches the block header in the header chain.
+        let transactions_root = ledger_proof.transactions_root();
+        if transactions_root != header.transactions_root() {
+            return Err(anyhow!(
+                "Transactions root {} does not match block {} in the header chain",
+                transactions_root,
+                height
+            ));
+        }
+
+        // Ensure the transactions root belongs to the block header.
+        if !ledger_proof
+            .block_header_inclusion_proof()
+            .verify(&ledger_proof.block_header_root(), &transactions_root)?
+        {
+            return Err(anyhow!(
+                "Transactions root {} does not belong to block header {}",
+                transactions_root,
+                ledger_proof.block_header_root()
+            ));
+        }
+
+        // Ensure the transaction belongs to the transactions root.
+        let transaction_id = ledger_proof.transaction_id();
+        if !ledger_proof
+            .transactions_inclusion_proof()
+            .verify(&transactions_root, &transaction_id)?
+        {
+            return Err(anyhow!(
+                "Transaction {} does not belong to transactions root {}",
+                transaction_id,
+                transactions_root
+            ));
+        }
+
+        // Ensure the transition belongs to the transaction.
+        let transition_id = ledger_proof.transition_id();
+        if !ledger_proof
+            .transaction_inclusion_proof()
+            .verify(&transaction_id, &transition_id)?
+        {
+            return Err(anyhow!(
+                "Transition {} does not belong to transaction {}",
+                transition_id,
+                transaction_id
+            ));
+        }
+
+        // Ensure the commitment belongs to the transition.
+        if !ledger_proof
+            .transition_inclusion_proof()
+            .verify(&transition_id, &commitment)?
+        {
+            return Err(anyhow!(
+                "Commitment {} does not belong to transition {}",
+                commitment,
+                transition_id
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet1::Testnet1, testnet2::Testnet2};
+
+    use rand::thread_rng;
+    use std::sync::atomic::AtomicBool;
+
+    #[test]
+    fn test_new() {
+        let header_chain = HeaderChain::<Testnet1>::new().unwrap();
+        assert_eq!(0, header_chain.latest_block_height());
+        assert_eq!(Testnet1::genesis_block().hash(), header_chain.latest_block_hash());
+
+        let header_chain = HeaderChain::<Testnet2>::new().unwrap();
+        assert_eq!(0, header_chain.latest_block_height());
+        assert_eq!(Testnet2::genesis_block().hash(), header_chain.latest_block_hash());
+    }
+
+    #[test]
+    fn test_verify_ledger_proof() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let recipient = Account::<Testnet2>::new(rng).address();
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let mut header_chain = HeaderChain::<Testnet2>::new().unwrap();
+
+        // Mine the next block, and follow it with the header chain.
+        let record = ledger.mine_next_block(recipient, true, &terminator, rng).unwrap();
+        header_chain.add_next(ledger.latest_block().unwrap().header()).unwrap();
+        assert_eq!(ledger.latest_block_hash(), header_chain.latest_block_hash());
+        assert_eq!(ledger.latest_ledger_root(), header_chain.latest_ledger_root());
+
+        // Ensure the ledger proof for the coinbase record verifies against the header chain.
+        let ledger_proof = ledger.to_ledger_proof(record.commitment()).unwrap();
+        header_chain
+            .verify_ledger_proof(record.commitment(), &ledger_proof)
+            .unwrap();
+
+        // Ensure the ledger proof is rejected for another commitment.
+        let genesis_commitment = Testnet2::genesis_block().commitments().next().unwrap();
+        assert!(header_chain
+            .verify_ledger_proof(*genesis_commitment, &ledger_proof)
+            .is_err());
+
+        // Ensure the ledger proof is rejected once the block header is removed.
+        header_chain.remove_last(1).unwrap();
+        assert!(header_chain
+            .verify_ledger_proof(record.commitment(), &ledger_proof)
+            .is_err());
+    }
+}
@@ -21,22 +21,32 @@ use chrono::Utc;
 use rand::{CryptoRng, Rng};
 use std::{collections::HashMap, sync::atomic::AtomicBool};
 
+/// The maximum size of the unconfirmed transactions included in a block template, in bytes.
+const MAXIMUM_BLOCK_TEMPLATE_TRANSACTIONS_SIZE_IN_BYTES: usize = 2 * 1024 * 1024;
+
 #[derive(Clone, Debug)]
-pub struct Ledger<N: Network> {
+pub struct Ledger<N: Network, S: LedgerStorage<N> = MemoryStorage<N>> {
     /// The canonical chain of blocks.
-    canon_blocks: Blocks<N>,
-    /// The set of unknown orphan blocks.
-    orphan_blocks: HashMap<u32, Block<N>>,
+    canon_blocks: Blocks<N, S>,
+    /// The set of blocks on side chains, keyed by block hash.
+    side_blocks: HashMap<N::BlockHash, Block<N>>,
     /// The pool of unconfirmed transactions.
     memory_pool: MemoryPool<N>,
 }
 
 impl<N: Network> Ledger<N> {
-    /// Initializes a new instance of the ledger.
+    /// Initializes a new in-memory instance of the ledger.
     pub fn new() -> Result<Self> {
+        Self::open(MemoryStorage::new())
+    }
+}
+
+impl<N: Network, S: LedgerStorage<N>> Ledger<N, S> {
+    /// Initializes an instance of the ledger from the given storage.
+    pub fn open(storage: S) -> Result<Self> {
         Ok(Self {
-            canon_blocks: Blocks::new()?,
-            orphan_blocks: Default::default(),
+            canon_blocks: Blocks::open(storage)?,
+            side_blocks: Default::default(),
             memory_pool: MemoryPool::new(),
         })
     }
@@ -72,7 +82,7 @@ impl<N: Network> Ledger<N> {
     }
 
     /// Returns the latest block transactions.
-    pub fn latest_block_transactions(&self) -> Result<&Transactions<N>> {
+    pub fn latest_block_transactions(&self) -> Result<Transactions<N>> {
         self.canon_blocks.latest_block_transactions()
     }
 
@@ -81,68 +91,142 @@ impl<N: Network> Ledger<N> {
         self.canon_blocks.latest_block()
     }
 
+    /// Returns the block hash given the block height.
+    pub fn get_block_hash(&self, height: u32) -> Result<N::BlockHash> {
+        self.canon_blocks.get_block_hash(height)
+    }
+
+    /// Returns the block transactions given the block height.
+    pub fn get_block_transactions(&self, height: u32) -> Result<Transactions<N>> {
+        self.canon_blocks.get_block_transactions(height)
+    }
+
+    /// Returns the block given the block height.
+    pub fn get_block(&self, height: u32) -> Result<Block<N>> {
+        self.canon_blocks.get_block(height)
+    }
+
     /// Returns `true` if the given ledger root exists on the canon chain.
-    pub fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> bool {
+    pub fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> Result<bool> {
         self.canon_blocks.contains_ledger_root(ledger_root)
     }
 
     /// Returns `true` if the given block hash exists on the canon chain.
-    pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> bool {
+    pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> Result<bool> {
         self.canon_blocks.contains_block_hash(block_hash)
     }
 
     /// Returns `true` if the given transaction exists on the canon chain.
-    pub fn contains_transaction(&self, transaction: &Transaction<N>) -> bool {
+    pub fn contains_transaction(&self, transaction: &Transaction<N>) -> Result<bool> {
         self.canon_blocks.contains_transaction(transaction)
     }
 
-    /// Adds the given canon block, if it is well-formed and does not already exist.
+    /// Ensures the given block is valid as the next canon block, returning the consensus rule it violates otherwise.
+    pub fn validate_next_block(&self, block: &Block<N>) -> Result<(), BlockValidationError<N>> {
+        self.canon_blocks.validate_next(block)
+    }
+
+    /// Adds the given canon block, if it is well-formed and does not already exist,
+    /// and returns the unconfirmed transactions dropped from the memory pool, with the reason for each.
     /// Note: This method requires blocks to be added in order of canon block height.
-    pub fn add_next_block(&mut self, block: &Block<N>) -> Result<()> {
+    pub fn add_next_block(&mut self, block: &Block<N>) -> Result<Vec<(Transaction<N>, DropReason)>> {
         // Attempt to insert the block into canon.
         self.canon_blocks.add_next(block)?;
 
-        Ok(())
+        // Revalidate the memory pool against the new canon chain.
+        self.revalidate_memory_pool(&[])
     }
 
-    /// Adds the given orphan block, if it is well-formed and does not already exist.
-    pub fn add_orphan_block(&mut self, block: &Block<N>) -> Result<()> {
+    /// Adds the given block, if it is well-formed and does not already exist,
+    /// and returns the unconfirmed transactions dropped from the memory pool, with the reason for each.
+    /// If the block results in a side chain with a greater cumulative weight than the canon chain,
+    /// the canon chain is reorganized onto the side chain.
+    pub fn add_block(&mut self, block: &Block<N>) -> Result<Vec<(Transaction<N>, DropReason)>> {
+        let block_hash = block.hash();
+
         // Ensure the block does not exist in canon.
-        if self.canon_blocks.contains_block_hash(&block.hash()) {
-            return Err(anyhow!("Orphan block already exists in canon chain"));
+        if self.canon_blocks.contains_block_hash(&block_hash)? {
+            return Err(anyhow!("Block already exists in canon chain"));
+        }
+
+        // Ensure the block does not exist in a side chain.
+        if self.side_blocks.contains_key(&block_hash) {
+            return Err(anyhow!("Block already exists in a side chain"));
+        }
+
+        // Ensure the block is within the maximum fork depth.
+        if block.height().saturating_add(N::ALEO_MAXIMUM_FORK_DEPTH) <= self.latest_block_height() {
+            return Err(anyhow!("Block {} is beyond the maximum fork depth", block.height()));
         }
 
-        // Insert the block into the orphan blocks.
-        self.orphan_blocks.insert(block.height(), block.clone());
+        match block.previous_block_hash() == self.latest_block_hash() {
+            // Attempt to insert the block into canon.
+            true => self.canon_blocks.add_next(block)?,
+            // Ensure the block itself is valid, and insert the block into the side chains.
+            false => {
+                block.validate()?;
+                self.side_blocks.insert(block_hash, block.clone());
+            }
+        }
+
+        // Reorganize the canon chain, if a side chain has a greater cumulative weight.
+        let disconnected_blocks = self.update_canon_chain();
+
+        // Remove the side blocks that are beyond the maximum fork depth.
+        let latest_block_height = self.latest_block_height();
+        self.side_blocks
+            .retain(|_, block| block.height().saturating_add(N::ALEO_MAXIMUM_FORK_DEPTH) > latest_block_height);
+
+        // Revalidate the memory pool against the new canon chain.
+        self.revalidate_memory_pool(&disconnected_blocks)
+    }
 
+    /// Adds the given orphan block, if it is well-formed and does not already exist.
+    #[deprecated(note = "Blocks that do not extend the canon chain are tracked on side chains; use `add_block`")]
+    pub fn add_orphan_block(&mut self, block: &Block<N>) -> Result<()> {
+        self.add_block(block)?;
         Ok(())
     }
 
-    /// Adds the given unconfirmed transaction to the memory pool.
-    pub fn add_unconfirmed_transaction(&mut self, transaction: &Transaction<N>) -> Result<()> {
+    /// Returns the memory pool of unconfirmed transactions.
+    pub fn memory_pool(&self) -> &MemoryPool<N> {
+        &self.memory_pool
+    }
+
+    /// Returns `true` if the given block hash exists on a side chain.
+    pub fn contains_side_block_hash(&self, block_hash: &N::BlockHash) -> bool {
+        self.side_blocks.contains_key(block_hash)
+    }
+
+    /// Returns the number of blocks on side chains.
+    pub fn num_side_blocks(&self) -> usize {
+        self.side_blocks.len()
+    }
+
+    /// Adds the given unconfirmed transaction to the memory pool, returning the transactions
+    /// that were replaced or evicted from the memory pool.
+    pub fn add_unconfirmed_transaction(&mut self, transaction: &Transaction<N>) -> Result<Vec<Transaction<N>>> {
         // Ensure the transaction contains ledger roots from the canon chain.
-        if !self.canon_blocks.contains_ledger_root(&transaction.ledger_root()) {
+        if !self.canon_blocks.contains_ledger_root(&transaction.ledger_root())? {
             return Err(anyhow!("Transaction references a non-existent ledger root"));
         }
 
         // Ensure the transaction does not contain serial numbers already in the canon chain.
         for serial_number in transaction.serial_numbers() {
-            if self.canon_blocks.contains_serial_number(serial_number) {
+            if self.canon_blocks.contains_serial_number(serial_number)? {
                 return Err(anyhow!("Transaction contains a serial number already in existence"));
             }
         }
 
         // Ensure the transaction does not contain commitments already in the canon chain.
         for commitment in transaction.commitments() {
-            if self.canon_blocks.contains_commitment(commitment) {
+            if self.canon_blocks.contains_commitment(commitment)? {
                 return Err(anyhow!("Transaction contains a commitment already in existence"));
             }
         }
 
         // Attempt to add the transaction into the memory pool.
-        self.memory_pool.add_transaction(transaction)?;
-
-        Ok(())
+        self.memory_pool.add_transaction(transaction)
     }
 
     /// Mines a new block and adds it to the canon blocks.
@@ -167,7 +251,7 @@ impl<N: Network> Ledger<N> {
             let anchor_block_header = self
                 .canon_blocks
                 .get_block_header(crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT)?;
-            Blocks::<N>::compute_difficulty_target(anchor_block_header, block_timestamp, block_height)
+            Blocks::<N>::compute_difficulty_target(&anchor_block_header, block_timestamp, block_height)
         } else {
             Blocks::<N>::compute_difficulty_target(N::genesis_block().header(), block_timestamp, block_height)
         };
@@ -181,7 +265,10 @@ impl<N: Network> Ledger<N> {
         let amount = Block::<N>::block_reward(block_height);
         let (coinbase_transaction, coinbase_record) =
             Transaction::<N>::new_coinbase(recipient, amount, is_public, rng)?;
-        let transactions = Transactions::from(&[vec![coinbase_transaction], self.memory_pool.transactions()].concat())?;
+        let unconfirmed_transactions = self
+            .memory_pool
+            .transactions_for_block(MAXIMUM_BLOCK_TEMPLATE_TRANSACTIONS_SIZE_IN_BYTES);
+        let transactions = Transactions::from(&[vec![coinbase_transaction], unconfirmed_transactions].concat())?;
 
         // Retrieve the current ledger root.
         let previous_ledger_root = self.canon_blocks.latest_ledger_root();
@@ -204,12 +291,200 @@ impl<N: Network> Ledger<N> {
         // Attempt to add the block to the canon chain.
         self.add_next_block(&block)?;
 
-        // On success, clear the memory pool of its transactions.
-        self.memory_pool.clear_all_transactions();
-
         Ok(coinbase_record)
     }
 
+    /// Revalidates the unconfirmed transactions against the canon chain, and attempts to re-admit
+    /// the transactions of the given blocks disconnected from the canon chain into the memory pool.
+    /// Returns the dropped transactions, with the reason for each.
+    ///
+    /// This pass also drops the transactions whose ledger root has expired or is no longer canonical,
+    /// so the memory pool does not need a separate expiry pass.
+    fn revalidate_memory_pool(
+        &mut self,
+        disconnected_blocks: &[Block<N>],
+    ) -> Result<Vec<(Transaction<N>, DropReason)>> {
+        let mut dropped_transactions = Vec::new();
+
+        // Remove the unconfirmed transactions that are no longer valid on the canon chain.
+        for transaction in self.memory_pool.transactions() {
+            if let Some(reason) = self.check_unconfirmed_transaction(&transaction)? {
+                self.memory_pool.remove_transaction(&transaction);
+                dropped_transactions.push((transaction, reason));
+            }
+        }
+
+        // Re-admit the transactions of the disconnected blocks, skipping the coinbase transactions.
+        for transaction in disconnected_blocks
+            .iter()
+            .flat_map(|block| block.transactions().iter())
+            .filter(|transaction| !transaction.value_balance().is_negative())
+        {
+            if let Some(reason) = self.check_unconfirmed_transaction(transaction)? {
+                // Transactions that remain on the canon chain are not dropped.
+                if reason != DropReason::Confirmed {
+                    dropped_transactions.push((transaction.clone(), reason));
+                }
+                continue;
+            }
+
+            match self.memory_pool.add_transaction(transaction) {
+                Ok(evicted_transactions) => dropped_transactions.extend(
+                    evicted_transactions
+                        .into_iter()
+                        .map(|evicted_transaction| (evicted_transaction, DropReason::Evicted)),
+                ),
+                Err(_) => dropped_transactions.push((transaction.clone(), DropReason::Rejected)),
+            }
+        }
+
+        Ok(dropped_transactions)
+    }
+
+    /// Returns the reason the given unconfirmed transaction is invalid on the canon chain, if any.
+    fn check_unconfirmed_transaction(&self, transaction: &Transaction<N>) -> Result<Option<DropReason>> {
+        // Ensure the transaction does not exist in the canon chain.
+        if self.canon_blocks.contains_transaction(transaction)? {
+            return Ok(Some(DropReason::Confirmed));
+        }
+
+        // Ensure the transaction does not contain serial numbers already in the canon chain.
+        for serial_number in transaction.serial_numbers() {
+            if self.canon_blocks.contains_serial_number(serial_number)? {
+                return Ok(Some(DropReason::ConflictingSerialNumber));
+            }
+        }
+
+        // Ensure the transaction does not contain commitments already in the canon chain.
+        for commitment in transaction.commitments() {
+            if self.canon_blocks.contains_commitment(commitment)? {
+                return Ok(Some(DropReason::ConflictingCommitment));
+            }
+        }
+
+        // Ensure the ledger root of the transaction is recent on the canon chain.
+        let ledger_root_height = match self.canon_blocks.get_ledger_root_height(&transaction.ledger_root()) {
+            Ok(height) => height,
+            Err(_) => return Ok(Some(DropReason::UnknownLedgerRoot)),
+        };
+        match self.latest_block_height().saturating_sub(ledger_root_height) > self.memory_pool.maximum_ledger_root_age()
+        {
+            true => Ok(Some(DropReason::ExpiredLedgerRoot)),
+            false => Ok(None),
+        }
+    }
+
+    /// Reorganizes the canon chain onto the side chain with the greatest cumulative weight,
+    /// if it exceeds the cumulative weight of the canon chain. Returns the blocks disconnected from the canon chain.
+    fn update_canon_chain(&mut self) -> Vec<Block<N>> {
+        // Sort the side blocks in descending order of cumulative weight.
+        let mut side_tips = self
+            .side_blocks
+            .values()
+            .map(|block| (block.cumulative_weight(), block.hash()))
+            .collect::<Vec<_>>();
+        side_tips.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        for (cumulative_weight, block_hash) in side_tips {
+            // Ensure the side chain has a greater cumulative weight than the canon chain.
+            match self.latest_cumulative_weight() {
+                Ok(latest_cumulative_weight) if cumulative_weight > latest_cumulative_weight => (),
+                _ => return Vec::new(),
+            }
+
+            // Retrieve the side chain, skipping it if it does not connect to the canon chain.
+            let (fork_height, side_chain) = match self.get_side_chain(&block_hash) {
+                Ok(Some(side_chain)) => side_chain,
+                _ => continue,
+            };
+
+            // Attempt to reorganize the canon chain onto the side chain.
+            match self.reorganize(fork_height, &side_chain) {
+                Ok(disconnected_blocks) => return disconnected_blocks,
+                Err(error) => eprintln!("Failed to reorganize onto side chain {}: {}", block_hash, error),
+            }
+        }
+
+        Vec::new()
+    }
+
+    /// Returns the fork height and the blocks of the side chain ending in the given block hash,
+    /// in ascending order of block height. Returns `None` if the side chain does not connect to the canon chain.
+    fn get_side_chain(&self, block_hash: &N::BlockHash) -> Result<Option<(u32, Vec<Block<N>>)>> {
+        let mut side_chain = Vec::new();
+
+        // Walk back the side chain until reaching a block that is not on a side chain.
+        let mut block_hash = *block_hash;
+        while let Some(block) = self.side_blocks.get(&block_hash) {
+            side_chain.push(block.clone());
+            block_hash = block.previous_block_hash();
+        }
+
+        // Ensure the side chain connects to the canon chain.
+        if !self.canon_blocks.contains_block_hash(&block_hash)? {
+            return Ok(None);
+        }
+        let fork_height = self.canon_blocks.get_block_height(&block_hash)?;
+
+        side_chain.reverse();
+        Ok(Some((fork_height, side_chain)))
+    }
+
+    /// Rolls back the canon chain to the given fork height, and applies the given side chain,
+    /// returning the blocks disconnected from the canon chain.
+    /// If any block of the side chain is invalid, the invalid block is removed from the side chains,
+    /// and the canon chain is restored.
+    fn reorganize(&mut self, fork_height: u32, side_chain: &[Block<N>]) -> Result<Vec<Block<N>>> {
+        // Ensure the fork is within the maximum fork depth.
+        let fork_depth = self.latest_block_height().saturating_sub(fork_height);
+        if fork_depth > N::ALEO_MAXIMUM_FORK_DEPTH {
+            return Err(anyhow!("The fork depth {} exceeds the maximum fork depth", fork_depth));
+        }
+
+        // Roll back the canon chain to the fork height.
+        let removed_blocks = self.canon_blocks.remove_last(fork_depth)?;
+
+        // Apply the side chain to the canon chain.
+        for (index, block) in side_chain.iter().enumerate() {
+            if let Err(error) = self.canon_blocks.add_next(block) {
+                // Remove the invalid block from the side chains.
+                self.side_blocks.remove(&block.hash());
+
+                // Restore the canon chain, before returning the error.
+                if let Err(restore_error) = self.restore_canon_chain(index as u32, &removed_blocks) {
+                    return Err(anyhow!(
+                        "{}, and failed to restore the canon chain: {}",
+                        error,
+                        restore_error
+                    ));
+                }
+
+                return Err(error);
+            }
+        }
+
+        // Move the applied blocks out of the side chains, and the removed blocks into the side chains.
+        for block in side_chain {
+            self.side_blocks.remove(&block.hash());
+        }
+        for block in &removed_blocks {
+            self.side_blocks.insert(block.hash(), block.clone());
+        }
+
+        Ok(removed_blocks)
+    }
+
+    /// Removes the given number of side chain blocks applied to the canon chain, and re-adds the given blocks
+    /// removed from the canon chain. The removed blocks were validated when they were first added,
+    /// so they are restored without validating them again.
+    fn restore_canon_chain(&mut self, num_applied_blocks: u32, removed_blocks: &[Block<N>]) -> Result<()> {
+        self.canon_blocks.remove_last(num_applied_blocks)?;
+        for block in removed_blocks {
+            self.canon_blocks.add_next_unchecked(block)?;
+        }
+        Ok(())
+    }
+
     /// Returns the ledger tree.
     pub fn to_ledger_tree(&self) -> &LedgerTree<N> {
         self.canon_blocks.to_ledger_tree()
@@ -227,6 +502,13 @@ impl<N: Network> Ledger<N> {
 mod tests {
     use super::*;
     use crate::{testnet1::Testnet1, testnet2::Testnet2};
+    #[cfg(feature = "devnet")]
+    use crate::{
+        devnet::Devnet,
+        ledger::memory_pool::tests::{new_ledger_with_records, new_transaction},
+    };
+    #[cfg(feature = "devnet")]
+    use snarkvm_utilities::{FromBytes, ToBytes};
 
     use rand::thread_rng;
 
@@ -263,4 +545,311 @@ mod tests {
             assert_eq!(1, ledger.latest_block_height());
         }
     }
+
+    #[test]
+    fn test_validate_next_block() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let recipient = Account::<Testnet2>::new(rng);
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let mut other_ledger = Ledger::<Testnet2>::new().unwrap();
+
+        // Ensure a block at an incorrect height is rejected.
+        match ledger.validate_next_block(Testnet2::genesis_block()) {
+            Err(BlockValidationError::IncorrectBlockHeight { expected, found }) => {
+                assert_eq!(1, expected);
+                assert_eq!(0, found);
+            }
+            result => panic!("Expected an incorrect block height, found {:?}", result),
+        }
+
+        // Ensure a valid next block passes validation without being added.
+        other_ledger
+            .mine_next_block(recipient.address(), true, &terminator, rng)
+            .unwrap();
+        let block = other_ledger.latest_block().unwrap();
+        ledger.validate_next_block(&block).unwrap();
+        assert_eq!(0, ledger.latest_block_height());
+
+        // Ensure a block that already exists is rejected.
+        ledger.add_next_block(&block).unwrap();
+        match ledger.validate_next_block(&block) {
+            Err(BlockValidationError::IncorrectBlockHeight { expected, found }) => {
+                assert_eq!(2, expected);
+                assert_eq!(1, found);
+            }
+            result => panic!("Expected an incorrect block height, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_remove_last_block() {
+        let rng = &mut thread_rng();
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+
+        let genesis_ledger_root = ledger.latest_ledger_root();
+        ledger
+            .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        let block = ledger.latest_block().unwrap();
+
+        // Roll back the block, and ensure its state is removed.
+        let removed_blocks = ledger.canon_blocks.remove_last(1).unwrap();
+        assert_eq!(vec![block.clone()], removed_blocks);
+        assert_eq!(0, ledger.latest_block_height());
+        assert_eq!(genesis_ledger_root, ledger.latest_ledger_root());
+        assert!(!ledger.contains_block_hash(&block.hash()).unwrap());
+        for commitment in block.commitments() {
+            assert!(!ledger.canon_blocks.contains_commitment(commitment).unwrap());
+        }
+        for serial_number in block.serial_numbers() {
+            assert!(!ledger.canon_blocks.contains_serial_number(serial_number).unwrap());
+        }
+
+        // Reapply the block.
+        ledger.add_next_block(&block).unwrap();
+        assert_eq!(1, ledger.latest_block_height());
+        assert_eq!(block.hash(), ledger.latest_block_hash());
+    }
+
+    #[test]
+    fn test_reorganize() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+        let recipient = Account::<Testnet2>::new(rng);
+
+        // Mine a canon chain of one block.
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        ledger.mine_next_block(recipient.address(), true, &terminator, rng).unwrap();
+        let canon_block = ledger.latest_block().unwrap();
+
+        // Mine a competing chain of two blocks.
+        let mut fork = Ledger::<Testnet2>::new().unwrap();
+        fork.mine_next_block(recipient.address(), true, &terminator, rng).unwrap();
+        fork.mine_next_block(recipient.address(), true, &terminator, rng).unwrap();
+
+        // Add the competing chain, and ensure the ledger reorganizes onto it.
+        ledger.add_block(&fork.canon_blocks.get_block(1).unwrap()).unwrap();
+        ledger.add_block(&fork.canon_blocks.get_block(2).unwrap()).unwrap();
+        assert_eq!(2, ledger.latest_block_height());
+        assert_eq!(fork.latest_block_hash(), ledger.latest_block_hash());
+        assert_eq!(fork.latest_ledger_root(), ledger.latest_ledger_root());
+
+        // Ensure the previous canon block is now on a side chain, and its state is removed.
+        assert!(ledger.contains_side_block_hash(&canon_block.hash()));
+        assert!(!ledger.contains_block_hash(&canon_block.hash()).unwrap());
+        for commitment in canon_block.commitments() {
+            assert!(!ledger.canon_blocks.contains_commitment(commitment).unwrap());
+        }
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_reorganize_onto_invalid_side_chain() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+        let recipient = Account::<Devnet>::new(rng);
+
+        // Mine a canon chain of one block.
+        let mut ledger = Ledger::<Devnet>::new().unwrap();
+        ledger.mine_next_block(recipient.address(), true, &terminator, rng).unwrap();
+        let canon_block = ledger.latest_block().unwrap();
+        let canon_ledger_root = ledger.latest_ledger_root();
+
+        // Mine a competing block, and follow it with a block that does not extend it.
+        let mut fork = Ledger::<Devnet>::new().unwrap();
+        fork.mine_next_block(recipient.address(), true, &terminator, rng).unwrap();
+        let side_chain = vec![fork.latest_block().unwrap(), canon_block.clone()];
+
+        // Ensure the reorganization fails, and the canon chain is restored.
+        assert!(ledger.reorganize(0, &side_chain).is_err());
+        assert_eq!(1, ledger.latest_block_height());
+        assert_eq!(canon_block.hash(), ledger.latest_block_hash());
+        assert_eq!(canon_ledger_root, ledger.latest_ledger_root());
+        assert!(!ledger.contains_block_hash(&side_chain[0].hash()).unwrap());
+        for commitment in canon_block.commitments() {
+            assert!(ledger.canon_blocks.contains_commitment(commitment).unwrap());
+        }
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_reject_multi_transition_coinbase_after_genesis() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+        let recipient = Account::<Devnet>::new(rng);
+
+        // Mint the starting supply over multiple coinbase transitions, as a configured genesis block does.
+        let starting_supply = Block::<Devnet>::block_reward(0);
+        let amount = AleoAmount::from_i64(starting_supply.as_i64() / 4);
+        let genesis_block = GenesisBuilder::<Devnet>::new()
+            .add_allocation(recipient.address(), amount)
+            .add_allocation(recipient.address(), amount)
+            .add_allocation(recipient.address(), starting_supply.sub(amount).sub(amount))
+            .build(rng)
+            .unwrap();
+        let coinbase_transaction = genesis_block.to_coinbase_transaction().unwrap();
+        assert!(coinbase_transaction.transitions().len() > 1);
+
+        // Carry the multi-transition coinbase in the block after the genesis block.
+        let mut ledger = Ledger::<Devnet>::new().unwrap();
+        ledger.mine_next_block(recipient.address(), true, &terminator, rng).unwrap();
+        let next_block = ledger.latest_block().unwrap();
+        let block = Block::from_unchecked(
+            next_block.previous_block_hash(),
+            next_block.header().clone(),
+            genesis_block.transactions().clone(),
+        )
+        .unwrap();
+        assert_eq!(1, block.height());
+
+        // Ensure the block is rejected when it is deserialized.
+        assert!(Block::<Devnet>::read_le(&block.to_bytes_le().unwrap()[..]).is_err());
+
+        // Ensure the block is rejected when it is added to the chain.
+        let mut blocks = Blocks::<Devnet>::new().unwrap();
+        let error = blocks.add_next(&block).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<BlockValidationError<Devnet>>(),
+            Some(BlockValidationError::InvalidTransaction(transaction_id))
+                if *transaction_id == coinbase_transaction.transaction_id()
+        ));
+        assert_eq!(0, blocks.latest_block_height());
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_expire_unconfirmed_transaction() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+        let account = Account::<Devnet>::new(rng);
+
+        // Construct a transaction against the ledger root after the first block.
+        let (mut miner, records) = new_ledger_with_records(&account, 1, rng);
+        let transaction = new_transaction(&miner, &account, &records, 1, rng);
+
+        // Admit the transaction into a ledger whose ledger roots expire after one block.
+        let mut ledger = Ledger::<Devnet>::new().unwrap();
+        ledger.memory_pool = MemoryPool::with_limits(64 * 1024 * 1024, 10_000, 1);
+        ledger.add_next_block(&miner.get_block(1).unwrap()).unwrap();
+        ledger.add_unconfirmed_transaction(&transaction).unwrap();
+
+        // Ensure the transaction remains while its ledger root is within the maximum age.
+        miner
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+        assert!(ledger.add_next_block(&miner.get_block(2).unwrap()).unwrap().is_empty());
+        assert!(ledger.memory_pool().contains_transaction(&transaction));
+
+        // Ensure the transaction is dropped once its ledger root exceeds the maximum age.
+        miner
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+        let dropped_transactions = ledger.add_next_block(&miner.get_block(3).unwrap()).unwrap();
+        assert_eq!(
+            vec![(transaction.clone(), DropReason::ExpiredLedgerRoot)],
+            dropped_transactions
+        );
+        assert!(!ledger.memory_pool().contains_transaction(&transaction));
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_revalidate_after_next_block() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+        let account = Account::<Devnet>::new(rng);
+        let (mut ledger, records) = new_ledger_with_records(&account, 2, rng);
+
+        let spent = new_transaction(&ledger, &account, &records[0..1], 1, rng);
+        let confirmed = new_transaction(&ledger, &account, &records[1..2], 1, rng);
+        ledger.add_unconfirmed_transaction(&spent).unwrap();
+        ledger.add_unconfirmed_transaction(&confirmed).unwrap();
+
+        // Mine a block that confirms one transaction, and spends the record of the other in a replacement.
+        let mut miner = ledger.clone();
+        let replacement = new_transaction(&ledger, &account, &records[0..1], 2, rng);
+        assert_eq!(
+            vec![spent.clone()],
+            miner.add_unconfirmed_transaction(&replacement).unwrap()
+        );
+        miner
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+
+        // Ensure both transactions are dropped from the memory pool, with the reason for each.
+        let mut dropped_transactions = ledger.add_next_block(&miner.latest_block().unwrap()).unwrap();
+        dropped_transactions.sort_by_key(|(_, reason)| *reason == DropReason::Confirmed);
+        assert_eq!(
+            vec![
+                (spent, DropReason::ConflictingSerialNumber),
+                (confirmed, DropReason::Confirmed)
+            ],
+            dropped_transactions
+        );
+        assert_eq!(0, ledger.memory_pool().num_transactions());
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_revalidate_after_reorganize() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+        let account = Account::<Devnet>::new(rng);
+        let (mut ledger, records) = new_ledger_with_records(&account, 1, rng);
+
+        // Mine a competing chain of two blocks.
+        let mut fork = ledger.clone();
+        fork.mine_next_block(account.address(), true, &terminator, rng).unwrap();
+        fork.mine_next_block(account.address(), true, &terminator, rng).unwrap();
+
+        // Confirm a transaction in the canon chain.
+        let transaction = new_transaction(&ledger, &account, &records, 1, rng);
+        ledger.add_unconfirmed_transaction(&transaction).unwrap();
+        ledger
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+        assert!(ledger.contains_transaction(&transaction).unwrap());
+        assert_eq!(0, ledger.memory_pool().num_transactions());
+
+        // Reorganize onto the competing chain, and ensure the disconnected transaction is re-admitted.
+        assert!(ledger.add_block(&fork.get_block(2).unwrap()).unwrap().is_empty());
+        assert!(ledger.add_block(&fork.get_block(3).unwrap()).unwrap().is_empty());
+        assert_eq!(fork.latest_block_hash(), ledger.latest_block_hash());
+        assert!(!ledger.contains_transaction(&transaction).unwrap());
+        assert_eq!(vec![transaction], ledger.memory_pool().transactions());
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn test_reopen_sled_storage() {
+        let rng = &mut thread_rng();
+        let directory = tempfile::tempdir().unwrap();
+
+        let recipient = Account::<Testnet2>::new(rng);
+        let (block, ledger_root) = {
+            let mut ledger = Ledger::open(SledStorage::<Testnet2>::open(directory.path()).unwrap()).unwrap();
+            assert_eq!(0, ledger.latest_block_height());
+            ledger
+                .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+                .unwrap();
+            assert_eq!(1, ledger.latest_block_height());
+            (ledger.latest_block().unwrap(), ledger.latest_ledger_root())
+        };
+        let block_hash = block.hash();
+
+        // Reopen the ledger from disk, without replaying the chain.
+        let ledger = Ledger::open(SledStorage::<Testnet2>::open(directory.path()).unwrap()).unwrap();
+        assert_eq!(1, ledger.latest_block_height());
+        assert_eq!(block_hash, ledger.latest_block_hash());
+        assert_eq!(ledger_root, ledger.latest_ledger_root());
+        assert!(ledger.contains_block_hash(&block_hash).unwrap());
+        assert_eq!(1, ledger.latest_block_transactions().unwrap().len());
+
+        // Ensure the block read back from storage, without re-verifying it, is the mined block.
+        assert_eq!(block, ledger.latest_block().unwrap());
+        assert!(block.is_valid());
+    }
 }
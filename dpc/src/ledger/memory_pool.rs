@@ -15,31 +15,138 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::prelude::*;
+use snarkvm_utilities::ToBytes;
 
 use anyhow::{anyhow, Result};
-use std::collections::{HashMap, HashSet};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+/// The default maximum size of the memory pool, in bytes.
+const DEFAULT_MAXIMUM_SIZE_IN_BYTES: usize = 64 * 1024 * 1024;
+/// The default maximum number of transactions in the memory pool.
+const DEFAULT_MAXIMUM_NUM_TRANSACTIONS: usize = 10_000;
+/// The default maximum number of blocks that the ledger root of a transaction may fall behind the latest block.
+const DEFAULT_MAXIMUM_LEDGER_ROOT_AGE: u32 = 100;
+
+/// The fee paid by a transaction per byte.
+#[derive(Copy, Clone, Debug)]
+pub struct FeeRate {
+    /// The fee of the transaction, in gates.
+    fee: i64,
+    /// The size of the transaction, in bytes.
+    size_in_bytes: usize,
+}
+
+impl FeeRate {
+    /// Returns the fee rate of the given transaction.
+    pub fn new<N: Network>(transaction: &Transaction<N>) -> Result<Self> {
+        Ok(Self {
+            fee: transaction.value_balance().0,
+            size_in_bytes: transaction.to_bytes_le()?.len(),
+        })
+    }
+
+    /// Returns the fee of the transaction, in gates.
+    pub fn fee(&self) -> i64 {
+        self.fee
+    }
+
+    /// Returns the size of the transaction, in bytes.
+    pub fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+}
+
+impl PartialEq for FeeRate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for FeeRate {}
+
+impl PartialOrd for FeeRate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FeeRate {
+    /// Compares the fees per byte, by cross-multiplying the fees and sizes to avoid rounding.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.fee as i128 * other.size_in_bytes as i128;
+        let rhs = other.fee as i128 * self.size_in_bytes as i128;
+        lhs.cmp(&rhs)
+    }
+}
+
+/// The reason an unconfirmed transaction was dropped from the memory pool.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DropReason {
+    /// The transaction is included in the canon chain.
+    Confirmed,
+    /// A serial number of the transaction is already spent in the canon chain.
+    ConflictingSerialNumber,
+    /// A commitment of the transaction already exists in the canon chain.
+    ConflictingCommitment,
+    /// The ledger root of the transaction does not exist in the canon chain.
+    UnknownLedgerRoot,
+    /// The ledger root of the transaction has fallen too far behind the canon chain.
+    ExpiredLedgerRoot,
+    /// The transaction was replaced or evicted by a transaction with a higher fee.
+    Evicted,
+    /// The transaction was rejected by the memory pool.
+    Rejected,
+}
 
 #[derive(Clone, Debug)]
 pub struct MemoryPool<N: Network> {
     /// The pool of unconfirmed transactions.
-    transactions: HashMap<N::TransactionID, Transaction<N>>,
-    /// The list of unconfirmed serial numbers.
-    serial_numbers: HashSet<N::SerialNumber>,
-    /// The list of unconfirmed commitments.
-    commitments: HashSet<N::Commitment>,
+    transactions: HashMap<N::TransactionID, (Transaction<N>, FeeRate)>,
+    /// The total size of the unconfirmed transactions, in bytes.
+    size_in_bytes: usize,
+    /// The mapping of unconfirmed serial numbers to transaction IDs.
+    serial_numbers: HashMap<N::SerialNumber, N::TransactionID>,
+    /// The mapping of unconfirmed commitments to transaction IDs.
+    commitments: HashMap<N::Commitment, N::TransactionID>,
     /// The set of open requests.
     #[allow(dead_code)]
     requests: HashSet<Request<N>>,
+    /// The maximum size of the memory pool, in bytes.
+    maximum_size_in_bytes: usize,
+    /// The maximum number of transactions in the memory pool.
+    maximum_num_transactions: usize,
+    /// The maximum number of blocks that the ledger root of a transaction may fall behind the latest block.
+    maximum_ledger_root_age: u32,
 }
 
 impl<N: Network> MemoryPool<N> {
     /// Initializes a new instance of a memory pool.
     pub fn new() -> Self {
+        Self::with_limits(
+            DEFAULT_MAXIMUM_SIZE_IN_BYTES,
+            DEFAULT_MAXIMUM_NUM_TRANSACTIONS,
+            DEFAULT_MAXIMUM_LEDGER_ROOT_AGE,
+        )
+    }
+
+    /// Initializes a new instance of a memory pool with the given limits.
+    pub fn with_limits(
+        maximum_size_in_bytes: usize,
+        maximum_num_transactions: usize,
+        maximum_ledger_root_age: u32,
+    ) -> Self {
         Self {
             transactions: Default::default(),
+            size_in_bytes: 0,
             serial_numbers: Default::default(),
             commitments: Default::default(),
             requests: Default::default(),
+            maximum_size_in_bytes,
+            maximum_num_transactions,
+            maximum_ledger_root_age,
         }
     }
 
@@ -48,13 +155,51 @@ impl<N: Network> MemoryPool<N> {
         self.transactions.contains_key(&transaction.transaction_id())
     }
 
-    /// Returns the transactions in the memory pool.
+    /// Returns the transactions in the memory pool, in descending order of fee rate.
     pub fn transactions(&self) -> Vec<Transaction<N>> {
-        self.transactions.values().cloned().collect()
+        self.sorted_transactions().into_iter().map(|(transaction, _)| transaction.clone()).collect()
     }
 
-    /// Adds the given unconfirmed transaction to the memory pool.
-    pub fn add_transaction(&mut self, transaction: &Transaction<N>) -> Result<()> {
+    /// Returns the number of transactions in the memory pool.
+    pub fn num_transactions(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns the total size of the transactions in the memory pool, in bytes.
+    pub fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
+    /// Returns the maximum number of blocks that the ledger root of a transaction may fall behind the latest block.
+    pub fn maximum_ledger_root_age(&self) -> u32 {
+        self.maximum_ledger_root_age
+    }
+
+    /// Returns the highest-paying transactions in the memory pool that fit in the given size budget,
+    /// in descending order of fee rate.
+    pub fn transactions_for_block(&self, maximum_size_in_bytes: usize) -> Vec<Transaction<N>> {
+        let mut size_in_bytes = 0;
+        let mut transactions = Vec::new();
+        for (transaction, fee_rate) in self.sorted_transactions() {
+            // Skip the transaction if it does not fit in the remaining size budget.
+            if size_in_bytes + fee_rate.size_in_bytes() > maximum_size_in_bytes {
+                continue;
+            }
+            size_in_bytes += fee_rate.size_in_bytes();
+            transactions.push(transaction.clone());
+        }
+        transactions
+    }
+
+    /// Adds the given unconfirmed transaction to the memory pool, returning the evicted transactions.
+    ///
+    /// If the transaction conflicts with transactions in the memory pool, it replaces them,
+    /// provided it pays a strictly higher absolute fee than all of them combined,
+    /// and a strictly higher fee rate than each of them.
+    ///
+    /// If the memory pool is full, the transactions with the lowest fee rate are evicted,
+    /// provided they pay a lower fee rate than the given transaction.
+    pub fn add_transaction(&mut self, transaction: &Transaction<N>) -> Result<Vec<Transaction<N>>> {
         // Ensure the unconfirmed transaction itself is valid.
         if !transaction.is_valid() {
             return Err(anyhow!("The unconfirmed transaction is invalid"));
@@ -71,80 +216,138 @@ impl<N: Network> MemoryPool<N> {
             return Err(anyhow!("Transaction already exists in memory pool"));
         }
 
-        // Ensure the memory pool does not already contain a given serial numbers.
-        for serial_number in transaction.serial_numbers() {
-            if self.serial_numbers.contains(serial_number) {
-                return Err(anyhow!("Serial number already used in memory pool"));
-            }
+        // Ensure the transaction fits in the memory pool.
+        let fee_rate = FeeRate::new(transaction)?;
+        if fee_rate.size_in_bytes() > self.maximum_size_in_bytes {
+            return Err(anyhow!("Transaction exceeds the maximum memory pool size"));
         }
 
-        // Ensure the memory pool does not already contain a given commitments.
-        for commitment in transaction.commitments() {
-            if self.commitments.contains(commitment) {
-                return Err(anyhow!("Commitment already used in memory pool"));
-            }
+        // Retrieve the transactions in the memory pool that use the given serial numbers or commitments.
+        let conflicting_transaction_ids = transaction
+            .serial_numbers()
+            .filter_map(|serial_number| self.serial_numbers.get(serial_number))
+            .chain(
+                transaction
+                    .commitments()
+                    .filter_map(|commitment| self.commitments.get(commitment)),
+            )
+            .copied()
+            .collect::<HashSet<_>>();
+        let conflicting_transactions = conflicting_transaction_ids
+            .iter()
+            .filter_map(|transaction_id| self.transactions.get(transaction_id))
+            .collect::<Vec<_>>();
+
+        // Ensure the transaction pays a higher absolute fee than the conflicting transactions combined.
+        let conflicting_fee = conflicting_transactions
+            .iter()
+            .map(|(_, conflicting_fee_rate)| conflicting_fee_rate.fee() as i128)
+            .sum::<i128>();
+        if !conflicting_transactions.is_empty() && fee_rate.fee() as i128 <= conflicting_fee {
+            return Err(anyhow!(
+                "Transaction fee of {} does not exceed the fee of {} of the conflicting transactions in memory pool",
+                fee_rate.fee(),
+                conflicting_fee
+            ));
         }
 
-        // Add the transaction to the memory pool. This code section executes atomically.
+        // Ensure the transaction pays a higher fee rate than each of the conflicting transactions.
+        if conflicting_transactions
+            .iter()
+            .any(|(_, conflicting_fee_rate)| fee_rate <= *conflicting_fee_rate)
         {
-            let mut memory_pool = self.clone();
+            return Err(anyhow!(
+                "Transaction fee rate does not exceed the fee rate of the conflicting transactions in memory pool"
+            ));
+        }
 
-            memory_pool.transactions.insert(transaction_id, transaction.clone());
-            for serial_number in transaction.serial_numbers() {
-                memory_pool.serial_numbers.insert(*serial_number);
-            }
-            for commitment in transaction.commitments() {
-                memory_pool.commitments.insert(*commitment);
+        // Select the transactions with the lowest fee rate to evict, until the transaction fits in the memory pool.
+        // The memory pool is only modified once every check has passed, so a rejected transaction leaves it intact.
+        let mut num_transactions = self.transactions.len() - conflicting_transactions.len();
+        let mut size_in_bytes = self.size_in_bytes
+            - conflicting_transactions
+                .iter()
+                .map(|(_, conflicting_fee_rate)| conflicting_fee_rate.size_in_bytes())
+                .sum::<usize>();
+        let mut lowest_fee_rate_transactions = self
+            .sorted_transactions()
+            .into_iter()
+            .rev()
+            .filter(|(candidate, _)| !conflicting_transaction_ids.contains(&candidate.transaction_id()));
+        let mut evicted_transactions = Vec::new();
+        while num_transactions + 1 > self.maximum_num_transactions
+            || size_in_bytes + fee_rate.size_in_bytes() > self.maximum_size_in_bytes
+        {
+            match lowest_fee_rate_transactions.next() {
+                Some((lowest_transaction, lowest_fee_rate)) if lowest_fee_rate < fee_rate => {
+                    num_transactions -= 1;
+                    size_in_bytes -= lowest_fee_rate.size_in_bytes();
+                    evicted_transactions.push(lowest_transaction.clone());
+                }
+                _ => return Err(anyhow!("Memory pool is full of transactions with a higher fee rate")),
             }
-
-            *self = memory_pool;
         }
 
-        Ok(())
-    }
-
-    /// Clear a transaction (and associated state) from the memory pool.
-    pub fn remove_transaction(&mut self, transaction: &Transaction<N>) {
-        // This code section executes atomically.
-
-        let mut memory_pool = self.clone();
+        // Replace the conflicting transactions, and evict the selected transactions.
+        let evicted_transactions = conflicting_transactions
+            .into_iter()
+            .map(|(conflicting_transaction, _)| conflicting_transaction.clone())
+            .chain(evicted_transactions)
+            .collect::<Vec<_>>();
+        self.remove_transactions(&evicted_transactions);
 
-        memory_pool.transactions.remove(&transaction.transaction_id());
+        // Add the transaction to the memory pool.
+        self.transactions
+            .insert(transaction_id, (transaction.clone(), fee_rate));
+        self.size_in_bytes += fee_rate.size_in_bytes();
         for serial_number in transaction.serial_numbers() {
-            memory_pool.serial_numbers.remove(serial_number);
+            self.serial_numbers.insert(*serial_number, transaction_id);
         }
         for commitment in transaction.commitments() {
-            memory_pool.commitments.remove(commitment);
+            self.commitments.insert(*commitment, transaction_id);
         }
 
-        *self = memory_pool;
+        Ok(evicted_transactions)
     }
 
-    /// Clear a list of transactions (and associated state) from the memory pool.
-    pub fn remove_transactions(&mut self, transactions: &[Transaction<N>]) {
-        // This code section executes atomically.
-
-        let mut memory_pool = self.clone();
-
-        for transaction in transactions {
-            memory_pool.transactions.remove(&transaction.transaction_id());
+    /// Clear a transaction (and associated state) from the memory pool.
+    pub fn remove_transaction(&mut self, transaction: &Transaction<N>) {
+        if let Some((_, fee_rate)) = self.transactions.remove(&transaction.transaction_id()) {
+            self.size_in_bytes -= fee_rate.size_in_bytes();
             for serial_number in transaction.serial_numbers() {
-                memory_pool.serial_numbers.remove(serial_number);
+                self.serial_numbers.remove(serial_number);
             }
             for commitment in transaction.commitments() {
-                memory_pool.commitments.remove(commitment);
+                self.commitments.remove(commitment);
             }
         }
+    }
 
-        *self = memory_pool;
+    /// Clear a list of transactions (and associated state) from the memory pool.
+    pub fn remove_transactions(&mut self, transactions: &[Transaction<N>]) {
+        for transaction in transactions {
+            self.remove_transaction(transaction);
+        }
     }
 
     /// Clears all transactions (and associated state) from the memory pool.
     pub fn clear_all_transactions(&mut self) {
         self.transactions = Default::default();
+        self.size_in_bytes = 0;
         self.serial_numbers = Default::default();
         self.commitments = Default::default();
     }
+
+    /// Returns the transactions in the memory pool with their fee rates, in descending order of fee rate.
+    fn sorted_transactions(&self) -> Vec<(&Transaction<N>, FeeRate)> {
+        let mut transactions = self
+            .transactions
+            .values()
+            .map(|(transaction, fee_rate)| (transaction, *fee_rate))
+            .collect::<Vec<_>>();
+        transactions.sort_by(|(_, a), (_, b)| b.cmp(a));
+        transactions
+    }
 }
 
 impl<N: Network> Default for MemoryPool<N> {
@@ -152,3 +355,186 @@ impl<N: Network> Default for MemoryPool<N> {
         Self::new()
     }
 }
+
+#[cfg(all(test, feature = "devnet"))]
+pub(crate) mod tests {
+    use super::*;
+    use crate::devnet::Devnet;
+
+    use rand::{thread_rng, CryptoRng, Rng};
+    use std::sync::atomic::AtomicBool;
+
+    /// Returns a devnet ledger with the given number of blocks mined to the given account, and their coinbase records.
+    pub(crate) fn new_ledger_with_records<R: Rng + CryptoRng>(
+        account: &Account<Devnet>,
+        num_blocks: usize,
+        rng: &mut R,
+    ) -> (Ledger<Devnet>, Vec<Record<Devnet>>) {
+        let mut ledger = Ledger::<Devnet>::new().unwrap();
+        let records = (0..num_blocks)
+            .map(|_| {
+                ledger
+                    .mine_next_block(account.address(), true, &AtomicBool::new(false), rng)
+                    .unwrap()
+            })
+            .collect();
+        (ledger, records)
+    }
+
+    /// Returns a transaction that spends the given records of the given account, and pays the given fee.
+    pub(crate) fn new_transaction<R: Rng + CryptoRng>(
+        ledger: &Ledger<Devnet>,
+        account: &Account<Devnet>,
+        records: &[Record<Devnet>],
+        fee: i64,
+        rng: &mut R,
+    ) -> Transaction<Devnet> {
+        TransactionBuilder::new()
+            .add_records(records.to_vec())
+            .add_recipient(account.address(), AleoAmount::from_i64(1))
+            .set_fee(AleoAmount::from_i64(fee))
+            .build(account.private_key(), ledger, rng)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_fee_rate_ordering() {
+        let fee_rate = |fee, size_in_bytes| FeeRate { fee, size_in_bytes };
+
+        // A higher fee per byte is a higher fee rate, regardless of the absolute fee.
+        assert!(fee_rate(100, 10) > fee_rate(150, 20));
+        assert!(fee_rate(0, 10) < fee_rate(1, 1000));
+        // Equal fees per byte are equal fee rates.
+        assert_eq!(fee_rate(100, 10), fee_rate(200, 20));
+        // The comparison does not overflow.
+        assert!(fee_rate(i64::MAX, usize::MAX) > fee_rate(i64::MAX - 1, usize::MAX));
+    }
+
+    #[test]
+    fn test_evict_lowest_fee_rate() {
+        let rng = &mut thread_rng();
+        let account = Account::<Devnet>::new(rng);
+        let (ledger, records) = new_ledger_with_records(&account, 3, rng);
+
+        let low = new_transaction(&ledger, &account, &records[0..1], 1, rng);
+        let medium = new_transaction(&ledger, &account, &records[1..2], 2, rng);
+        let high = new_transaction(&ledger, &account, &records[2..3], 3, rng);
+
+        let mut memory_pool =
+            MemoryPool::with_limits(DEFAULT_MAXIMUM_SIZE_IN_BYTES, 2, DEFAULT_MAXIMUM_LEDGER_ROOT_AGE);
+        assert!(memory_pool.add_transaction(&low).unwrap().is_empty());
+        assert!(memory_pool.add_transaction(&medium).unwrap().is_empty());
+
+        // Ensure a transaction with a higher fee rate evicts the transaction with the lowest fee rate.
+        assert_eq!(vec![low.clone()], memory_pool.add_transaction(&high).unwrap());
+        assert_eq!(vec![high.clone(), medium.clone()], memory_pool.transactions());
+
+        // Ensure a transaction with the lowest fee rate is rejected, and leaves the memory pool intact.
+        let size_in_bytes = memory_pool.size_in_bytes();
+        assert!(memory_pool.add_transaction(&low).is_err());
+        assert_eq!(vec![high, medium], memory_pool.transactions());
+        assert_eq!(size_in_bytes, memory_pool.size_in_bytes());
+    }
+
+    #[test]
+    fn test_evict_to_fit_size() {
+        let rng = &mut thread_rng();
+        let account = Account::<Devnet>::new(rng);
+        let (ledger, records) = new_ledger_with_records(&account, 2, rng);
+
+        let low = new_transaction(&ledger, &account, &records[0..1], 1, rng);
+        let high = new_transaction(&ledger, &account, &records[1..2], 2, rng);
+        let maximum_size_in_bytes = FeeRate::new(&low)
+            .unwrap()
+            .size_in_bytes()
+            .max(FeeRate::new(&high).unwrap().size_in_bytes());
+
+        // Ensure a transaction that does not fit alongside the lower-paying transaction evicts it.
+        let mut memory_pool = MemoryPool::with_limits(
+            maximum_size_in_bytes,
+            DEFAULT_MAXIMUM_NUM_TRANSACTIONS,
+            DEFAULT_MAXIMUM_LEDGER_ROOT_AGE,
+        );
+        assert!(memory_pool.add_transaction(&low).unwrap().is_empty());
+        assert_eq!(vec![low], memory_pool.add_transaction(&high).unwrap());
+        assert_eq!(vec![high.clone()], memory_pool.transactions());
+        assert_eq!(
+            FeeRate::new(&high).unwrap().size_in_bytes(),
+            memory_pool.size_in_bytes()
+        );
+    }
+
+    #[test]
+    fn test_replace_by_fee() {
+        let rng = &mut thread_rng();
+        let account = Account::<Devnet>::new(rng);
+        let (ledger, records) = new_ledger_with_records(&account, 1, rng);
+
+        let original = new_transaction(&ledger, &account, &records, 2, rng);
+        let mut memory_pool = MemoryPool::new();
+        assert!(memory_pool.add_transaction(&original).unwrap().is_empty());
+
+        // Ensure a conflicting transaction with an equal or lower fee is rejected.
+        for fee in [2, 1] {
+            let conflicting = new_transaction(&ledger, &account, &records, fee, rng);
+            assert!(memory_pool.add_transaction(&conflicting).is_err());
+            assert_eq!(vec![original.clone()], memory_pool.transactions());
+        }
+
+        // Ensure a conflicting transaction with a higher fee replaces the original transaction.
+        let replacement = new_transaction(&ledger, &account, &records, 3, rng);
+        assert_eq!(vec![original], memory_pool.add_transaction(&replacement).unwrap());
+        assert_eq!(vec![replacement.clone()], memory_pool.transactions());
+        assert_eq!(
+            FeeRate::new(&replacement).unwrap().size_in_bytes(),
+            memory_pool.size_in_bytes()
+        );
+        for serial_number in replacement.serial_numbers() {
+            assert_eq!(
+                Some(&replacement.transaction_id()),
+                memory_pool.serial_numbers.get(serial_number)
+            );
+        }
+    }
+
+    #[test]
+    fn test_replace_by_fee_with_multiple_conflicts() {
+        let rng = &mut thread_rng();
+        let account = Account::<Devnet>::new(rng);
+        let (ledger, records) = new_ledger_with_records(&account, 2, rng);
+
+        let first = new_transaction(&ledger, &account, &records[0..1], 2, rng);
+        let second = new_transaction(&ledger, &account, &records[1..2], 2, rng);
+        let mut memory_pool = MemoryPool::new();
+        assert!(memory_pool.add_transaction(&first).unwrap().is_empty());
+        assert!(memory_pool.add_transaction(&second).unwrap().is_empty());
+
+        // Ensure a transaction that does not exceed the combined fee of the conflicting transactions is rejected.
+        let underpaying = new_transaction(&ledger, &account, &records, 4, rng);
+        assert!(memory_pool.add_transaction(&underpaying).is_err());
+        assert_eq!(2, memory_pool.num_transactions());
+
+        // Ensure a transaction that exceeds the combined fee replaces every conflicting transaction.
+        let replacement = new_transaction(&ledger, &account, &records, 5, rng);
+        let replaced_ids = memory_pool
+            .add_transaction(&replacement)
+            .unwrap()
+            .iter()
+            .map(Transaction::transaction_id)
+            .collect::<HashSet<_>>();
+        assert_eq!(
+            [first.transaction_id(), second.transaction_id()]
+                .iter()
+                .copied()
+                .collect::<HashSet<_>>(),
+            replaced_ids
+        );
+        assert_eq!(vec![replacement.clone()], memory_pool.transactions());
+        assert_eq!(
+            FeeRate::new(&replacement).unwrap().size_in_bytes(),
+            memory_pool.size_in_bytes()
+        );
+        assert_eq!(replacement.serial_numbers().count(), memory_pool.serial_numbers.len());
+        assert_eq!(replacement.commitments().count(), memory_pool.commitments.len());
+    }
+}
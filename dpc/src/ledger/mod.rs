@@ -17,6 +17,9 @@
 pub mod blocks;
 pub use blocks::*;
 
+pub mod header_chain;
+pub use header_chain::*;
+
 pub mod ledger;
 pub use ledger::*;
 
@@ -28,3 +31,6 @@ pub use ledger_tree::*;
 
 pub mod memory_pool;
 pub use memory_pool::*;
+
+pub mod storage;
+pub use storage::*;
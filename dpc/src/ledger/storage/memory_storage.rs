@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+/// An in-memory storage backend for the canonical chain of blocks.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"), Default(bound = "N: Network"))]
+pub struct MemoryStorage<N: Network> {
+    /// The latest block height, if any.
+    latest_height: Option<u32>,
+    /// The chain of block hashes.
+    block_hashes: HashMap<u32, N::BlockHash>,
+    /// The mapping of block hashes to block heights.
+    block_heights: HashMap<N::BlockHash, u32>,
+    /// The chain of previous block hashes.
+    previous_hashes: HashMap<u32, N::BlockHash>,
+    /// The chain of block headers.
+    headers: HashMap<u32, BlockHeader<N>>,
+    /// The chain of block transactions.
+    transactions: HashMap<u32, Transactions<N>>,
+    /// The mapping of previous ledger roots to block heights.
+    ledger_roots: HashMap<N::LedgerRoot, u32>,
+    /// The mapping of transaction IDs to block heights.
+    transaction_heights: HashMap<N::TransactionID, u32>,
+    /// The mapping of serial numbers to transaction IDs.
+    serial_numbers: HashMap<N::SerialNumber, N::TransactionID>,
+    /// The mapping of commitments to transaction IDs.
+    commitments: HashMap<N::Commitment, N::TransactionID>,
+}
+
+impl<N: Network> MemoryStorage<N> {
+    /// Initializes a new instance of an empty in-memory storage.
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl<N: Network> LedgerStorage<N> for MemoryStorage<N> {
+    fn latest_block_height(&self) -> Result<Option<u32>> {
+        Ok(self.latest_height)
+    }
+
+    fn get_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
+        Ok(self.block_hashes.get(&height).copied())
+    }
+
+    fn get_block_height(&self, block_hash: &N::BlockHash) -> Result<Option<u32>> {
+        Ok(self.block_heights.get(block_hash).copied())
+    }
+
+    fn get_previous_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
+        Ok(self.previous_hashes.get(&height).copied())
+    }
+
+    fn get_block_header(&self, height: u32) -> Result<Option<BlockHeader<N>>> {
+        Ok(self.headers.get(&height).cloned())
+    }
+
+    fn get_block_transactions(&self, height: u32) -> Result<Option<Transactions<N>>> {
+        Ok(self.transactions.get(&height).cloned())
+    }
+
+    fn get_transaction_height(&self, transaction_id: &N::TransactionID) -> Result<Option<u32>> {
+        Ok(self.transaction_heights.get(transaction_id).copied())
+    }
+
+    fn get_commitment_transaction_id(&self, commitment: &N::Commitment) -> Result<Option<N::TransactionID>> {
+        Ok(self.commitments.get(commitment).copied())
+    }
+
+    fn get_ledger_root_height(&self, ledger_root: &N::LedgerRoot) -> Result<Option<u32>> {
+        Ok(self.ledger_roots.get(ledger_root).copied())
+    }
+
+    fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> Result<bool> {
+        Ok(self.ledger_roots.contains_key(ledger_root))
+    }
+
+    fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> Result<bool> {
+        Ok(self.serial_numbers.contains_key(serial_number))
+    }
+
+    fn contains_commitment(&self, commitment: &N::Commitment) -> Result<bool> {
+        Ok(self.commitments.contains_key(commitment))
+    }
+
+    fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        Ok(self.transaction_heights.contains_key(transaction_id))
+    }
+
+    fn insert_block(&mut self, block: &Block<N>) -> Result<()> {
+        let height = block.height();
+        if self.block_hashes.contains_key(&height) {
+            return Err(anyhow!("Block height {} already exists in storage", height));
+        }
+
+        // Add the block to storage. This code section executes atomically, as none of its operations can fail.
+        self.latest_height = Some(std::cmp::max(height, self.latest_height.unwrap_or_default()));
+        self.block_hashes.insert(height, block.hash());
+        self.block_heights.insert(block.hash(), height);
+        self.previous_hashes.insert(height, block.previous_block_hash());
+        self.headers.insert(height, block.header().clone());
+        self.transactions.insert(height, block.transactions().clone());
+        self.ledger_roots.insert(block.previous_ledger_root(), height);
+        for transaction in block.transactions().iter() {
+            let transaction_id = transaction.transaction_id();
+            self.transaction_heights.insert(transaction_id, height);
+            for serial_number in transaction.serial_numbers() {
+                self.serial_numbers.insert(*serial_number, transaction_id);
+            }
+            for commitment in transaction.commitments() {
+                self.commitments.insert(*commitment, transaction_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn remove_blocks(&mut self, start_height: u32) -> Result<()> {
+        let latest_height = match self.latest_height {
+            Some(latest_height) if start_height <= latest_height => latest_height,
+            _ => return Err(anyhow!("Block height {} does not exist in storage", start_height)),
+        };
+
+        // Remove the blocks from storage. This code section executes atomically, as none of its operations can fail.
+        for height in start_height..=latest_height {
+            if let Some(block_hash) = self.block_hashes.remove(&height) {
+                self.block_heights.remove(&block_hash);
+            }
+            self.previous_hashes.remove(&height);
+            if let Some(header) = self.headers.remove(&height) {
+                self.ledger_roots.remove(&header.previous_ledger_root());
+            }
+            if let Some(transactions) = self.transactions.remove(&height) {
+                for transaction in transactions.iter() {
+                    self.transaction_heights.remove(&transaction.transaction_id());
+                    for serial_number in transaction.serial_numbers() {
+                        self.serial_numbers.remove(serial_number);
+                    }
+                    for commitment in transaction.commitments() {
+                        self.commitments.remove(commitment);
+                    }
+                }
+            }
+        }
+        self.latest_height = start_height.checked_sub(1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use sled::{Batch, Db, IVec};
+use std::path::Path;
+
+/// The prefixes used to separate each map within the key space of the database.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+enum MapID {
+    BlockHashes = 0,
+    BlockHeights = 1,
+    PreviousHashes = 2,
+    Headers = 3,
+    Transactions = 4,
+    LedgerRoots = 5,
+    TransactionHeights = 6,
+    SerialNumbers = 7,
+    Commitments = 8,
+}
+
+impl MapID {
+    /// Returns the database key for the given map and key bytes.
+    fn key(self, key: impl ToBytes) -> Result<Vec<u8>> {
+        let mut bytes = vec![self as u8];
+        key.write_le(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Returns the database key for the given map and block height.
+    /// Note: Heights are encoded in big-endian to preserve their ordering in the database.
+    fn height_key(self, height: u32) -> Vec<u8> {
+        let mut bytes = vec![self as u8];
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes
+    }
+}
+
+/// An embedded on-disk storage backend for the canonical chain of blocks.
+/// It is not `Clone`, as clones would share the database without sharing the in-memory state of their chain.
+#[derive(Debug)]
+pub struct SledStorage<N: Network> {
+    database: Db,
+    _network: std::marker::PhantomData<N>,
+}
+
+impl<N: Network> SledStorage<N> {
+    /// Opens the storage at the given path, creating it if it does not exist.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(Self {
+            database: sled::open(path)?,
+            _network: std::marker::PhantomData,
+        })
+    }
+
+    /// Flushes all pending writes to disk.
+    pub fn flush(&self) -> Result<()> {
+        self.database.flush()?;
+        Ok(())
+    }
+
+    /// Returns the value for the given database key, if it exists.
+    fn get<T: FromBytes>(&self, key: &[u8]) -> Result<Option<T>> {
+        match self.database.get(key)? {
+            Some(bytes) => Ok(Some(FromBytes::read_le(&bytes[..])?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the block height stored under the given database key, if it exists.
+    fn get_height(&self, key: &[u8]) -> Result<Option<u32>> {
+        match self.database.get(key)? {
+            Some(bytes) => Ok(Some(Self::decode_height(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns `true` if the given database key exists.
+    fn contains(&self, key: &[u8]) -> Result<bool> {
+        Ok(self.database.contains_key(key)?)
+    }
+
+    /// Decodes a big-endian block height.
+    fn decode_height(bytes: &[u8]) -> Result<u32> {
+        let mut height = [0u8; 4];
+        match bytes.len() == 4 {
+            true => height.copy_from_slice(bytes),
+            false => return Err(anyhow!("Invalid block height encoding of {} bytes", bytes.len())),
+        }
+        Ok(u32::from_be_bytes(height))
+    }
+}
+
+impl<N: Network> LedgerStorage<N> for SledStorage<N> {
+    fn latest_block_height(&self) -> Result<Option<u32>> {
+        match self.database.scan_prefix([MapID::BlockHashes as u8]).keys().next_back() {
+            Some(key) => Ok(Some(Self::decode_height(&key?[1..])?)),
+            None => Ok(None),
+        }
+    }
+
+    fn get_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
+        self.get(&MapID::BlockHashes.height_key(height))
+    }
+
+    fn get_block_height(&self, block_hash: &N::BlockHash) -> Result<Option<u32>> {
+        self.get_height(&MapID::BlockHeights.key(block_hash)?)
+    }
+
+    fn get_previous_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
+        self.get(&MapID::PreviousHashes.height_key(height))
+    }
+
+    fn get_block_header(&self, height: u32) -> Result<Option<BlockHeader<N>>> {
+        self.get(&MapID::Headers.height_key(height))
+    }
+
+    fn get_block_transactions(&self, height: u32) -> Result<Option<Transactions<N>>> {
+        // The transactions were checked before they were stored, so they are not re-verified here.
+        match self.database.get(MapID::Transactions.height_key(height))? {
+            Some(bytes) => Ok(Some(Transactions::read_le_unchecked(&bytes[..])?)),
+            None => Ok(None),
+        }
+    }
+
+    fn get_transaction_height(&self, transaction_id: &N::TransactionID) -> Result<Option<u32>> {
+        self.get_height(&MapID::TransactionHeights.key(transaction_id)?)
+    }
+
+    fn get_commitment_transaction_id(&self, commitment: &N::Commitment) -> Result<Option<N::TransactionID>> {
+        self.get(&MapID::Commitments.key(commitment)?)
+    }
+
+    fn get_ledger_root_height(&self, ledger_root: &N::LedgerRoot) -> Result<Option<u32>> {
+        self.get_height(&MapID::LedgerRoots.key(ledger_root)?)
+    }
+
+    fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> Result<bool> {
+        self.contains(&MapID::LedgerRoots.key(ledger_root)?)
+    }
+
+    fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> Result<bool> {
+        self.contains(&MapID::SerialNumbers.key(serial_number)?)
+    }
+
+    fn contains_commitment(&self, commitment: &N::Commitment) -> Result<bool> {
+        self.contains(&MapID::Commitments.key(commitment)?)
+    }
+
+    fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        self.contains(&MapID::TransactionHeights.key(transaction_id)?)
+    }
+
+    fn insert_block(&mut self, block: &Block<N>) -> Result<()> {
+        let height = block.height();
+        if self.contains(&MapID::BlockHashes.height_key(height))? {
+            return Err(anyhow!("Block height {} already exists in storage", height));
+        }
+
+        let height_bytes = IVec::from(&height.to_be_bytes());
+
+        // Serialize the transactions list, without re-verifying each transaction.
+        let mut transactions_bytes = Vec::new();
+        block.transactions().write_le_unchecked(&mut transactions_bytes)?;
+
+        // Add the block to storage. This batch is applied atomically.
+        let mut batch = Batch::default();
+        batch.insert(MapID::BlockHashes.height_key(height), block.hash().to_bytes_le()?);
+        batch.insert(MapID::BlockHeights.key(block.hash())?, height_bytes.clone());
+        batch.insert(
+            MapID::PreviousHashes.height_key(height),
+            block.previous_block_hash().to_bytes_le()?,
+        );
+        batch.insert(MapID::Headers.height_key(height), block.header().to_bytes_le()?);
+        batch.insert(MapID::Transactions.height_key(height), transactions_bytes);
+        batch.insert(
+            MapID::LedgerRoots.key(block.previous_ledger_root())?,
+            height_bytes.clone(),
+        );
+        for transaction in block.transactions().iter() {
+            let transaction_id = transaction.transaction_id();
+            let transaction_id_bytes = transaction_id.to_bytes_le()?;
+            batch.insert(MapID::TransactionHeights.key(transaction_id)?, height_bytes.clone());
+            for serial_number in transaction.serial_numbers() {
+                batch.insert(MapID::SerialNumbers.key(serial_number)?, transaction_id_bytes.clone());
+            }
+            for commitment in transaction.commitments() {
+                batch.insert(MapID::Commitments.key(commitment)?, transaction_id_bytes.clone());
+            }
+        }
+        self.database.apply_batch(batch)?;
+
+        Ok(())
+    }
+
+    fn remove_blocks(&mut self, start_height: u32) -> Result<()> {
+        let latest_height = match self.latest_block_height()? {
+            Some(latest_height) if start_height <= latest_height => latest_height,
+            _ => return Err(anyhow!("Block height {} does not exist in storage", start_height)),
+        };
+
+        // Remove the blocks from storage. This batch is applied atomically.
+        let mut batch = Batch::default();
+        for height in start_height..=latest_height {
+            if let Some(block_hash) = self.get::<N::BlockHash>(&MapID::BlockHashes.height_key(height))? {
+                batch.remove(MapID::BlockHeights.key(block_hash)?);
+            }
+            batch.remove(MapID::BlockHashes.height_key(height));
+            batch.remove(MapID::PreviousHashes.height_key(height));
+            if let Some(header) = self.get::<BlockHeader<N>>(&MapID::Headers.height_key(height))? {
+                batch.remove(MapID::LedgerRoots.key(header.previous_ledger_root())?);
+            }
+            batch.remove(MapID::Headers.height_key(height));
+            if let Some(transactions) = self.get_block_transactions(height)? {
+                for transaction in transactions.iter() {
+                    batch.remove(MapID::TransactionHeights.key(transaction.transaction_id())?);
+                    for serial_number in transaction.serial_numbers() {
+                        batch.remove(MapID::SerialNumbers.key(serial_number)?);
+                    }
+                    for commitment in transaction.commitments() {
+                        batch.remove(MapID::Commitments.key(commitment)?);
+                    }
+                }
+            }
+            batch.remove(MapID::Transactions.height_key(height));
+        }
+        self.database.apply_batch(batch)?;
+
+        Ok(())
+    }
+}
@@ -61,6 +61,9 @@ pub use transition::*;
 pub mod virtual_machine;
 pub use virtual_machine::*;
 
+pub mod wallet;
+pub use wallet::*;
+
 pub mod prelude {
     pub use crate::{
         account::*,
@@ -72,5 +75,6 @@ pub mod prelude {
         transaction::*,
         transition::*,
         virtual_machine::*,
+        wallet::*,
     };
 }
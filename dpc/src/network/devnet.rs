@@ -0,0 +1,519 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT,
+    posw::PoSW,
+    Address,
+    AleoLocator,
+    AleoObject,
+    Block,
+    Ciphertext,
+    Execution,
+    Function,
+    GenesisBlockCell,
+    InnerCircuit,
+    InnerPublicVariables,
+    Network,
+    Noop,
+    NoopPrivateVariables,
+    OuterCircuit,
+    OuterPublicVariables,
+    PoSWScheme,
+    PrivateKey,
+    Program,
+    ProgramPrivateVariables,
+    ProgramPublicVariables,
+    SynthesizedCircuit,
+};
+use snarkvm_algorithms::{
+    crh::{sha256::sha256, PedersenCompressedCRH, PoseidonCRH, BHPCRH},
+    encryption::ECIESPoseidonEncryption,
+    merkle_tree::{MaskedMerkleTreeParameters, MerklePath, MerkleTreeParameters},
+    prelude::*,
+    prf::PoseidonPRF,
+    signature::AleoSignatureScheme,
+    snark::groth16::Groth16,
+};
+use snarkvm_curves::{
+    bls12_377::Bls12_377,
+    bw6_761::BW6_761,
+    edwards_bls12::{
+        EdwardsAffine as EdwardsBls12Affine,
+        EdwardsParameters,
+        EdwardsProjective as EdwardsBls12Projective,
+    },
+    edwards_bw6::EdwardsProjective as EdwardsBW6,
+    traits::*,
+};
+use snarkvm_gadgets::{
+    algorithms::{
+        crh::{BHPCRHGadget, PedersenCompressedCRHGadget, PoseidonCRHGadget},
+        encryption::ECIESPoseidonEncryptionGadget,
+        prf::PoseidonPRFGadget,
+        signature::AleoSignatureSchemeGadget,
+        snark::Groth16VerifierGadget,
+    },
+    curves::{bls12_377::PairingGadget, edwards_bls12::EdwardsBls12Gadget, edwards_bw6::EdwardsBW6Gadget},
+};
+use snarkvm_marlin::{
+    ahp::AHPForR1CS,
+    constraints::snark::MarlinSNARK,
+    marlin::{MarlinPoswMode, MarlinTestnet1Mode},
+    FiatShamirAlgebraicSpongeRng,
+    PoseidonSponge,
+};
+use snarkvm_polycommit::sonic_pc::SonicKZG10;
+use snarkvm_utilities::ToMinimalBits;
+
+use anyhow::Result;
+use once_cell::sync::{Lazy, OnceCell};
+use rand::{CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, rc::Rc};
+
+/// The seed from which all devnet parameters are deterministically derived.
+pub const DEVNET_SEED: &str = "AleoDevnetSetup0";
+
+///
+/// A local development network.
+///
+/// The devnet uses small tree depths and a trivial difficulty target, and does not ship parameter files.
+/// Instead, its circuit keys are generated from `DEVNET_SEED` on first use and cached, so every devnet
+/// instance agrees on the same keys without any downloads. The genesis block is mined on first use,
+/// paying the coinbase to `Devnet::genesis_private_key()`. As all of its proofs are sampled from an RNG
+/// seeded with `DEVNET_SEED`, every devnet process mines the same genesis block.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Devnet;
+
+impl Devnet {
+    /// Returns the private key of the recipient of the genesis block coinbase.
+    pub fn genesis_private_key() -> &'static PrivateKey<Self> {
+        static GENESIS_PRIVATE_KEY: OnceCell<PrivateKey<Devnet>> = OnceCell::new();
+        GENESIS_PRIVATE_KEY.get_or_init(|| PrivateKey::new(&mut Self::setup_rng("genesis private key")))
+    }
+
+    /// Returns a deterministic RNG for the given setup, derived from `DEVNET_SEED`.
+    fn setup_rng(persona: &str) -> ChaChaRng {
+        ChaChaRng::from_seed(sha256(format!("{}.{}", DEVNET_SEED, persona).as_bytes()))
+    }
+}
+
+/// The genesis block of Devnet.
+static GENESIS_BLOCK: Lazy<GenesisBlockCell<Devnet>> = Lazy::new(GenesisBlockCell::new);
+
+#[rustfmt::skip]
+impl Network for Devnet {
+    const NETWORK_ID: u16 = 3u16;
+    const NETWORK_NAME: &'static str = "devnet";
+
+    const NUM_INPUT_RECORDS: usize = 2;
+    const NUM_OUTPUT_RECORDS: usize = 2;
+    const NUM_TRANSITIONS: u8 = u8::pow(2, Self::TRANSACTION_TREE_DEPTH as u32);
+    const NUM_EVENTS: u16 = 2;
+
+    const BLOCK_HASH_PREFIX: u16 = hrp2!("ab");
+    const LEDGER_ROOT_PREFIX: u16 = hrp2!("al");
+    const PROGRAM_ID_PREFIX: u16 = hrp2!("ap");
+    const TRANSITION_ID_PREFIX: u16 = hrp2!("as");
+    const TRANSACTION_ID_PREFIX: u16 = hrp2!("at");
+
+    const COMMITMENT_PREFIX: u16 = hrp2!("cm");
+    const FUNCTION_INPUTS_HASH_PREFIX: u16 = hrp2!("fi");
+    const FUNCTION_ID_PREFIX: u16 = hrp2!("fn");
+    const HEADER_NONCE_PREFIX: u16 = hrp2!("hn");
+    const HEADER_ROOT_PREFIX: u16 = hrp2!("hr");
+    const HEADER_TRANSACTIONS_ROOT_PREFIX: u16 = hrp2!("ht");
+    const INNER_CIRCUIT_ID_PREFIX: u16 = hrp2!("ic");
+    const RECORD_RANDOMIZER_PREFIX: u16 = hrp2!("rr");
+    const RECORD_VIEW_KEY_COMMITMENT_PREFIX: u16 = hrp2!("rc");
+    const SERIAL_NUMBER_PREFIX: u16 = hrp2!("sn");
+
+    const HEADER_PROOF_PREFIX: u32 = hrp4!("hzkp");
+    const INNER_PROOF_PREFIX: u32 = hrp4!("izkp");
+    const OUTER_PROOF_PREFIX: u32 = hrp4!("ozkp");
+    const PROGRAM_PROOF_PREFIX: u32 = hrp4!("pzkp");
+    const RECORD_CIPHERTEXT_PREFIX: u32 = hrp4!("recd");
+    const RECORD_VIEW_KEY_PREFIX: u32 = hrp4!("rcvk");
+    const SIGNATURE_PREFIX: u32 = hrp4!("sign");
+
+    const ADDRESS_SIZE_IN_BYTES: usize = 32;
+    const HEADER_SIZE_IN_BYTES: usize = 903;
+    const HEADER_PROOF_SIZE_IN_BYTES: usize = 771;
+    const INNER_PROOF_SIZE_IN_BYTES: usize = 193;
+    const OUTER_PROOF_SIZE_IN_BYTES: usize = 289;
+    const PROGRAM_PROOF_SIZE_IN_BYTES: usize = 193;
+    const RECORD_SIZE_IN_BYTES: usize = 280;
+    const RECORD_CIPHERTEXT_SIZE_IN_BYTES: usize = 288;
+    const RECORD_PAYLOAD_SIZE_IN_BYTES: usize = 128;
+    const RECORD_VIEW_KEY_SIZE_IN_BYTES: usize = 32;
+    const SIGNATURE_SIZE_IN_BYTES: usize = 128;
+
+    const HEADER_TRANSACTIONS_TREE_DEPTH: usize = 8;
+    const HEADER_TREE_DEPTH: usize = 2;
+    const LEDGER_TREE_DEPTH: usize = 16;
+    const PROGRAM_TREE_DEPTH: usize = 4;
+    const TRANSITION_TREE_DEPTH: usize = 2;
+    const TRANSACTION_TREE_DEPTH: usize = 3;
+
+    const ALEO_BLOCK_TIME_IN_SECS: i64 = 1i64;
+    const ALEO_STARTING_SUPPLY_IN_CREDITS: i64 = 1_000_000_000;
+    const ALEO_FUTURE_TIME_LIMIT_IN_SECS: i64 = 90;
+    const ALEO_MAXIMUM_FORK_DEPTH: u32 = 4096;
+    const ALEO_CONFIGURABLE_GENESIS: bool = true;
+
+    type InnerCurve = Bls12_377;
+    type InnerScalarField = <Self::InnerCurve as PairingEngine>::Fr;
+    
+    type OuterCurve = BW6_761;
+    type OuterBaseField = <Self::OuterCurve as PairingEngine>::Fq;
+    type OuterScalarField = <Self::OuterCurve as PairingEngine>::Fr;
+
+    type ProgramAffineCurve = EdwardsBls12Affine;
+    type ProgramAffineCurveGadget = EdwardsBls12Gadget;
+    type ProgramProjectiveCurve = EdwardsBls12Projective;
+    type ProgramCurveParameters = EdwardsParameters;
+    type ProgramBaseField = <Self::ProgramCurveParameters as ModelParameters>::BaseField;
+    type ProgramScalarField = <Self::ProgramCurveParameters as ModelParameters>::ScalarField;
+
+    type InnerSNARK = Groth16<Self::InnerCurve, InnerPublicVariables<Devnet>>;
+    type InnerSNARKGadget = Groth16VerifierGadget<Self::InnerCurve, PairingGadget>;
+    type InnerProof = AleoObject<<Self::InnerSNARK as SNARK>::Proof, { Self::INNER_PROOF_PREFIX }, { Self::INNER_PROOF_SIZE_IN_BYTES }>;
+
+    type OuterSNARK = Groth16<Self::OuterCurve, OuterPublicVariables<Devnet>>;
+    type OuterProof = AleoObject<<Self::OuterSNARK as SNARK>::Proof, { Self::OUTER_PROOF_PREFIX }, { Self::OUTER_PROOF_SIZE_IN_BYTES }>;
+
+    type ProgramSNARK = Groth16<Self::InnerCurve, ProgramPublicVariables<Self>>;
+    type ProgramSNARKGadget = Groth16VerifierGadget<Self::InnerCurve, PairingGadget>;
+    type ProgramProvingKey = <Self::ProgramSNARK as SNARK>::ProvingKey;
+    type ProgramVerifyingKey = <Self::ProgramSNARK as SNARK>::VerifyingKey;
+    type ProgramProof = AleoObject<<Self::ProgramSNARK as SNARK>::Proof, { Self::PROGRAM_PROOF_PREFIX }, { Self::PROGRAM_PROOF_SIZE_IN_BYTES }>;
+
+    type PoSWSNARK = MarlinSNARK<Self::InnerScalarField, Self::OuterScalarField, SonicKZG10<Self::InnerCurve>, FiatShamirAlgebraicSpongeRng<Self::InnerScalarField, Self::OuterScalarField, PoseidonSponge<Self::OuterScalarField, 6, 1>>, MarlinPoswMode, Vec<Self::InnerScalarField>>;
+    type PoSWProof = AleoObject<<Self::PoSWSNARK as SNARK>::Proof, { Self::HEADER_PROOF_PREFIX }, { Self::HEADER_PROOF_SIZE_IN_BYTES }>;
+    type PoSW = PoSW<Self>;
+
+    type AccountEncryptionScheme = ECIESPoseidonEncryption<Self::ProgramCurveParameters>;
+    type AccountEncryptionGadget = ECIESPoseidonEncryptionGadget<Self::ProgramCurveParameters, Self::InnerScalarField>;
+
+    type AccountSeedPRF = PoseidonPRF<Self::ProgramScalarField, 4, false>;
+    type AccountSeed = <Self::AccountSeedPRF as PRF>::Seed;
+    
+    type AccountSignatureScheme = AleoSignatureScheme<Self::ProgramCurveParameters>;
+    type AccountSignatureGadget = AleoSignatureSchemeGadget<Self::ProgramCurveParameters, Self::InnerScalarField>;
+    type AccountSignaturePublicKey = <Self::AccountSignatureScheme as SignatureScheme>::PublicKey;
+    type AccountSignature = AleoObject<<Self::AccountSignatureScheme as SignatureScheme>::Signature, { Self::SIGNATURE_PREFIX }, { Self::SIGNATURE_SIZE_IN_BYTES }>;
+
+    type BlockHashCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
+    type BlockHashCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
+    type BlockHash = AleoLocator<<Self::BlockHashCRH as CRH>::Output, { Self::BLOCK_HASH_PREFIX }>;
+
+    type BlockHeaderRootCRH = PedersenCompressedCRH<Self::ProgramProjectiveCurve, 4, 128>;
+    type BlockHeaderRootCRHGadget = PedersenCompressedCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 4, 128>;
+    type BlockHeaderRootParameters = MaskedMerkleTreeParameters<Self::BlockHeaderRootCRH, { Self::HEADER_TREE_DEPTH }>;
+    type BlockHeaderRoot = AleoLocator<<Self::BlockHeaderRootCRH as CRH>::Output, { Self::HEADER_ROOT_PREFIX }>;
+
+    type CommitmentScheme = BHPCRH<Self::ProgramProjectiveCurve, 41, 63>;
+    type CommitmentGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 41, 63>;
+    type Commitment = AleoLocator<<Self::CommitmentScheme as CRH>::Output, { Self::COMMITMENT_PREFIX }>;
+
+    type FunctionIDCRH = PoseidonCRH<Self::OuterScalarField, 34>;
+    type FunctionIDCRHGadget = PoseidonCRHGadget<Self::OuterScalarField, 34>;
+    type FunctionID = AleoLocator<<Self::FunctionIDCRH as CRH>::Output, { Self::FUNCTION_ID_PREFIX }>;
+
+    type FunctionInputsCRH = PoseidonCRH<Self::InnerScalarField, 128>;
+    type FunctionInputsCRHGadget = PoseidonCRHGadget<Self::InnerScalarField, 128>;
+    type FunctionInputsHash = AleoLocator<<Self::FunctionInputsCRH as CRH>::Output, { Self::FUNCTION_INPUTS_HASH_PREFIX }>;
+
+    type InnerCircuitIDCRH = BHPCRH<EdwardsBW6, 85, 63>;
+    type InnerCircuitIDCRHGadget = BHPCRHGadget<EdwardsBW6, Self::OuterScalarField, EdwardsBW6Gadget, 85, 63>;
+    type InnerCircuitID = AleoLocator<<Self::InnerCircuitIDCRH as CRH>::Output, { Self::INNER_CIRCUIT_ID_PREFIX }>;
+
+    type LedgerRootCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
+    type LedgerRootCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
+    type LedgerRootParameters = MerkleTreeParameters<Self::LedgerRootCRH, { Self::LEDGER_TREE_DEPTH }>;
+    type LedgerRoot = AleoLocator<<Self::LedgerRootCRH as CRH>::Output, { Self::LEDGER_ROOT_PREFIX }>;
+
+    type PoSWMaskPRF = PoseidonPRF<Self::InnerScalarField, 4, false>;
+    type PoSWMaskPRFGadget = PoseidonPRFGadget<Self::InnerScalarField, 4, false>;
+    type PoSWNonce = AleoLocator<Self::InnerScalarField, { Self::HEADER_NONCE_PREFIX }>;
+
+    type ProgramIDCRH = BHPCRH<EdwardsBW6, 16, 48>;
+    type ProgramIDCRHGadget = BHPCRHGadget<EdwardsBW6, Self::OuterScalarField, EdwardsBW6Gadget, 16, 48>;
+    type ProgramIDParameters = MerkleTreeParameters<Self::ProgramIDCRH, { Self::PROGRAM_TREE_DEPTH }>;
+    type ProgramID = AleoLocator<<Self::ProgramIDCRH as CRH>::Output, { Self::PROGRAM_ID_PREFIX }>;
+
+    type RecordCiphertext = AleoObject<Ciphertext<Self>, { Self::RECORD_CIPHERTEXT_PREFIX }, { Self::RECORD_CIPHERTEXT_SIZE_IN_BYTES }>;
+    type RecordRandomizer = AleoLocator<<Self::AccountEncryptionScheme as EncryptionScheme>::CiphertextRandomizer, { Self::RECORD_RANDOMIZER_PREFIX }>;
+    type RecordViewKey = AleoObject<<Self::AccountEncryptionScheme as EncryptionScheme>::SymmetricKey, { Self::RECORD_VIEW_KEY_PREFIX }, { Self::RECORD_VIEW_KEY_SIZE_IN_BYTES }>;
+    type RecordViewKeyCommitment = AleoLocator<<Self::AccountEncryptionScheme as EncryptionScheme>::SymmetricKeyCommitment, { Self::RECORD_VIEW_KEY_COMMITMENT_PREFIX }>;
+
+    type SerialNumberPRF = PoseidonPRF<Self::InnerScalarField, 4, false>;
+    type SerialNumberPRFGadget = PoseidonPRFGadget<Self::InnerScalarField, 4, false>;
+    type SerialNumber = AleoLocator<<Self::SerialNumberPRF as PRF>::Output, { Self::SERIAL_NUMBER_PREFIX }>;
+
+    type TransactionsRootCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
+    type TransactionsRootCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
+    type TransactionsRootParameters = MerkleTreeParameters<Self::TransactionsRootCRH, { Self::HEADER_TRANSACTIONS_TREE_DEPTH }>;
+    type TransactionsRoot = AleoLocator<<Self::TransactionsRootCRH as CRH>::Output, { Self::HEADER_TRANSACTIONS_ROOT_PREFIX }>;
+
+    type TransactionIDCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
+    type TransactionIDCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
+    type TransactionIDParameters = MerkleTreeParameters<Self::TransactionIDCRH, { Self::TRANSACTION_TREE_DEPTH }>;
+    type TransactionID = AleoLocator<<Self::TransactionIDCRH as CRH>::Output, { Self::TRANSACTION_ID_PREFIX }>;
+
+    type TransitionIDCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
+    type TransitionIDCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
+    type TransitionIDParameters = MerkleTreeParameters<Self::TransitionIDCRH, { Self::TRANSITION_TREE_DEPTH }>;
+    type TransitionID = AleoLocator<<Self::TransitionIDCRH as CRH>::Output, { Self::TRANSITION_ID_PREFIX }>;
+
+    dpc_setup!{Devnet, account_encryption_scheme, AccountEncryptionScheme, ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT}
+    dpc_setup!{Devnet, account_signature_scheme, AccountSignatureScheme, ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT}
+    dpc_setup!{Devnet, block_hash_crh, BlockHashCRH, "AleoBlockHashCRH0"}
+    dpc_setup!{Devnet, block_header_root_parameters, BlockHeaderRootParameters, "AleoBlockHeaderRootCRH0"}
+    dpc_setup!{Devnet, commitment_scheme, CommitmentScheme, "AleoCommitmentScheme0"}
+    dpc_setup!{Devnet, function_id_crh, FunctionIDCRH, "AleoFunctionIDCRH0"}
+    dpc_setup!{Devnet, inner_circuit_id_crh, InnerCircuitIDCRH, "AleoInnerCircuitIDCRH0"}
+    dpc_setup!{Devnet, ledger_root_parameters, LedgerRootParameters, "AleoLedgerRootCRH0"}
+    dpc_setup!{Devnet, program_id_parameters, ProgramIDParameters, "AleoProgramIDCRH0"}
+    dpc_setup!{Devnet, transactions_root_parameters, TransactionsRootParameters, "AleoTransactionsRootCRH0"}
+    dpc_setup!{Devnet, transaction_id_parameters, TransactionIDParameters, "AleoTransactionIDCRH0"}
+    dpc_setup!{Devnet, transition_id_parameters, TransitionIDParameters, "AleoTransitionIDCRH0"}
+
+    fn inner_proving_key() -> &'static <Self::InnerSNARK as SNARK>::ProvingKey {
+        static INNER_PROVING_KEY: OnceCell<<<Devnet as Network>::InnerSNARK as SNARK>::ProvingKey> = OnceCell::new();
+        INNER_PROVING_KEY.get_or_init(|| {
+            let (proving_key, _) = Self::InnerSNARK::setup(
+                &InnerCircuit::<Self>::blank(),
+                &mut SRS::CircuitSpecific(&mut Self::setup_rng("inner circuit")),
+            ).expect("Failed to generate the inner circuit proving key");
+            proving_key
+        })
+    }
+
+    fn inner_verifying_key() -> &'static <Self::InnerSNARK as SNARK>::VerifyingKey {
+        &Self::inner_proving_key().vk
+    }
+
+    fn outer_proving_key() -> &'static <Self::OuterSNARK as SNARK>::ProvingKey {
+        static OUTER_PROVING_KEY: OnceCell<<<Devnet as Network>::OuterSNARK as SNARK>::ProvingKey> = OnceCell::new();
+        OUTER_PROVING_KEY.get_or_init(|| {
+            let mut rng = Self::setup_rng("outer circuit");
+
+            // The outer circuit is synthesized over a proof of the blank inner circuit.
+            let inner_proof = Self::InnerSNARK::prove(Self::inner_proving_key(), &InnerCircuit::<Self>::blank(), &mut rng)
+                .expect("Failed to prove the blank inner circuit");
+            let execution = Execution {
+                program_id: *Self::noop_program_id(),
+                program_path: Self::noop_program_path().clone(),
+                verifying_key: Self::noop_circuit_verifying_key().clone(),
+                proof: Noop::<Self>::new()
+                    .execute(ProgramPublicVariables::blank(), &NoopPrivateVariables::<Self>::new_blank().expect("Failed to initialize noop variables"))
+                    .expect("Failed to execute the noop function"),
+            };
+
+            let (proving_key, _) = Self::OuterSNARK::setup(
+                &OuterCircuit::<Self>::blank(Self::inner_verifying_key().clone(), inner_proof.into(), execution),
+                &mut SRS::CircuitSpecific(&mut rng),
+            ).expect("Failed to generate the outer circuit proving key");
+            proving_key
+        })
+    }
+
+    fn outer_verifying_key() -> &'static <Self::OuterSNARK as SNARK>::VerifyingKey {
+        &Self::outer_proving_key().vk
+    }
+
+    fn noop_circuit_proving_key() -> &'static <Self::ProgramSNARK as SNARK>::ProvingKey {
+        static NOOP_CIRCUIT_PROVING_KEY: OnceCell<<<Devnet as Network>::ProgramSNARK as SNARK>::ProvingKey> = OnceCell::new();
+        NOOP_CIRCUIT_PROVING_KEY.get_or_init(|| {
+            let (proving_key, _) = Self::ProgramSNARK::setup(
+                &SynthesizedCircuit::<Self>::Noop(Default::default()),
+                &mut SRS::CircuitSpecific(&mut Self::setup_rng("noop circuit")),
+            ).expect("Failed to generate the noop circuit proving key");
+            proving_key
+        })
+    }
+
+    fn noop_circuit_verifying_key() -> &'static <Self::ProgramSNARK as SNARK>::VerifyingKey {
+        &Self::noop_circuit_proving_key().vk
+    }
+
+    fn posw_proving_key() -> &'static <Self::PoSWSNARK as SNARK>::ProvingKey {
+        Self::posw().proving_key().as_ref().expect("Failed to load the PoSW proving key")
+    }
+
+    fn posw_verifying_key() -> &'static <Self::PoSWSNARK as SNARK>::VerifyingKey {
+        Self::posw().verifying_key()
+    }
+
+    fn inner_circuit_id() -> &'static Self::InnerCircuitID {
+        static INNER_CIRCUIT_ID: OnceCell<<Devnet as Network>::InnerCircuitID> = OnceCell::new();
+        INNER_CIRCUIT_ID.get_or_init(|| Self::inner_circuit_id_crh()
+            .hash_bits(&Self::inner_verifying_key().to_minimal_bits())
+            .expect("Failed to hash inner circuit verifying key elements").into())
+    }
+
+    fn noop_program() -> &'static Program<Self> {
+        static NOOP_PROGRAM: OnceCell<Program<Devnet>> = OnceCell::new();
+        NOOP_PROGRAM.get_or_init(|| Program::<Devnet>::new_noop().expect("Failed to fetch the noop program"))
+    }
+
+    fn noop_program_id() -> &'static Self::ProgramID {
+        static NOOP_PROGRAM_ID: OnceCell<<Devnet as Network>::ProgramID> = OnceCell::new();
+        NOOP_PROGRAM_ID.get_or_init(|| Devnet::noop_program().program_id())
+    }
+    
+    fn noop_program_path() -> &'static MerklePath<Self::ProgramIDParameters> {
+        static NOOP_PROGRAM_PATH: OnceCell<MerklePath<<Devnet as Network>::ProgramIDParameters>> = OnceCell::new();
+        NOOP_PROGRAM_PATH.get_or_init(|| Self::noop_program().to_program_path(Self::noop_function_id()).expect("Failed to fetch the noop program path"))
+    }
+    
+    fn noop_function_id() -> &'static Self::FunctionID {
+        static NOOP_FUNCTION_ID: OnceCell<<Devnet as Network>::FunctionID> = OnceCell::new();
+        NOOP_FUNCTION_ID.get_or_init(|| Self::function_id(Self::noop_circuit_verifying_key()).expect("Failed to hash noop circuit verifying key"))
+    }
+
+    fn posw() -> &'static Self::PoSW {
+        static POSW: OnceCell<<Devnet as Network>::PoSW> = OnceCell::new();
+        POSW.get_or_init(|| {
+            let mut rng = Self::setup_rng("posw");
+            let max_degree = AHPForR1CS::<Self::InnerScalarField, MarlinTestnet1Mode>::max_degree(40000, 40000, 60000)
+                .expect("Failed to compute the PoSW maximum degree");
+            let universal_srs = Self::PoSWSNARK::universal_setup(&max_degree, &mut rng)
+                .expect("Failed to generate the PoSW universal SRS");
+            <Self::PoSW as PoSWScheme<Self>>::setup::<ChaChaRng>(&mut SRS::Universal(&universal_srs))
+                .expect("Failed to generate PoSW")
+        })
+    }
+
+    fn genesis_block() -> &'static Block<Self> {
+        GENESIS_BLOCK.get_or_init(|| {
+            let recipient = Address::from_private_key(Self::genesis_private_key());
+            Block::new_genesis(recipient, &mut Self::setup_rng("genesis block")).expect("Failed to generate the genesis block")
+        })
+    }
+
+    fn set_genesis_block(block: Block<Self>) -> Result<()> {
+        GENESIS_BLOCK.set(block)
+    }
+
+    /// Returns the program SRS for Aleo applications.
+    fn program_srs<R: Rng + CryptoRng>(rng: &mut R) -> Rc<RefCell<SRS<'_, R, <Self::ProgramSNARK as SNARK>::UniversalSetupParameters>>> {
+        Rc::new(RefCell::new(SRS::CircuitSpecific(rng)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ledger::memory_pool::tests::new_transaction, prelude::*};
+
+    use rand::thread_rng;
+    use std::sync::atomic::AtomicBool;
+
+    #[test]
+    fn test_network_name_sanity_check() {
+        assert_eq!(Devnet::NETWORK_NAME, "devnet");
+    }
+
+    #[test]
+    fn test_noop_circuit_determinism() {
+        // Verify the noop circuit keys are reproduced from the devnet seed.
+        let (_, verifying_key) = <Devnet as Network>::ProgramSNARK::setup(
+            &SynthesizedCircuit::<Devnet>::Noop(Default::default()),
+            &mut SRS::CircuitSpecific(&mut Devnet::setup_rng("noop circuit")),
+        )
+        .unwrap();
+        assert_eq!(Devnet::noop_circuit_verifying_key(), &verifying_key);
+    }
+
+    #[test]
+    fn test_inner_circuit_id_derivation() {
+        // Verify the inner circuit ID matches the one derived from the inner circuit verifying key.
+        assert_eq!(
+            Devnet::inner_circuit_id(),
+            &Devnet::inner_circuit_id_crh()
+                .hash_bits(&Devnet::inner_verifying_key().to_minimal_bits())
+                .expect("Failed to hash inner circuit ID")
+                .into(),
+            "The inner circuit ID does not correspond to the inner circuit verifying key"
+        );
+    }
+
+    #[test]
+    fn test_genesis_block_determinism() {
+        // Verify the genesis block is reproduced from the devnet seed.
+        let recipient = Address::from_private_key(Devnet::genesis_private_key());
+        let genesis_block = Block::new_genesis(recipient, &mut Devnet::setup_rng("genesis block")).unwrap();
+        assert_eq!(Devnet::genesis_block(), &genesis_block);
+    }
+
+    #[test]
+    fn test_ledger_flow() {
+        let rng = &mut thread_rng();
+        let account = Account::<Devnet>::new(rng);
+        let recipient = Account::<Devnet>::new(rng);
+
+        // Mine a block to the account.
+        let mut ledger = Ledger::<Devnet>::new().unwrap();
+        let record = ledger
+            .mine_next_block(account.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        assert_eq!(1, ledger.latest_block_height());
+
+        // Transfer from the account to the recipient, through the memory pool.
+        let transaction = TransactionBuilder::new()
+            .add_record(record.clone())
+            .add_recipient(recipient.address(), AleoAmount::from_i64(100))
+            .set_fee(AleoAmount::from_i64(1))
+            .build(account.private_key(), &ledger, rng)
+            .unwrap();
+        ledger.add_unconfirmed_transaction(&transaction).unwrap();
+        assert_eq!(vec![transaction.clone()], ledger.memory_pool().transactions());
+
+        // Mine the transaction into the next block.
+        ledger
+            .mine_next_block(account.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        assert_eq!(2, ledger.latest_block_height());
+        assert!(ledger.contains_transaction(&transaction).unwrap());
+        assert!(ledger.memory_pool().transactions().is_empty());
+
+        // Ensure the spent record can not be spent again.
+        let double_spend = new_transaction(&ledger, &account, &[record], 1, rng);
+        assert!(ledger.add_unconfirmed_transaction(&double_spend).is_err());
+
+        // Ensure the ledger replays onto a new ledger.
+        let mut replica = Ledger::<Devnet>::new().unwrap();
+        for height in 1..=ledger.latest_block_height() {
+            replica.add_next_block(&ledger.get_block(height).unwrap()).unwrap();
+        }
+        assert_eq!(ledger.latest_block_hash(), replica.latest_block_hash());
+        assert_eq!(ledger.latest_ledger_root(), replica.latest_ledger_root());
+    }
+
+    #[test]
+    fn test_posw_tree_sanity_check() {
+        // Verify the PoSW tree depth matches the declared depth.
+        assert_eq!(
+            Devnet::HEADER_TREE_DEPTH,
+            <<Devnet as Network>::BlockHeaderRootParameters as MerkleParameters>::DEPTH
+        );
+    }
+}
@@ -21,6 +21,7 @@ use crate::{
     AleoObject,
     Block,
     Ciphertext,
+    GenesisBlockCell,
     InnerPublicVariables,
     Network,
     OuterPublicVariables,
@@ -68,7 +69,8 @@ use snarkvm_parameters::{testnet1::*, Genesis};
 use snarkvm_polycommit::sonic_pc::SonicKZG10;
 use snarkvm_utilities::{FromBytes, ToMinimalBits};
 
-use once_cell::sync::OnceCell;
+use anyhow::Result;
+use once_cell::sync::{Lazy, OnceCell};
 use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
 use std::{cell::RefCell, rc::Rc};
@@ -76,6 +78,9 @@ use std::{cell::RefCell, rc::Rc};
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Testnet1;
 
+/// The genesis block of Testnet1.
+static GENESIS_BLOCK: Lazy<GenesisBlockCell<Testnet1>> = Lazy::new(GenesisBlockCell::new);
+
 #[rustfmt::skip]
 impl Network for Testnet1 {
     const NETWORK_ID: u16 = 1u16;
@@ -134,6 +139,7 @@ impl Network for Testnet1 {
     const ALEO_STARTING_SUPPLY_IN_CREDITS: i64 = 1_000_000_000;
     const ALEO_FUTURE_TIME_LIMIT_IN_SECS: i64 = 90;
     const ALEO_MAXIMUM_FORK_DEPTH: u32 = 4096;
+    const ALEO_CONFIGURABLE_GENESIS: bool = false;
 
     type InnerCurve = Bls12_377;
     type InnerScalarField = <Self::InnerCurve as PairingEngine>::Fr;
@@ -298,8 +304,11 @@ impl Network for Testnet1 {
     }
 
     fn genesis_block() -> &'static Block<Self> {
-        static BLOCK: OnceCell<Block<Testnet1>> = OnceCell::new();
-        BLOCK.get_or_init(|| FromBytes::read_le(&GenesisBlock::load_bytes()[..]).expect("Failed to load genesis block"))
+        GENESIS_BLOCK.get_or_init(|| FromBytes::read_le(&GenesisBlock::load_bytes()[..]).expect("Failed to load genesis block"))
+    }
+
+    fn set_genesis_block(block: Block<Self>) -> Result<()> {
+        GENESIS_BLOCK.set(block)
     }
 
     /// Returns the program SRS for Aleo applications.
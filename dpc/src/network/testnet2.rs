@@ -21,6 +21,7 @@ use crate::{
     AleoObject,
     Block,
     Ciphertext,
+    GenesisBlockCell,
     InnerPublicVariables,
     Network,
     OuterPublicVariables,
@@ -70,7 +71,8 @@ use snarkvm_parameters::{testnet2::*, Genesis};
 use snarkvm_polycommit::sonic_pc::{sonic_kzg10::SonicKZG10Gadget, SonicKZG10};
 use snarkvm_utilities::{FromBytes, ToMinimalBits};
 
-use once_cell::sync::OnceCell;
+use anyhow::Result;
+use once_cell::sync::{Lazy, OnceCell};
 use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
 use std::{cell::RefCell, rc::Rc};
@@ -96,6 +98,9 @@ pub type DeprecatedPoSWProof<N> = AleoObject<<DeprecatedPoSWSNARK<N> as SNARK>::
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Testnet2;
 
+/// The genesis block of Testnet2.
+static GENESIS_BLOCK: Lazy<GenesisBlockCell<Testnet2>> = Lazy::new(GenesisBlockCell::new);
+
 // TODO (raychu86): Optimize each of the window sizes in the type declarations below.
 #[rustfmt::skip]
 impl Network for Testnet2 {
@@ -155,6 +160,7 @@ impl Network for Testnet2 {
     const ALEO_STARTING_SUPPLY_IN_CREDITS: i64 = 1_000_000_000;
     const ALEO_FUTURE_TIME_LIMIT_IN_SECS: i64 = 90;
     const ALEO_MAXIMUM_FORK_DEPTH: u32 = 4096;
+    const ALEO_CONFIGURABLE_GENESIS: bool = false;
 
     type InnerCurve = Bls12_377;
     type InnerScalarField = <Self::InnerCurve as PairingEngine>::Fr;
@@ -319,8 +325,11 @@ impl Network for Testnet2 {
     }
     
     fn genesis_block() -> &'static Block<Self> {
-        static BLOCK: OnceCell<Block<Testnet2>> = OnceCell::new();
-        BLOCK.get_or_init(|| FromBytes::read_le(&GenesisBlock::load_bytes()[..]).expect("Failed to load the genesis block"))
+        GENESIS_BLOCK.get_or_init(|| FromBytes::read_le(&GenesisBlock::load_bytes()[..]).expect("Failed to load the genesis block"))
+    }
+
+    fn set_genesis_block(block: Block<Self>) -> Result<()> {
+        GENESIS_BLOCK.set(block)
     }
     
     /// Returns the program SRS for Aleo applications.
@@ -18,7 +18,7 @@
 //! which are then used to build a tree instantiated with a masked Pedersen hash. The prover
 //! inputs a mask computed as Blake2s(nonce || root), which the verifier also checks.
 
-use crate::{BlockTemplate, Network};
+use crate::{BlockTemplate, Network, WorkUnit};
 use snarkvm_algorithms::prelude::*;
 use snarkvm_gadgets::{
     algorithms::merkle_tree::compute_masked_root,
@@ -40,7 +40,12 @@ pub struct PoSWCircuit<N: Network> {
 impl<N: Network> PoSWCircuit<N> {
     /// Creates a PoSW circuit from the provided transaction ids and nonce.
     pub fn new(block_template: &BlockTemplate<N>, nonce: N::PoSWNonce) -> Result<Self> {
-        let tree = block_template.to_header_tree()?;
+        Self::from_work_unit(&WorkUnit::new(block_template), nonce)
+    }
+
+    /// Creates a PoSW circuit from the provided work unit and nonce.
+    pub fn from_work_unit(work_unit: &WorkUnit<N>, nonce: N::PoSWNonce) -> Result<Self> {
+        let tree = work_unit.to_header_tree()?;
 
         Ok(Self {
             block_header_root: (*tree.root()).into(),
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{BlockHeader, Network};
+
+use std::time::Duration;
+
+/// The progress of a PoSW mining session.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MiningProgress {
+    /// The number of PoSW proofs attempted.
+    pub iterations: u64,
+    /// The time elapsed since mining started.
+    pub elapsed: Duration,
+    /// The lowest proof difficulty seen so far, where lower is better.
+    pub best_proof_difficulty: Option<u64>,
+}
+
+impl MiningProgress {
+    /// Records a PoSW attempt with the given proof difficulty.
+    pub(crate) fn update(&mut self, proof_difficulty: u64, elapsed: Duration) {
+        self.iterations += 1;
+        self.elapsed = elapsed;
+        self.best_proof_difficulty = Some(match self.best_proof_difficulty {
+            Some(best_proof_difficulty) => best_proof_difficulty.min(proof_difficulty),
+            None => proof_difficulty,
+        });
+    }
+
+    /// Combines the progress of two concurrent mining sessions.
+    pub(crate) fn merge(&mut self, other: &Self) {
+        self.iterations += other.iterations;
+        self.elapsed = self.elapsed.max(other.elapsed);
+        self.best_proof_difficulty = match (self.best_proof_difficulty, other.best_proof_difficulty) {
+            (Some(first), Some(second)) => Some(first.min(second)),
+            (first, second) => first.or(second),
+        };
+    }
+}
+
+/// The outcome of a PoSW mining session.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub enum MiningOutcome<N: Network> {
+    /// A block header that satisfies the difficulty target was found.
+    Found(BlockHeader<N>),
+    /// Mining was cancelled by the terminator.
+    Cancelled(MiningProgress),
+    /// Mining reached the deadline without finding a block header.
+    Expired(MiningProgress),
+}
+
+impl<N: Network> MiningOutcome<N> {
+    /// Returns the block header, if one was found.
+    pub fn block_header(self) -> Option<BlockHeader<N>> {
+        match self {
+            Self::Found(block_header) => Some(block_header),
+            Self::Cancelled(_) | Self::Expired(_) => None,
+        }
+    }
+}
@@ -17,6 +17,9 @@
 pub mod circuit;
 pub use circuit::*;
 
+pub mod mining;
+pub use mining::*;
+
 mod posw;
 pub(crate) use posw::PoSW;
 
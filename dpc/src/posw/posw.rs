@@ -21,17 +21,25 @@ use crate::{
     BlockHeader,
     BlockHeaderMetadata,
     BlockTemplate,
+    MiningOutcome,
+    MiningProgress,
     Network,
     PoSWError,
     PoSWProof,
     PoSWScheme,
 };
-use snarkvm_algorithms::{traits::SNARK, SRS};
+use snarkvm_algorithms::{errors::SNARKError, traits::SNARK, SRS};
 use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
 
 use chrono::Utc;
-use core::sync::atomic::AtomicBool;
-use rand::{CryptoRng, Rng};
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use crossbeam_utils::thread;
+use rand::{CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use std::{
+    sync::mpsc::{self, RecvTimeoutError},
+    time::{Duration, Instant},
+};
 
 /// A Proof of Succinct Work miner and verifier.
 #[derive(Clone)]
@@ -87,32 +95,64 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
     }
 
     ///
-    /// Given the block template, compute a PoSW and nonce that satisfies the difficulty target.
+    /// Given the block template, compute a PoSW and nonce that satisfies the difficulty target,
+    /// within the allowed mining duration from the block timestamp.
     ///
     fn mine<R: Rng + CryptoRng>(
         &self,
         block_template: &BlockTemplate<N>,
         terminator: &AtomicBool,
         rng: &mut R,
-    ) -> Result<BlockHeader<N>, PoSWError> {
+    ) -> Result<MiningOutcome<N>, PoSWError> {
         const MAXIMUM_MINING_DURATION: i64 = 600; // 600 seconds = 10 minutes.
 
+        // The genesis timestamp is fixed by the network rather than read from the clock,
+        // so the genesis block is given the allowed mining duration from now.
+        let remaining_duration = match block_template.block_height() {
+            0 => MAXIMUM_MINING_DURATION,
+            _ => block_template.block_timestamp() + MAXIMUM_MINING_DURATION - Utc::now().timestamp(),
+        };
+        let deadline = Instant::now() + Duration::from_secs(remaining_duration.max(0) as u64);
+
+        self.mine_with_deadline(block_template, deadline, terminator, |_| (), rng)
+    }
+
+    ///
+    /// Given the block template, compute a PoSW proof and nonce such that they are under the difficulty target,
+    /// until the deadline passes or the terminator is set. Reports the mining progress after each attempt.
+    ///
+    fn mine_with_deadline<R: Rng + CryptoRng, F: FnMut(&MiningProgress)>(
+        &self,
+        block_template: &BlockTemplate<N>,
+        deadline: Instant,
+        terminator: &AtomicBool,
+        mut progress: F,
+        rng: &mut R,
+    ) -> Result<MiningOutcome<N>, PoSWError> {
         // Instantiate the circuit.
         let mut circuit = PoSWCircuit::<N>::new(block_template, UniformRand::rand(rng))?;
 
-        let mut iteration = 1;
+        let start = Instant::now();
+        let mut status = MiningProgress::default();
         loop {
-            // Every 100 iterations, check that the miner is still within the allowed mining duration.
-            if iteration % 100 == 0
-                && Utc::now().timestamp() >= block_template.block_timestamp() + MAXIMUM_MINING_DURATION
-            {
-                return Err(PoSWError::Message(
-                    "Failed mine block in the allowed mining duration".to_string(),
-                ));
+            // Check that the miner is still within the deadline, and has not been cancelled.
+            if terminator.load(Ordering::SeqCst) {
+                return Ok(MiningOutcome::Cancelled(status));
+            }
+            if Instant::now() >= deadline {
+                return Ok(MiningOutcome::Expired(status));
             }
 
             // Run one iteration of PoSW.
-            let proof = self.prove_once_unchecked(&mut circuit, block_template, terminator, rng)?;
+            let proof = match self.prove_once_unchecked(&mut circuit, block_template, terminator, rng) {
+                Ok(proof) => proof,
+                Err(PoSWError::SNARKError(SNARKError::Terminated)) => return Ok(MiningOutcome::Cancelled(status)),
+                Err(error) => return Err(error),
+            };
+
+            // Report the mining progress.
+            status.update(proof.to_proof_difficulty()?, start.elapsed());
+            progress(&status);
 
             // Check if the updated block header is valid.
             if self.verify(
@@ -122,17 +162,149 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
                 &proof,
             ) {
                 // Construct a block header.
-                return Ok(BlockHeader::from(
+                return Ok(MiningOutcome::Found(BlockHeader::from(
                     block_template.previous_ledger_root(),
                     block_template.transactions().transactions_root(),
                     BlockHeaderMetadata::new(block_template),
                     circuit.nonce(),
                     proof,
-                )?);
+                )?));
+            }
+        }
+    }
+
+    ///
+    /// Given the block template, compute a PoSW proof and nonce such that they are under the difficulty target,
+    /// using the given number of worker threads. Each worker samples nonces from its own RNG, seeded from `rng`,
+    /// so a seeded `rng` makes the nonce stream of every worker reproducible. The winner is the block header found
+    /// in the earliest attempt, with ties broken by the lowest nonce, so it does not depend on thread scheduling.
+    /// Workers abort their current proof once it can no longer win, or when mining is cancelled or a worker fails.
+    ///
+    fn mine_parallel<R: Rng + CryptoRng>(
+        &self,
+        block_template: &BlockTemplate<N>,
+        num_threads: usize,
+        deadline: Instant,
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<MiningOutcome<N>, PoSWError> {
+        if num_threads == 0 {
+            return Err(PoSWError::Message(
+                "PoSW mining requires at least one worker thread".to_string(),
+            ));
+        }
+
+        // Seed the RNG of each worker, so that each worker samples its own nonces.
+        let seeds: Vec<<ChaChaRng as SeedableRng>::Seed> = (0..num_threads).map(|_| rng.gen()).collect();
+
+        // Each worker has its own stop signal, so that a worker is only aborted once its current attempt
+        // can no longer win. The earliest attempt to find a block header is shared by all workers.
+        let stops: Vec<AtomicBool> = (0..num_threads)
+            .map(|_| AtomicBool::new(terminator.load(Ordering::SeqCst)))
+            .collect();
+        let attempts: Vec<AtomicU64> = (0..num_threads).map(|_| AtomicU64::new(0)).collect();
+        let earliest_attempt = AtomicU64::new(u64::MAX);
+        let has_failed = AtomicBool::new(false);
+
+        // Each worker holds a sender until it finishes, so the channel disconnects once every worker has finished.
+        let (finished_sender, finished_receiver) = mpsc::channel::<()>();
+
+        let results = thread::scope(|scope| {
+            let workers = seeds
+                .into_iter()
+                .zip(stops.iter().zip(&attempts))
+                .map(|(seed, (stop, attempt))| {
+                    let (earliest_attempt, has_failed) = (&earliest_attempt, &has_failed);
+                    let finished_sender = finished_sender.clone();
+                    scope.spawn(move |_| {
+                        let _finished_sender = finished_sender;
+                        let outcome = self.mine_with_deadline(
+                            block_template,
+                            deadline,
+                            stop,
+                            |progress| {
+                                // Stop before starting an attempt that is later than the earliest block header found.
+                                attempt.store(progress.iterations, Ordering::SeqCst);
+                                if progress.iterations >= earliest_attempt.load(Ordering::SeqCst) {
+                                    stop.store(true, Ordering::SeqCst);
+                                }
+                            },
+                            &mut ChaChaRng::from_seed(seed),
+                        );
+                        // Record the attempt that found the block header, which is the last attempt reported.
+                        let attempt = attempt.load(Ordering::SeqCst);
+                        match &outcome {
+                            Ok(MiningOutcome::Found(_)) => {
+                                earliest_attempt.fetch_min(attempt, Ordering::SeqCst);
+                            }
+                            Err(_) => has_failed.store(true, Ordering::SeqCst),
+                            Ok(_) => (),
+                        }
+                        (attempt, outcome)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            // Until all workers finish, abort every worker on an error or if the caller cancels mining,
+            // and otherwise abort each worker whose current attempt is later than the earliest block header found.
+            drop(finished_sender);
+            while let Err(RecvTimeoutError::Timeout) = finished_receiver.recv_timeout(Duration::from_millis(10)) {
+                let is_aborted = terminator.load(Ordering::SeqCst) || has_failed.load(Ordering::SeqCst);
+                for (stop, attempt) in stops.iter().zip(&attempts) {
+                    let current_attempt = attempt.load(Ordering::SeqCst) + 1;
+                    if is_aborted || current_attempt > earliest_attempt.load(Ordering::SeqCst) {
+                        stop.store(true, Ordering::SeqCst);
+                    }
+                }
             }
 
-            // Increment the iteration by one.
-            iteration += 1;
+            workers
+                .into_iter()
+                .map(|worker| {
+                    worker
+                        .join()
+                        .map_err(|_| PoSWError::Message("A PoSW mining worker panicked".to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .map_err(|_| PoSWError::Message("A PoSW mining worker panicked".to_string()))??;
+
+        // Select the block header found in the earliest attempt, breaking ties by the lowest nonce.
+        // Every worker completes its attempts up to the earliest attempt that found a block header,
+        // so for a seeded `rng` the winner does not depend on the scheduling of the workers.
+        let winner = results
+            .iter()
+            .filter_map(|(attempt, outcome)| match outcome {
+                Ok(MiningOutcome::Found(block_header)) => Some((*attempt, block_header)),
+                _ => None,
+            })
+            .min_by_key(|(attempt, block_header)| (*attempt, *block_header.nonce()));
+        if let Some((_, block_header)) = winner {
+            return Ok(MiningOutcome::Found(block_header.clone()));
+        }
+
+        // Otherwise, return the first error of the workers, if any.
+        let outcomes = results
+            .into_iter()
+            .map(|(_, outcome)| outcome)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Otherwise, combine the progress of the workers.
+        let mut progress = MiningProgress::default();
+        let mut is_expired = false;
+        for outcome in &outcomes {
+            match outcome {
+                MiningOutcome::Found(_) => unreachable!("A found block header is always recorded"),
+                MiningOutcome::Cancelled(worker_progress) => progress.merge(worker_progress),
+                MiningOutcome::Expired(worker_progress) => {
+                    progress.merge(worker_progress);
+                    is_expired = true;
+                }
+            }
+        }
+        match is_expired {
+            true => Ok(MiningOutcome::Expired(progress)),
+            false => Ok(MiningOutcome::Cancelled(progress)),
         }
     }
 
@@ -146,6 +318,20 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
         block_template: &BlockTemplate<N>,
         terminator: &AtomicBool,
         rng: &mut R,
+    ) -> Result<PoSWProof<N>, PoSWError> {
+        self.prove_once_unchecked_at_height(circuit, block_template.block_height(), terminator, rng)
+    }
+
+    ///
+    /// Given the PoSW circuit for a block at the given height, compute a PoSW proof.
+    /// WARNING - This method does *not* ensure the resulting proof satisfies the difficulty target.
+    ///
+    fn prove_once_unchecked_at_height<R: Rng + CryptoRng>(
+        &self,
+        circuit: &mut PoSWCircuit<N>,
+        block_height: u32,
+        terminator: &AtomicBool,
+        rng: &mut R,
     ) -> Result<PoSWProof<N>, PoSWError> {
         let pk = self.proving_key.as_ref().expect("tried to mine without a PK set up");
 
@@ -154,8 +340,7 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
 
         // TODO (raychu86): TEMPORARY - Remove this after testnet2 period.
         // Mine blocks with the deprecated PoSW mode for blocks behind `V12_UPGRADE_BLOCK_HEIGHT`.
-        if <N as Network>::NETWORK_ID == 2 && block_template.block_height() <= crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT
-        {
+        if <N as Network>::NETWORK_ID == 2 && block_height <= crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT {
             let pk = <crate::testnet2::DeprecatedPoSWSNARK<N> as SNARK>::ProvingKey::from_bytes_le(&pk.to_bytes_le()?)?;
             // Construct a PoSW proof.
             Ok(PoSWProof::<N>::new_hiding(
@@ -239,9 +424,17 @@ mod tests {
     use core::sync::atomic::AtomicBool;
 
     use crate::{testnet2::Testnet2, BlockTemplate, Network, PoSWScheme};
+    #[cfg(feature = "devnet")]
+    use crate::{block::test_helpers::genesis_template, devnet::Devnet, MiningOutcome};
     use snarkvm_utilities::ToBytes;
 
     use rand::thread_rng;
+    #[cfg(feature = "devnet")]
+    use rand::SeedableRng;
+    #[cfg(feature = "devnet")]
+    use rand_chacha::ChaChaRng;
+    #[cfg(feature = "devnet")]
+    use std::time::{Duration, Instant};
 
     #[test]
     fn test_load() {
@@ -266,6 +459,8 @@ mod tests {
         // Construct a block header.
         let block_header = Testnet2::posw()
             .mine(&block_template, &AtomicBool::new(false), &mut thread_rng())
+            .unwrap()
+            .block_header()
             .unwrap();
 
         assert_eq!(
@@ -274,4 +469,182 @@ mod tests {
         ); // NOTE: Marlin proofs use compressed serialization
         assert!(Testnet2::posw().verify_from_block_header(&block_header));
     }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_mine_with_deadline() {
+        let block_template = genesis_template::<Devnet>(u64::MAX);
+
+        let mut reports = Vec::new();
+        let outcome = Devnet::posw()
+            .mine_with_deadline(
+                &block_template,
+                Instant::now() + Duration::from_secs(600),
+                &AtomicBool::new(false),
+                |progress| reports.push(*progress),
+                &mut thread_rng(),
+            )
+            .unwrap();
+
+        let block_header = outcome.block_header().expect("Failed to mine a block header");
+        assert!(Devnet::posw().verify_from_block_header(&block_header));
+
+        // Ensure the progress was reported for every attempt.
+        assert!(!reports.is_empty());
+        for (i, progress) in reports.iter().enumerate() {
+            assert_eq!(progress.iterations, i as u64 + 1);
+            assert!(progress.best_proof_difficulty.is_some());
+        }
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_mine_with_deadline_expired() {
+        let rng = &mut thread_rng();
+
+        // A deadline in the past expires before any attempt is made.
+        let block_template = genesis_template::<Devnet>(u64::MAX);
+        let outcome = Devnet::posw()
+            .mine_with_deadline(&block_template, Instant::now(), &AtomicBool::new(false), |_| (), rng)
+            .unwrap();
+        match outcome {
+            MiningOutcome::Expired(progress) => assert_eq!(progress.iterations, 0),
+            outcome => panic!("Expected an expired outcome, found {:?}", outcome),
+        }
+
+        // An unsatisfiable difficulty target expires after the deadline, reporting the best proof seen.
+        let block_template = genesis_template::<Devnet>(0);
+        let outcome = Devnet::posw()
+            .mine_with_deadline(
+                &block_template,
+                Instant::now() + Duration::from_millis(100),
+                &AtomicBool::new(false),
+                |_| (),
+                rng,
+            )
+            .unwrap();
+        match outcome {
+            MiningOutcome::Expired(progress) => {
+                assert!(progress.iterations >= 1);
+                assert!(progress.best_proof_difficulty.is_some());
+            }
+            outcome => panic!("Expected an expired outcome, found {:?}", outcome),
+        }
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_mine_expires_from_block_timestamp() {
+        // A template whose timestamp is older than the allowed mining duration expires immediately.
+        let block = Devnet::genesis_block();
+        let block_template = BlockTemplate::<Devnet>::new(
+            block.hash(),
+            1,
+            block.timestamp(),
+            u64::MAX,
+            block.cumulative_weight(),
+            block.previous_ledger_root(),
+            block.transactions().clone(),
+            block.to_coinbase_transaction().unwrap().to_records().next().unwrap(),
+        );
+        let outcome = Devnet::posw()
+            .mine(&block_template, &AtomicBool::new(false), &mut thread_rng())
+            .unwrap();
+        match outcome {
+            MiningOutcome::Expired(progress) => assert_eq!(progress.iterations, 0),
+            outcome => panic!("Expected an expired outcome, found {:?}", outcome),
+        }
+
+        // The genesis block is mined regardless of its fixed timestamp.
+        let block_template = genesis_template::<Devnet>(u64::MAX);
+        let outcome = Devnet::posw()
+            .mine(&block_template, &AtomicBool::new(false), &mut thread_rng())
+            .unwrap();
+        assert!(outcome.block_header().is_some());
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_mine_with_deadline_cancelled() {
+        let block_template = genesis_template::<Devnet>(u64::MAX);
+        let outcome = Devnet::posw()
+            .mine_with_deadline(
+                &block_template,
+                Instant::now() + Duration::from_secs(600),
+                &AtomicBool::new(true),
+                |_| (),
+                &mut thread_rng(),
+            )
+            .unwrap();
+        match outcome {
+            MiningOutcome::Cancelled(progress) => assert_eq!(progress.iterations, 0),
+            outcome => panic!("Expected a cancelled outcome, found {:?}", outcome),
+        }
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_mine_parallel() {
+        let block_template = genesis_template::<Devnet>(u64::MAX);
+        let outcome = Devnet::posw()
+            .mine_parallel(
+                &block_template,
+                4,
+                Instant::now() + Duration::from_secs(600),
+                &AtomicBool::new(false),
+                &mut thread_rng(),
+            )
+            .unwrap();
+
+        let block_header = outcome.block_header().expect("Failed to mine a block header");
+        assert!(Devnet::posw().verify_from_block_header(&block_header));
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_mine_parallel_is_deterministic() {
+        // Use a difficulty target that most attempts miss, so the workers race over several attempts.
+        let block_template = genesis_template::<Devnet>(u64::MAX / 4);
+        let mine = |seed: u64| {
+            Devnet::posw()
+                .mine_parallel(
+                    &block_template,
+                    3,
+                    Instant::now() + Duration::from_secs(600),
+                    &AtomicBool::new(false),
+                    &mut ChaChaRng::seed_from_u64(seed),
+                )
+                .unwrap()
+                .block_header()
+                .expect("Failed to mine a block header")
+        };
+
+        let block_header = mine(1);
+        assert_eq!(block_header.nonce(), mine(1).nonce());
+        assert_ne!(block_header.nonce(), mine(2).nonce());
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_mine_parallel_cancelled() {
+        let block_template = genesis_template::<Devnet>(0);
+        let rng = &mut thread_rng();
+
+        let outcome = Devnet::posw()
+            .mine_parallel(
+                &block_template,
+                2,
+                Instant::now() + Duration::from_secs(600),
+                &AtomicBool::new(true),
+                rng,
+            )
+            .unwrap();
+        assert!(matches!(outcome, MiningOutcome::Cancelled(_)));
+
+        // Mining requires at least one worker thread.
+        let terminator = AtomicBool::new(false);
+        assert!(Devnet::posw()
+            .mine_parallel(&block_template, 0, Instant::now(), &terminator, rng)
+            .is_err());
+    }
 }
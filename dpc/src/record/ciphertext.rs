@@ -26,6 +26,7 @@ use snarkvm_utilities::{
 };
 
 use anyhow::Result;
+use rayon::prelude::*;
 
 #[derive(Derivative)]
 #[derivative(
@@ -79,6 +80,37 @@ impl<N: Network> Ciphertext<N> {
         *self.record_view_key_commitment == candidate_record_view_key_commitment
     }
 
+    /// Returns `true` for each ciphertext that belongs to the given account view key,
+    /// in the same order as the given ciphertexts.
+    ///
+    /// This is equivalent to calling `is_owner` on each ciphertext, but shares the
+    /// precomputation for the account view key across all ciphertexts.
+    pub fn is_owner_batch(account_view_key: &ViewKey<N>, ciphertexts: &[&Self]) -> Vec<bool> {
+        // Compute the candidate record view keys.
+        let randomizers = ciphertexts
+            .iter()
+            .map(|ciphertext| *ciphertext.randomizer)
+            .collect::<Vec<_>>();
+        let candidate_record_view_keys =
+            N::account_encryption_scheme().generate_symmetric_keys(account_view_key, &randomizers);
+
+        // Check if the computed record view key commitments match.
+        ciphertexts
+            .par_iter()
+            .zip_eq(candidate_record_view_keys.par_iter())
+            .map(
+                |(ciphertext, candidate_record_view_key)| match candidate_record_view_key {
+                    Some(candidate_record_view_key) => {
+                        *ciphertext.record_view_key_commitment
+                            == N::account_encryption_scheme()
+                                .generate_symmetric_key_commitment(candidate_record_view_key)
+                    }
+                    None => false,
+                },
+            )
+            .collect()
+    }
+
     /// Returns the record commitment.
     pub fn commitment(&self) -> N::Commitment {
         self.commitment
@@ -14,8 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{testnet2::*, Account, AccountScheme, AleoAmount, Network, Payload, Record, ViewKey};
-use snarkvm_utilities::{FromBytes, ToBytes};
+use crate::{
+    testnet2::*,
+    Account,
+    AccountScheme,
+    AleoAmount,
+    Bech32Locator,
+    Ciphertext,
+    Network,
+    Payload,
+    Record,
+    ViewKey,
+};
+use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
 
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaChaRng;
@@ -55,3 +66,62 @@ fn test_record_ciphertext() {
         assert_eq!(expected_record, candidate_record);
     }
 }
+
+#[test]
+fn test_record_ciphertext_is_owner_batch() {
+    let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+    let account = Account::<Testnet2>::new(rng);
+    let account_view_key = ViewKey::from_private_key(account.private_key());
+
+    // Sample ciphertexts for the account, for other accounts, and with malformed randomizers.
+    let mut ciphertexts = Vec::with_capacity(ITERATIONS);
+    for i in 0..ITERATIONS {
+        let owner = match i % 2 {
+            0 => account.address(),
+            _ => Account::<Testnet2>::new(rng).address(),
+        };
+
+        let value: i64 = rng.gen();
+        let mut payload = [0u8; Testnet2::RECORD_PAYLOAD_SIZE_IN_BYTES];
+        rng.fill(&mut payload);
+
+        let record = Record::new(
+            owner,
+            AleoAmount::from_i64(value),
+            Payload::from_bytes_le(&payload).unwrap(),
+            *Testnet2::noop_program_id(),
+            rng,
+        )
+        .unwrap();
+
+        let ciphertext = (**record.ciphertext()).clone();
+        match i % 5 {
+            4 => {
+                // Replace the ciphertext randomizer with a random element.
+                let offset = <Testnet2 as Network>::RecordRandomizer::data_size_in_bytes()
+                    + <Testnet2 as Network>::RecordViewKeyCommitment::data_size_in_bytes();
+                ciphertexts.push(
+                    Ciphertext::<Testnet2>::from(
+                        UniformRand::rand(rng),
+                        *ciphertext.record_view_key_commitment(),
+                        ciphertext.to_bytes_le().unwrap()[offset..].to_vec(),
+                    )
+                    .unwrap(),
+                )
+            }
+            _ => ciphertexts.push(ciphertext),
+        }
+    }
+
+    // Ensure the batch results match the single-item results.
+    let ciphertexts = ciphertexts.iter().collect::<Vec<_>>();
+    let expected = ciphertexts
+        .iter()
+        .map(|ciphertext| ciphertext.is_owner(&account_view_key))
+        .collect::<Vec<_>>();
+    let candidate = Ciphertext::is_owner_batch(&account_view_key, &ciphertexts);
+    assert_eq!(expected, candidate);
+    assert!(candidate.iter().any(|is_owner| *is_owner));
+    assert!(candidate.iter().any(|is_owner| !*is_owner));
+}
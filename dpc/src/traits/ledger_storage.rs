@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Block, BlockHeader, Network, Transactions};
+
+use anyhow::Result;
+
+/// The storage backend for the canonical chain of blocks.
+pub trait LedgerStorage<N: Network>: Send + Sync {
+    /// Returns the latest block height, if the storage contains any blocks.
+    fn latest_block_height(&self) -> Result<Option<u32>>;
+
+    /// Returns the block hash for the given block height, if it exists.
+    fn get_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>>;
+
+    /// Returns the block height for the given block hash, if it exists.
+    fn get_block_height(&self, block_hash: &N::BlockHash) -> Result<Option<u32>>;
+
+    /// Returns the previous block hash for the given block height, if it exists.
+    fn get_previous_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>>;
+
+    /// Returns the block header for the given block height, if it exists.
+    fn get_block_header(&self, height: u32) -> Result<Option<BlockHeader<N>>>;
+
+    /// Returns the block transactions for the given block height, if they exist.
+    fn get_block_transactions(&self, height: u32) -> Result<Option<Transactions<N>>>;
+
+    /// Returns the height of the block containing the given transaction ID, if it exists.
+    fn get_transaction_height(&self, transaction_id: &N::TransactionID) -> Result<Option<u32>>;
+
+    /// Returns the ID of the transaction containing the given commitment, if it exists.
+    fn get_commitment_transaction_id(&self, commitment: &N::Commitment) -> Result<Option<N::TransactionID>>;
+
+    /// Returns the height of the block whose previous ledger root is the given ledger root, if it exists.
+    fn get_ledger_root_height(&self, ledger_root: &N::LedgerRoot) -> Result<Option<u32>>;
+
+    /// Returns `true` if the given ledger root is the previous ledger root of a stored block.
+    fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> Result<bool>;
+
+    /// Returns `true` if the given serial number exists.
+    fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> Result<bool>;
+
+    /// Returns `true` if the given commitment exists.
+    fn contains_commitment(&self, commitment: &N::Commitment) -> Result<bool>;
+
+    /// Returns `true` if the given transaction ID exists.
+    fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool>;
+
+    /// Inserts the given block and all of its indices. This operation executes atomically.
+    fn insert_block(&mut self, block: &Block<N>) -> Result<()>;
+
+    /// Removes all blocks from the given block height onwards, and all of their indices.
+    /// This operation executes atomically.
+    fn remove_blocks(&mut self, start_height: u32) -> Result<()>;
+}
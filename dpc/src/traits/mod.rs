@@ -28,3 +28,6 @@ pub use network::*;
 
 pub mod posw;
 pub use posw::*;
+
+pub mod ledger_storage;
+pub use ledger_storage::*;
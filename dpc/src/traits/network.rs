@@ -45,7 +45,7 @@ use snarkvm_utilities::{
 use anyhow::Result;
 use rand::{CryptoRng, Rng};
 use serde::{de::DeserializeOwned, Serialize};
-use std::{borrow::Borrow, cell::RefCell, ops::Deref, rc::Rc, str::FromStr};
+use std::{borrow::Borrow, cell::RefCell, ops::Deref, path::Path, rc::Rc, str::FromStr};
 
 pub trait Bech32Locator<F: Field>:
     From<F>
@@ -158,6 +158,8 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
 
     /// The maximum number of blocks that a fork can be.
     const ALEO_MAXIMUM_FORK_DEPTH: u32;
+    /// If `true`, the genesis block may have any timestamp and difficulty target, as set by a `GenesisBuilder`.
+    const ALEO_CONFIGURABLE_GENESIS: bool;
 
     /// Inner curve type declarations.
     type InnerCurve: PairingEngine<Fr = Self::InnerScalarField, Fq = Self::OuterScalarField>;
@@ -327,6 +329,17 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
 
     fn genesis_block() -> &'static Block<Self>;
 
+    /// Sets the genesis block of the network, in place of its default genesis block.
+    /// This must be called before the genesis block is first used, and returns an error
+    /// if the network does not have a configurable genesis block.
+    fn set_genesis_block(block: Block<Self>) -> Result<()>;
+
+    /// Loads the genesis block of the network from the given file, in place of its default genesis block.
+    /// This must be called before the genesis block is first used.
+    fn load_genesis_block<P: AsRef<Path>>(path: P) -> Result<()> {
+        Self::set_genesis_block(Block::read_genesis(path)?)
+    }
+
     /// Returns the function ID given a program function verifying key.
     fn function_id(
         verifying_key: &<Self::ProgramSNARK as SNARK>::VerifyingKey,
@@ -16,11 +16,12 @@
 
 use core::sync::atomic::AtomicBool;
 
-use crate::{BlockHeader, BlockTemplate, Network, PoSWCircuit, PoSWError, PoSWProof};
+use crate::{BlockHeader, BlockTemplate, MiningOutcome, MiningProgress, Network, PoSWCircuit, PoSWError, PoSWProof};
 use snarkvm_algorithms::{traits::SNARK, SRS};
 
 use anyhow::Result;
 use rand::{CryptoRng, Rng};
+use std::time::Instant;
 
 pub trait PoSWScheme<N: Network>: Clone + Send + Sync {
     /// Sets up an instance of PoSW using an SRS.
@@ -37,14 +38,43 @@ pub trait PoSWScheme<N: Network>: Clone + Send + Sync {
     /// Returns a reference to the PoSW circuit verifying key.
     fn verifying_key(&self) -> &<N::PoSWSNARK as SNARK>::VerifyingKey;
 
-    /// Given the block template, compute a PoSW proof and nonce
-    /// such that they are under the difficulty target.
+    /// Given the block template, compute a PoSW proof and nonce such that they are under
+    /// the difficulty target, within the allowed mining duration from the block timestamp.
     fn mine<R: Rng + CryptoRng>(
         &self,
         block_template: &BlockTemplate<N>,
         terminator: &AtomicBool,
         rng: &mut R,
-    ) -> Result<BlockHeader<N>, PoSWError>;
+    ) -> Result<MiningOutcome<N>, PoSWError>;
+
+    ///
+    /// Given the block template, compute a PoSW proof and nonce such that they are under the difficulty target,
+    /// until the deadline passes or the terminator is set. Reports the mining progress after each attempt.
+    ///
+    fn mine_with_deadline<R: Rng + CryptoRng, F: FnMut(&MiningProgress)>(
+        &self,
+        block_template: &BlockTemplate<N>,
+        deadline: Instant,
+        terminator: &AtomicBool,
+        progress: F,
+        rng: &mut R,
+    ) -> Result<MiningOutcome<N>, PoSWError>;
+
+    ///
+    /// Given the block template, compute a PoSW proof and nonce such that they are under the difficulty target,
+    /// using the given number of worker threads. Each worker samples nonces from its own RNG, seeded from `rng`,
+    /// so a seeded `rng` makes the nonce stream of every worker reproducible. The winner is the block header found
+    /// in the earliest attempt, with ties broken by the lowest nonce, so it does not depend on thread scheduling.
+    /// Workers abort their current proof once it can no longer win, or when mining is cancelled or a worker fails.
+    ///
+    fn mine_parallel<R: Rng + CryptoRng>(
+        &self,
+        block_template: &BlockTemplate<N>,
+        num_threads: usize,
+        deadline: Instant,
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<MiningOutcome<N>, PoSWError>;
 
     ///
     /// Given the block template, compute a PoSW proof.
@@ -58,6 +88,18 @@ pub trait PoSWScheme<N: Network>: Clone + Send + Sync {
         rng: &mut R,
     ) -> Result<PoSWProof<N>, PoSWError>;
 
+    ///
+    /// Given the PoSW circuit for a block at the given height, compute a PoSW proof.
+    /// WARNING - This method does *not* ensure the resulting proof satisfies the difficulty target.
+    ///
+    fn prove_once_unchecked_at_height<R: Rng + CryptoRng>(
+        &self,
+        circuit: &mut PoSWCircuit<N>,
+        block_height: u32,
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<PoSWProof<N>, PoSWError>;
+
     /// Verifies the Proof of Succinct Work against the nonce, root, and difficulty target.
     fn verify_from_block_header(&self, block_header: &BlockHeader<N>) -> bool;
 
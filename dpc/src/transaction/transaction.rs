@@ -23,12 +23,15 @@ use crate::{
     LedgerTreeScheme,
     LocalProof,
     Network,
+    OuterPublicVariables,
     Request,
     Transition,
     Transitions,
     VirtualMachine,
 };
+use snarkvm_algorithms::traits::SNARK;
 use snarkvm_utilities::{
+    error,
     has_duplicates,
     io::{Read, Result as IoResult, Write},
     FromBytes,
@@ -39,7 +42,7 @@ use snarkvm_utilities::{
 
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
-use rand::{CryptoRng, Rng};
+use rand::{thread_rng, CryptoRng, Rng};
 use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt,
@@ -92,20 +95,47 @@ impl<N: Network> Transaction<N> {
         inner_circuit_id: N::InnerCircuitID,
         ledger_root: N::LedgerRoot,
         transitions: Vec<Transition<N>>,
+    ) -> Result<Self> {
+        Self::from_transitions(inner_circuit_id, ledger_root, transitions, false)
+    }
+
+    ///
+    /// Initializes an instance of `Transaction` from the given inputs. If `is_genesis` is set,
+    /// the transaction may be the coinbase of a genesis block, which mints over multiple transitions
+    /// on networks with a configurable genesis block.
+    ///
+    #[inline]
+    pub(crate) fn from_transitions(
+        inner_circuit_id: N::InnerCircuitID,
+        ledger_root: N::LedgerRoot,
+        transitions: Vec<Transition<N>>,
+        is_genesis: bool,
+    ) -> Result<Self> {
+        let transaction = Self::from_unchecked(inner_circuit_id, ledger_root, transitions)?;
+
+        match transaction.is_valid_for(is_genesis) {
+            true => Ok(transaction),
+            false => Err(anyhow!("Failed to initialize a transaction")),
+        }
+    }
+
+    ///
+    /// Initializes an instance of `Transaction` from the given inputs, without checking it is well-formed.
+    /// This is only used for transactions read from trusted storage, which were checked before they were stored.
+    ///
+    pub(crate) fn from_unchecked(
+        inner_circuit_id: N::InnerCircuitID,
+        ledger_root: N::LedgerRoot,
+        transitions: Vec<Transition<N>>,
     ) -> Result<Self> {
         let transaction_id = Self::compute_transaction_id(&transitions)?;
 
-        let transaction = Self {
+        Ok(Self {
             transaction_id,
             inner_circuit_id,
             ledger_root,
             transitions,
-        };
-
-        match transaction.is_valid() {
-            true => Ok(transaction),
-            false => Err(anyhow!("Failed to initialize a transaction")),
-        }
+        })
     }
 
     /// Returns `true` if the transaction is well-formed, meaning it contains
@@ -113,64 +143,87 @@ impl<N: Network> Transaction<N> {
     /// correct ciphertext IDs, and a valid proof.
     #[inline]
     pub fn is_valid(&self) -> bool {
+        self.is_valid_for(false)
+    }
+
+    /// Returns `true` if the transaction is well-formed, allowing a genesis block coinbase if `is_genesis` is set.
+    #[inline]
+    pub(crate) fn is_valid_for(&self, is_genesis: bool) -> bool {
+        match self.to_outer_proofs(is_genesis) {
+            Some(outer_proofs) => Self::verify_outer_proofs(&outer_proofs),
+            None => false,
+        }
+    }
+
+    /// Returns the public variables and proof of each transition, for verification,
+    /// or `None` if the transaction is malformed.
+    pub(crate) fn to_outer_proofs(&self, is_genesis: bool) -> Option<Vec<(OuterPublicVariables<N>, &N::OuterProof)>> {
         // Ensure the number of transitions is between 1 and N::NUM_TRANSITIONS.
         let num_transitions = self.transitions.len();
         if num_transitions < 1 || num_transitions > N::NUM_TRANSITIONS as usize {
             eprintln!("Transaction contains invalid number of transitions");
-            return false;
+            return None;
         }
 
         // Ensure the number of events is less than `N::NUM_EVENTS`.
         if self.events().count() > num_transitions * N::NUM_EVENTS as usize {
             eprintln!("Transaction contains an invalid number of events");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the number of serial numbers in the transaction is incorrect.
+        // Returns `None` if the number of serial numbers in the transaction is incorrect.
         if self.serial_numbers().count() != num_transitions * N::NUM_INPUT_RECORDS {
             eprintln!("Transaction contains incorrect number of serial numbers");
-            return false;
+            return None;
         }
 
-        // Returns `false` if there are duplicate serial numbers in the transaction.
+        // Returns `None` if there are duplicate serial numbers in the transaction.
         if has_duplicates(self.serial_numbers()) {
             eprintln!("Transaction contains duplicate serial numbers");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the number of commitments in the transaction is incorrect.
+        // Returns `None` if the number of commitments in the transaction is incorrect.
         if self.commitments().count() != num_transitions * N::NUM_OUTPUT_RECORDS {
             eprintln!("Transaction contains incorrect number of commitments");
-            return false;
+            return None;
         }
 
-        // Returns `false` if there are duplicate commitments numbers in the transaction.
+        // Returns `None` if there are duplicate commitments numbers in the transaction.
         if has_duplicates(self.commitments()) {
             eprintln!("Transaction contains duplicate commitments");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the number of record ciphertexts in the transaction is incorrect.
+        // Returns `None` if the number of record ciphertexts in the transaction is incorrect.
         if self.ciphertexts().count() != num_transitions * N::NUM_OUTPUT_RECORDS {
             eprintln!("Transaction contains incorrect number of record ciphertexts");
-            return false;
+            return None;
         }
 
-        // Returns `false` if there are duplicate ciphertexts in the transition.
+        // Returns `None` if there are duplicate ciphertexts in the transition.
         if has_duplicates(self.ciphertexts()) {
             eprintln!("Transaction contains duplicate ciphertexts");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the transaction is not a coinbase, and has a transition with a negative value balance.
+        // Returns `None` if the transaction is not a coinbase, and has a transition with a negative value balance.
+        // On networks with a configurable genesis block, the genesis block coinbase may mint the premine
+        // over multiple transitions, as long as every transition is a coinbase.
         if self.transitions.len() > 1
             && self
                 .transitions
                 .iter()
                 .any(|transition| transition.value_balance().is_negative())
+            && !(is_genesis
+                && N::ALEO_CONFIGURABLE_GENESIS
+                && self
+                    .transitions
+                    .iter()
+                    .all(|transition| transition.value_balance().is_negative()))
         {
             eprintln!("Transaction contains a transition with a negative value balance");
-            return false;
+            return None;
         }
 
         // Initialize a local transitions tree.
@@ -178,38 +231,63 @@ impl<N: Network> Transaction<N> {
             Ok(transitions) => transitions,
             Err(error) => {
                 eprintln!("Transaction failed to initialize a local transitions tree: {}", error);
-                return false;
+                return None;
             }
         };
 
-        // Returns `false` if any transition is invalid.
+        let mut outer_proofs = Vec::with_capacity(num_transitions);
         for transition in &self.transitions {
-            // Returns `false` if the transition is invalid.
-            if !transition.verify(self.inner_circuit_id, self.ledger_root, transitions.root()) {
-                eprintln!("Transaction contains an invalid transition");
-                return false;
+            // Returns `None` if the transition is malformed.
+            match transition.to_outer_public_variables(self.inner_circuit_id, self.ledger_root, transitions.root()) {
+                Some(public_variables) => outer_proofs.push((public_variables, transition.proof())),
+                None => {
+                    eprintln!("Transaction contains an invalid transition");
+                    return None;
+                }
             }
 
             // Update the local transitions tree.
             if let Err(error) = transitions.add(transition) {
                 eprintln!("Transaction failed to update local transitions tree: {}", error);
-                return false;
+                return None;
             }
         }
 
-        // Returns `false` if the size of the local transitions tree does not match the number of transitions.
+        // Returns `None` if the size of the local transitions tree does not match the number of transitions.
         if transitions.len() != num_transitions {
             eprintln!("Transaction contains invalid local transitions tree state");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the final transitions root does not match the transaction ID.
+        // Returns `None` if the final transitions root does not match the transaction ID.
         if transitions.root() != self.transaction_id {
             eprintln!("Transaction contains an invalid transaction ID");
-            return false;
+            return None;
         }
 
-        true
+        Some(outer_proofs)
+    }
+
+    /// Returns `true` if all of the given transition proofs are valid, verifying them as one batch.
+    pub(crate) fn verify_outer_proofs(outer_proofs: &[(OuterPublicVariables<N>, &N::OuterProof)]) -> bool {
+        let inputs_and_proofs = outer_proofs
+            .iter()
+            .map(|(public_variables, proof)| (public_variables, &***proof))
+            .collect::<Vec<_>>();
+
+        match N::OuterSNARK::batch_verify(N::outer_verifying_key(), &inputs_and_proofs, &mut thread_rng()) {
+            Ok(is_valid) => match is_valid {
+                true => true,
+                false => {
+                    eprintln!("Transaction contains an invalid transition proof");
+                    false
+                }
+            },
+            Err(error) => {
+                eprintln!("Failed to validate transition proofs: {:?}", error);
+                false
+            }
+        }
     }
 
     /// Returns `true` if the given transition ID exists.
@@ -335,9 +413,52 @@ impl<N: Network> Transaction<N> {
     }
 }
 
-impl<N: Network> FromBytes for Transaction<N> {
-    #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+impl<N: Network> Transaction<N> {
+    /// Reads a transaction of the block at the given height, allowing a genesis block coinbase only at height 0.
+    pub(crate) fn read_le_in_block<R: Read>(mut reader: R, block_height: u32) -> IoResult<Self> {
+        let (inner_circuit_id, ledger_root, transitions) = Self::read_fields_le(&mut reader)?;
+        Self::from_transitions(inner_circuit_id, ledger_root, transitions, block_height == 0)
+            .map_err(|_| error("Failed to deserialize a transaction"))
+    }
+
+    /// Recovers a transaction of the block at the given height from JSON,
+    /// allowing a genesis block coinbase only at height 0.
+    pub(crate) fn from_json_in_block(transaction: &serde_json::Value, block_height: u32) -> Result<Self> {
+        Self::from_json(transaction, block_height == 0)
+    }
+
+    /// Recovers a transaction from JSON, allowing a genesis block coinbase if `is_genesis` is set.
+    fn from_json(transaction: &serde_json::Value, is_genesis: bool) -> Result<Self> {
+        let transaction_id: N::TransactionID = serde_json::from_value(transaction["transaction_id"].clone())?;
+
+        // Recover the transaction.
+        let transaction = Self::from_transitions(
+            serde_json::from_value(transaction["inner_circuit_id"].clone())?,
+            serde_json::from_value(transaction["ledger_root"].clone())?,
+            serde_json::from_value(transaction["transitions"].clone())?,
+            is_genesis,
+        )?;
+
+        // Ensure the transaction ID matches.
+        match transaction_id == transaction.transaction_id() {
+            true => Ok(transaction),
+            false => Err(anyhow!(
+                "Incorrect transaction ID during deserialization. Expected {}, found {}",
+                transaction.transaction_id(),
+                transaction_id
+            )),
+        }
+    }
+
+    /// Reads the transaction from trusted storage, without checking it is well-formed.
+    pub(crate) fn read_le_unchecked<R: Read>(mut reader: R) -> IoResult<Self> {
+        let (inner_circuit_id, ledger_root, transitions) = Self::read_fields_le(&mut reader)?;
+        Self::from_unchecked(inner_circuit_id, ledger_root, transitions)
+            .map_err(|_| error("Failed to compute the transaction ID"))
+    }
+
+    /// Reads the inner circuit ID, ledger root, and transitions of a transaction.
+    fn read_fields_le<R: Read>(mut reader: R) -> IoResult<(N::InnerCircuitID, N::LedgerRoot, Vec<Transition<N>>)> {
         let inner_circuit_id = FromBytes::read_le(&mut reader)?;
         let ledger_root = FromBytes::read_le(&mut reader)?;
 
@@ -347,7 +468,17 @@ impl<N: Network> FromBytes for Transaction<N> {
             transitions.push(FromBytes::read_le(&mut reader)?);
         }
 
-        Ok(Self::from(inner_circuit_id, ledger_root, transitions).expect("Failed to deserialize a transaction"))
+        Ok((inner_circuit_id, ledger_root, transitions))
+    }
+}
+
+impl<N: Network> FromBytes for Transaction<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let (inner_circuit_id, ledger_root, transitions) = Self::read_fields_le(&mut reader)?;
+
+        // A transaction read on its own is not in a genesis block, so it may not be a genesis block coinbase.
+        Self::from(inner_circuit_id, ledger_root, transitions).map_err(|_| error("Failed to deserialize a transaction"))
     }
 }
 
@@ -399,28 +530,9 @@ impl<'de, N: Network> Deserialize<'de> for Transaction<N> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         match deserializer.is_human_readable() {
             true => {
+                // A transaction read on its own is not in a genesis block, so it may not be a genesis block coinbase.
                 let transaction = serde_json::Value::deserialize(deserializer)?;
-                let transaction_id =
-                    N::TransactionID::deserialize(transaction["transaction_id"].clone()).map_err(de::Error::custom)?;
-
-                // Recover the transaction.
-                let transaction = Self::from(
-                    serde_json::from_value(transaction["inner_circuit_id"].clone()).map_err(de::Error::custom)?,
-                    serde_json::from_value(transaction["ledger_root"].clone()).map_err(de::Error::custom)?,
-                    serde_json::from_value(transaction["transitions"].clone()).map_err(de::Error::custom)?,
-                )
-                .map_err(de::Error::custom)?;
-
-                // Ensure the transaction ID matches.
-                match transaction_id == transaction.transaction_id() {
-                    true => Ok(transaction),
-                    false => Err(anyhow!(
-                        "Incorrect transaction ID during deserialization. Expected {}, found {}",
-                        transaction.transaction_id(),
-                        transaction_id
-                    ))
-                    .map_err(de::Error::custom),
-                }
+                Self::from_json(&transaction, false).map_err(de::Error::custom)
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "transaction"),
         }
@@ -482,6 +594,22 @@ mod tests {
         assert_eq!(expected_record.program_id(), candidate_record.program_id());
     }
 
+    #[test]
+    fn test_multiple_coinbase_transitions() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+
+        // Craft a transaction with 2 coinbase transitions.
+        let mut vm = VirtualMachine::<Testnet2>::new(LedgerTree::<Testnet2>::new().unwrap().root()).unwrap();
+        for _ in 0..2 {
+            let request = Request::new_coinbase(account.address(), AleoAmount(1234), true, rng).unwrap();
+            vm = vm.execute(&request, rng).unwrap().0;
+        }
+
+        // Ensure a coinbase transaction with multiple transitions is rejected on a network with a fixed genesis block.
+        assert!(vm.finalize().is_err());
+    }
+
     #[test]
     fn test_transaction_serde_json() {
         let rng = &mut thread_rng();
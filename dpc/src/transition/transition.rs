@@ -112,27 +112,15 @@ impl<N: Network> Transition<N> {
         ledger_root: N::LedgerRoot,
         local_transitions_root: N::TransactionID,
     ) -> bool {
-        // Ensure the number of events is less than `N::NUM_EVENTS`.
-        if self.events.len() > N::NUM_EVENTS as usize {
-            eprintln!("Transition contains an invalid number of events");
-            return false;
-        }
+        // Returns `false` if the transition is malformed.
+        let public_variables =
+            match self.to_outer_public_variables(inner_circuit_id, ledger_root, local_transitions_root) {
+                Some(public_variables) => public_variables,
+                None => return false,
+            };
 
         // Returns `false` if the transition proof is invalid.
-        match N::OuterSNARK::verify(
-            N::outer_verifying_key(),
-            &OuterPublicVariables::new(
-                InnerPublicVariables::new(
-                    self.transition_id,
-                    self.value_balance,
-                    ledger_root,
-                    local_transitions_root,
-                    None,
-                ),
-                &inner_circuit_id,
-            ),
-            &self.proof,
-        ) {
+        match N::OuterSNARK::verify(N::outer_verifying_key(), &public_variables, &self.proof) {
             Ok(is_valid) => match is_valid {
                 true => true,
                 false => {
@@ -147,6 +135,32 @@ impl<N: Network> Transition<N> {
         }
     }
 
+    /// Returns the public variables of the transition proof,
+    /// or `None` if the transition is malformed.
+    pub(crate) fn to_outer_public_variables(
+        &self,
+        inner_circuit_id: N::InnerCircuitID,
+        ledger_root: N::LedgerRoot,
+        local_transitions_root: N::TransactionID,
+    ) -> Option<OuterPublicVariables<N>> {
+        // Ensure the number of events is less than `N::NUM_EVENTS`.
+        if self.events.len() > N::NUM_EVENTS as usize {
+            eprintln!("Transition contains an invalid number of events");
+            return None;
+        }
+
+        Some(OuterPublicVariables::new(
+            InnerPublicVariables::new(
+                self.transition_id,
+                self.value_balance,
+                ledger_root,
+                local_transitions_root,
+                None,
+            ),
+            &inner_circuit_id,
+        ))
+    }
+
     /// Returns `true` if the given serial number exists.
     pub fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> bool {
         self.serial_numbers.contains(serial_number)
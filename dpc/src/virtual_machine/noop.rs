@@ -22,6 +22,7 @@ use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError, ToCo
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use anyhow::Result;
+use rand::{CryptoRng, Rng};
 use std::{
     io::{Read, Result as IoResult, Write},
     marker::PhantomData,
@@ -34,6 +35,15 @@ impl<N: Network> Noop<N> {
     pub fn new() -> Self {
         Self(PhantomData)
     }
+
+    /// Executes the function with the given RNG, returning a proof.
+    pub fn prove<R: Rng + CryptoRng>(&self, public: ProgramPublicVariables<N>, rng: &mut R) -> Result<N::ProgramProof> {
+        let proof =
+            <N::ProgramSNARK as SNARK>::prove(N::noop_circuit_proving_key(), &SynthesizedCircuit::Noop(public), rng)?
+                .into();
+        assert!(self.verify(&public, &proof));
+        Ok(proof)
+    }
 }
 
 impl<N: Network> Function<N> for Noop<N> {
@@ -63,14 +73,7 @@ impl<N: Network> Function<N> for Noop<N> {
         public: ProgramPublicVariables<N>,
         _private: &dyn ProgramPrivateVariables<N>,
     ) -> Result<N::ProgramProof> {
-        let proof = <N::ProgramSNARK as SNARK>::prove(
-            N::noop_circuit_proving_key(),
-            &SynthesizedCircuit::Noop(public),
-            &mut rand::thread_rng(),
-        )?
-        .into();
-        assert!(self.verify(&public, &proof));
-        Ok(proof)
+        self.prove(public, &mut rand::thread_rng())
     }
 
     /// Returns true if the execution of the function is valid.
@@ -53,7 +53,7 @@ impl<N: Network> VirtualMachine<N> {
     }
 
     /// Executes the request, returning a transaction.
-    pub fn execute<R: Rng + CryptoRng>(mut self, request: &Request<N>, rng: &mut R) -> Result<(Self, Response<N>)> {
+    pub fn execute<R: Rng + CryptoRng>(self, request: &Request<N>, rng: &mut R) -> Result<(Self, Response<N>)> {
         // Ensure the request is valid.
         if !request.is_valid() {
             return Err(anyhow!("Virtual machine received an invalid request"));
@@ -76,6 +76,16 @@ impl<N: Network> VirtualMachine<N> {
             )?,
         };
 
+        self.execute_response(request, response, rng)
+    }
+
+    /// Proves the given response to the request, returning the updated virtual machine.
+    pub(crate) fn execute_response<R: Rng + CryptoRng>(
+        mut self,
+        request: &Request<N>,
+        response: Response<N>,
+        rng: &mut R,
+    ) -> Result<(Self, Response<N>)> {
         let program_id = request.to_program_id()?;
         let transition_id = response.transition_id();
         let value_balance = response.value_balance();
@@ -85,10 +95,7 @@ impl<N: Network> VirtualMachine<N> {
             program_id: *N::noop_program_id(),
             program_path: N::noop_program_path().clone(),
             verifying_key: N::noop_circuit_verifying_key().clone(),
-            proof: Noop::<N>::new().execute(
-                ProgramPublicVariables::new(transition_id),
-                &NoopPrivateVariables::<N>::new_blank()?,
-            )?,
+            proof: Noop::<N>::new().prove(ProgramPublicVariables::new(transition_id), rng)?,
         };
 
         // Compute the inner circuit proof, and verify that the inner proof passes.
@@ -136,6 +143,11 @@ impl<N: Network> VirtualMachine<N> {
         Transaction::from(*N::inner_circuit_id(), self.ledger_root, self.transitions.clone())
     }
 
+    /// Finalizes the virtual machine state and returns the coinbase transaction of a genesis block.
+    pub(crate) fn finalize_genesis(&self) -> Result<Transaction<N>> {
+        Transaction::from_transitions(*N::inner_circuit_id(), self.ledger_root, self.transitions.clone(), true)
+    }
+
     /// Performs a noop transition.
     fn noop<R: Rng + CryptoRng>(request: &Request<N>, rng: &mut R) -> Result<Response<N>> {
         ResponseBuilder::new().add_request(request.clone()).build(rng)
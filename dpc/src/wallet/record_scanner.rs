@@ -0,0 +1,494 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use std::{
+    collections::HashMap,
+    io::{Read, Result as IoResult, Write},
+};
+
+/// A record owned by the scanning account, with the heights of the blocks that created and spent it.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct OwnedRecord<N: Network> {
+    /// The decrypted record.
+    record: Record<N>,
+    /// The height of the block that created the record.
+    created_height: u32,
+    /// The height of the block that spent the record, if it is spent.
+    spent_height: Option<u32>,
+}
+
+impl<N: Network> OwnedRecord<N> {
+    /// Returns the decrypted record.
+    pub fn record(&self) -> &Record<N> {
+        &self.record
+    }
+
+    /// Returns the height of the block that created the record.
+    pub fn created_height(&self) -> u32 {
+        self.created_height
+    }
+
+    /// Returns the height of the block that spent the record, if it is spent.
+    pub fn spent_height(&self) -> Option<u32> {
+        self.spent_height
+    }
+
+    /// Returns `true` if the record is unspent.
+    pub fn is_unspent(&self) -> bool {
+        self.spent_height.is_none()
+    }
+}
+
+impl<N: Network> FromBytes for OwnedRecord<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let record = FromBytes::read_le(&mut reader)?;
+        let created_height = FromBytes::read_le(&mut reader)?;
+        let spent_height = match bool::read_le(&mut reader)? {
+            true => Some(FromBytes::read_le(&mut reader)?),
+            false => None,
+        };
+
+        Ok(Self {
+            record,
+            created_height,
+            spent_height,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for OwnedRecord<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.record.write_le(&mut writer)?;
+        self.created_height.write_le(&mut writer)?;
+        match self.spent_height {
+            Some(spent_height) => {
+                true.write_le(&mut writer)?;
+                spent_height.write_le(&mut writer)
+            }
+            None => false.write_le(&mut writer),
+        }
+    }
+}
+
+/// A resumable checkpoint of a record scanner.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct RecordScannerCheckpoint<N: Network> {
+    /// The height of the next block to scan.
+    next_height: u32,
+    /// The hash of the latest scanned block, if it is known.
+    latest_block_hash: Option<N::BlockHash>,
+    /// The owned records that are unspent or were recently spent.
+    records: Vec<OwnedRecord<N>>,
+}
+
+impl<N: Network> RecordScannerCheckpoint<N> {
+    /// Returns the height of the next block to scan.
+    pub fn next_height(&self) -> u32 {
+        self.next_height
+    }
+}
+
+impl<N: Network> FromBytes for RecordScannerCheckpoint<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let next_height = FromBytes::read_le(&mut reader)?;
+        let latest_block_hash = match bool::read_le(&mut reader)? {
+            true => Some(FromBytes::read_le(&mut reader)?),
+            false => None,
+        };
+
+        // The number of records is untrusted, so the records are not preallocated.
+        let num_records: u32 = FromBytes::read_le(&mut reader)?;
+        let mut records = Vec::new();
+        for _ in 0..num_records {
+            records.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        Ok(Self {
+            next_height,
+            latest_block_hash,
+            records,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for RecordScannerCheckpoint<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.next_height.write_le(&mut writer)?;
+        match self.latest_block_hash {
+            Some(latest_block_hash) => {
+                true.write_le(&mut writer)?;
+                latest_block_hash.write_le(&mut writer)?;
+            }
+            None => false.write_le(&mut writer)?,
+        }
+        (self.records.len() as u32).write_le(&mut writer)?;
+        self.records.write_le(&mut writer)
+    }
+}
+
+/// A wallet-side scanner that finds the records owned by an account on the ledger,
+/// and tracks which of them are spent.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"))]
+pub struct RecordScanner<N: Network> {
+    /// The account view key, used to trial-decrypt the record ciphertexts.
+    view_key: ViewKey<N>,
+    /// The account compute key, used to compute the serial numbers of owned records.
+    compute_key: ComputeKey<N>,
+    /// The height of the next block to scan.
+    next_height: u32,
+    /// The hash of the latest scanned block, if it is known.
+    latest_block_hash: Option<N::BlockHash>,
+    /// The owned records that are unspent or were recently spent, keyed by serial number.
+    records: HashMap<N::SerialNumber, OwnedRecord<N>>,
+}
+
+impl<N: Network> RecordScanner<N> {
+    /// Initializes a new record scanner for the given account, starting from the given block height.
+    pub fn new(view_key: ViewKey<N>, compute_key: ComputeKey<N>, start_height: u32) -> Result<Self> {
+        // Ensure the view key and compute key belong to the same account.
+        if Address::from_view_key(&view_key) != Address::from_compute_key(&compute_key) {
+            return Err(anyhow!(
+                "The given view key and compute key belong to different accounts"
+            ));
+        }
+
+        Ok(Self {
+            view_key,
+            compute_key,
+            next_height: start_height,
+            latest_block_hash: None,
+            records: Default::default(),
+        })
+    }
+
+    /// Initializes a new record scanner for the given account private key, starting from the given block height.
+    pub fn from_private_key(private_key: &PrivateKey<N>, start_height: u32) -> Self {
+        Self {
+            view_key: ViewKey::from_private_key(private_key),
+            compute_key: private_key.to_compute_key(),
+            next_height: start_height,
+            latest_block_hash: None,
+            records: Default::default(),
+        }
+    }
+
+    /// Initializes a record scanner for the given account, resuming from the given checkpoint.
+    pub fn from_checkpoint(
+        view_key: ViewKey<N>,
+        compute_key: ComputeKey<N>,
+        checkpoint: RecordScannerCheckpoint<N>,
+    ) -> Result<Self> {
+        let mut scanner = Self::new(view_key, compute_key, checkpoint.next_height)?;
+        scanner.latest_block_hash = checkpoint.latest_block_hash;
+        for owned_record in checkpoint.records {
+            let serial_number = owned_record.record.to_serial_number(&scanner.compute_key)?;
+            scanner.records.insert(serial_number, owned_record);
+        }
+        Ok(scanner)
+    }
+
+    /// Returns a checkpoint of the scanner, from which scanning may be resumed.
+    pub fn to_checkpoint(&self) -> RecordScannerCheckpoint<N> {
+        let mut records = self.records.values().cloned().collect::<Vec<_>>();
+        records.sort_by_key(|owned_record| (owned_record.created_height, owned_record.spent_height));
+
+        RecordScannerCheckpoint {
+            next_height: self.next_height,
+            latest_block_hash: self.latest_block_hash,
+            records,
+        }
+    }
+
+    /// Returns the height of the next block to scan.
+    pub fn next_height(&self) -> u32 {
+        self.next_height
+    }
+
+    /// Returns the spendable balance of the account.
+    pub fn balance(&self) -> AleoAmount {
+        self.unspent_records().map(Record::value).sum()
+    }
+
+    /// Returns the unspent records of the account.
+    pub fn unspent_records(&self) -> impl Iterator<Item = &Record<N>> + '_ {
+        self.records
+            .values()
+            .filter(|owned_record| owned_record.is_unspent())
+            .map(OwnedRecord::record)
+    }
+
+    /// Returns the owned records that are unspent or were recently spent, with their block heights.
+    pub fn owned_records(&self) -> impl Iterator<Item = &OwnedRecord<N>> + '_ {
+        self.records.values()
+    }
+
+    /// Scans the canon blocks of the given ledger, from the next block height to the latest block height,
+    /// returning the number of blocks scanned. If the latest scanned block is no longer canon,
+    /// the scanner first rewinds by the maximum fork depth.
+    pub fn scan<S: LedgerStorage<N>>(&mut self, ledger: &Ledger<N, S>) -> Result<u32> {
+        // Rewind the scanner, if the latest scanned block is no longer in the canon chain.
+        if let Some(latest_block_hash) = self.latest_block_hash {
+            let latest_height = self.next_height.saturating_sub(1);
+            if latest_height > ledger.latest_block_height()
+                || ledger.get_block_hash(latest_height)? != latest_block_hash
+            {
+                self.rewind(self.next_height.saturating_sub(N::ALEO_MAXIMUM_FORK_DEPTH));
+            }
+        }
+
+        let start_height = self.next_height;
+        for height in start_height..=ledger.latest_block_height() {
+            let block_hash = ledger.get_block_hash(height)?;
+            let transactions = ledger.get_block_transactions(height)?;
+            self.scan_transactions(height, block_hash, &transactions)?;
+        }
+
+        Ok(self.next_height.saturating_sub(start_height))
+    }
+
+    /// Scans the given block, which must be the next block to scan.
+    pub fn scan_block(&mut self, block: &Block<N>) -> Result<()> {
+        // Ensure the block is the next block to scan.
+        if block.height() != self.next_height {
+            return Err(anyhow!(
+                "Expected to scan block {}, but found block {}",
+                self.next_height,
+                block.height()
+            ));
+        }
+
+        // Ensure the block builds on the latest scanned block.
+        if let Some(latest_block_hash) = self.latest_block_hash {
+            if block.previous_block_hash() != latest_block_hash {
+                return Err(anyhow!("The given block does not build on the latest scanned block"));
+            }
+        }
+
+        self.scan_transactions(block.height(), block.hash(), block.transactions())
+    }
+
+    /// Rewinds the scanner to scan again from the given block height,
+    /// forgetting the records created and restoring the records spent from this height onwards.
+    pub fn rewind(&mut self, height: u32) {
+        if height >= self.next_height {
+            return;
+        }
+
+        self.records
+            .retain(|_, owned_record| owned_record.created_height < height);
+        for owned_record in self.records.values_mut() {
+            if matches!(owned_record.spent_height, Some(spent_height) if spent_height >= height) {
+                owned_record.spent_height = None;
+            }
+        }
+
+        self.next_height = height;
+        self.latest_block_hash = None;
+    }
+
+    /// Scans the given transactions of the block at the given height.
+    fn scan_transactions(
+        &mut self,
+        height: u32,
+        block_hash: N::BlockHash,
+        transactions: &Transactions<N>,
+    ) -> Result<()> {
+        // Find the ciphertexts owned by the account, in one batch for the entire block.
+        let ciphertexts = transactions
+            .iter()
+            .flat_map(Transaction::ciphertexts)
+            .map(|ciphertext| &**ciphertext)
+            .collect::<Vec<_>>();
+        let mut is_owner = Ciphertext::is_owner_batch(&self.view_key, &ciphertexts).into_iter();
+
+        // Process the transactions in block order. For each transaction, insert the records it creates
+        // before marking the records it spends, as a record may be created and spent in the same block.
+        for transaction in transactions.iter() {
+            // Decrypt the records owned by the account.
+            for ciphertext in transaction.ciphertexts() {
+                if !is_owner.next().unwrap_or(false) {
+                    continue;
+                }
+
+                // Skip ciphertexts that fail to decrypt, or decrypt to dummy records.
+                let record = match Record::from_account_view_key(&self.view_key, ciphertext) {
+                    Ok(record) if !record.is_dummy() => record,
+                    _ => continue,
+                };
+
+                let serial_number = record.to_serial_number(&self.compute_key)?;
+                self.records.insert(serial_number, OwnedRecord {
+                    record,
+                    created_height: height,
+                    spent_height: None,
+                });
+            }
+
+            // Mark the owned records spent by the transaction.
+            for serial_number in transaction.serial_numbers() {
+                if let Some(owned_record) = self.records.get_mut(serial_number) {
+                    owned_record.spent_height.get_or_insert(height);
+                }
+            }
+        }
+
+        // Forget the spent records that are beyond the maximum fork depth.
+        self.records.retain(|_, owned_record| match owned_record.spent_height {
+            Some(spent_height) => spent_height.saturating_add(N::ALEO_MAXIMUM_FORK_DEPTH) > height,
+            None => true,
+        });
+
+        self.next_height = height + 1;
+        self.latest_block_hash = Some(block_hash);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+    #[cfg(feature = "devnet")]
+    use crate::{
+        devnet::Devnet,
+        ledger::memory_pool::tests::{new_ledger_with_records, new_transaction},
+    };
+
+    use rand::thread_rng;
+    use std::sync::atomic::AtomicBool;
+
+    #[test]
+    fn test_scan_coinbase_records() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let account = Account::<Testnet2>::new(rng);
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let coinbase_record = ledger
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+
+        // Scan the ledger for the coinbase record.
+        let mut scanner = RecordScanner::from_private_key(account.private_key(), 0);
+        assert_eq!(2, scanner.scan(&ledger).unwrap());
+        assert_eq!(vec![&coinbase_record], scanner.unspent_records().collect::<Vec<_>>());
+        assert_eq!(coinbase_record.value(), scanner.balance());
+
+        // Resume scanning from a checkpoint.
+        ledger
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+        let checkpoint = scanner.to_checkpoint();
+        let checkpoint = RecordScannerCheckpoint::read_le(&checkpoint.to_bytes_le().unwrap()[..]).unwrap();
+        let mut scanner = RecordScanner::from_checkpoint(
+            account.view_key().clone(),
+            account.private_key().to_compute_key(),
+            checkpoint,
+        )
+        .unwrap();
+        assert_eq!(1, scanner.scan(&ledger).unwrap());
+        assert_eq!(2, scanner.unspent_records().count());
+        assert_eq!(3, scanner.next_height());
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_scan_record_created_and_spent_in_one_transaction() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let account = Account::<Devnet>::new(rng);
+        let (mut ledger, records) = new_ledger_with_records(&account, 1, rng);
+
+        // Pay two recipients, so the change of the first transition is spent by the second transition.
+        let first_recipient = Account::<Devnet>::new(rng).address();
+        let second_recipient = Account::<Devnet>::new(rng).address();
+        let transaction = TransactionBuilder::new()
+            .add_records(records.clone())
+            .add_recipient(first_recipient, AleoAmount::from_i64(1))
+            .add_recipient(second_recipient, AleoAmount::from_i64(2))
+            .set_fee(AleoAmount::from_i64(3))
+            .build(account.private_key(), &ledger, rng)
+            .unwrap();
+        assert_eq!(2, transaction.transitions().len());
+        ledger.add_unconfirmed_transaction(&transaction).unwrap();
+        ledger.mine_next_block(first_recipient, true, &terminator, rng).unwrap();
+
+        // Ensure the change of the first transition is spent, and only the final change is unspent.
+        let mut scanner = RecordScanner::from_private_key(account.private_key(), 0);
+        assert_eq!(3, scanner.scan(&ledger).unwrap());
+        assert_eq!(1, scanner.unspent_records().count());
+        assert_eq!(records[0].value().sub(AleoAmount::from_i64(6)), scanner.balance());
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_scan_record_created_and_spent_in_one_block() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let account = Account::<Devnet>::new(rng);
+        let other = Account::<Devnet>::new(rng);
+        let (mut ledger, records) = new_ledger_with_records(&account, 1, rng);
+
+        // Create records for the account, and spend them in a later transaction.
+        let creating = new_transaction(&ledger, &account, &records, 1, rng);
+        ledger.add_unconfirmed_transaction(&creating).unwrap();
+        ledger.mine_next_block(other.address(), true, &terminator, rng).unwrap();
+        let mut scanner = RecordScanner::from_private_key(account.private_key(), 0);
+        scanner.scan(&ledger).unwrap();
+        let created_records = scanner.unspent_records().cloned().collect::<Vec<_>>();
+        let spending = new_transaction(&ledger, &account, &created_records, 1, rng);
+
+        // Scan a block containing both transactions, in order.
+        let transactions =
+            Transactions::from(&[ledger.get_block_transactions(2).unwrap().to_vec(), vec![spending]].concat()).unwrap();
+        let mut scanner = RecordScanner::from_private_key(account.private_key(), 0);
+        for height in 0..2 {
+            let transactions = ledger.get_block_transactions(height).unwrap();
+            scanner.scan_transactions(height, ledger.get_block_hash(height).unwrap(), &transactions).unwrap();
+        }
+        scanner.scan_transactions(2, ledger.get_block_hash(2).unwrap(), &transactions).unwrap();
+
+        // Ensure the change created by the first transaction is spent by the second transaction,
+        // which selects it over the smaller payment, leaving the payment and the two new records unspent.
+        let change = created_records.iter().max_by_key(|record| record.value()).unwrap();
+        assert_eq!(3, scanner.unspent_records().count());
+        assert!(!scanner.unspent_records().any(|record| record == change));
+        assert_eq!(records[0].value().sub(AleoAmount::from_i64(2)), scanner.balance());
+    }
+}
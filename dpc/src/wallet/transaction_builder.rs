@@ -0,0 +1,490 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use anyhow::{anyhow, Result};
+use rand::{CryptoRng, Rng};
+use std::cmp::Reverse;
+
+/// A planned transfer transition, which consumes the change record of the previous
+/// transition (if any) and the selected input records at the given indices.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct TransferPlan {
+    /// The indices of the selected input records consumed by the transition.
+    inputs: Vec<usize>,
+    /// The index of the recipient paid by the transition, or `None` if the transition only pays the fee.
+    recipient: Option<usize>,
+    /// The amount paid to the recipient.
+    amount: AleoAmount,
+    /// The portion of the fee paid by the transition.
+    fee: AleoAmount,
+    /// The change returned to the caller by the transition.
+    change: AleoAmount,
+}
+
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct TransactionBuilder<N: Network> {
+    /// The candidate records to spend, owned by the caller.
+    records: Vec<Record<N>>,
+    /// The recipients and amounts to pay.
+    recipients: Vec<(Address<N>, AleoAmount)>,
+    /// The network fee being paid.
+    fee: AleoAmount,
+    /// The visibility of the operations.
+    is_public: bool,
+    /// A list of errors accumulated from calling the builder.
+    errors: Vec<String>,
+}
+
+impl<N: Network> TransactionBuilder<N> {
+    ///
+    /// Initializes a new instance of `TransactionBuilder`.
+    ///
+    pub fn new() -> Self {
+        Self {
+            records: Vec::new(),
+            recipients: Vec::new(),
+            fee: AleoAmount::ZERO,
+            is_public: false,
+            errors: Vec::new(),
+        }
+    }
+
+    ///
+    /// Adds the given unspent record as a candidate input into the builder.
+    ///
+    pub fn add_record(mut self, record: Record<N>) -> Self {
+        if self
+            .records
+            .iter()
+            .any(|candidate| candidate.commitment() == record.commitment())
+        {
+            self.errors.push("Builder already added the given record".into());
+        } else if record.program_id() != *N::noop_program_id() {
+            self.errors
+                .push("Builder only supports records of the noop program".into());
+        } else {
+            self.records.push(record);
+        }
+        self
+    }
+
+    ///
+    /// Adds the given unspent records as candidate inputs into the builder.
+    ///
+    pub fn add_records(mut self, records: Vec<Record<N>>) -> Self {
+        for record in records {
+            self = self.add_record(record);
+        }
+        self
+    }
+
+    ///
+    /// Adds the given recipient and amount into the builder.
+    ///
+    pub fn add_recipient(mut self, recipient: Address<N>, amount: AleoAmount) -> Self {
+        match amount.is_positive() {
+            true => self.recipients.push((recipient, amount)),
+            false => self
+                .errors
+                .push("Builder requires a positive amount for each recipient".into()),
+        };
+        self
+    }
+
+    ///
+    /// Sets the network fee in the builder.
+    ///
+    pub fn set_fee(mut self, fee: AleoAmount) -> Self {
+        match fee.is_negative() {
+            true => self.errors.push("Builder requires a non-negative fee".into()),
+            false => self.fee = fee,
+        };
+        self
+    }
+
+    ///
+    /// Sets the visibility of the operations in the builder.
+    ///
+    pub fn set_public(mut self, is_public: bool) -> Self {
+        self.is_public = is_public;
+        self
+    }
+
+    ///
+    /// Finalizes the builder and returns a new instance of `Transaction`,
+    /// proving inclusion of the spent records with the latest ledger root.
+    ///
+    pub fn build<S: LedgerStorage<N>, R: Rng + CryptoRng>(
+        &self,
+        caller: &PrivateKey<N>,
+        ledger: &Ledger<N, S>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure there are no errors in the build process yet.
+        if !self.errors.is_empty() {
+            for error in &self.errors {
+                eprintln!("{}", error);
+            }
+            return Err(anyhow!(
+                "Transaction builder encountered build errors: {:?}",
+                self.errors
+            ));
+        }
+
+        // Select the input records, and plan the transitions to spend them.
+        let caller_address = caller.to_address();
+        let records = self.select_records(caller_address)?;
+        let plans = self.plan_transitions(&records.iter().map(Record::value).collect::<Vec<_>>())?;
+
+        let mut vm = VirtualMachine::<N>::new(ledger.latest_ledger_root())?;
+        let mut change: Option<Record<N>> = None;
+
+        for plan in plans {
+            let mut input_records = Vec::with_capacity(N::NUM_INPUT_RECORDS);
+            let mut ledger_proofs = Vec::with_capacity(N::NUM_INPUT_RECORDS);
+
+            // Spend the change of the previous transition, which is proven against the local transitions.
+            if let Some(change) = change.take() {
+                ledger_proofs.push(LedgerProof::new_dummy(vm.to_local_proof(change.commitment())?)?);
+                input_records.push(change);
+            }
+
+            // Spend the selected records, which are proven against the ledger.
+            for index in plan.inputs {
+                ledger_proofs.push(ledger.to_ledger_proof(records[index].commitment())?);
+                input_records.push(records[index].clone());
+            }
+
+            // Pad the inputs with noop records.
+            while input_records.len() < N::NUM_INPUT_RECORDS {
+                input_records.push(Record::new_noop(caller_address, rng)?);
+                ledger_proofs.push(LedgerProof::default());
+            }
+
+            // Pay the recipient, or pay the caller if the transition only pays the fee.
+            let recipient = match plan.recipient {
+                Some(index) => self.recipients[index].0,
+                None => caller_address,
+            };
+
+            let request = Request::new_transfer(
+                caller,
+                input_records,
+                ledger_proofs,
+                recipient,
+                plan.amount,
+                plan.fee,
+                self.is_public,
+                rng,
+            )?;
+            let (next_vm, response) = vm.execute(&request, rng)?;
+            vm = next_vm;
+
+            // Select the output record that returns the change to the caller.
+            let change_value = plan.change;
+            change = match response
+                .records()
+                .iter()
+                .find(|record| record.owner() == caller_address && record.value() == change_value)
+            {
+                Some(record) => Some(record.clone()),
+                None => {
+                    return Err(anyhow!(
+                        "Transition {} is missing the change record",
+                        response.transition_id()
+                    ))
+                }
+            };
+        }
+
+        vm.finalize()
+    }
+
+    ///
+    /// Returns the records to spend, selecting the largest records first
+    /// until their total value covers the recipient amounts and the fee.
+    ///
+    fn select_records(&self, caller: Address<N>) -> Result<Vec<Record<N>>> {
+        // Ensure the caller owns every candidate record.
+        if self.records.iter().any(|record| record.owner() != caller) {
+            return Err(anyhow!("Address from caller private key does not match record owner"));
+        }
+
+        // Sort the candidate records with value by descending value.
+        let mut candidates = self
+            .records
+            .iter()
+            .filter(|record| !record.is_dummy() && record.value().is_positive())
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|record| Reverse(record.value()));
+
+        let target = self.to_total_amount()?;
+        let mut balance = AleoAmount::ZERO;
+        let mut records = Vec::new();
+        for record in candidates {
+            if balance >= target {
+                break;
+            }
+            balance = balance.add(record.value());
+            records.push(record.clone());
+        }
+
+        match balance >= target {
+            true => Ok(records),
+            false => Err(VMError::BalanceInsufficient.into()),
+        }
+    }
+
+    ///
+    /// Returns the transitions to spend the given input values. Each transition consumes the change
+    /// of the previous transition and the next input values, then pays as much of the outstanding fee,
+    /// followed by as much of the outstanding recipient amounts, as its balance allows.
+    ///
+    fn plan_transitions(&self, input_values: &[AleoAmount]) -> Result<Vec<TransferPlan>> {
+        if self.recipients.is_empty() {
+            return Err(anyhow!("Builder is missing recipients"));
+        }
+
+        let mut plans = Vec::new();
+        let mut change: Option<AleoAmount> = None;
+        let mut next_input = 0;
+        let mut fee_remaining = self.fee;
+        let mut next_recipient = 0;
+        let mut paid = AleoAmount::ZERO;
+
+        while !fee_remaining.is_zero() || next_recipient < self.recipients.len() {
+            // Ensure the transaction does not exceed the maximum number of transitions.
+            if plans.len() >= N::NUM_TRANSITIONS as usize {
+                return Err(anyhow!(
+                    "Transaction requires more than {} transitions",
+                    N::NUM_TRANSITIONS
+                ));
+            }
+
+            // Consume the change of the previous transition, and the next input values.
+            let mut balance = change.unwrap_or(AleoAmount::ZERO);
+            let num_inputs = (N::NUM_INPUT_RECORDS - change.is_some() as usize).min(input_values.len() - next_input);
+            let inputs = (next_input..next_input + num_inputs).collect::<Vec<_>>();
+            for index in &inputs {
+                balance = balance.add(input_values[*index]);
+            }
+            next_input += num_inputs;
+
+            // Ensure the transition makes progress.
+            if balance.is_zero() {
+                return Err(VMError::BalanceInsufficient.into());
+            }
+
+            // Pay as much of the outstanding fee as possible.
+            let fee = fee_remaining.min(balance);
+            fee_remaining = fee_remaining.sub(fee);
+            let available = balance.sub(fee);
+
+            // Pay as much of the outstanding recipient amounts as possible.
+            let (recipient, amount) = match self.recipients.get(next_recipient) {
+                Some((_, recipient_amount)) => {
+                    let amount = recipient_amount.sub(paid).min(available);
+                    let recipient = Some(next_recipient);
+                    paid = paid.add(amount);
+                    if paid == *recipient_amount {
+                        next_recipient += 1;
+                        paid = AleoAmount::ZERO;
+                    }
+                    (recipient, amount)
+                }
+                None => (None, AleoAmount::ZERO),
+            };
+
+            let remaining = available.sub(amount);
+            change = Some(remaining);
+            plans.push(TransferPlan {
+                inputs,
+                recipient,
+                amount,
+                fee,
+                change: remaining,
+            });
+        }
+
+        Ok(plans)
+    }
+
+    /// Returns the sum of the recipient amounts and the fee.
+    fn to_total_amount(&self) -> Result<AleoAmount> {
+        let mut total = self.fee.as_i64();
+        for (_, amount) in &self.recipients {
+            total = total.checked_add(amount.as_i64()).ok_or(VMError::BalanceOverflow)?;
+        }
+        Ok(AleoAmount::from_i64(total))
+    }
+}
+
+impl<N: Network> Default for TransactionBuilder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+    #[cfg(feature = "devnet")]
+    use crate::{devnet::Devnet, ledger::memory_pool::tests::new_ledger_with_records};
+
+    use rand::thread_rng;
+    #[cfg(feature = "devnet")]
+    use std::sync::atomic::AtomicBool;
+
+    fn new_record<R: Rng + CryptoRng>(owner: Address<Testnet2>, value: i64, rng: &mut R) -> Record<Testnet2> {
+        Record::new(
+            owner,
+            AleoAmount::from_i64(value),
+            Default::default(),
+            *Testnet2::noop_program_id(),
+            rng,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_select_records() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+        let recipient = Account::<Testnet2>::new(rng).address();
+
+        let records = vec![
+            new_record(account.address(), 3, rng),
+            new_record(account.address(), 1, rng),
+            new_record(account.address(), 4, rng),
+        ];
+
+        // Ensure the largest records are selected first.
+        let builder = TransactionBuilder::<Testnet2>::new()
+            .add_records(records.clone())
+            .add_recipient(recipient, AleoAmount::from_i64(4))
+            .set_fee(AleoAmount::from_i64(1));
+        let selected = builder.select_records(account.address()).unwrap();
+        assert_eq!(vec![records[2].clone(), records[0].clone()], selected);
+
+        // Ensure an insufficient balance is rejected.
+        let builder = builder.add_recipient(recipient, AleoAmount::from_i64(4));
+        assert!(builder.select_records(account.address()).is_err());
+
+        // Ensure records of another owner are rejected.
+        assert!(builder.select_records(recipient).is_err());
+    }
+
+    #[test]
+    fn test_plan_transitions() {
+        let rng = &mut thread_rng();
+        let first_recipient = Account::<Testnet2>::new(rng).address();
+        let second_recipient = Account::<Testnet2>::new(rng).address();
+
+        let builder = TransactionBuilder::<Testnet2>::new()
+            .add_recipient(first_recipient, AleoAmount::from_i64(12))
+            .add_recipient(second_recipient, AleoAmount::from_i64(2))
+            .set_fee(AleoAmount::from_i64(2));
+
+        let input_values = [5, 5, 5, 1]
+            .iter()
+            .map(|value| AleoAmount::from_i64(*value))
+            .collect::<Vec<_>>();
+        let plans = builder.plan_transitions(&input_values).unwrap();
+        assert_eq!(
+            vec![
+                TransferPlan {
+                    inputs: vec![0, 1],
+                    recipient: Some(0),
+                    amount: AleoAmount::from_i64(8),
+                    fee: AleoAmount::from_i64(2),
+                    change: AleoAmount::ZERO,
+                },
+                TransferPlan {
+                    inputs: vec![2],
+                    recipient: Some(0),
+                    amount: AleoAmount::from_i64(4),
+                    fee: AleoAmount::ZERO,
+                    change: AleoAmount::from_i64(1),
+                },
+                TransferPlan {
+                    inputs: vec![3],
+                    recipient: Some(1),
+                    amount: AleoAmount::from_i64(2),
+                    fee: AleoAmount::ZERO,
+                    change: AleoAmount::ZERO,
+                },
+            ],
+            plans
+        );
+
+        // Ensure the transitions cannot exceed the maximum number of transitions.
+        let input_values = vec![AleoAmount::from_i64(1); 2 * Testnet2::NUM_TRANSITIONS as usize];
+        let builder = TransactionBuilder::<Testnet2>::new().add_recipient(
+            first_recipient,
+            AleoAmount::from_i64(2 * Testnet2::NUM_TRANSITIONS as i64),
+        );
+        assert!(builder.plan_transitions(&input_values).is_err());
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_build_transaction() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let account = Account::<Devnet>::new(rng);
+        let recipient = Account::<Devnet>::new(rng);
+        let (mut ledger, records) = new_ledger_with_records(&account, 3, rng);
+        let total = records.iter().map(Record::value).sum::<AleoAmount>();
+
+        // Pay an amount that requires more input records than a single transition can spend.
+        let amount = records[0].value().add(records[1].value()).add(AleoAmount::from_i64(1));
+        let fee = AleoAmount::from_i64(2);
+        let transaction = TransactionBuilder::new()
+            .add_records(records)
+            .add_recipient(recipient.address(), amount)
+            .set_fee(fee)
+            .build(account.private_key(), &ledger, rng)
+            .unwrap();
+        assert_eq!(2, transaction.transitions().len());
+        assert_eq!(fee, transaction.value_balance());
+
+        // Confirm the transaction, and ensure the recipient and the caller hold the expected balances.
+        ledger.add_unconfirmed_transaction(&transaction).unwrap();
+        ledger
+            .mine_next_block(recipient.address(), true, &terminator, rng)
+            .unwrap();
+        assert!(ledger.contains_transaction(&transaction).unwrap());
+
+        let mut scanner = RecordScanner::from_private_key(account.private_key(), 0);
+        scanner.scan(&ledger).unwrap();
+        assert_eq!(total.sub(amount).sub(fee), scanner.balance());
+
+        let coinbase_value = ledger
+            .latest_block()
+            .unwrap()
+            .to_coinbase_transaction()
+            .unwrap()
+            .value_balance();
+        let mut scanner = RecordScanner::from_private_key(recipient.private_key(), 0);
+        scanner.scan(&ledger).unwrap();
+        assert_eq!(amount.sub(coinbase_value), scanner.balance());
+    }
+}
@@ -22,9 +22,9 @@ use std::{
     time::Duration,
 };
 
-use snarkvm_algorithms::{SNARKError, SNARK, SRS};
+use snarkvm_algorithms::{SNARK, SRS};
 use snarkvm_curves::bls12_377::Fr;
-use snarkvm_dpc::{testnet2::Testnet2, BlockTemplate, Network, PoSWError, PoSWScheme};
+use snarkvm_dpc::{testnet2::Testnet2, BlockTemplate, MiningOutcome, Network, PoSWScheme};
 use snarkvm_marlin::marlin::{CircuitProvingKey, MarlinPoswMode, MarlinTestnet1Mode};
 
 use rand::{rngs::ThreadRng, thread_rng};
@@ -52,7 +52,7 @@ fn test_posw_terminate() {
     });
     let result = Testnet2::posw().mine(&block_template, &AtomicBool::new(true), &mut thread_rng());
 
-    assert!(matches!(result, Err(PoSWError::SNARKError(SNARKError::Terminated))));
+    assert!(matches!(result, Ok(MiningOutcome::Cancelled(_))));
 }
 
 /// TODO (howardwu): Update this when testnet2 is live.
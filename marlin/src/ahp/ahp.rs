@@ -97,6 +97,30 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             .map(|s| s.to_string())
     }
 
+    /// The label of the given per-instance polynomial, for the `index`-th instance of a batch.
+    pub(crate) fn instance_label(label: &str, index: usize) -> String {
+        format!("{}_{}", label, index)
+    }
+
+    /// The labels of the polynomials output by the AHP indexer and prover, for a batch of instances.
+    /// Each instance has its own `w`, `z_a`, and `z_b`, and all other polynomials are shared.
+    pub(crate) fn batch_polynomial_labels(batch_size: usize) -> impl Iterator<Item = String> {
+        let instance_polynomials = (0..batch_size).flat_map(|i| {
+            vec![
+                Self::instance_label("w", i),
+                Self::instance_label("z_a", i),
+                Self::instance_label("z_b", i),
+            ]
+        });
+        let mask_poly = if MM::ZK { Some("mask_poly".to_string()) } else { None };
+
+        Self::indexer_polynomials()
+            .map(|s| s.to_string())
+            .chain(instance_polynomials)
+            .chain(mask_poly)
+            .chain(["t", "g_1", "h_1", "g_2", "h_2"].iter().map(|s| s.to_string()))
+    }
+
     /// Check that the (formatted) public input is of the form 2^n for some integer n.
     pub fn num_formatted_public_inputs_is_admissible(num_inputs: usize) -> bool {
         num_inputs.count_ones() == 1
@@ -158,14 +182,6 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         state: &verifier::VerifierState<F, MM>,
     ) -> Result<Vec<LinearCombination<F>>, AHPError> {
         let domain_h = state.domain_h;
-        let domain_k = state.domain_k;
-        let k_size = domain_k.size_as_field_element;
-
-        let public_input = ProverConstraintSystem::format_public_input(public_input);
-        if !Self::formatted_public_input_is_admissible(&public_input) {
-            return Err(AHPError::InvalidPublicInputLength);
-        }
-        let x_domain = EvaluationDomain::new(public_input.len()).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
         let first_round_msg = state.first_round_message.unwrap();
         let alpha = first_round_msg.alpha;
@@ -174,7 +190,6 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let eta_c = first_round_msg.eta_c;
 
         let beta = state.second_round_message.unwrap().beta;
-        let gamma = state.gamma.unwrap();
 
         let mut linear_combinations = Vec::with_capacity(9);
 
@@ -184,21 +199,13 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let t = LinearCombination::new("t", vec![(F::one(), "t")]);
 
         let r_alpha_at_beta = domain_h.eval_unnormalized_bivariate_lagrange_poly(alpha, beta);
-        let v_H_at_alpha = domain_h.evaluate_vanishing_polynomial(alpha);
         let v_H_at_beta = domain_h.evaluate_vanishing_polynomial(beta);
-        let v_X_at_beta = x_domain.evaluate_vanishing_polynomial(beta);
+        let (v_X_at_beta, x_at_beta) = Self::public_input_evaluations(public_input, beta)?;
 
         let z_b_at_beta = evals.get_lc_eval(&z_b, beta)?;
         let t_at_beta = evals.get_lc_eval(&t, beta)?;
         let g_1_at_beta = evals.get_lc_eval(&g_1, beta)?;
 
-        let x_at_beta = x_domain
-            .evaluate_all_lagrange_coefficients(beta)
-            .into_iter()
-            .zip(public_input)
-            .map(|(l, x)| l * x)
-            .fold(F::zero(), |x, y| x + y);
-
         #[rustfmt::skip]
         let outer_sumcheck = {
             let mut lc_terms = vec![];
@@ -219,6 +226,135 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         linear_combinations.push(g_1);
         linear_combinations.push(t);
         linear_combinations.push(outer_sumcheck);
+        linear_combinations.extend(Self::inner_sumcheck_linear_combinations(evals, state, t_at_beta)?);
+
+        linear_combinations.sort_by(|a, b| a.label.cmp(&b.label));
+        Ok(linear_combinations)
+    }
+
+    /// Construct the linear combinations that are checked by the AHP, for a batch of instances.
+    /// The outer sumcheck is checked over the sum of the instances weighted by the batch combiners,
+    /// and the inner sumcheck is shared by all instances.
+    /// Public inputs should be unformatted.
+    #[allow(non_snake_case)]
+    pub fn construct_batch_linear_combinations<E: EvaluationsProvider<F>>(
+        public_inputs: &[Vec<F>],
+        batch_combiners: &[F],
+        evals: &E,
+        state: &verifier::VerifierState<F, MM>,
+    ) -> Result<Vec<LinearCombination<F>>, AHPError> {
+        if public_inputs.is_empty() {
+            return Err(AHPError::EmptyBatch);
+        }
+        if public_inputs.len() != batch_combiners.len() {
+            return Err(AHPError::InvalidBatchSize);
+        }
+
+        let domain_h = state.domain_h;
+
+        let first_round_msg = state.first_round_message.unwrap();
+        let alpha = first_round_msg.alpha;
+        let eta_a = first_round_msg.eta_a;
+        let eta_b = first_round_msg.eta_b;
+        let eta_c = first_round_msg.eta_c;
+
+        let beta = state.second_round_message.unwrap().beta;
+
+        let mut linear_combinations = Vec::with_capacity(public_inputs.len() + 8);
+
+        // Outer sumcheck:
+        let g_1 = LinearCombination::new("g_1", vec![(F::one(), "g_1")]);
+        let t = LinearCombination::new("t", vec![(F::one(), "t")]);
+
+        let r_alpha_at_beta = domain_h.eval_unnormalized_bivariate_lagrange_poly(alpha, beta);
+        let v_H_at_beta = domain_h.evaluate_vanishing_polynomial(beta);
+
+        let t_at_beta = evals.get_lc_eval(&t, beta)?;
+        let g_1_at_beta = evals.get_lc_eval(&g_1, beta)?;
+
+        let mut lc_terms = vec![];
+        if MM::ZK {
+            lc_terms.push((F::one(), "mask_poly".into()));
+        }
+        for (i, (public_input, combiner)) in public_inputs.iter().zip(batch_combiners).enumerate() {
+            let z_b_label = Self::instance_label("z_b", i);
+            let z_b = LinearCombination::new(z_b_label.clone(), vec![(F::one(), z_b_label)]);
+
+            let (v_X_at_beta, x_at_beta) = Self::public_input_evaluations(public_input, beta)?;
+            let z_b_at_beta = evals.get_lc_eval(&z_b, beta)?;
+
+            lc_terms.push((
+                *combiner * r_alpha_at_beta * (eta_a + (eta_c * z_b_at_beta)),
+                Self::instance_label("z_a", i).into(),
+            ));
+            lc_terms.push((*combiner * r_alpha_at_beta * eta_b * z_b_at_beta, LCTerm::One));
+            lc_terms.push((
+                -*combiner * t_at_beta * v_X_at_beta,
+                Self::instance_label("w", i).into(),
+            ));
+            lc_terms.push((-*combiner * t_at_beta * x_at_beta, LCTerm::One));
+
+            linear_combinations.push(z_b);
+        }
+        lc_terms.push((-v_H_at_beta, "h_1".into()));
+        lc_terms.push((-beta * g_1_at_beta, LCTerm::One));
+        let outer_sumcheck = LinearCombination::new("outer_sumcheck", lc_terms);
+        debug_assert!(evals.get_lc_eval(&outer_sumcheck, beta)?.is_zero());
+
+        linear_combinations.push(g_1);
+        linear_combinations.push(t);
+        linear_combinations.push(outer_sumcheck);
+        linear_combinations.extend(Self::inner_sumcheck_linear_combinations(evals, state, t_at_beta)?);
+
+        linear_combinations.sort_by(|a, b| a.label.cmp(&b.label));
+        Ok(linear_combinations)
+    }
+
+    /// Returns the evaluations of the vanishing polynomial of the input domain,
+    /// and of the polynomial interpolating the given (unformatted) public input, at the given point.
+    #[allow(non_snake_case)]
+    fn public_input_evaluations(public_input: &[F], point: F) -> Result<(F, F), AHPError> {
+        let public_input = ProverConstraintSystem::format_public_input(public_input);
+        if !Self::formatted_public_input_is_admissible(&public_input) {
+            return Err(AHPError::InvalidPublicInputLength);
+        }
+        let x_domain = EvaluationDomain::new(public_input.len()).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        let v_X_at_point = x_domain.evaluate_vanishing_polynomial(point);
+        let x_at_point = x_domain
+            .evaluate_all_lagrange_coefficients(point)
+            .into_iter()
+            .zip(public_input)
+            .map(|(l, x)| l * x)
+            .fold(F::zero(), |x, y| x + y);
+
+        Ok((v_X_at_point, x_at_point))
+    }
+
+    /// Construct the linear combinations of the inner sumcheck, which depend only on the index.
+    #[allow(non_snake_case)]
+    fn inner_sumcheck_linear_combinations<E: EvaluationsProvider<F>>(
+        evals: &E,
+        state: &verifier::VerifierState<F, MM>,
+        t_at_beta: F,
+    ) -> Result<Vec<LinearCombination<F>>, AHPError> {
+        let domain_h = state.domain_h;
+        let domain_k = state.domain_k;
+        let k_size = domain_k.size_as_field_element;
+
+        let first_round_msg = state.first_round_message.unwrap();
+        let alpha = first_round_msg.alpha;
+        let eta_a = first_round_msg.eta_a;
+        let eta_b = first_round_msg.eta_b;
+        let eta_c = first_round_msg.eta_c;
+
+        let beta = state.second_round_message.unwrap().beta;
+        let gamma = state.gamma.unwrap();
+
+        let v_H_at_alpha = domain_h.evaluate_vanishing_polynomial(alpha);
+        let v_H_at_beta = domain_h.evaluate_vanishing_polynomial(beta);
+
+        let mut linear_combinations = Vec::with_capacity(5);
 
         //  Inner sumcheck:
         let beta_alpha = beta * alpha;
@@ -261,7 +397,6 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             linear_combinations.push(vanishing_poly_k_gamma);
         }
 
-        linear_combinations.sort_by(|a, b| a.label.cmp(&b.label));
         Ok(linear_combinations)
     }
 }
@@ -21,10 +21,14 @@ use crate::String;
 pub enum AHPError {
     /// An error occurred during constraint generation.
     ConstraintSystemError(snarkvm_r1cs::errors::SynthesisError),
+    /// A batch must contain at least one instance.
+    EmptyBatch,
     /// An error occurred during Fiat-Shamir.
     FiatShamirError(crate::fiat_shamir::FiatShamirError),
     /// The instance generated during proving does not match that in the index.
     InstanceDoesNotMatchIndex,
+    /// The number of batch combiners does not match the number of instances in the batch.
+    InvalidBatchSize,
     /// The number of public inputs is incorrect.
     InvalidPublicInputLength,
     /// During verification, a required evaluation is missing
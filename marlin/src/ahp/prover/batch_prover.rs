@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    ahp::{
+        indexer::{Circuit, CircuitInfo},
+        verifier::{VerifierFirstMessage, VerifierSecondMessage},
+        AHPError,
+        AHPForR1CS,
+    },
+    marlin::MarlinMode,
+    prover::{
+        prover::{ProverSecondOracles, ProverThirdOracles},
+        state::{BatchProverState, ProverState},
+        ProverMessage,
+    },
+    Vec,
+};
+use snarkvm_fields::{Field, PrimeField};
+use snarkvm_polycommit::LabeledPolynomial;
+use snarkvm_r1cs::ConstraintSynthesizer;
+
+use rand_core::RngCore;
+
+/// The first set of prover oracles, when proving a batch of instances.
+pub struct BatchProverFirstOracles<F: Field> {
+    /// The LDEs of `w`, for each instance.
+    pub w: Vec<LabeledPolynomial<F>>,
+    /// The LDEs of `Az`, for each instance.
+    pub z_a: Vec<LabeledPolynomial<F>>,
+    /// The LDEs of `Bz`, for each instance.
+    pub z_b: Vec<LabeledPolynomial<F>>,
+    /// The sum-check hiding polynomial, shared by all instances.
+    pub mask_poly: Option<LabeledPolynomial<F>>,
+}
+
+impl<F: Field> BatchProverFirstOracles<F> {
+    /// Iterate over the polynomials output by the prover in the first round.
+    /// The polynomials of each instance are followed by those of the next instance,
+    /// and the hiding polynomial comes last.
+    pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
+        self.w
+            .iter()
+            .zip(&self.z_a)
+            .zip(&self.z_b)
+            .flat_map(|((w, z_a), z_b)| vec![w, z_a, z_b])
+            .chain(self.mask_poly.as_ref())
+    }
+}
+
+impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
+    /// Initialize the AHP prover for a batch of instances of the given index.
+    pub fn batch_prover_init<'a, C: ConstraintSynthesizer<F>>(
+        index: &'a Circuit<F, MM>,
+        circuits: &[C],
+    ) -> Result<BatchProverState<'a, F, MM>, AHPError> {
+        if circuits.is_empty() {
+            return Err(AHPError::EmptyBatch);
+        }
+
+        let init_time = start_timer!(|| format!("AHP::Prover::BatchInit with {} instances", circuits.len()));
+        let instances = circuits
+            .iter()
+            .map(|circuit| Self::prover_init(index, circuit))
+            .collect::<Result<Vec<_>, _>>()?;
+        end_timer!(init_time);
+
+        Ok(BatchProverState {
+            instances,
+            verifier_first_message: None,
+            mask_poly: None,
+        })
+    }
+
+    /// Output the first round message and the next state, for a batch of instances.
+    #[allow(clippy::type_complexity)]
+    pub fn batch_prover_first_round<'a, R: RngCore>(
+        mut state: BatchProverState<'a, F, MM>,
+        rng: &mut R,
+    ) -> Result<
+        (
+            ProverMessage<F>,
+            BatchProverFirstOracles<F>,
+            BatchProverState<'a, F, MM>,
+        ),
+        AHPError,
+    > {
+        let round_time = start_timer!(|| "AHP::Prover::BatchFirstRound");
+
+        let hiding_bound = if MM::ZK { Some(1) } else { None };
+
+        let mut oracles = BatchProverFirstOracles {
+            w: Vec::with_capacity(state.batch_size()),
+            z_a: Vec::with_capacity(state.batch_size()),
+            z_b: Vec::with_capacity(state.batch_size()),
+            mask_poly: None,
+        };
+
+        for (i, instance) in state.instances.iter_mut().enumerate() {
+            let (w_poly, z_a_poly, z_b_poly) = Self::first_round_polynomials(instance, rng);
+
+            assert!(w_poly.degree() < instance.domain_h.size() - instance.domain_x.size() + instance.zk_bound);
+            assert!(z_a_poly.degree() < instance.domain_h.size() + instance.zk_bound);
+            assert!(z_b_poly.degree() < instance.domain_h.size() + instance.zk_bound);
+
+            let w = LabeledPolynomial::new(Self::instance_label("w", i), w_poly, None, hiding_bound);
+            let z_a = LabeledPolynomial::new(Self::instance_label("z_a", i), z_a_poly, None, hiding_bound);
+            let z_b = LabeledPolynomial::new(Self::instance_label("z_b", i), z_b_poly, None, hiding_bound);
+
+            oracles.w.push(w.clone());
+            oracles.z_a.push(z_a.clone());
+            oracles.z_b.push(z_b.clone());
+
+            instance.w_poly = Some(w);
+            instance.mz_polys = Some((z_a, z_b));
+        }
+
+        let instance = &state.instances[0];
+        let mask_poly = Self::mask_polynomial(instance.domain_h, instance.zk_bound, rng)
+            .map(|mask_poly| LabeledPolynomial::new("mask_poly".into(), mask_poly, None, None));
+
+        oracles.mask_poly = mask_poly.clone();
+        state.mask_poly = mask_poly;
+
+        let msg = ProverMessage::default();
+        end_timer!(round_time);
+
+        Ok((msg, oracles, state))
+    }
+
+    /// Output the number of oracles sent by the prover in the first round, for a batch of instances.
+    pub fn batch_prover_num_first_round_oracles(batch_size: usize) -> usize {
+        3 * batch_size + if MM::ZK { 1 } else { 0 }
+    }
+
+    /// Output the degree bounds of oracles in the first round, for a batch of instances.
+    pub fn batch_prover_first_round_degree_bounds(
+        _info: &CircuitInfo<F>,
+        batch_size: usize,
+    ) -> impl Iterator<Item = Option<usize>> {
+        vec![None; Self::batch_prover_num_first_round_oracles(batch_size)].into_iter()
+    }
+
+    /// Output the second round message and the next state, for a batch of instances.
+    /// The first sumcheck is run once, over the sum of the instances weighted by the batch combiners.
+    pub fn batch_prover_second_round<'a, R: RngCore>(
+        verifier_message: &VerifierFirstMessage<F>,
+        batch_combiners: &[F],
+        mut state: BatchProverState<'a, F, MM>,
+        _r: &mut R,
+    ) -> Result<(ProverMessage<F>, ProverSecondOracles<F>, BatchProverState<'a, F, MM>), AHPError> {
+        let round_time = start_timer!(|| "AHP::Prover::BatchSecondRound");
+
+        if batch_combiners.len() != state.batch_size() {
+            return Err(AHPError::InvalidBatchSize);
+        }
+
+        let mask_poly = state.mask_poly.as_ref();
+        assert_eq!(MM::ZK, mask_poly.is_some());
+
+        let instances = state
+            .instances
+            .iter()
+            .zip(batch_combiners)
+            .map(|(instance, combiner)| {
+                let (summed_z_m, z_poly) = Self::first_sumcheck_polynomials(instance, verifier_message);
+                (*combiner, summed_z_m, z_poly)
+            })
+            .collect::<Vec<_>>();
+
+        let instance = &state.instances[0];
+        let oracles = Self::first_sumcheck_oracles(
+            instance.index,
+            instance.domain_x,
+            instance.domain_h,
+            instance.zk_bound,
+            mask_poly,
+            verifier_message,
+            &instances,
+        );
+
+        let msg = ProverMessage::default();
+
+        for instance in state.instances.iter_mut() {
+            instance.w_poly = None;
+        }
+        state.verifier_first_message = Some(*verifier_message);
+        end_timer!(round_time);
+
+        Ok((msg, oracles, state))
+    }
+
+    /// Output the third round message, for a batch of instances.
+    /// The second sumcheck depends only on the index, so it is shared by all instances.
+    pub fn batch_prover_third_round<R: RngCore>(
+        verifier_message: &VerifierSecondMessage<F>,
+        state: BatchProverState<'_, F, MM>,
+        _r: &mut R,
+    ) -> Result<(ProverMessage<F>, ProverThirdOracles<F>), AHPError> {
+        let round_time = start_timer!(|| "AHP::Prover::BatchThirdRound");
+
+        let verifier_first_message = state
+            .verifier_first_message
+            .expect("BatchProverState should include verifier_first_msg when batch_prover_third_round is called");
+
+        let ProverState {
+            index,
+            domain_h,
+            domain_k,
+            ..
+        } = &state.instances[0];
+
+        let oracles =
+            Self::second_sumcheck_oracles(index, &verifier_first_message, verifier_message, *domain_h, *domain_k);
+
+        let msg = ProverMessage::default();
+        end_timer!(round_time);
+
+        Ok((msg, oracles))
+    }
+}
@@ -16,6 +16,8 @@
 
 #![allow(non_snake_case)]
 
+mod batch_prover;
+
 mod constraint_system;
 pub(crate) use constraint_system::*;
 
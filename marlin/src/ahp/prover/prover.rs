@@ -221,8 +221,49 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     ) -> Result<(ProverMessage<F>, ProverFirstOracles<F>, ProverState<'a, F, MM>), AHPError> {
         let round_time = start_timer!(|| "AHP::Prover::FirstRound");
         let domain_h = state.domain_h;
+        let domain_x = state.domain_x;
         let zk_bound = state.zk_bound;
 
+        let (w_poly, z_a_poly, z_b_poly) = Self::first_round_polynomials(&state, rng);
+        let mask_poly = Self::mask_polynomial(domain_h, zk_bound, rng);
+
+        let msg = ProverMessage::default();
+
+        assert!(w_poly.degree() < domain_h.size() - domain_x.size() + zk_bound);
+        assert!(z_a_poly.degree() < domain_h.size() + zk_bound);
+        assert!(z_b_poly.degree() < domain_h.size() + zk_bound);
+
+        let hiding_bound = if MM::ZK { Some(1) } else { None };
+
+        let w = LabeledPolynomial::new("w".to_string(), w_poly, None, hiding_bound);
+        let z_a = LabeledPolynomial::new("z_a".to_string(), z_a_poly, None, hiding_bound);
+        let z_b = LabeledPolynomial::new("z_b".to_string(), z_b_poly, None, hiding_bound);
+
+        let mask_poly =
+            mask_poly.map(|mask_poly| LabeledPolynomial::new("mask_poly".to_string(), mask_poly, None, None));
+
+        let oracles = ProverFirstOracles {
+            w: w.clone(),
+            z_a: z_a.clone(),
+            z_b: z_b.clone(),
+            mask_poly: mask_poly.clone(),
+        };
+
+        state.w_poly = Some(w);
+        state.mz_polys = Some((z_a, z_b));
+        state.mask_poly = mask_poly;
+        end_timer!(round_time);
+
+        Ok((msg, oracles, state))
+    }
+
+    /// Output the polynomials `w`, `z_A`, and `z_B` of the given instance.
+    pub(super) fn first_round_polynomials<R: RngCore>(
+        state: &ProverState<'_, F, MM>,
+        rng: &mut R,
+    ) -> (Polynomial<F>, Polynomial<F>, Polynomial<F>) {
+        let domain_h = state.domain_h;
+
         let v_H = domain_h.vanishing_polynomial().into();
 
         let x_time = start_timer!(|| "Computing x polynomial and evals");
@@ -273,7 +314,16 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         }
         end_timer!(z_b_poly_time);
 
-        let mask_poly = if MM::ZK {
+        (w_poly, z_a_poly, z_b_poly)
+    }
+
+    /// Output the sum-check hiding polynomial, if the mode is zero-knowledge.
+    pub(super) fn mask_polynomial<R: RngCore>(
+        domain_h: EvaluationDomain<F>,
+        zk_bound: usize,
+        rng: &mut R,
+    ) -> Option<Polynomial<F>> {
+        if MM::ZK {
             let mask_poly_time = start_timer!(|| "Computing mask polynomial");
             let mask_poly_degree = 3 * domain_h.size() + 2 * zk_bound - 3;
             let mut mask_poly = Polynomial::rand(mask_poly_degree, rng);
@@ -284,36 +334,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             Some(mask_poly)
         } else {
             None
-        };
-
-        let msg = ProverMessage::default();
-
-        assert!(w_poly.degree() < domain_h.size() - domain_x.size() + zk_bound);
-        assert!(z_a_poly.degree() < domain_h.size() + zk_bound);
-        assert!(z_b_poly.degree() < domain_h.size() + zk_bound);
-
-        let hiding_bound = if MM::ZK { Some(1) } else { None };
-
-        let w = LabeledPolynomial::new("w".to_string(), w_poly, None, hiding_bound);
-        let z_a = LabeledPolynomial::new("z_a".to_string(), z_a_poly, None, hiding_bound);
-        let z_b = LabeledPolynomial::new("z_b".to_string(), z_b_poly, None, hiding_bound);
-
-        let mask_poly =
-            mask_poly.map(|mask_poly| LabeledPolynomial::new("mask_poly".to_string(), mask_poly, None, None));
-
-        let oracles = ProverFirstOracles {
-            w: w.clone(),
-            z_a: z_a.clone(),
-            z_b: z_b.clone(),
-            mask_poly: mask_poly.clone(),
-        };
-
-        state.w_poly = Some(w);
-        state.mz_polys = Some((z_a, z_b));
-        state.mask_poly = mask_poly;
-        end_timer!(round_time);
-
-        Ok((msg, oracles, state))
+        }
     }
 
     fn calculate_t<'a>(
@@ -337,7 +358,11 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
     /// Output the number of oracles sent by the prover in the first round.
     pub fn prover_num_first_round_oracles() -> usize {
-        if MM::ZK { 4 } else { 3 }
+        if MM::ZK {
+            4
+        } else {
+            3
+        }
     }
 
     /// Output the degree bounds of oracles in the first round.
@@ -357,17 +382,38 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     ) -> (ProverMessage<F>, ProverSecondOracles<F>, ProverState<'a, F, MM>) {
         let round_time = start_timer!(|| "AHP::Prover::SecondRound");
 
-        let domain_h = state.domain_h;
-        let zk_bound = state.zk_bound;
-
         let mask_poly = state.mask_poly.as_ref();
         assert_eq!(MM::ZK, mask_poly.is_some());
 
+        let (summed_z_m, z_poly) = Self::first_sumcheck_polynomials(&state, verifier_message);
+
+        let oracles = Self::first_sumcheck_oracles(
+            state.index,
+            state.domain_x,
+            state.domain_h,
+            state.zk_bound,
+            mask_poly,
+            verifier_message,
+            &[(F::one(), summed_z_m, z_poly)],
+        );
+
+        let msg = ProverMessage::default();
+
+        state.w_poly = None;
+        state.verifier_first_message = Some(*verifier_message);
+        end_timer!(round_time);
+
+        (msg, oracles, state)
+    }
+
+    /// Output the polynomials `sum_M eta_M z_M` and `z` of the given instance,
+    /// as used in the first sumcheck.
+    pub(super) fn first_sumcheck_polynomials(
+        state: &ProverState<'_, F, MM>,
+        verifier_message: &VerifierFirstMessage<F>,
+    ) -> (Polynomial<F>, Polynomial<F>) {
         let VerifierFirstMessage {
-            alpha,
-            eta_a,
-            eta_b,
-            eta_c,
+            eta_a, eta_b, eta_c, ..
         } = *verifier_message;
 
         let summed_z_m_poly_time = start_timer!(|| "Compute z_m poly");
@@ -387,24 +433,6 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let summed_z_m = Polynomial::from_coefficients_vec(summed_z_m_coeffs);
         end_timer!(summed_z_m_poly_time);
 
-        let r_alpha_x_evals_time = start_timer!(|| "Compute r_alpha_x evals");
-        let r_alpha_x_evals = domain_h.batch_eval_unnormalized_bivariate_lagrange_poly_with_diff_inputs(alpha);
-        end_timer!(r_alpha_x_evals_time);
-
-        let r_alpha_poly_time = start_timer!(|| "Compute r_alpha_x poly");
-        let r_alpha_poly = Polynomial::from_coefficients_vec(domain_h.ifft(&r_alpha_x_evals));
-        end_timer!(r_alpha_poly_time);
-
-        let t_poly_time = start_timer!(|| "Compute t poly");
-        let t_poly = Self::calculate_t(
-            vec![&state.index.a, &state.index.b, &state.index.c].into_iter(),
-            &[eta_a, eta_b, eta_c],
-            state.domain_x,
-            state.domain_h,
-            r_alpha_x_evals,
-        );
-        end_timer!(t_poly_time);
-
         let z_poly_time = start_timer!(|| "Compute z poly");
 
         let domain_x = EvaluationDomain::new(state.padded_public_variables.len())
@@ -417,36 +445,82 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         cfg_iter_mut!(z_poly.coeffs)
             .zip(&x_poly.coeffs)
             .for_each(|(z, x)| *z += x);
-        assert!(z_poly.degree() < domain_h.size() + zk_bound);
+        assert!(z_poly.degree() < state.domain_h.size() + state.zk_bound);
 
         end_timer!(z_poly_time);
 
+        (summed_z_m, z_poly)
+    }
+
+    /// Output the oracles of the first sumcheck, over the sum of the given instances.
+    /// Each instance is given by its coefficient in the sum, its polynomial `sum_M eta_M z_M`,
+    /// and its polynomial `z`.
+    pub(super) fn first_sumcheck_oracles(
+        index: &Circuit<F, MM>,
+        domain_x: EvaluationDomain<F>,
+        domain_h: EvaluationDomain<F>,
+        zk_bound: usize,
+        mask_poly: Option<&LabeledPolynomial<F>>,
+        verifier_message: &VerifierFirstMessage<F>,
+        instances: &[(F, Polynomial<F>, Polynomial<F>)],
+    ) -> ProverSecondOracles<F> {
+        let VerifierFirstMessage {
+            alpha,
+            eta_a,
+            eta_b,
+            eta_c,
+        } = *verifier_message;
+
+        let r_alpha_x_evals_time = start_timer!(|| "Compute r_alpha_x evals");
+        let r_alpha_x_evals = domain_h.batch_eval_unnormalized_bivariate_lagrange_poly_with_diff_inputs(alpha);
+        end_timer!(r_alpha_x_evals_time);
+
+        let r_alpha_poly_time = start_timer!(|| "Compute r_alpha_x poly");
+        let r_alpha_poly = Polynomial::from_coefficients_vec(domain_h.ifft(&r_alpha_x_evals));
+        end_timer!(r_alpha_poly_time);
+
+        let t_poly_time = start_timer!(|| "Compute t poly");
+        let t_poly = Self::calculate_t(
+            vec![&index.a, &index.b, &index.c].into_iter(),
+            &[eta_a, eta_b, eta_c],
+            domain_x,
+            domain_h,
+            r_alpha_x_evals,
+        );
+        end_timer!(t_poly_time);
+
         let q_1_time = start_timer!(|| "Compute q_1 poly");
 
+        let max_summed_z_m_len = instances.iter().map(|(_, summed_z_m, _)| summed_z_m.coeffs.len()).max();
+        let max_z_poly_len = instances.iter().map(|(_, _, z_poly)| z_poly.len()).max();
         let mul_domain_size = *[
             mask_poly.map_or(0, |p| p.len()),
-            r_alpha_poly.coeffs.len() + summed_z_m.coeffs.len(),
-            t_poly.coeffs.len() + z_poly.len(),
+            r_alpha_poly.coeffs.len() + max_summed_z_m_len.unwrap_or(0),
+            t_poly.coeffs.len() + max_z_poly_len.unwrap_or(0),
         ]
         .iter()
         .max()
         .unwrap();
         let mul_domain =
             EvaluationDomain::new(mul_domain_size).expect("field is not smooth enough to construct domain");
-        let mut r_alpha_evals = r_alpha_poly.evaluate_over_domain_by_ref(mul_domain);
-        let summed_z_m_evals = summed_z_m.evaluate_over_domain_by_ref(mul_domain);
-        let z_poly_evals = z_poly.evaluate_over_domain_by_ref(mul_domain);
+        let r_alpha_evals = r_alpha_poly.evaluate_over_domain_by_ref(mul_domain);
         let t_poly_m_evals = t_poly.evaluate_over_domain_by_ref(mul_domain);
 
-        cfg_iter_mut!(r_alpha_evals.evaluations)
-            .zip(&summed_z_m_evals.evaluations)
-            .zip(&z_poly_evals.evaluations)
-            .zip(&t_poly_m_evals.evaluations)
-            .for_each(|(((a, b), &c), d)| {
-                *a *= b;
-                *a -= &(c * d);
-            });
-        let mut rhs = r_alpha_evals.interpolate();
+        let mut rhs_evals = vec![F::zero(); mul_domain.size()];
+        for (coefficient, summed_z_m, z_poly) in instances {
+            let summed_z_m_evals = summed_z_m.evaluate_over_domain_by_ref(mul_domain);
+            let z_poly_evals = z_poly.evaluate_over_domain_by_ref(mul_domain);
+
+            cfg_iter_mut!(rhs_evals)
+                .zip(&r_alpha_evals.evaluations)
+                .zip(&summed_z_m_evals.evaluations)
+                .zip(&z_poly_evals.evaluations)
+                .zip(&t_poly_m_evals.evaluations)
+                .for_each(|((((rhs, a), b), &c), d)| {
+                    *rhs += &(*coefficient * (*a * b - (c * d)));
+                });
+        }
+        let mut rhs = EvaluationsOnDomain::from_vec_and_domain(rhs_evals, mul_domain).interpolate();
         rhs += mask_poly.map_or(&Polynomial::zero(), |p| p.polynomial());
         let q_1 = rhs;
         end_timer!(q_1_time);
@@ -456,23 +530,15 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let g_1 = Polynomial::from_coefficients_slice(&x_g_1.coeffs[1..]);
         end_timer!(sumcheck_time);
 
-        let msg = ProverMessage::default();
-
         assert!(g_1.degree() <= domain_h.size() - 2);
         assert!(h_1.degree() <= 2 * domain_h.size() + 2 * zk_bound - 2);
 
         let hiding_bound = if MM::ZK { Some(1) } else { None };
-        let oracles = ProverSecondOracles {
+        ProverSecondOracles {
             t: LabeledPolynomial::new("t".into(), t_poly, None, None),
             g_1: LabeledPolynomial::new("g_1".into(), g_1, Some(domain_h.size() - 2), hiding_bound),
             h_1: LabeledPolynomial::new("h_1".into(), h_1, None, None),
-        };
-
-        state.w_poly = None;
-        state.verifier_first_message = Some(*verifier_message);
-        end_timer!(round_time);
-
-        (msg, oracles, state)
+        }
     }
 
     /// Output the number of oracles sent by the prover in the second round.
@@ -503,15 +569,34 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             ..
         } = prover_state;
 
+        let verifier_first_message = verifier_first_message
+            .expect("ProverState should include verifier_first_msg when prover_third_round is called");
+
+        let oracles =
+            Self::second_sumcheck_oracles(index, &verifier_first_message, verifier_message, domain_h, domain_k);
+
+        let msg = ProverMessage::default();
+        end_timer!(round_time);
+
+        Ok((msg, oracles))
+    }
+
+    /// Output the oracles of the second sumcheck, which depend only on the index and the verifier messages.
+    pub(super) fn second_sumcheck_oracles(
+        index: &Circuit<F, MM>,
+        verifier_first_message: &VerifierFirstMessage<F>,
+        verifier_second_message: &VerifierSecondMessage<F>,
+        domain_h: EvaluationDomain<F>,
+        domain_k: EvaluationDomain<F>,
+    ) -> ProverThirdOracles<F> {
         let VerifierFirstMessage {
             eta_a,
             eta_b,
             eta_c,
             alpha,
-        } = verifier_first_message
-            .expect("ProverState should include verifier_first_msg when prover_third_round is called");
+        } = *verifier_first_message;
 
-        let beta = verifier_message.beta;
+        let beta = verifier_second_message.beta;
 
         let v_H_at_alpha = domain_h.evaluate_vanishing_polynomial(alpha);
         let v_H_at_beta = domain_h.evaluate_vanishing_polynomial(beta);
@@ -595,18 +680,13 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let g_2 = DensePolynomial::from_coefficients_slice(&f.coeffs[1..]);
         drop(f);
 
-        let msg = ProverMessage::default();
-
         assert!(h_2.degree() <= domain_k.size() - 2);
         assert!(g_2.degree() <= domain_k.size() - 2);
 
-        let oracles = ProverThirdOracles {
+        ProverThirdOracles {
             g_2: LabeledPolynomial::new("g_2".to_string(), g_2, Some(domain_k.size() - 2), None),
             h_2: LabeledPolynomial::new("h_2".to_string(), h_2, None, None),
-        };
-        end_timer!(round_time);
-
-        Ok((msg, oracles))
+        }
     }
 
     /// Output the number of oracles sent by the prover in the third round.
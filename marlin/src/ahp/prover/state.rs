@@ -66,3 +66,32 @@ impl<'a, F: PrimeField, MM: MarlinMode> ProverState<'a, F, MM> {
         &self.padded_public_variables
     }
 }
+
+/// State for the AHP prover, when proving a batch of instances of one circuit.
+pub struct BatchProverState<'a, F: PrimeField, MM: MarlinMode> {
+    /// the states of the individual instances
+    pub(super) instances: Vec<ProverState<'a, F, MM>>,
+
+    /// the random values sent by the verifier in the first round
+    pub(super) verifier_first_message: Option<VerifierFirstMessage<F>>,
+
+    /// the blinding polynomial for the first round, shared by all instances
+    pub(super) mask_poly: Option<LabeledPolynomial<F>>,
+}
+
+impl<'a, F: PrimeField, MM: MarlinMode> BatchProverState<'a, F, MM> {
+    /// Get the number of instances in the batch.
+    pub fn batch_size(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// Get the public inputs of the instances.
+    pub fn public_inputs(&self) -> Vec<Vec<F>> {
+        self.instances.iter().map(ProverState::public_input).collect()
+    }
+
+    /// Get the padded public inputs of the instances.
+    pub fn padded_public_inputs(&self) -> Vec<&[F]> {
+        self.instances.iter().map(ProverState::padded_public_input).collect()
+    }
+}
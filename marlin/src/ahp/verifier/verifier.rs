@@ -25,6 +25,7 @@ use crate::{
     },
     marlin::MarlinMode,
     traits::FiatShamirRng,
+    Vec,
 };
 use snarkvm_algorithms::fft::EvaluationDomain;
 use snarkvm_fields::PrimeField;
@@ -78,6 +79,35 @@ impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
         Ok((message, new_state))
     }
 
+    /// Output the first message, the combiners of the instances, and next round state, for a batch of instances.
+    #[allow(clippy::type_complexity)]
+    pub fn verifier_batch_first_round<BaseField: PrimeField, R: FiatShamirRng<TargetField, BaseField>>(
+        index_info: CircuitInfo<TargetField>,
+        batch_size: usize,
+        fs_rng: &mut R,
+    ) -> Result<
+        (
+            VerifierFirstMessage<TargetField>,
+            Vec<TargetField>,
+            VerifierState<TargetField, MM>,
+        ),
+        AHPError,
+    > {
+        if batch_size == 0 {
+            return Err(AHPError::EmptyBatch);
+        }
+
+        let (message, state) = Self::verifier_first_round(index_info, fs_rng)?;
+
+        // The first instance is weighted by one, and each remaining instance by a random combiner.
+        let mut batch_combiners = vec![TargetField::one()];
+        if batch_size > 1 {
+            batch_combiners.extend(fs_rng.squeeze_nonnative_field_elements(batch_size - 1, OptimizationType::Weight)?);
+        }
+
+        Ok((message, batch_combiners, state))
+    }
+
     /// Output the second message and next round state.
     pub fn verifier_second_round<BaseField: PrimeField, R: FiatShamirRng<TargetField, BaseField>>(
         mut state: VerifierState<TargetField, MM>,
@@ -199,4 +229,22 @@ impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
 
         (query_set, state)
     }
+
+    /// Output the query state and next round state, for a batch of instances.
+    pub fn verifier_batch_query_set<'b, R: RngCore>(
+        state: VerifierState<TargetField, MM>,
+        batch_size: usize,
+        rng: &mut R,
+    ) -> (QuerySet<'b, TargetField>, VerifierState<TargetField, MM>) {
+        let beta = state.second_round_message.unwrap().beta;
+        let (mut query_set, state) = Self::verifier_query_set(state, rng);
+
+        // Each instance has its own `z_b`, which is queried at `beta`.
+        query_set.remove(&("z_b".into(), ("beta".into(), beta)));
+        for i in 0..batch_size {
+            query_set.insert((Self::instance_label("z_b", i), ("beta".into(), beta)));
+        }
+
+        (query_set, state)
+    }
 }
@@ -34,9 +34,15 @@ impl From<MarlinError> for MarlinConstraintsError {
                 AHPError::ConstraintSystemError(error) => Self {
                     error_msg: error.to_string(),
                 },
+                AHPError::EmptyBatch => Self {
+                    error_msg: String::from("empty batch"),
+                },
                 AHPError::FiatShamirError(error) => Self {
                     error_msg: error.to_string(),
                 },
+                AHPError::InvalidBatchSize => Self {
+                    error_msg: String::from("invalid batch size"),
+                },
                 AHPError::InvalidPublicInputLength => Self {
                     error_msg: String::from("invalid public input length"),
                 },
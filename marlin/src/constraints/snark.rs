@@ -119,6 +119,31 @@ where
             Err(e) => Err(SNARKError::from(e)),
         }
     }
+
+    fn batch_verify_prepared<R: Rng + CryptoRng>(
+        prepared_verifying_key: &Self::PreparedVerifyingKey,
+        inputs_and_proofs: &[(&Self::VerifierInput, &Self::Proof)],
+        rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        let inputs = inputs_and_proofs
+            .iter()
+            .map(|(input, _)| input.to_field_elements())
+            .collect::<Result<Vec<_>, _>>()?;
+        let public_inputs_and_proofs = inputs
+            .iter()
+            .zip(inputs_and_proofs)
+            .map(|(input, (_, proof))| (&input[..], *proof))
+            .collect::<Vec<_>>();
+
+        match MarlinCore::<TargetField, BaseField, PC, FS, MM>::batch_verify(
+            &prepared_verifying_key.orig_vk,
+            &public_inputs_and_proofs,
+            rng,
+        ) {
+            Ok(res) => Ok(res),
+            Err(e) => Err(SNARKError::from(e)),
+        }
+    }
 }
 
 #[cfg(test)]
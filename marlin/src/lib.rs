@@ -80,6 +80,9 @@ pub mod constraints;
 /// Implements the base Marlin zkSNARK proof system.
 pub mod marlin;
 
+/// Implements a PLONK-style universal zkSNARK with custom gates.
+pub mod plonk;
+
 /// RNGs for the Marlin SNARK.
 pub mod fiat_shamir;
 pub use fiat_shamir::*;
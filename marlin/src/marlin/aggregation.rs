@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    fiat_shamir::traits::FiatShamirRng,
+    marlin::{CircuitVerifyingKey, MarlinError, MarlinMode, MarlinSNARK, Proof},
+    Vec,
+};
+use snarkvm_curves::traits::PairingEngine;
+use snarkvm_polycommit::sonic_pc::{Accumulator, SonicKZG10};
+use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+/// Marlin proofs that share a circuit verifying key are aggregated by accumulating their
+/// `SonicKZG10` opening checks into a single [`Accumulator`], whose pairing check uses a number
+/// of pairings that is independent of the number of aggregated proofs.
+///
+/// Checking that an aggregate was derived from the given proofs recomputes the accumulation,
+/// which needs the proofs themselves and is linear in their number, but computes no pairings.
+/// An aggregate may also fold in previous aggregates, so that a chain of aggregates,
+/// such as one per block, only needs the pairing check of its last aggregate.
+impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode>
+    MarlinSNARK<E::Fr, E::Fq, SonicKZG10<E>, FS, MM>
+{
+    /// The personalization string for proof aggregation.
+    /// Used to personalize the Fiat-Shamir RNG that samples the accumulation randomizers.
+    pub const AGGREGATION_PROTOCOL_NAME: &'static [u8] = b"MARLIN-2019-AGGREGATION";
+
+    /// Aggregates the given proofs for the circuit of the given verifying key, and the given
+    /// previous aggregates, into a single aggregate. Returns `None` if any of the proofs is malformed.
+    ///
+    /// No pairings are computed; the aggregate must still be verified with
+    /// [`verify_aggregate`](Self::verify_aggregate).
+    pub fn aggregate_proofs(
+        circuit_verifying_key: &CircuitVerifyingKey<E::Fr, E::Fq, SonicKZG10<E>, MM>,
+        previous_aggregates: &[Accumulator<E>],
+        public_inputs_and_proofs: &[(&[E::Fr], &Proof<E::Fr, E::Fq, SonicKZG10<E>>)],
+    ) -> Result<Option<Accumulator<E>>, MarlinError> {
+        Self::aggregate_proofs_with_fs_parameters(
+            circuit_verifying_key,
+            &FS::sample_params(),
+            previous_aggregates,
+            public_inputs_and_proofs,
+        )
+    }
+
+    /// Aggregates the given proofs for the circuit of the given verifying key, and the given
+    /// previous aggregates, into a single aggregate. Returns `None` if any of the proofs is malformed.
+    ///
+    /// No pairings are computed; the aggregate must still be verified with
+    /// [`verify_aggregate`](Self::verify_aggregate).
+    pub fn aggregate_proofs_with_fs_parameters(
+        circuit_verifying_key: &CircuitVerifyingKey<E::Fr, E::Fq, SonicKZG10<E>, MM>,
+        fs_parameters: &FS::Parameters,
+        previous_aggregates: &[Accumulator<E>],
+        public_inputs_and_proofs: &[(&[E::Fr], &Proof<E::Fr, E::Fq, SonicKZG10<E>>)],
+    ) -> Result<Option<Accumulator<E>>, MarlinError> {
+        let aggregation_time = start_timer!(|| format!(
+            "Marlin::AggregateProofs ({} proofs, {} previous aggregates)",
+            public_inputs_and_proofs.len(),
+            previous_aggregates.len()
+        ));
+
+        let mut checks = Vec::with_capacity(public_inputs_and_proofs.len());
+        for (public_input, proof) in public_inputs_and_proofs {
+            match Self::verifier_check(circuit_verifying_key, fs_parameters, public_input, proof)? {
+                Some((check, _)) => checks.push(check),
+                None => {
+                    end_timer!(aggregation_time);
+                    return Ok(None);
+                }
+            }
+        }
+
+        // The randomizers of the accumulation are sampled from a Fiat-Shamir RNG over all of its inputs,
+        // so that the aggregate is deterministic, and can be recomputed by the verifier.
+        let mut fs_rng = FS::with_parameters(fs_parameters);
+        fs_rng.absorb_bytes(
+            &to_bytes_le![
+                &Self::AGGREGATION_PROTOCOL_NAME,
+                circuit_verifying_key,
+                previous_aggregates.len() as u64,
+                public_inputs_and_proofs.len() as u64
+            ]
+            .unwrap(),
+        );
+        for previous_aggregate in previous_aggregates {
+            fs_rng.absorb_bytes(&to_bytes_le![previous_aggregate].unwrap());
+        }
+        for (public_input, proof) in public_inputs_and_proofs {
+            fs_rng.absorb_bytes(&to_bytes_le![public_input.len() as u64, public_input, proof].unwrap());
+        }
+
+        let aggregate = SonicKZG10::accumulate_combinations(
+            &circuit_verifying_key.verifier_key,
+            previous_aggregates,
+            &checks,
+            &mut fs_rng,
+        )?;
+
+        end_timer!(aggregation_time);
+        Ok(aggregate)
+    }
+
+    /// Verify that the given aggregate is the aggregation of the given proofs and previous aggregates,
+    /// without checking the aggregate itself. No pairings are computed.
+    pub fn verify_aggregation(
+        circuit_verifying_key: &CircuitVerifyingKey<E::Fr, E::Fq, SonicKZG10<E>, MM>,
+        previous_aggregates: &[Accumulator<E>],
+        public_inputs_and_proofs: &[(&[E::Fr], &Proof<E::Fr, E::Fq, SonicKZG10<E>>)],
+        aggregate: &Accumulator<E>,
+    ) -> Result<bool, MarlinError> {
+        Self::verify_aggregation_with_fs_parameters(
+            circuit_verifying_key,
+            &FS::sample_params(),
+            previous_aggregates,
+            public_inputs_and_proofs,
+            aggregate,
+        )
+    }
+
+    /// Verify that the given aggregate is the aggregation of the given proofs and previous aggregates,
+    /// without checking the aggregate itself. No pairings are computed.
+    pub fn verify_aggregation_with_fs_parameters(
+        circuit_verifying_key: &CircuitVerifyingKey<E::Fr, E::Fq, SonicKZG10<E>, MM>,
+        fs_parameters: &FS::Parameters,
+        previous_aggregates: &[Accumulator<E>],
+        public_inputs_and_proofs: &[(&[E::Fr], &Proof<E::Fr, E::Fq, SonicKZG10<E>>)],
+        aggregate: &Accumulator<E>,
+    ) -> Result<bool, MarlinError> {
+        let expected_aggregate = Self::aggregate_proofs_with_fs_parameters(
+            circuit_verifying_key,
+            fs_parameters,
+            previous_aggregates,
+            public_inputs_and_proofs,
+        )?;
+
+        Ok(expected_aggregate.as_ref() == Some(aggregate))
+    }
+
+    /// Checks the deferred pairing check of the given aggregate, using a number of pairings that is
+    /// independent of the number of aggregated proofs. This only checks the aggregate itself, and says
+    /// nothing about which proofs it aggregates; see [`verify_aggregate`](Self::verify_aggregate).
+    pub(crate) fn check_aggregate(
+        circuit_verifying_key: &CircuitVerifyingKey<E::Fr, E::Fq, SonicKZG10<E>, MM>,
+        aggregate: &Accumulator<E>,
+    ) -> Result<bool, MarlinError> {
+        let check_time = start_timer!(|| "Marlin::CheckAggregate");
+        let is_valid = SonicKZG10::check_accumulator(&circuit_verifying_key.verifier_key, aggregate)?;
+        end_timer!(check_time, || format!(
+            " PC::Check for aggregated opening checks: {}",
+            is_valid
+        ));
+        Ok(is_valid)
+    }
+
+    /// Verify that the given aggregate is the aggregation of the given proofs and previous aggregates,
+    /// and that all proofs in it assert that all constraints are satisfied.
+    pub fn verify_aggregate(
+        circuit_verifying_key: &CircuitVerifyingKey<E::Fr, E::Fq, SonicKZG10<E>, MM>,
+        previous_aggregates: &[Accumulator<E>],
+        public_inputs_and_proofs: &[(&[E::Fr], &Proof<E::Fr, E::Fq, SonicKZG10<E>>)],
+        aggregate: &Accumulator<E>,
+    ) -> Result<bool, MarlinError> {
+        Self::verify_aggregate_with_fs_parameters(
+            circuit_verifying_key,
+            &FS::sample_params(),
+            previous_aggregates,
+            public_inputs_and_proofs,
+            aggregate,
+        )
+    }
+
+    /// Verify that the given aggregate is the aggregation of the given proofs and previous aggregates,
+    /// and that all proofs in it assert that all constraints are satisfied.
+    pub fn verify_aggregate_with_fs_parameters(
+        circuit_verifying_key: &CircuitVerifyingKey<E::Fr, E::Fq, SonicKZG10<E>, MM>,
+        fs_parameters: &FS::Parameters,
+        previous_aggregates: &[Accumulator<E>],
+        public_inputs_and_proofs: &[(&[E::Fr], &Proof<E::Fr, E::Fq, SonicKZG10<E>>)],
+        aggregate: &Accumulator<E>,
+    ) -> Result<bool, MarlinError> {
+        let verifier_time =
+            start_timer!(|| format!("Marlin::VerifyAggregate ({} proofs)", public_inputs_and_proofs.len()));
+        let is_valid = Self::verify_aggregation_with_fs_parameters(
+            circuit_verifying_key,
+            fs_parameters,
+            previous_aggregates,
+            public_inputs_and_proofs,
+            aggregate,
+        )? && Self::check_aggregate(circuit_verifying_key, aggregate)?;
+        end_timer!(verifier_time);
+        Ok(is_valid)
+    }
+}
@@ -15,10 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
+    ahp::{verifier::VerifierState, AHPError, AHPForR1CS, EvaluationsProvider},
     fiat_shamir::traits::FiatShamirRng,
     marlin::{CircuitProvingKey, CircuitVerifyingKey, MarlinError, MarlinMode, Proof, UniversalSRS},
-    prover::ProverConstraintSystem,
+    prover::{ProverConstraintSystem, ProverMessage},
     String,
     ToString,
     Vec,
@@ -30,10 +30,14 @@ use snarkvm_polycommit::{
     Evaluations,
     LabeledCommitment,
     LabeledPolynomial,
+    LinearCombination,
+    LinearCombinationsCheck,
+    OpeningChallenges,
     PCProof,
     PCRandomness,
     PCUniversalParams,
     PolynomialCommitment,
+    QuerySet,
 };
 use snarkvm_r1cs::{ConstraintSynthesizer, SynthesisError};
 use snarkvm_utilities::{to_bytes_le, ToBytes};
@@ -310,22 +314,16 @@ impl<
         }
 
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
-        let (first_commitments, first_commitment_randomnesses) = PC::commit(
-            &circuit_proving_key.committer_key,
+        let (first_commitments, first_commitment_randomnesses) = Self::commit_prover_round(
+            circuit_proving_key,
             prover_first_oracles.iter(),
-            Some(zk_rng),
+            &prover_first_message,
+            &mut fs_rng,
+            terminator,
+            zk_rng,
         )?;
         end_timer!(first_round_comm_time);
 
-        if MM::RECURSION {
-            fs_rng.absorb_native_field_elements(&first_commitments);
-            if !prover_first_message.field_elements.is_empty() {
-                fs_rng.absorb_nonnative_field_elements(&prover_first_message.field_elements, OptimizationType::Weight);
-            }
-        } else {
-            fs_rng.absorb_bytes(&to_bytes_le![first_commitments, prover_first_message].unwrap());
-        }
-
         if terminator.load(Ordering::Relaxed) {
             return Err(MarlinError::Terminated);
         }
@@ -347,23 +345,16 @@ impl<
             AHPForR1CS::<_, MM>::prover_second_round(&verifier_first_message, prover_state, zk_rng);
 
         let second_round_comm_time = start_timer!(|| "Committing to second round polys");
-        let (second_commitments, second_commitment_randomnesses) = PC::commit_with_terminator(
-            &circuit_proving_key.committer_key,
+        let (second_commitments, second_commitment_randomnesses) = Self::commit_prover_round(
+            circuit_proving_key,
             prover_second_oracles.iter(),
+            &prover_second_message,
+            &mut fs_rng,
             terminator,
-            Some(zk_rng),
+            zk_rng,
         )?;
         end_timer!(second_round_comm_time);
 
-        if MM::RECURSION {
-            fs_rng.absorb_native_field_elements(&second_commitments);
-            if !prover_second_message.field_elements.is_empty() {
-                fs_rng.absorb_nonnative_field_elements(&prover_second_message.field_elements, OptimizationType::Weight);
-            }
-        } else {
-            fs_rng.absorb_bytes(&to_bytes_le![second_commitments, prover_second_message].unwrap());
-        }
-
         if terminator.load(Ordering::Relaxed) {
             return Err(MarlinError::Terminated);
         }
@@ -383,23 +374,16 @@ impl<
             AHPForR1CS::<_, MM>::prover_third_round(&verifier_second_msg, prover_state, zk_rng)?;
 
         let third_round_comm_time = start_timer!(|| "Committing to third round polys");
-        let (third_commitments, third_commitment_randomnesses) = PC::commit_with_terminator(
-            &circuit_proving_key.committer_key,
+        let (third_commitments, third_commitment_randomnesses) = Self::commit_prover_round(
+            circuit_proving_key,
             prover_third_oracles.iter(),
+            &prover_third_message,
+            &mut fs_rng,
             terminator,
-            Some(zk_rng),
+            zk_rng,
         )?;
         end_timer!(third_round_comm_time);
 
-        if MM::RECURSION {
-            fs_rng.absorb_native_field_elements(&third_commitments);
-            if !prover_third_message.field_elements.is_empty() {
-                fs_rng.absorb_nonnative_field_elements(&prover_third_message.field_elements, OptimizationType::Weight);
-            }
-        } else {
-            fs_rng.absorb_bytes(&to_bytes_le![third_commitments, prover_third_message].unwrap());
-        }
-
         let verifier_state = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut fs_rng)?;
         // --------------------------------------------------------------------
 
@@ -407,29 +391,7 @@ impl<
             return Err(MarlinError::Terminated);
         }
 
-        let vanishing_polys = if MM::RECURSION {
-            let domain_h = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_constraints)
-                .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-            let domain_k = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_non_zero)
-                .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-
-            vec![
-                LabeledPolynomial::new(
-                    "vanishing_poly_h".to_string(),
-                    domain_h.vanishing_polynomial().into(),
-                    None,
-                    None,
-                ),
-                LabeledPolynomial::new(
-                    "vanishing_poly_k".to_string(),
-                    domain_k.vanishing_polynomial().into(),
-                    None,
-                    None,
-                ),
-            ]
-        } else {
-            vec![]
-        };
+        let vanishing_polys = Self::vanishing_polynomials(circuit_proving_key)?;
 
         // Gather prover polynomials in one vector.
         let polynomials: Vec<_> = circuit_proving_key
@@ -451,6 +413,317 @@ impl<
             AHPForR1CS::<TargetField, MM>::polynomial_labels().count()
         );
 
+        // Compute the AHP verifier's query set.
+        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state, &mut fs_rng);
+        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(&public_input, &polynomials, &verifier_state)?;
+
+        let proof = Self::prove_openings(
+            circuit_proving_key,
+            polynomials,
+            [first_commitments, second_commitments, third_commitments],
+            [
+                first_commitment_randomnesses,
+                second_commitment_randomnesses,
+                third_commitment_randomnesses,
+            ],
+            vec![prover_first_message, prover_second_message, prover_third_message],
+            &query_set,
+            &lc_s,
+            5,
+            &mut fs_rng,
+            terminator,
+            zk_rng,
+        )?;
+        end_timer!(prover_time);
+
+        Ok(proof)
+    }
+
+    /// Create a zkSNARK asserting that the constraint systems of a batch of instances
+    /// of the same circuit are all satisfied.
+    ///
+    /// Every instance must be an instance of the circuit indexed by `circuit_proving_key`,
+    /// as batching instances of different circuits into one proof is not supported.
+    pub fn batch_prove<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
+        circuits: &[C],
+        zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, BaseField, PC>, MarlinError> {
+        Self::batch_prove_with_terminator(circuit_proving_key, circuits, &AtomicBool::new(false), zk_rng)
+    }
+
+    /// Same as [`batch_prove`] with an added termination flag, [`terminator`].
+    ///
+    /// The instances share one run of the AHP: each instance commits to its own `w`, `z_a`, and `z_b`,
+    /// while the sumcheck polynomials, and the opening proof, are shared by the whole batch.
+    /// As the index polynomials are shared too, all instances must be of the circuit of `circuit_proving_key`.
+    pub fn batch_prove_with_terminator<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
+        circuits: &[C],
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, BaseField, PC>, MarlinError> {
+        let prover_time = start_timer!(|| format!("Marlin::BatchProver ({} instances)", circuits.len()));
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(MarlinError::Terminated);
+        }
+
+        let prover_init_state = AHPForR1CS::<_, MM>::batch_prover_init(&circuit_proving_key.circuit, circuits)?;
+        let batch_size = prover_init_state.batch_size();
+        let public_inputs = prover_init_state.public_inputs();
+        let padded_public_inputs = prover_init_state.padded_public_inputs();
+
+        let mut fs_rng = FS::new();
+
+        if MM::RECURSION {
+            fs_rng.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME, batch_size as u64].unwrap());
+            fs_rng.absorb_native_field_elements(&circuit_proving_key.circuit_verifying_key.circuit_commitments);
+            for padded_public_input in &padded_public_inputs {
+                fs_rng.absorb_nonnative_field_elements(padded_public_input, OptimizationType::Weight);
+            }
+        } else {
+            fs_rng.absorb_bytes(
+                &to_bytes_le![
+                    &Self::PROTOCOL_NAME,
+                    &circuit_proving_key.circuit_verifying_key,
+                    batch_size as u64
+                ]
+                .unwrap(),
+            );
+            for padded_public_input in &padded_public_inputs {
+                fs_rng.absorb_bytes(&to_bytes_le![padded_public_input].unwrap());
+            }
+        }
+
+        // --------------------------------------------------------------------
+        // First round
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(MarlinError::Terminated);
+        }
+
+        let (prover_first_message, prover_first_oracles, prover_state) =
+            AHPForR1CS::<_, MM>::batch_prover_first_round(prover_init_state, zk_rng)?;
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(MarlinError::Terminated);
+        }
+
+        let first_round_comm_time = start_timer!(|| "Committing to first round polys");
+        let (first_commitments, first_commitment_randomnesses) = Self::commit_prover_round(
+            circuit_proving_key,
+            prover_first_oracles.iter(),
+            &prover_first_message,
+            &mut fs_rng,
+            terminator,
+            zk_rng,
+        )?;
+        end_timer!(first_round_comm_time);
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(MarlinError::Terminated);
+        }
+
+        let (verifier_first_message, batch_combiners, verifier_state) =
+            AHPForR1CS::<_, MM>::verifier_batch_first_round(
+                circuit_proving_key.circuit_verifying_key.circuit_info,
+                batch_size,
+                &mut fs_rng,
+            )?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(MarlinError::Terminated);
+        }
+
+        let (prover_second_message, prover_second_oracles, prover_state) =
+            AHPForR1CS::<_, MM>::batch_prover_second_round(
+                &verifier_first_message,
+                &batch_combiners,
+                prover_state,
+                zk_rng,
+            )?;
+
+        let second_round_comm_time = start_timer!(|| "Committing to second round polys");
+        let (second_commitments, second_commitment_randomnesses) = Self::commit_prover_round(
+            circuit_proving_key,
+            prover_second_oracles.iter(),
+            &prover_second_message,
+            &mut fs_rng,
+            terminator,
+            zk_rng,
+        )?;
+        end_timer!(second_round_comm_time);
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(MarlinError::Terminated);
+        }
+
+        let (verifier_second_msg, verifier_state) =
+            AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut fs_rng)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(MarlinError::Terminated);
+        }
+
+        let (prover_third_message, prover_third_oracles) =
+            AHPForR1CS::<_, MM>::batch_prover_third_round(&verifier_second_msg, prover_state, zk_rng)?;
+
+        let third_round_comm_time = start_timer!(|| "Committing to third round polys");
+        let (third_commitments, third_commitment_randomnesses) = Self::commit_prover_round(
+            circuit_proving_key,
+            prover_third_oracles.iter(),
+            &prover_third_message,
+            &mut fs_rng,
+            terminator,
+            zk_rng,
+        )?;
+        end_timer!(third_round_comm_time);
+
+        let verifier_state = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut fs_rng)?;
+        // --------------------------------------------------------------------
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(MarlinError::Terminated);
+        }
+
+        let vanishing_polys = Self::vanishing_polynomials(circuit_proving_key)?;
+
+        // Gather prover polynomials in one vector.
+        let polynomials: Vec<_> = circuit_proving_key
+            .circuit
+            .iter()
+            .chain(vanishing_polys.iter())
+            .chain(prover_first_oracles.iter())
+            .chain(prover_second_oracles.iter())
+            .chain(prover_third_oracles.iter())
+            .collect();
+
+        // Sanity check, whose length should be updated if the underlying structs are updated.
+        assert_eq!(
+            polynomials.len(),
+            AHPForR1CS::<TargetField, MM>::batch_polynomial_labels(batch_size).count()
+        );
+
+        // Compute the AHP verifier's query set.
+        let (query_set, verifier_state) =
+            AHPForR1CS::<_, MM>::verifier_batch_query_set(verifier_state, batch_size, &mut fs_rng);
+        let lc_s = AHPForR1CS::<_, MM>::construct_batch_linear_combinations(
+            &public_inputs,
+            &batch_combiners,
+            &polynomials,
+            &verifier_state,
+        )?;
+
+        let proof = Self::prove_openings(
+            circuit_proving_key,
+            polynomials,
+            [first_commitments, second_commitments, third_commitments],
+            [
+                first_commitment_randomnesses,
+                second_commitment_randomnesses,
+                third_commitment_randomnesses,
+            ],
+            vec![prover_first_message, prover_second_message, prover_third_message],
+            &query_set,
+            &lc_s,
+            Self::batch_num_opening_challenges(query_set.len()),
+            &mut fs_rng,
+            terminator,
+            zk_rng,
+        )?;
+        end_timer!(prover_time);
+
+        Ok(proof)
+    }
+
+    /// Commits to the oracles of a prover round, and absorbs the commitments
+    /// and the prover message into the Fiat-Shamir RNG.
+    #[allow(clippy::type_complexity)]
+    fn commit_prover_round<'a, R: RngCore>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
+        prover_oracles: impl IntoIterator<Item = &'a LabeledPolynomial<TargetField>>,
+        prover_message: &ProverMessage<TargetField>,
+        fs_rng: &mut FS,
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+    ) -> Result<(Vec<LabeledCommitment<PC::Commitment>>, Vec<PC::Randomness>), MarlinError> {
+        let (commitments, commitment_randomnesses) = PC::commit_with_terminator(
+            &circuit_proving_key.committer_key,
+            prover_oracles,
+            terminator,
+            Some(zk_rng),
+        )?;
+
+        if MM::RECURSION {
+            fs_rng.absorb_native_field_elements(&commitments);
+            if !prover_message.field_elements.is_empty() {
+                fs_rng.absorb_nonnative_field_elements(&prover_message.field_elements, OptimizationType::Weight);
+            }
+        } else {
+            fs_rng.absorb_bytes(&to_bytes_le![commitments, prover_message].unwrap());
+        }
+
+        Ok((commitments, commitment_randomnesses))
+    }
+
+    /// Returns the vanishing polynomials that the prover opens in recursion mode.
+    fn vanishing_polynomials(
+        circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
+    ) -> Result<Vec<LabeledPolynomial<TargetField>>, MarlinError> {
+        if !MM::RECURSION {
+            return Ok(vec![]);
+        }
+
+        let domain_h = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_constraints)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_k = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_non_zero)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        Ok(vec![
+            LabeledPolynomial::new(
+                "vanishing_poly_h".to_string(),
+                domain_h.vanishing_polynomial().into(),
+                None,
+                None,
+            ),
+            LabeledPolynomial::new(
+                "vanishing_poly_k".to_string(),
+                domain_k.vanishing_polynomial().into(),
+                None,
+                None,
+            ),
+        ])
+    }
+
+    /// Evaluates the linear combinations over the query set, and opens them,
+    /// to complete a proof from the commitments and messages of the three prover rounds.
+    #[allow(clippy::too_many_arguments)]
+    fn prove_openings<R: RngCore>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
+        polynomials: Vec<&LabeledPolynomial<TargetField>>,
+        round_commitments: [Vec<LabeledCommitment<PC::Commitment>>; 3],
+        round_commitment_randomnesses: [Vec<PC::Randomness>; 3],
+        prover_messages: Vec<ProverMessage<TargetField>>,
+        query_set: &QuerySet<TargetField>,
+        lc_s: &[LinearCombination<TargetField>],
+        num_opening_challenges: usize,
+        fs_rng: &mut FS,
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, BaseField, PC>, MarlinError> {
+        let [first_commitments, second_commitments, third_commitments] = round_commitments;
+        let [first_commitment_randomnesses, second_commitment_randomnesses, third_commitment_randomnesses] =
+            round_commitment_randomnesses;
+
         // Gather commitments in one vector.
         #[rustfmt::skip]
         let commitments = vec![
@@ -467,9 +740,9 @@ impl<
             .cloned()
             .zip(indexer_polynomials)
             .map(|(c, l)| LabeledCommitment::new(l.to_string(), c, None))
-            .chain(first_commitments.into_iter())
-            .chain(second_commitments.into_iter())
-            .chain(third_commitments.into_iter())
+            .chain(first_commitments)
+            .chain(second_commitments)
+            .chain(third_commitments)
             .collect();
 
         // Gather commitment randomness together.
@@ -487,17 +760,13 @@ impl<
             assert!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
         }
 
-        // Compute the AHP verifier's query set.
-        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state, &mut fs_rng);
-        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(&public_input, &polynomials, &verifier_state)?;
-
         if terminator.load(Ordering::Relaxed) {
             return Err(MarlinError::Terminated);
         }
 
         let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
         let mut evaluations_unsorted = Vec::new();
-        for (label, (_point_name, point)) in &query_set {
+        for (label, (_point_name, point)) in query_set {
             let lc = lc_s
                 .iter()
                 .find(|lc| &lc.label == label)
@@ -523,19 +792,17 @@ impl<
         }
 
         let pc_proof = if MM::RECURSION {
-            let num_open_challenges: usize = 5;
-
             let mut opening_challenges = Vec::new();
-            opening_challenges.append(&mut fs_rng.squeeze_128_bits_nonnative_field_elements(num_open_challenges)?);
+            opening_challenges.append(&mut fs_rng.squeeze_128_bits_nonnative_field_elements(num_opening_challenges)?);
 
             let opening_challenges_f = |i| opening_challenges[i as usize];
 
             PC::open_combinations_individual_opening_challenges(
                 &circuit_proving_key.committer_key,
-                &lc_s,
+                lc_s,
                 polynomials,
                 &labeled_commitments,
-                &query_set,
+                query_set,
                 &opening_challenges_f,
                 &commitment_randomnesses,
             )?
@@ -544,10 +811,10 @@ impl<
 
             PC::open_combinations(
                 &circuit_proving_key.committer_key,
-                &lc_s,
+                lc_s,
                 polynomials,
                 &labeled_commitments,
-                &query_set,
+                query_set,
                 opening_challenge,
                 &commitment_randomnesses,
                 Some(zk_rng),
@@ -558,13 +825,9 @@ impl<
             return Err(MarlinError::Terminated);
         }
 
-        // Gather prover messages together.
-        let prover_messages = vec![prover_first_message, prover_second_message, prover_third_message];
-
         let proof = Proof::new(commitments, evaluations, prover_messages, pc_proof);
         assert_eq!(proof.pc_proof.is_hiding(), MM::ZK);
         proof.print_size_info();
-        end_timer!(prover_time);
 
         Ok(proof)
     }
@@ -588,9 +851,247 @@ impl<
         proof: &Proof<TargetField, BaseField, PC>,
     ) -> Result<bool, MarlinError> {
         let verifier_time = start_timer!(|| "Marlin::Verify");
+        let (check, mut fs_rng) = match Self::verifier_check(circuit_verifying_key, fs_parameters, public_input, proof)?
+        {
+            Some(check_and_fs_rng) => check_and_fs_rng,
+            None => return Ok(false),
+        };
+
+        let evaluations_are_correct = match &check.opening_challenges {
+            OpeningChallenges::Individual(opening_challenges) => PC::check_combinations_individual_opening_challenges(
+                &circuit_verifying_key.verifier_key,
+                &check.linear_combinations,
+                &check.commitments,
+                &check.query_set,
+                &check.evaluations,
+                &check.proof,
+                &|i| opening_challenges[i as usize],
+                &mut fs_rng,
+            )?,
+            OpeningChallenges::Single(opening_challenge) => PC::check_combinations(
+                &circuit_verifying_key.verifier_key,
+                &check.linear_combinations,
+                &check.commitments,
+                &check.query_set,
+                &check.evaluations,
+                &check.proof,
+                *opening_challenge,
+                &mut fs_rng,
+            )?,
+        };
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("PC::Check failed");
+        }
+        end_timer!(verifier_time, || format!(
+            " PC::Check for AHP Verifier linear equations: {}",
+            evaluations_are_correct
+        ));
+        Ok(evaluations_are_correct)
+    }
+
+    /// Verify that a batch of proofs for the constraint system defined by `C` assert that
+    /// all constraints are satisfied, checking the openings of all proofs at once.
+    pub fn batch_verify<R: RngCore>(
+        circuit_verifying_key: &CircuitVerifyingKey<TargetField, BaseField, PC, MM>,
+        public_inputs_and_proofs: &[(&[TargetField], &Proof<TargetField, BaseField, PC>)],
+        rng: &mut R,
+    ) -> Result<bool, MarlinError> {
+        Self::batch_verify_with_fs_parameters(
+            circuit_verifying_key,
+            &FS::sample_params(),
+            public_inputs_and_proofs,
+            rng,
+        )
+    }
+
+    /// Verify that a batch of proofs for the constraint system defined by `C` assert that
+    /// all constraints are satisfied, checking the openings of all proofs at once.
+    pub fn batch_verify_with_fs_parameters<R: RngCore>(
+        circuit_verifying_key: &CircuitVerifyingKey<TargetField, BaseField, PC, MM>,
+        fs_parameters: &FS::Parameters,
+        public_inputs_and_proofs: &[(&[TargetField], &Proof<TargetField, BaseField, PC>)],
+        rng: &mut R,
+    ) -> Result<bool, MarlinError> {
+        let verifier_time = start_timer!(|| format!("Marlin::BatchVerify ({} proofs)", public_inputs_and_proofs.len()));
+
+        let mut checks = Vec::with_capacity(public_inputs_and_proofs.len());
+        for (public_input, proof) in public_inputs_and_proofs {
+            match Self::verifier_check(circuit_verifying_key, fs_parameters, public_input, proof)? {
+                Some((check, _)) => checks.push(check),
+                None => return Ok(false),
+            }
+        }
+
+        let evaluations_are_correct = PC::batch_check_combinations(&circuit_verifying_key.verifier_key, &checks, rng)?;
+
+        end_timer!(verifier_time, || format!(
+            " PC::Check for AHP Verifier linear equations: {}",
+            evaluations_are_correct
+        ));
+        Ok(evaluations_are_correct)
+    }
+
+    /// Verify that a proof from [`batch_prove`] asserts that the constraint systems
+    /// of all instances, with the given public inputs, are satisfied.
+    /// All instances are of the one circuit of `circuit_verifying_key`.
+    pub fn verify_batch_proof(
+        circuit_verifying_key: &CircuitVerifyingKey<TargetField, BaseField, PC, MM>,
+        public_inputs: &[Vec<TargetField>],
+        proof: &Proof<TargetField, BaseField, PC>,
+    ) -> Result<bool, MarlinError> {
+        Self::verify_batch_proof_with_fs_parameters(circuit_verifying_key, &FS::sample_params(), public_inputs, proof)
+    }
+
+    /// Verify that a proof from [`batch_prove`] asserts that the constraint systems
+    /// of all instances, with the given public inputs, are satisfied.
+    /// All instances are of the one circuit of `circuit_verifying_key`.
+    pub fn verify_batch_proof_with_fs_parameters(
+        circuit_verifying_key: &CircuitVerifyingKey<TargetField, BaseField, PC, MM>,
+        fs_parameters: &FS::Parameters,
+        public_inputs: &[Vec<TargetField>],
+        proof: &Proof<TargetField, BaseField, PC>,
+    ) -> Result<bool, MarlinError> {
+        let verifier_time = start_timer!(|| format!("Marlin::VerifyBatchProof ({} instances)", public_inputs.len()));
+
+        let batch_size = public_inputs.len();
+        if batch_size == 0 {
+            return Err(AHPError::EmptyBatch.into());
+        }
+
+        if proof.commitments.len() != 3 {
+            #[cfg(debug_assertions)]
+            eprintln!("Proof contains {} rounds of commitments", proof.commitments.len());
+            return Ok(false);
+        }
+        let first_commitments = &proof.commitments[0];
+        let proof_has_correct_zk_mode = first_commitments.len()
+            == AHPForR1CS::<TargetField, MM>::batch_prover_num_first_round_oracles(batch_size)
+            && proof.pc_proof.is_hiding() == MM::ZK;
+        if !proof_has_correct_zk_mode {
+            #[cfg(debug_assertions)]
+            eprintln!(
+                "Incorrect number of commitments in the first round ({}) or proof has incorrect hiding mode ({})",
+                first_commitments.len(),
+                proof.pc_proof.is_hiding()
+            );
+            return Ok(false);
+        }
+
+        let padded_public_inputs = public_inputs
+            .iter()
+            .map(|public_input| Self::pad_public_input(public_input))
+            .collect::<Vec<_>>();
+        let public_inputs = padded_public_inputs
+            .iter()
+            .map(|padded_public_input| ProverConstraintSystem::unformat_public_input(padded_public_input))
+            .collect::<Vec<_>>();
+
+        let mut fs_rng = FS::with_parameters(fs_parameters);
+
+        if MM::RECURSION {
+            fs_rng.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME, batch_size as u64].unwrap());
+            fs_rng.absorb_native_field_elements(&circuit_verifying_key.circuit_commitments);
+            for padded_public_input in &padded_public_inputs {
+                fs_rng.absorb_nonnative_field_elements(padded_public_input, OptimizationType::Weight);
+            }
+        } else {
+            fs_rng
+                .absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME, &circuit_verifying_key, batch_size as u64].unwrap());
+            for padded_public_input in &padded_public_inputs {
+                fs_rng.absorb_bytes(&to_bytes_le![padded_public_input].unwrap());
+            }
+        }
+
+        let (batch_combiners, verifier_state) = Self::verifier_rounds(proof, &mut fs_rng, |fs_rng| {
+            let (_, batch_combiners, verifier_state) = AHPForR1CS::<_, MM>::verifier_batch_first_round(
+                circuit_verifying_key.circuit_info,
+                batch_size,
+                fs_rng,
+            )?;
+            Ok((batch_combiners, verifier_state))
+        })?;
+
+        let index_info = circuit_verifying_key.circuit_info;
+        let commitments = Self::verifier_commitments(
+            circuit_verifying_key,
+            proof,
+            AHPForR1CS::<_, MM>::batch_prover_first_round_degree_bounds(&index_info, batch_size),
+            AHPForR1CS::<TargetField, MM>::batch_polynomial_labels(batch_size),
+        );
+
+        let (query_set, verifier_state) =
+            AHPForR1CS::<_, MM>::verifier_batch_query_set(verifier_state, batch_size, &mut fs_rng);
+
+        let evaluations = match Self::verifier_evaluations(&query_set, proof, &mut fs_rng) {
+            Some(evaluations) => evaluations,
+            None => return Ok(false),
+        };
+
+        let lc_s = AHPForR1CS::<_, MM>::construct_batch_linear_combinations(
+            &public_inputs,
+            &batch_combiners,
+            &evaluations,
+            &verifier_state,
+        )?;
+
+        let opening_challenges =
+            Self::verifier_opening_challenges(&mut fs_rng, Self::batch_num_opening_challenges(query_set.len()))?;
+
+        let check = LinearCombinationsCheck {
+            linear_combinations: lc_s,
+            commitments,
+            query_set,
+            evaluations,
+            proof: proof.pc_proof.clone(),
+            opening_challenges,
+        };
+        let evaluations_are_correct =
+            PC::batch_check_combinations(&circuit_verifying_key.verifier_key, &[check], &mut fs_rng)?;
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("PC::Check failed");
+        }
+        end_timer!(verifier_time, || format!(
+            " PC::Check for AHP Verifier linear equations: {}",
+            evaluations_are_correct
+        ));
+        Ok(evaluations_are_correct)
+    }
+
+    /// Returns the number of individual opening challenges for a batch proof with the given query set size,
+    /// which suffices for every queried polynomial, and its shifted polynomial, to have its own challenge.
+    fn batch_num_opening_challenges(query_set_size: usize) -> usize {
+        2 * query_set_size
+    }
+
+    /// Returns the public input, formatted and padded to the size of the input domain.
+    fn pad_public_input(public_input: &[TargetField]) -> Vec<TargetField> {
+        let domain_x = EvaluationDomain::<TargetField>::new(public_input.len() + 1).unwrap();
+
+        if cfg!(debug_assertions) {
+            println!("Number of given public inputs: {}", public_input.len());
+            println!("Size of evaluation domain x: {}", domain_x.size());
+        }
+
+        let mut new_input = vec![TargetField::one()];
+        new_input.extend_from_slice(public_input);
+        new_input.resize(core::cmp::max(public_input.len(), domain_x.size()), TargetField::zero());
+        assert!(new_input.first().unwrap().is_one());
+        new_input
+    }
+
+    /// Runs the AHP verifier on the given proof, returning the polynomial commitment check it reduces to
+    /// and the Fiat-Shamir RNG, or `None` if the proof has an incorrect zero-knowledge mode.
+    pub(super) fn verifier_check(
+        circuit_verifying_key: &CircuitVerifyingKey<TargetField, BaseField, PC, MM>,
+        fs_parameters: &FS::Parameters,
+        public_input: &[TargetField],
+        proof: &Proof<TargetField, BaseField, PC>,
+    ) -> Result<Option<(LinearCombinationsCheck<TargetField, BaseField, PC>, FS)>, MarlinError> {
         let first_commitments = &proof.commitments[0];
-        let second_commitments = &proof.commitments[1];
-        let third_commitments = &proof.commitments[2];
         let proof_has_correct_zk_mode = if MM::ZK {
             first_commitments.len() == 4 && proof.pc_proof.is_hiding()
         } else {
@@ -602,23 +1103,10 @@ impl<
                 first_commitments.len(),
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
-        let padded_public_input = {
-            let domain_x = EvaluationDomain::<TargetField>::new(public_input.len() + 1).unwrap();
-
-            if cfg!(debug_assertions) {
-                println!("Number of given public inputs: {}", public_input.len());
-                println!("Size of evaluation domain x: {}", domain_x.size());
-            }
-
-            let mut new_input = vec![TargetField::one()];
-            new_input.extend_from_slice(public_input);
-            new_input.resize(core::cmp::max(public_input.len(), domain_x.size()), TargetField::zero());
-            assert!(new_input.first().unwrap().is_one());
-            new_input
-        };
+        let padded_public_input = Self::pad_public_input(public_input);
         let public_input = ProverConstraintSystem::unformat_public_input(&padded_public_input);
 
         if cfg!(debug_assertions) {
@@ -637,85 +1125,122 @@ impl<
             );
         }
 
-        // --------------------------------------------------------------------
-        // First round
+        let ((), verifier_state) = Self::verifier_rounds(proof, &mut fs_rng, |fs_rng| {
+            let (_, verifier_state) =
+                AHPForR1CS::<_, MM>::verifier_first_round(circuit_verifying_key.circuit_info, fs_rng)?;
+            Ok(((), verifier_state))
+        })?;
 
-        if MM::RECURSION {
-            fs_rng.absorb_native_field_elements(first_commitments);
-            if !proof.prover_messages[0].field_elements.is_empty() {
-                fs_rng.absorb_nonnative_field_elements(
-                    &proof.prover_messages[0].field_elements,
-                    OptimizationType::Weight,
-                );
-            };
-        } else {
-            fs_rng.absorb_bytes(&to_bytes_le![first_commitments, proof.prover_messages[0]].unwrap());
-        }
+        let index_info = circuit_verifying_key.circuit_info;
+        let commitments = Self::verifier_commitments(
+            circuit_verifying_key,
+            proof,
+            AHPForR1CS::<_, MM>::prover_first_round_degree_bounds(&index_info),
+            AHPForR1CS::<TargetField, MM>::polynomial_labels(),
+        );
 
-        let (_, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_first_round(circuit_verifying_key.circuit_info, &mut fs_rng)?;
+        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state, &mut fs_rng);
+
+        let evaluations = match Self::verifier_evaluations(&query_set, proof, &mut fs_rng) {
+            Some(evaluations) => evaluations,
+            None => return Ok(None),
+        };
+
+        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(&public_input, &evaluations, &verifier_state)?;
+
+        let opening_challenges = Self::verifier_opening_challenges(&mut fs_rng, 7)?;
+
+        let check = LinearCombinationsCheck {
+            linear_combinations: lc_s,
+            commitments,
+            query_set,
+            evaluations,
+            proof: proof.pc_proof.clone(),
+            opening_challenges,
+        };
+        Ok(Some((check, fs_rng)))
+    }
+
+    /// Absorbs the commitments and messages of the three prover rounds of the given proof
+    /// into the Fiat-Shamir RNG, and derives the verifier challenges of each round,
+    /// starting from the given first round of the AHP verifier.
+    fn verifier_rounds<T>(
+        proof: &Proof<TargetField, BaseField, PC>,
+        fs_rng: &mut FS,
+        verifier_first_round: impl FnOnce(&mut FS) -> Result<(T, VerifierState<TargetField, MM>), AHPError>,
+    ) -> Result<(T, VerifierState<TargetField, MM>), MarlinError> {
+        // --------------------------------------------------------------------
+        // First round
+        Self::absorb_prover_round(&proof.commitments[0], &proof.prover_messages[0], fs_rng);
+        let (first_round_output, verifier_state) = verifier_first_round(fs_rng)?;
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Second round
-        if MM::RECURSION {
-            fs_rng.absorb_native_field_elements(second_commitments);
-            if !proof.prover_messages[1].field_elements.is_empty() {
-                fs_rng.absorb_nonnative_field_elements(
-                    &proof.prover_messages[1].field_elements,
-                    OptimizationType::Weight,
-                );
-            };
-        } else {
-            fs_rng.absorb_bytes(&to_bytes_le![second_commitments, proof.prover_messages[1]].unwrap());
-        }
-
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut fs_rng)?;
+        Self::absorb_prover_round(&proof.commitments[1], &proof.prover_messages[1], fs_rng);
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, fs_rng)?;
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Third round
+        Self::absorb_prover_round(&proof.commitments[2], &proof.prover_messages[2], fs_rng);
+        let verifier_state = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, fs_rng)?;
+        // --------------------------------------------------------------------
+
+        Ok((first_round_output, verifier_state))
+    }
+
+    /// Absorbs the commitments and the message of a prover round into the Fiat-Shamir RNG.
+    fn absorb_prover_round(
+        commitments: &[PC::Commitment],
+        prover_message: &ProverMessage<TargetField>,
+        fs_rng: &mut FS,
+    ) {
         if MM::RECURSION {
-            fs_rng.absorb_native_field_elements(third_commitments);
-            if !proof.prover_messages[2].field_elements.is_empty() {
-                fs_rng.absorb_nonnative_field_elements(
-                    &proof.prover_messages[2].field_elements,
-                    OptimizationType::Weight,
-                );
-            };
+            fs_rng.absorb_native_field_elements(commitments);
+            if !prover_message.field_elements.is_empty() {
+                fs_rng.absorb_nonnative_field_elements(&prover_message.field_elements, OptimizationType::Weight);
+            }
         } else {
-            fs_rng.absorb_bytes(&to_bytes_le![third_commitments, proof.prover_messages[2]].unwrap());
+            fs_rng.absorb_bytes(&to_bytes_le![commitments, prover_message].unwrap());
         }
+    }
 
-        let verifier_state = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut fs_rng)?;
-        // --------------------------------------------------------------------
-
+    /// Returns the labeled commitments of the circuit and of the three prover rounds of the given proof.
+    fn verifier_commitments(
+        circuit_verifying_key: &CircuitVerifyingKey<TargetField, BaseField, PC, MM>,
+        proof: &Proof<TargetField, BaseField, PC>,
+        first_round_degree_bounds: impl Iterator<Item = Option<usize>>,
+        polynomial_labels: impl Iterator<Item = String>,
+    ) -> Vec<LabeledCommitment<PC::Commitment>> {
         // Collect degree bounds for commitments. Indexed polynomials have *no*
         // degree bounds because we know the committed index polynomial has the
         // correct degree.
         let index_info = circuit_verifying_key.circuit_info;
         let degree_bounds = vec![None; circuit_verifying_key.circuit_commitments.len()]
             .into_iter()
-            .chain(AHPForR1CS::<_, MM>::prover_first_round_degree_bounds(&index_info))
+            .chain(first_round_degree_bounds)
             .chain(AHPForR1CS::<_, MM>::prover_second_round_degree_bounds(&index_info))
             .chain(AHPForR1CS::<_, MM>::prover_third_round_degree_bounds(&index_info));
 
-        let polynomial_labels = AHPForR1CS::<TargetField, MM>::polynomial_labels();
-
         // Gather commitments in one vector.
-        let commitments: Vec<_> = circuit_verifying_key
+        circuit_verifying_key
             .iter()
-            .chain(first_commitments)
-            .chain(second_commitments)
-            .chain(third_commitments)
+            .chain(proof.commitments.iter().flatten())
             .cloned()
             .zip(polynomial_labels)
             .zip(degree_bounds)
             .map(|((c, l), d)| LabeledCommitment::new(l, c, d))
-            .collect();
-
-        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state, &mut fs_rng);
+            .collect()
+    }
 
+    /// Absorbs the evaluations of the given proof into the Fiat-Shamir RNG, and returns them
+    /// keyed by their queries, or `None` if the proof has an incorrect number of evaluations.
+    fn verifier_evaluations(
+        query_set: &QuerySet<TargetField>,
+        proof: &Proof<TargetField, BaseField, PC>,
+        fs_rng: &mut FS,
+    ) -> Option<Evaluations<'static, TargetField>> {
         if MM::RECURSION {
             fs_rng.absorb_nonnative_field_elements(&proof.evaluations, OptimizationType::Weight);
         } else {
@@ -733,55 +1258,27 @@ impl<
                 evaluation_labels.push((label, q));
             }
         }
+        if evaluation_labels.len() != proof.evaluations.len() {
+            eprintln!("Proof contains an incorrect number of evaluations");
+            return None;
+        }
         evaluation_labels.sort_by(|a, b| a.0.cmp(&b.0));
         for (q, eval) in evaluation_labels.into_iter().zip(&proof.evaluations) {
             evaluations.insert(q, *eval);
         }
+        Some(evaluations)
+    }
 
-        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(&public_input, &evaluations, &verifier_state)?;
-
-        let evaluations_are_correct = if MM::RECURSION {
-            let num_open_challenges: usize = 7;
-
-            let mut opening_challenges = Vec::new();
-            opening_challenges.append(&mut fs_rng.squeeze_128_bits_nonnative_field_elements(num_open_challenges)?);
-
-            let opening_challenges_f = |i| opening_challenges[i as usize];
-
-            PC::check_combinations_individual_opening_challenges(
-                &circuit_verifying_key.verifier_key,
-                &lc_s,
-                &commitments,
-                &query_set,
-                &evaluations,
-                &proof.pc_proof,
-                &opening_challenges_f,
-                &mut fs_rng,
-            )?
+    /// Squeezes the opening challenges for the polynomial commitment check from the Fiat-Shamir RNG.
+    fn verifier_opening_challenges(
+        fs_rng: &mut FS,
+        num_opening_challenges: usize,
+    ) -> Result<OpeningChallenges<TargetField>, MarlinError> {
+        Ok(if MM::RECURSION {
+            OpeningChallenges::Individual(fs_rng.squeeze_128_bits_nonnative_field_elements(num_opening_challenges)?)
         } else {
-            let opening_challenge: TargetField = fs_rng.squeeze_128_bits_nonnative_field_elements(1)?[0];
-
-            PC::check_combinations(
-                &circuit_verifying_key.verifier_key,
-                &lc_s,
-                &commitments,
-                &query_set,
-                &evaluations,
-                &proof.pc_proof,
-                opening_challenge,
-                &mut fs_rng,
-            )?
-        };
-
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("PC::Check failed");
-        }
-        end_timer!(verifier_time, || format!(
-            " PC::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+            OpeningChallenges::Single(fs_rng.squeeze_128_bits_nonnative_field_elements(1)?[0])
+        })
     }
 
     /// Verify that a proof for the constraint system defined by `C` asserts that
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+/// The aggregation of Marlin proofs that share a circuit verifying key.
+mod aggregation;
+
 /// The Marlin circuit proving key.
 mod circuit_proving_key;
 pub use circuit_proving_key::*;
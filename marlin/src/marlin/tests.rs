@@ -14,11 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::Field;
+use crate::{
+    ahp::AHPForR1CS,
+    fiat_shamir::FiatShamirRng,
+    marlin::{CircuitProvingKey, CircuitVerifyingKey, MarlinMode, MarlinSNARK, Proof},
+};
+use snarkvm_curves::bls12_377::{Fq, Fr};
+use snarkvm_fields::{Field, One, Zero};
+use snarkvm_polycommit::PolynomialCommitment;
 use snarkvm_r1cs::errors::SynthesisError;
 
 use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem};
 
+use rand::Rng;
+
 #[derive(Copy, Clone)]
 pub struct Circuit<F: Field> {
     pub a: Option<F>,
@@ -29,6 +38,47 @@ pub struct Circuit<F: Field> {
 
 impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for Circuit<ConstraintF> {
     fn generate_constraints<CS: ConstraintSystem<ConstraintF>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        self.synthesize(cs, ConstraintF::zero())
+    }
+}
+
+/// A circuit with the constraints of `Circuit`, whose assignment of the public input `c` is offset by `c_offset`.
+/// The assignment does not satisfy the constraints for any nonzero offset.
+#[derive(Copy, Clone)]
+pub struct OffsetCircuit<F: Field> {
+    pub circuit: Circuit<F>,
+    pub c_offset: F,
+}
+
+impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for OffsetCircuit<ConstraintF> {
+    fn generate_constraints<CS: ConstraintSystem<ConstraintF>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        self.circuit.synthesize(cs, self.c_offset)
+    }
+}
+
+impl<ConstraintF: Field> Circuit<ConstraintF> {
+    /// Returns a circuit with the given dimensions, and a random assignment of `a` and `b`.
+    pub fn rand<R: Rng>(num_constraints: usize, num_variables: usize, rng: &mut R) -> Self {
+        Self {
+            a: Some(ConstraintF::rand(rng)),
+            b: Some(ConstraintF::rand(rng)),
+            num_constraints,
+            num_variables,
+        }
+    }
+
+    /// Returns the public inputs `c = a * b` and `d = a * b * b` of the circuit.
+    pub fn public_inputs(&self) -> Vec<ConstraintF> {
+        let (a, b) = (self.a.unwrap(), self.b.unwrap());
+        vec![a * b, a * b * b]
+    }
+
+    /// Synthesizes the circuit, offsetting the assignment of the public input `c` by `c_offset`.
+    fn synthesize<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        cs: &mut CS,
+        c_offset: ConstraintF,
+    ) -> Result<(), SynthesisError> {
         let a = cs.alloc(|| "a", || self.a.ok_or(SynthesisError::AssignmentMissing))?;
         let b = cs.alloc(|| "b", || self.b.ok_or(SynthesisError::AssignmentMissing))?;
         let c = cs.alloc_input(
@@ -38,7 +88,7 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for Circuit<Constrai
                 let b = self.b.ok_or(SynthesisError::AssignmentMissing)?;
 
                 a.mul_assign(&b);
-                Ok(a)
+                Ok(a + c_offset)
             },
         )?;
         let d = cs.alloc_input(
@@ -69,6 +119,49 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for Circuit<Constrai
     }
 }
 
+/// Runs a universal setup of the test size, and indexes a `Circuit` with the given dimensions.
+#[allow(clippy::type_complexity)]
+fn circuit_setup<FS, PC, MM, R>(
+    num_constraints: usize,
+    num_variables: usize,
+    rng: &mut R,
+) -> (CircuitProvingKey<Fr, Fq, PC, MM>, CircuitVerifyingKey<Fr, Fq, PC, MM>)
+where
+    FS: FiatShamirRng<Fr, Fq>,
+    PC: PolynomialCommitment<Fr, Fq>,
+    MM: MarlinMode,
+    R: Rng,
+{
+    let max_degree = AHPForR1CS::<Fr, MM>::max_degree(100, 25, 300).unwrap();
+    let universal_srs = MarlinSNARK::<Fr, Fq, PC, FS, MM>::universal_setup(max_degree, rng).unwrap();
+    let circuit = Circuit::rand(num_constraints, num_variables, rng);
+    MarlinSNARK::<Fr, Fq, PC, FS, MM>::circuit_setup(&universal_srs, &circuit).unwrap()
+}
+
+/// Proves the given number of random instances of a `Circuit` with the given dimensions,
+/// returning the public inputs and the proof of each instance.
+fn prove_instances<FS, PC, MM, R>(
+    circuit_proving_key: &CircuitProvingKey<Fr, Fq, PC, MM>,
+    num_constraints: usize,
+    num_variables: usize,
+    num_instances: usize,
+    rng: &mut R,
+) -> Vec<(Vec<Fr>, Proof<Fr, Fq, PC>)>
+where
+    FS: FiatShamirRng<Fr, Fq>,
+    PC: PolynomialCommitment<Fr, Fq>,
+    MM: MarlinMode,
+    R: Rng,
+{
+    (0..num_instances)
+        .map(|_| {
+            let circuit = Circuit::rand(num_constraints, num_variables, rng);
+            let proof = MarlinSNARK::<Fr, Fq, PC, FS, MM>::prove(circuit_proving_key, &circuit, rng).unwrap();
+            (circuit.public_inputs(), proof)
+        })
+        .collect()
+}
+
 mod marlin {
     use super::*;
     use crate::{
@@ -76,8 +169,15 @@ mod marlin {
         marlin::{MarlinPoswMode, MarlinSNARK, MarlinTestnet1Mode},
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
-    use snarkvm_polycommit::{marlin_pc::MarlinKZG10, sonic_pc::SonicKZG10};
-    use snarkvm_utilities::rand::{test_rng, UniformRand};
+    use snarkvm_polycommit::{
+        marlin_pc::MarlinKZG10,
+        sonic_pc::{Accumulator, SonicKZG10},
+    };
+    use snarkvm_utilities::{
+        rand::{test_rng, UniformRand},
+        FromBytes,
+        ToBytes,
+    };
 
     use blake2::Blake2s;
     use core::ops::MulAssign;
@@ -90,6 +190,8 @@ mod marlin {
 
     type MarlinSonicPoswInst = MarlinSNARK<Fr, Fq, MultiPCSonic, FiatShamirChaChaRng<Fr, Fq, Blake2s>, MarlinPoswMode>;
 
+    type FS = FiatShamirChaChaRng<Fr, Fq, Blake2s>;
+
     macro_rules! impl_marlin_test {
         ($test_struct: ident, $marlin_inst: tt, $marlin_mode: tt) => {
             struct $test_struct {}
@@ -127,6 +229,53 @@ mod marlin {
                         assert!(!$marlin_inst::verify(&index_vk, &[a, a], &proof).unwrap());
                     }
                 }
+
+                pub(crate) fn test_batch_verify(num_constraints: usize, num_variables: usize) {
+                    let rng = &mut test_rng();
+
+                    let (index_pk, index_vk) =
+                        circuit_setup::<FS, _, $marlin_mode, _>(num_constraints, num_variables, rng);
+                    let inputs_and_proofs =
+                        prove_instances::<FS, _, _, _>(&index_pk, num_constraints, num_variables, 5, rng);
+                    let mut batch = inputs_and_proofs
+                        .iter()
+                        .map(|(inputs, proof)| (&inputs[..], proof))
+                        .collect::<Vec<_>>();
+
+                    assert!($marlin_inst::batch_verify(&index_vk, &[], rng).unwrap());
+                    assert!($marlin_inst::batch_verify(&index_vk, &batch, rng).unwrap());
+
+                    // Ensure a single invalid proof fails the batch.
+                    let invalid_inputs = [Fr::rand(rng), Fr::rand(rng)];
+                    batch[2].0 = &invalid_inputs;
+                    assert!(!$marlin_inst::batch_verify(&index_vk, &batch, rng).unwrap());
+                }
+
+                pub(crate) fn test_batch_prove(num_constraints: usize, num_variables: usize) {
+                    let rng = &mut test_rng();
+
+                    let (index_pk, index_vk) =
+                        circuit_setup::<FS, _, $marlin_mode, _>(num_constraints, num_variables, rng);
+
+                    for batch_size in [1, 4] {
+                        let circuits = (0..batch_size)
+                            .map(|_| Circuit::rand(num_constraints, num_variables, rng))
+                            .collect::<Vec<_>>();
+                        let mut public_inputs = circuits.iter().map(Circuit::public_inputs).collect::<Vec<_>>();
+
+                        let proof = $marlin_inst::batch_prove(&index_pk, &circuits, rng).unwrap();
+                        assert!($marlin_inst::verify_batch_proof(&index_vk, &public_inputs, &proof).unwrap());
+
+                        // Ensure an invalid public input fails the batch.
+                        let mut invalid_inputs = public_inputs.clone();
+                        invalid_inputs[batch_size - 1] = vec![Fr::rand(rng), Fr::rand(rng)];
+                        assert!(!$marlin_inst::verify_batch_proof(&index_vk, &invalid_inputs, &proof).unwrap());
+
+                        // Ensure the proof does not verify for a different number of instances.
+                        public_inputs.push(public_inputs[0].clone());
+                        assert!(!$marlin_inst::verify_batch_proof(&index_vk, &public_inputs, &proof).unwrap());
+                    }
+                }
             }
         };
     }
@@ -184,6 +333,151 @@ mod marlin {
         SonicPCTest::test_circuit(num_constraints, num_variables);
         SonicPCPoswTest::test_circuit(num_constraints, num_variables);
     }
+
+    #[test]
+    fn prove_and_batch_verify() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        MarlinPCTest::test_batch_verify(num_constraints, num_variables);
+        SonicPCTest::test_batch_verify(num_constraints, num_variables);
+        SonicPCPoswTest::test_batch_verify(num_constraints, num_variables);
+    }
+
+    #[test]
+    fn batch_prove_and_verify() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        MarlinPCTest::test_batch_prove(num_constraints, num_variables);
+        SonicPCTest::test_batch_prove(num_constraints, num_variables);
+        SonicPCPoswTest::test_batch_prove(num_constraints, num_variables);
+    }
+
+    /// With debug assertions, the prover asserts that the outer sumcheck holds, and panics on an unsatisfied instance.
+    /// Otherwise, the prover returns a proof that the verifier rejects.
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "outer_sumcheck"))]
+    fn batch_prove_unsatisfied() {
+        let rng = &mut test_rng();
+
+        let (index_pk, index_vk) = circuit_setup::<FS, MultiPCSonic, MarlinTestnet1Mode, _>(25, 25, rng);
+
+        // Construct a batch in which one instance does not satisfy the constraints.
+        let mut circuits = Vec::with_capacity(4);
+        let mut public_inputs = Vec::with_capacity(4);
+        for i in 0..4 {
+            let circuit = Circuit::rand(25, 25, rng);
+            let c_offset = if i == 2 { Fr::one() } else { Fr::zero() };
+            let mut inputs = circuit.public_inputs();
+            inputs[0] += c_offset;
+            circuits.push(OffsetCircuit { circuit, c_offset });
+            public_inputs.push(inputs);
+        }
+
+        let proof = MarlinSonicInst::batch_prove(&index_pk, &circuits, rng).unwrap();
+        assert!(!MarlinSonicInst::verify_batch_proof(&index_vk, &public_inputs, &proof).unwrap());
+    }
+
+    #[test]
+    fn prove_and_aggregate() {
+        let rng = &mut test_rng();
+
+        let (index_pk, index_vk) = circuit_setup::<FS, MultiPCSonic, MarlinTestnet1Mode, _>(25, 25, rng);
+        let inputs_and_proofs = prove_instances::<FS, _, _, _>(&index_pk, 25, 25, 5, rng);
+        let proofs = inputs_and_proofs
+            .iter()
+            .map(|(inputs, proof)| (&inputs[..], proof))
+            .collect::<Vec<_>>();
+
+        // Aggregate the first proofs, and fold the first aggregate into an aggregate of the remaining proofs.
+        let first_aggregate = MarlinSonicInst::aggregate_proofs(&index_vk, &[], &proofs[..3])
+            .unwrap()
+            .unwrap();
+        assert!(MarlinSonicInst::verify_aggregate(&index_vk, &[], &proofs[..3], &first_aggregate).unwrap());
+
+        let previous_aggregates = [first_aggregate];
+        let second_aggregate = MarlinSonicInst::aggregate_proofs(&index_vk, &previous_aggregates, &proofs[3..])
+            .unwrap()
+            .unwrap();
+        assert!(
+            MarlinSonicInst::verify_aggregate(&index_vk, &previous_aggregates, &proofs[3..], &second_aggregate)
+                .unwrap()
+        );
+        assert!(!MarlinSonicInst::verify_aggregation(&index_vk, &[], &proofs[3..], &second_aggregate).unwrap());
+
+        // The aggregate of all proofs has as many combined commitments as the aggregate of one proof,
+        // so its pairing check does not depend on the number of proofs.
+        let single_aggregate = MarlinSonicInst::aggregate_proofs(&index_vk, &[], &proofs[..1])
+            .unwrap()
+            .unwrap();
+        let full_aggregate = MarlinSonicInst::aggregate_proofs(&index_vk, &[], &proofs)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            single_aggregate.combined_commitments.len(),
+            full_aggregate.combined_commitments.len()
+        );
+        assert!(MarlinSonicInst::verify_aggregate(&index_vk, &[], &proofs, &full_aggregate).unwrap());
+
+        let aggregate_bytes = second_aggregate.to_bytes_le().unwrap();
+        let recovered_aggregate = FromBytes::read_le(&aggregate_bytes[..]).unwrap();
+        assert_eq!(second_aggregate, recovered_aggregate);
+    }
+
+    #[test]
+    fn verify_tampered_aggregate() {
+        let rng = &mut test_rng();
+
+        let (index_pk, index_vk) = circuit_setup::<FS, MultiPCSonic, MarlinTestnet1Mode, _>(25, 25, rng);
+        let inputs_and_proofs = prove_instances::<FS, _, _, _>(&index_pk, 25, 25, 4, rng);
+        let mut proofs = inputs_and_proofs
+            .iter()
+            .map(|(inputs, proof)| (&inputs[..], proof))
+            .collect::<Vec<_>>();
+        let aggregate = MarlinSonicInst::aggregate_proofs(&index_vk, &[], &proofs)
+            .unwrap()
+            .unwrap();
+        assert!(MarlinSonicInst::verify_aggregate(&index_vk, &[], &proofs, &aggregate).unwrap());
+
+        // Ensure a trivial aggregate, which passes the pairing check, is not accepted for the proofs.
+        let trivial_aggregate = Accumulator::default();
+        assert!(MarlinSonicInst::check_aggregate(&index_vk, &trivial_aggregate).unwrap());
+        assert!(!MarlinSonicInst::verify_aggregate(&index_vk, &[], &proofs, &trivial_aggregate).unwrap());
+
+        // Ensure a tampered aggregate fails both its derivation from the proofs and its pairing check.
+        let mut tampered_aggregate = aggregate.clone();
+        core::mem::swap(
+            &mut tampered_aggregate.combined_witness,
+            &mut tampered_aggregate.combined_adjusted_witness,
+        );
+        assert!(!MarlinSonicInst::check_aggregate(&index_vk, &tampered_aggregate).unwrap());
+        assert!(!MarlinSonicInst::verify_aggregate(&index_vk, &[], &proofs, &tampered_aggregate).unwrap());
+
+        // Ensure the aggregate is not accepted for a different set of proofs.
+        assert!(!MarlinSonicInst::verify_aggregate(&index_vk, &[], &proofs[1..], &aggregate).unwrap());
+
+        // Ensure a single tampered proof fails the aggregate of the proofs.
+        let mut tampered_proof = proofs[2].1.clone();
+        tampered_proof.evaluations[0] += Fr::one();
+        let mut tampered_proofs = proofs.clone();
+        tampered_proofs[2].1 = &tampered_proof;
+        let invalid_aggregate = MarlinSonicInst::aggregate_proofs(&index_vk, &[], &tampered_proofs)
+            .unwrap()
+            .unwrap();
+        assert!(!MarlinSonicInst::check_aggregate(&index_vk, &invalid_aggregate).unwrap());
+        assert!(!MarlinSonicInst::verify_aggregate(&index_vk, &[], &tampered_proofs, &invalid_aggregate).unwrap());
+        assert!(!MarlinSonicInst::verify_aggregate(&index_vk, &[], &tampered_proofs, &aggregate).unwrap());
+
+        // Ensure a single invalid public input fails the aggregate of the proofs.
+        let invalid_inputs = [Fr::rand(rng), Fr::rand(rng)];
+        proofs[2].0 = &invalid_inputs;
+        let invalid_aggregate = MarlinSonicInst::aggregate_proofs(&index_vk, &[], &proofs)
+            .unwrap()
+            .unwrap();
+        assert!(!MarlinSonicInst::check_aggregate(&index_vk, &invalid_aggregate).unwrap());
+        assert!(!MarlinSonicInst::verify_aggregate(&index_vk, &[], &proofs, &invalid_aggregate).unwrap());
+    }
 }
 
 mod marlin_recursion {
@@ -207,6 +501,8 @@ mod marlin_recursion {
         MarlinRecursiveMode,
     >;
 
+    type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
+
     fn test_circuit(num_constraints: usize, num_variables: usize) {
         let rng = &mut test_rng();
 
@@ -281,6 +577,42 @@ mod marlin_recursion {
         test_circuit(num_constraints, num_variables);
     }
 
+    #[test]
+    fn prove_and_batch_verify() {
+        let rng = &mut test_rng();
+
+        let (index_pk, index_vk) = circuit_setup::<FS, MultiPC, MarlinRecursiveMode, _>(25, 25, rng);
+        let inputs_and_proofs = prove_instances::<FS, _, _, _>(&index_pk, 25, 25, 5, rng);
+        let mut batch = inputs_and_proofs
+            .iter()
+            .map(|(inputs, proof)| (&inputs[..], proof))
+            .collect::<Vec<_>>();
+
+        assert!(MarlinInst::batch_verify(&index_vk, &batch, rng).unwrap());
+
+        // Ensure a single invalid proof fails the batch.
+        let invalid_inputs = [Fr::rand(rng), Fr::rand(rng)];
+        batch[2].0 = &invalid_inputs;
+        assert!(!MarlinInst::batch_verify(&index_vk, &batch, rng).unwrap());
+    }
+
+    #[test]
+    fn batch_prove_and_verify() {
+        let rng = &mut test_rng();
+
+        let (index_pk, index_vk) = circuit_setup::<FS, MultiPC, MarlinRecursiveMode, _>(25, 25, rng);
+
+        let circuits = (0..3).map(|_| Circuit::rand(25, 25, rng)).collect::<Vec<_>>();
+        let mut public_inputs = circuits.iter().map(Circuit::public_inputs).collect::<Vec<_>>();
+
+        let proof = MarlinInst::batch_prove(&index_pk, &circuits, rng).unwrap();
+        assert!(MarlinInst::verify_batch_proof(&index_vk, &public_inputs, &proof).unwrap());
+
+        // Ensure an invalid public input fails the batch.
+        public_inputs[1] = vec![Fr::rand(rng), Fr::rand(rng)];
+        assert!(!MarlinInst::verify_batch_proof(&index_vk, &public_inputs, &proof).unwrap());
+    }
+
     // #[test]
     // /// Test on a constraint system that will trigger outlining.
     // fn prove_and_test_outlining() {
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    plonk::{Gate, GateSystem},
+    String,
+    ToString,
+    Vec,
+};
+use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain};
+use snarkvm_fields::PrimeField;
+use snarkvm_polycommit::LabeledPolynomial;
+use snarkvm_r1cs::errors::SynthesisError;
+use snarkvm_utilities::{errors::SerializationError, serialize::*};
+
+use crate::{Read, Write};
+
+/// The smallest evaluation domain used for a circuit.
+pub(crate) const MIN_DOMAIN_SIZE: usize = 8;
+
+/// The labels of the selector polynomials.
+pub(crate) const SELECTOR_LABELS: [&str; 6] = ["q_m", "q_l", "q_r", "q_o", "q_c", "q_h"];
+
+/// The labels of the permutation polynomials.
+pub(crate) const PERMUTATION_LABELS: [&str; 3] = ["sigma_a", "sigma_b", "sigma_c"];
+
+/// Returns the coset shifts `1`, `k_1` and `k_2` which identify the positions of the wires `a`, `b` and `c`
+/// in the copy-constraint permutation.
+pub(crate) fn coset_shifts<F: PrimeField>() -> [F; 3] {
+    let k_1 = F::multiplicative_generator();
+    [F::one(), k_1, k_1.square()]
+}
+
+/// Information about the size of a circuit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CircuitInfo {
+    /// The number of rows, that is, the number of public inputs plus the number of gates.
+    pub num_rows: usize,
+    /// The number of public inputs.
+    pub num_public_inputs: usize,
+    /// The size of the evaluation domain over which the rows are interpolated.
+    pub domain_size: usize,
+}
+
+impl CircuitInfo {
+    /// Returns the evaluation domain over which the rows are interpolated.
+    pub fn domain<F: PrimeField>(&self) -> Result<EvaluationDomain<F>, SynthesisError> {
+        EvaluationDomain::new(self.domain_size).ok_or(SynthesisError::PolynomialDegreeTooLarge)
+    }
+
+    /// Returns the maximum degree of the polynomials committed for this circuit.
+    ///
+    /// The wire polynomials are blinded to degree `n + 1`, the permutation polynomial
+    /// to degree `n + 2`, and the quotient chunks have degree at most `n`.
+    pub fn max_degree(&self) -> usize {
+        self.domain_size + 2
+    }
+}
+
+/// The preprocessed selector and permutation polynomials of a circuit.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Circuit<F: PrimeField> {
+    /// Information about the size of the circuit.
+    pub circuit_info: CircuitInfo,
+    /// The selector polynomials `q_m`, `q_l`, `q_r`, `q_o`, `q_c` and `q_h`.
+    pub selector_polynomials: Vec<LabeledPolynomial<F>>,
+    /// The permutation polynomials `sigma_a`, `sigma_b` and `sigma_c`.
+    pub permutation_polynomials: Vec<LabeledPolynomial<F>>,
+}
+
+impl<F: PrimeField> Circuit<F> {
+    /// Preprocesses the rows of `gate_system` into selector and permutation polynomials.
+    pub fn index(gate_system: &GateSystem<F>) -> Result<Self, SynthesisError> {
+        let index_time = start_timer!(|| "Plonk::Index");
+
+        let rows = gate_system.rows();
+        let domain = EvaluationDomain::<F>::new(core::cmp::max(rows.len(), MIN_DOMAIN_SIZE))
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let circuit_info = CircuitInfo {
+            num_rows: rows.len(),
+            num_public_inputs: gate_system.num_public_inputs(),
+            domain_size: domain.size(),
+        };
+
+        let mut padded_rows = rows;
+        padded_rows.resize(domain.size(), Gate::zero());
+
+        let selector_polynomials = SELECTOR_LABELS
+            .iter()
+            .enumerate()
+            .map(|(i, label)| {
+                let evaluations = padded_rows.iter().map(|row| row.selectors()[i]).collect::<Vec<_>>();
+                interpolate(&domain, label, &evaluations)
+            })
+            .collect();
+
+        // Collect the positions of every variable, and map each position to the next one in its cycle.
+        let mut positions = vec![Vec::new(); gate_system.num_variables()];
+        for (row, gate) in padded_rows.iter().enumerate() {
+            for (column, variable) in gate.wires().iter().enumerate() {
+                positions[variable.index()].push((column, row));
+            }
+        }
+
+        let shifts = coset_shifts::<F>();
+        let elements = domain.elements().collect::<Vec<_>>();
+        let mut sigmas = vec![vec![F::zero(); domain.size()]; 3];
+        for cycle in positions.iter().filter(|cycle| !cycle.is_empty()) {
+            for (i, &(column, row)) in cycle.iter().enumerate() {
+                let (next_column, next_row) = cycle[(i + 1) % cycle.len()];
+                sigmas[column][row] = shifts[next_column] * elements[next_row];
+            }
+        }
+
+        let permutation_polynomials = PERMUTATION_LABELS
+            .iter()
+            .zip(&sigmas)
+            .map(|(label, evaluations)| interpolate(&domain, label, evaluations))
+            .collect();
+
+        end_timer!(index_time);
+
+        Ok(Self {
+            circuit_info,
+            selector_polynomials,
+            permutation_polynomials,
+        })
+    }
+
+    /// Iterate over the selector and permutation polynomials in `self`.
+    pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
+        self.selector_polynomials.iter().chain(&self.permutation_polynomials)
+    }
+}
+
+/// Returns the labels of the selector and permutation polynomials, in the order of [`Circuit::iter`].
+pub(crate) fn circuit_polynomial_labels() -> impl Iterator<Item = String> {
+    SELECTOR_LABELS
+        .iter()
+        .chain(&PERMUTATION_LABELS)
+        .map(|label| label.to_string())
+}
+
+fn interpolate<F: PrimeField>(domain: &EvaluationDomain<F>, label: &str, evaluations: &[F]) -> LabeledPolynomial<F> {
+    let polynomial = DensePolynomial::from_coefficients_vec(domain.ifft(evaluations));
+    LabeledPolynomial::new(label.to_string(), polynomial, None, None)
+}
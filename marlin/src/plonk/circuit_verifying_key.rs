@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    plonk::{CircuitInfo, CircuitProvingKey, PreparedCircuitVerifyingKey},
+    Vec,
+};
+use snarkvm_algorithms::Prepare;
+use snarkvm_fields::{ConstraintFieldError, PrimeField, ToConstraintField};
+use snarkvm_polycommit::PolynomialCommitment;
+use snarkvm_utilities::{error, errors::SerializationError, serialize::*, FromBytes, ToBytes, ToMinimalBits};
+
+use crate::{Read, Write};
+use derivative::Derivative;
+
+/// Verification key for a specific circuit (i.e., selector and permutation polynomials).
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+#[derive(Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CircuitVerifyingKey<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> {
+    /// Stores information about the size of the circuit.
+    pub circuit_info: CircuitInfo,
+    /// Commitments to the selector and permutation polynomials.
+    pub circuit_commitments: Vec<PC::Commitment>,
+    /// The verifier key for this circuit, trimmed from the universal SRS.
+    pub verifier_key: PC::VerifierKey,
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> ToBytes for CircuitVerifyingKey<F, CF, PC> {
+    fn write_le<W: Write>(&self, mut w: W) -> crate::io::Result<()> {
+        CanonicalSerialize::serialize(self, &mut w).map_err(|_| error("could not serialize CircuitVerifyingKey"))
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> FromBytes for CircuitVerifyingKey<F, CF, PC> {
+    fn read_le<R: Read>(mut r: R) -> crate::io::Result<Self> {
+        CanonicalDeserialize::deserialize(&mut r).map_err(|_| error("could not deserialize CircuitVerifyingKey"))
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> ToMinimalBits for CircuitVerifyingKey<F, CF, PC> {
+    fn to_minimal_bits(&self) -> Vec<bool> {
+        assert!(self.circuit_info.domain_size < u64::MAX as usize);
+
+        let domain_size_bits = (self.circuit_info.domain_size as u64)
+            .to_le_bytes()
+            .iter()
+            .flat_map(|&byte| (0..8).map(move |i| (byte >> i) & 1u8 == 1u8))
+            .collect::<Vec<bool>>();
+        let num_public_inputs_bits = (self.circuit_info.num_public_inputs as u64)
+            .to_le_bytes()
+            .iter()
+            .flat_map(|&byte| (0..8).map(move |i| (byte >> i) & 1u8 == 1u8))
+            .collect::<Vec<bool>>();
+
+        let circuit_commitments_bits = self.circuit_commitments.to_minimal_bits();
+
+        [domain_size_bits, num_public_inputs_bits, circuit_commitments_bits].concat()
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> CircuitVerifyingKey<F, CF, PC> {
+    /// Iterate over the commitments to the circuit polynomials in `self`.
+    pub fn iter(&self) -> impl Iterator<Item = &PC::Commitment> {
+        self.circuit_commitments.iter()
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> From<CircuitProvingKey<F, CF, PC>>
+    for CircuitVerifyingKey<F, CF, PC>
+{
+    fn from(other: CircuitProvingKey<F, CF, PC>) -> Self {
+        other.circuit_verifying_key
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> From<PreparedCircuitVerifyingKey<F, CF, PC>>
+    for CircuitVerifyingKey<F, CF, PC>
+{
+    fn from(other: PreparedCircuitVerifyingKey<F, CF, PC>) -> Self {
+        other.orig_vk
+    }
+}
+
+impl<F, CF, PC> Prepare<PreparedCircuitVerifyingKey<F, CF, PC>> for CircuitVerifyingKey<F, CF, PC>
+where
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+{
+    /// Prepare the circuit verifying key.
+    fn prepare(&self) -> PreparedCircuitVerifyingKey<F, CF, PC> {
+        PreparedCircuitVerifyingKey {
+            prepared_circuit_commitments: self.circuit_commitments.iter().map(|comm| comm.prepare()).collect(),
+            prepared_verifier_key: self.verifier_key.prepare(),
+            orig_vk: (*self).clone(),
+        }
+    }
+}
+
+impl<F, CF, PC> ToConstraintField<CF> for CircuitVerifyingKey<F, CF, PC>
+where
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+{
+    fn to_field_elements(&self) -> Result<Vec<CF>, ConstraintFieldError> {
+        let mut res = Vec::new();
+        res.append(&mut CF::from(self.circuit_info.domain_size as u128).to_field_elements()?);
+        res.append(&mut CF::from(self.circuit_info.num_public_inputs as u128).to_field_elements()?);
+        for comm in self.circuit_commitments.iter() {
+            res.append(&mut comm.to_field_elements()?);
+        }
+
+        // Intentionally ignore the appending of the PC verifier key.
+
+        Ok(res)
+    }
+}
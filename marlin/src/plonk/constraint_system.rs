@@ -0,0 +1,440 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{BTreeMap, Vec};
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{
+    errors::SynthesisError,
+    ConstraintSynthesizer,
+    ConstraintSystem,
+    Index as VarIndex,
+    LinearCombination,
+    Variable,
+};
+
+/// A variable of the gate arithmetization.
+///
+/// The variable at index zero is a dummy variable fixed to zero,
+/// which is used for unused wires and padding gates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Witness(usize);
+
+impl Witness {
+    /// The dummy variable, which is fixed to zero.
+    pub const ZERO: Witness = Witness(0);
+
+    /// Returns the index of the variable.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// A gate enforcing `q_m * a * b + q_l * a + q_r * b + q_o * c + q_c + q_h * a^5 = 0`
+/// over the wires `a`, `b` and `c`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Gate<F: Field> {
+    /// The left wire.
+    pub a: Witness,
+    /// The right wire.
+    pub b: Witness,
+    /// The output wire.
+    pub c: Witness,
+    /// The multiplication selector.
+    pub q_m: F,
+    /// The left selector.
+    pub q_l: F,
+    /// The right selector.
+    pub q_r: F,
+    /// The output selector.
+    pub q_o: F,
+    /// The constant selector.
+    pub q_c: F,
+    /// The selector of the custom fifth-power gate.
+    pub q_h: F,
+}
+
+impl<F: Field> Gate<F> {
+    /// Returns a gate with all selectors set to zero, over the dummy variable.
+    pub fn zero() -> Self {
+        Self {
+            a: Witness::ZERO,
+            b: Witness::ZERO,
+            c: Witness::ZERO,
+            q_m: F::zero(),
+            q_l: F::zero(),
+            q_r: F::zero(),
+            q_o: F::zero(),
+            q_c: F::zero(),
+            q_h: F::zero(),
+        }
+    }
+
+    /// Returns the selectors of the gate, in the order of the committed selector polynomials.
+    pub fn selectors(&self) -> [F; 6] {
+        [self.q_m, self.q_l, self.q_r, self.q_o, self.q_c, self.q_h]
+    }
+
+    /// Returns the wires of the gate.
+    pub fn wires(&self) -> [Witness; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    /// Evaluates the gate equation on the given wire values.
+    pub fn evaluate(&self, a: F, b: F, c: F) -> F {
+        let a_squared = a.square();
+        self.q_m * a * b + self.q_l * a + self.q_r * b + self.q_o * c + self.q_c + self.q_h * a_squared.square() * a
+    }
+}
+
+/// A computation which can be expressed with the gates of a [`GateSystem`].
+pub trait GateSynthesizer<F: Field> {
+    /// Adds the gates of the computation to `gate_system`.
+    fn synthesize(&self, gate_system: &mut GateSystem<F>) -> Result<(), SynthesisError>;
+}
+
+/// Adapts an R1CS [`ConstraintSynthesizer`] to a [`GateSynthesizer`],
+/// through the R1CS converter implemented by [`GateSystem`].
+pub struct R1CSCircuit<'a, C>(pub &'a C);
+
+impl<'a, F: Field, C: ConstraintSynthesizer<F>> GateSynthesizer<F> for R1CSCircuit<'a, C> {
+    fn synthesize(&self, gate_system: &mut GateSystem<F>) -> Result<(), SynthesisError> {
+        self.0.generate_constraints(gate_system)
+    }
+}
+
+/// A system of gates and public inputs over a set of variables.
+///
+/// In setup mode the variables carry no values, and value closures are not invoked.
+///
+/// The gate system also implements [`ConstraintSystem`], converting every R1CS constraint
+/// `<A, z> * <B, z> = <C, z>` into gates: each linear combination with more than one variable
+/// is folded into a fresh variable with addition gates, and a single gate then enforces the
+/// product of the folded terms.
+pub struct GateSystem<F: Field> {
+    /// The values of the variables, or `None` in setup mode.
+    assignment: Option<Vec<F>>,
+    num_variables: usize,
+    public_inputs: Vec<Witness>,
+    gates: Vec<Gate<F>>,
+    r1cs_public_variables: Vec<Witness>,
+    r1cs_private_variables: Vec<Witness>,
+    num_r1cs_constraints: usize,
+}
+
+impl<F: Field> GateSystem<F> {
+    /// Initializes a gate system in setup mode.
+    pub fn new_setup_mode() -> Self {
+        Self::new(None)
+    }
+
+    /// Initializes a gate system in proving mode.
+    pub fn new_proving_mode() -> Self {
+        Self::new(Some(vec![F::zero()]))
+    }
+
+    fn new(assignment: Option<Vec<F>>) -> Self {
+        Self {
+            assignment,
+            num_variables: 1,
+            public_inputs: Vec::new(),
+            gates: Vec::new(),
+            r1cs_public_variables: Vec::new(),
+            r1cs_private_variables: Vec::new(),
+            num_r1cs_constraints: 0,
+        }
+    }
+
+    /// Returns `true` if the variables of the gate system carry no values.
+    pub fn is_in_setup_mode(&self) -> bool {
+        self.assignment.is_none()
+    }
+
+    /// Allocates a private variable.
+    pub fn alloc_witness<Fn: FnOnce() -> Result<F, SynthesisError>>(
+        &mut self,
+        f: Fn,
+    ) -> Result<Witness, SynthesisError> {
+        let value = match self.assignment {
+            Some(_) => Some(f()?),
+            None => None,
+        };
+        Ok(self.push_variable(value))
+    }
+
+    /// Allocates a public input variable.
+    pub fn alloc_public_input<Fn: FnOnce() -> Result<F, SynthesisError>>(
+        &mut self,
+        f: Fn,
+    ) -> Result<Witness, SynthesisError> {
+        let variable = self.alloc_witness(f)?;
+        self.public_inputs.push(variable);
+        Ok(variable)
+    }
+
+    /// Returns the value of `variable`, or `None` in setup mode.
+    pub fn value(&self, variable: Witness) -> Option<F> {
+        self.assignment.as_ref().map(|assignment| assignment[variable.0])
+    }
+
+    /// Adds a gate to the system.
+    pub fn add_gate(&mut self, gate: Gate<F>) {
+        self.gates.push(gate);
+    }
+
+    /// Enforces `a + b = c`.
+    pub fn enforce_add(&mut self, a: Witness, b: Witness, c: Witness) {
+        self.add_gate(Gate {
+            a,
+            b,
+            c,
+            q_l: F::one(),
+            q_r: F::one(),
+            q_o: -F::one(),
+            ..Gate::zero()
+        });
+    }
+
+    /// Enforces `a * b = c`.
+    pub fn enforce_mul(&mut self, a: Witness, b: Witness, c: Witness) {
+        self.add_gate(Gate {
+            a,
+            b,
+            c,
+            q_m: F::one(),
+            q_o: -F::one(),
+            ..Gate::zero()
+        });
+    }
+
+    /// Enforces `a^5 = c` with a single custom gate.
+    pub fn enforce_pow5(&mut self, a: Witness, c: Witness) {
+        self.add_gate(Gate {
+            a,
+            c,
+            q_o: -F::one(),
+            q_h: F::one(),
+            ..Gate::zero()
+        });
+    }
+
+    /// Enforces `a = constant`.
+    pub fn enforce_constant(&mut self, a: Witness, constant: F) {
+        self.add_gate(Gate {
+            a,
+            q_l: F::one(),
+            q_c: -constant,
+            ..Gate::zero()
+        });
+    }
+
+    /// Enforces `a = b`.
+    pub fn enforce_equal(&mut self, a: Witness, b: Witness) {
+        self.add_gate(Gate {
+            a,
+            b,
+            q_l: F::one(),
+            q_r: -F::one(),
+            ..Gate::zero()
+        });
+    }
+
+    /// Returns the number of variables, including the dummy variable.
+    pub fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    /// Returns the number of public inputs.
+    pub fn num_public_inputs(&self) -> usize {
+        self.public_inputs.len()
+    }
+
+    /// Returns the number of rows of the arithmetization: one per public input, followed by one per gate.
+    pub fn num_rows(&self) -> usize {
+        self.public_inputs.len() + self.gates.len()
+    }
+
+    /// Returns the rows of the arithmetization.
+    ///
+    /// The row of the `i`-th public input enforces `a = x_i` together with the public input polynomial,
+    /// and is followed by the gates in the order in which they were added.
+    pub fn rows(&self) -> Vec<Gate<F>> {
+        let public_input_rows = self.public_inputs.iter().map(|&a| Gate {
+            a,
+            q_l: F::one(),
+            ..Gate::zero()
+        });
+        public_input_rows.chain(self.gates.iter().copied()).collect()
+    }
+
+    /// Returns the values of the public inputs.
+    pub fn public_input(&self) -> Result<Vec<F>, SynthesisError> {
+        let assignment = self.assignment.as_ref().ok_or(SynthesisError::AssignmentMissing)?;
+        Ok(self
+            .public_inputs
+            .iter()
+            .map(|variable| assignment[variable.0])
+            .collect())
+    }
+
+    /// Returns `true` if every gate is satisfied, or `None` in setup mode.
+    pub fn is_satisfied(&self) -> Option<bool> {
+        let assignment = self.assignment.as_ref()?;
+        Some(self.gates.iter().all(|gate| {
+            let [a, b, c] = gate.wires();
+            gate.evaluate(assignment[a.0], assignment[b.0], assignment[c.0])
+                .is_zero()
+        }))
+    }
+
+    fn push_variable(&mut self, value: Option<F>) -> Witness {
+        let variable = Witness(self.num_variables);
+        self.num_variables += 1;
+        if let (Some(assignment), Some(value)) = (self.assignment.as_mut(), value) {
+            assignment.push(value);
+        }
+        variable
+    }
+
+    /// Reduces a linear combination to the form `coeff * variable + constant`,
+    /// folding the variable terms into a fresh variable with addition gates if necessary.
+    fn reduce_linear_combination(&mut self, lc: &LinearCombination<F>) -> (F, Witness, F) {
+        let mut constant = F::zero();
+        let mut terms = BTreeMap::<Witness, F>::new();
+        for (variable, coeff) in lc.as_ref() {
+            match variable.get_unchecked() {
+                VarIndex::Public(0) => constant += coeff,
+                VarIndex::Public(i) => *terms.entry(self.r1cs_public_variables[i - 1]).or_insert_with(F::zero) += coeff,
+                VarIndex::Private(i) => *terms.entry(self.r1cs_private_variables[i]).or_insert_with(F::zero) += coeff,
+            }
+        }
+
+        let mut terms = terms.into_iter().filter(|(_, coeff)| !coeff.is_zero());
+        let (mut coeff, mut variable) = match terms.next() {
+            Some((variable, coeff)) => (coeff, variable),
+            None => return (F::zero(), Witness::ZERO, constant),
+        };
+        for (next_variable, next_coeff) in terms {
+            let value = self
+                .value(variable)
+                .zip(self.value(next_variable))
+                .map(|(value, next_value)| coeff * value + next_coeff * next_value);
+            let sum = self.push_variable(value);
+            self.add_gate(Gate {
+                a: variable,
+                b: next_variable,
+                c: sum,
+                q_l: coeff,
+                q_r: next_coeff,
+                q_o: -F::one(),
+                ..Gate::zero()
+            });
+            coeff = F::one();
+            variable = sum;
+        }
+        (coeff, variable, constant)
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for GateSystem<F> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<Fn, A, AR>(&mut self, _: A, f: Fn) -> Result<Variable, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let index = self.r1cs_private_variables.len();
+        let variable = self.alloc_witness(f)?;
+        self.r1cs_private_variables.push(variable);
+        Ok(Variable::new_unchecked(VarIndex::Private(index)))
+    }
+
+    #[inline]
+    fn alloc_input<Fn, A, AR>(&mut self, _: A, f: Fn) -> Result<Variable, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        // The public variable at index zero is the constant one.
+        let index = self.r1cs_public_variables.len() + 1;
+        let variable = self.alloc_public_input(f)?;
+        self.r1cs_public_variables.push(variable);
+        Ok(Variable::new_unchecked(VarIndex::Public(index)))
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let (coeff_a, a, constant_a) = self.reduce_linear_combination(&a(LinearCombination::zero()));
+        let (coeff_b, b, constant_b) = self.reduce_linear_combination(&b(LinearCombination::zero()));
+        let (coeff_c, c, constant_c) = self.reduce_linear_combination(&c(LinearCombination::zero()));
+
+        // (coeff_a * a + constant_a) * (coeff_b * b + constant_b) - (coeff_c * c + constant_c) = 0
+        self.add_gate(Gate {
+            a,
+            b,
+            c,
+            q_m: coeff_a * coeff_b,
+            q_l: coeff_a * constant_b,
+            q_r: constant_a * coeff_b,
+            q_o: -coeff_c,
+            q_c: constant_a * constant_b - constant_c,
+            q_h: F::zero(),
+        });
+        self.num_r1cs_constraints += 1;
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn pop_namespace(&mut self) {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.num_r1cs_constraints
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.r1cs_public_variables.len() + 1
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.r1cs_private_variables.len()
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        self.assignment.is_none()
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_algorithms::SNARKError;
+
+use core::fmt::Debug;
+
+use crate::fiat_shamir::FiatShamirError;
+
+/// A `enum` specifying the possible failure modes of `Plonk`.
+#[derive(Debug)]
+pub enum PlonkError {
+    /// The circuit is too large for the universal public parameters.
+    CircuitTooLarge(usize, usize),
+    /// The synthesized circuit does not match the circuit in the proving key.
+    CircuitMismatch,
+    /// There was an error in Fiat-Shamir.
+    FiatShamirError(FiatShamirError),
+    /// There was a synthesis error.
+    R1CSError(snarkvm_r1cs::SynthesisError),
+    /// There was an error in the underlying polynomial commitment.
+    PolynomialCommitmentError(snarkvm_polycommit::Error),
+    Terminated,
+}
+
+impl From<FiatShamirError> for PlonkError {
+    fn from(err: FiatShamirError) -> Self {
+        PlonkError::FiatShamirError(err)
+    }
+}
+
+impl From<snarkvm_r1cs::SynthesisError> for PlonkError {
+    fn from(err: snarkvm_r1cs::SynthesisError) -> Self {
+        PlonkError::R1CSError(err)
+    }
+}
+
+impl From<snarkvm_polycommit::Error> for PlonkError {
+    fn from(err: snarkvm_polycommit::Error) -> Self {
+        match err {
+            snarkvm_polycommit::Error::Terminated => PlonkError::Terminated,
+            err => PlonkError::PolynomialCommitmentError(err),
+        }
+    }
+}
+
+impl From<PlonkError> for SNARKError {
+    fn from(error: PlonkError) -> Self {
+        match error {
+            PlonkError::Terminated => SNARKError::Terminated,
+            err => SNARKError::Crate("plonk", format!("{:?}", err)),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The preprocessed circuit polynomials.
+mod circuit;
+pub use circuit::*;
+
+/// The Plonk circuit proving key.
+mod circuit_proving_key;
+pub use circuit_proving_key::*;
+
+/// The Plonk circuit verifying key.
+mod circuit_verifying_key;
+pub use circuit_verifying_key::*;
+
+/// The gate system, with the converter from R1CS constraint systems.
+mod constraint_system;
+pub use constraint_system::*;
+
+/// Errors.
+mod errors;
+pub use errors::*;
+
+/// A generic implementation of the Plonk proof system.
+mod plonk;
+pub use plonk::*;
+
+/// The Plonk prepared circuit verifying key.
+mod prepared_circuit_verifying_key;
+pub use prepared_circuit_verifying_key::*;
+
+/// The Plonk zkSNARK proof.
+mod proof;
+pub use proof::*;
+
+/// The implementation of the SNARK trait for Plonk.
+mod snark;
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,711 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    fiat_shamir::traits::FiatShamirRng,
+    marlin::UniversalSRS,
+    plonk::{
+        circuit::{circuit_polynomial_labels, coset_shifts, MIN_DOMAIN_SIZE, PERMUTATION_LABELS, SELECTOR_LABELS},
+        Circuit,
+        CircuitProvingKey,
+        CircuitVerifyingKey,
+        GateSynthesizer,
+        GateSystem,
+        PlonkError,
+        PreparedCircuitVerifyingKey,
+        Proof,
+    },
+    String,
+    ToString,
+    Vec,
+};
+use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain};
+use snarkvm_fields::{batch_inversion, PrimeField};
+use snarkvm_gadgets::nonnative::params::OptimizationType;
+use snarkvm_polycommit::{
+    Evaluations,
+    LabeledCommitment,
+    LabeledPolynomial,
+    PCUniversalParams,
+    PolynomialCommitment,
+    QuerySet,
+};
+use snarkvm_r1cs::SynthesisError;
+use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+use core::{
+    iter,
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use derivative::Derivative;
+use rand_core::RngCore;
+
+/// The labels of the wire polynomials.
+const WIRE_LABELS: [&str; 3] = ["a", "b", "c"];
+
+/// The label of the permutation polynomial.
+const PERMUTATION_LABEL: &str = "z";
+
+/// The number of chunks the quotient polynomial is split into.
+///
+/// As the wire polynomials are blinded to degree `n + 1`, the custom gate `q_h * a^5`
+/// bounds the degree of the quotient polynomial by `5n + 4`, which fits into six chunks of `n` coefficients.
+const NUM_QUOTIENT_CHUNKS: usize = 6;
+
+/// The number of evaluations in a proof: every committed polynomial at the challenge point,
+/// and the permutation polynomial at the shifted challenge point.
+const NUM_EVALUATIONS: usize =
+    WIRE_LABELS.len() + SELECTOR_LABELS.len() + PERMUTATION_LABELS.len() + 1 + NUM_QUOTIENT_CHUNKS + 1;
+
+/// A PLONK-style universal zkSNARK with a custom fifth-power gate.
+///
+/// `V` is the type of the verifier input in the [`SNARK`](snarkvm_algorithms::SNARK) implementation.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct PlonkSNARK<
+    TargetField: PrimeField,
+    BaseField: PrimeField,
+    PC: PolynomialCommitment<TargetField, BaseField>,
+    FS: FiatShamirRng<TargetField, BaseField>,
+    V,
+> {
+    _phantom: PhantomData<(TargetField, BaseField, PC, FS, V)>,
+}
+
+impl<
+        TargetField: PrimeField,
+        BaseField: PrimeField,
+        PC: PolynomialCommitment<TargetField, BaseField>,
+        FS: FiatShamirRng<TargetField, BaseField>,
+        V,
+    > PlonkSNARK<TargetField, BaseField, PC, FS, V>
+{
+    /// The personalization string for this protocol.
+    /// Used to personalize the Fiat-Shamir RNG.
+    pub const PROTOCOL_NAME: &'static [u8] = b"PLONK-2019";
+
+    /// Returns the maximum degree of the universal SRS required for a circuit with `num_rows` rows,
+    /// that is, with `num_rows` public inputs and gates in total.
+    pub fn max_degree(num_rows: usize) -> Result<usize, PlonkError> {
+        let domain_size =
+            EvaluationDomain::<TargetField>::compute_size_of_domain(core::cmp::max(num_rows, MIN_DOMAIN_SIZE))
+                .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        Ok(domain_size + 2)
+    }
+
+    /// Generates the universal proving and verifying keys for the argument system.
+    pub fn universal_setup<R: RngCore>(
+        max_degree: usize,
+        rng: &mut R,
+    ) -> Result<UniversalSRS<TargetField, BaseField, PC>, PlonkError> {
+        let setup_time = start_timer!(|| { format!("Plonk::UniversalSetup with max_degree {}", max_degree,) });
+
+        let srs = PC::setup(max_degree, rng).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
+
+    /// Generate the circuit-specific prover and verifier keys. This is a trusted setup.
+    pub fn circuit_specific_setup<C: GateSynthesizer<TargetField>, R: RngCore>(
+        circuit: &C,
+        rng: &mut R,
+    ) -> Result<
+        (
+            CircuitProvingKey<TargetField, BaseField, PC>,
+            CircuitVerifyingKey<TargetField, BaseField, PC>,
+        ),
+        PlonkError,
+    > {
+        let mut gate_system = GateSystem::new_setup_mode();
+        circuit.synthesize(&mut gate_system)?;
+
+        let srs = Self::universal_setup(Self::max_degree(gate_system.num_rows())?, rng)?;
+        Self::circuit_setup(&srs, circuit)
+    }
+
+    /// Generates the circuit proving and verifying keys.
+    /// This is a deterministic algorithm that anyone can rerun.
+    pub fn circuit_setup<C: GateSynthesizer<TargetField>>(
+        universal_srs: &UniversalSRS<TargetField, BaseField, PC>,
+        circuit: &C,
+    ) -> Result<
+        (
+            CircuitProvingKey<TargetField, BaseField, PC>,
+            CircuitVerifyingKey<TargetField, BaseField, PC>,
+        ),
+        PlonkError,
+    > {
+        let index_time = start_timer!(|| "Plonk::CircuitSetup");
+
+        let mut gate_system = GateSystem::new_setup_mode();
+        circuit.synthesize(&mut gate_system)?;
+        let index = Circuit::index(&gate_system)?;
+
+        let max_degree = index.circuit_info.max_degree();
+        if universal_srs.max_degree() < max_degree {
+            return Err(PlonkError::CircuitTooLarge(universal_srs.max_degree(), max_degree));
+        }
+
+        // Plonk blinds its polynomials with multiples of the vanishing polynomial,
+        // so it does not need hiding commitments.
+        let (committer_key, verifier_key) = PC::trim(universal_srs, max_degree, 0, None)?;
+
+        let commit_time = start_timer!(|| "Commit to circuit polynomials");
+        let (circuit_commitments, circuit_commitment_randomness) = PC::commit(&committer_key, index.iter(), None)?;
+        end_timer!(commit_time);
+
+        let circuit_verifying_key = CircuitVerifyingKey {
+            circuit_info: index.circuit_info,
+            circuit_commitments: circuit_commitments
+                .into_iter()
+                .map(|c| c.commitment().clone())
+                .collect(),
+            verifier_key,
+        };
+
+        let circuit_proving_key = CircuitProvingKey {
+            circuit_verifying_key: circuit_verifying_key.clone(),
+            circuit_commitment_randomness,
+            circuit: index,
+            committer_key,
+        };
+
+        end_timer!(index_time);
+
+        Ok((circuit_proving_key, circuit_verifying_key))
+    }
+
+    /// Create a zkSNARK asserting that the circuit is satisfied.
+    pub fn prove<C: GateSynthesizer<TargetField>, R: RngCore>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC>,
+        circuit: &C,
+        zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, BaseField, PC>, PlonkError> {
+        Self::prove_with_terminator(circuit_proving_key, circuit, &AtomicBool::new(false), zk_rng)
+    }
+
+    /// Same as [`prove`] with an added termination flag, [`terminator`].
+    pub fn prove_with_terminator<C: GateSynthesizer<TargetField>, R: RngCore>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC>,
+        circuit: &C,
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, BaseField, PC>, PlonkError> {
+        let prover_time = start_timer!(|| "Plonk::Prover");
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(PlonkError::Terminated);
+        }
+
+        let mut gate_system = GateSystem::new_proving_mode();
+        circuit.synthesize(&mut gate_system)?;
+
+        let circuit_info = circuit_proving_key.circuit.circuit_info;
+        if gate_system.num_rows() != circuit_info.num_rows
+            || gate_system.num_public_inputs() != circuit_info.num_public_inputs
+        {
+            return Err(PlonkError::CircuitMismatch);
+        }
+
+        let public_input = gate_system.public_input()?;
+        let domain = circuit_info.domain::<TargetField>()?;
+        let n = domain.size();
+
+        let mut fs_rng = FS::new();
+        fs_rng.absorb_bytes(
+            &to_bytes_le![
+                &Self::PROTOCOL_NAME,
+                &circuit_proving_key.circuit_verifying_key,
+                &public_input
+            ]
+            .unwrap(),
+        );
+
+        // --------------------------------------------------------------------
+        // First round: commit to the wire polynomials.
+
+        let mut wire_evaluations = vec![vec![TargetField::zero(); n]; WIRE_LABELS.len()];
+        for (row, gate) in gate_system.rows().iter().enumerate() {
+            for (column, variable) in gate.wires().iter().enumerate() {
+                wire_evaluations[column][row] =
+                    gate_system.value(*variable).ok_or(SynthesisError::AssignmentMissing)?;
+            }
+        }
+
+        let wire_polynomials = WIRE_LABELS
+            .iter()
+            .zip(&wire_evaluations)
+            .map(|(label, evaluations)| {
+                LabeledPolynomial::new(
+                    label.to_string(),
+                    Self::blind(&domain, evaluations, 2, zk_rng),
+                    None,
+                    None,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(PlonkError::Terminated);
+        }
+
+        let first_round_comm_time = start_timer!(|| "Committing to wire polynomials");
+        let (wire_commitments, wire_randomness) =
+            PC::commit(&circuit_proving_key.committer_key, &wire_polynomials, None)?;
+        end_timer!(first_round_comm_time);
+
+        let first_commitments = wire_commitments
+            .iter()
+            .map(|c| c.commitment().clone())
+            .collect::<Vec<_>>();
+        fs_rng.absorb_bytes(&to_bytes_le![first_commitments].unwrap());
+
+        let challenges = fs_rng.squeeze_nonnative_field_elements(2, OptimizationType::Weight)?;
+        let (beta, gamma) = (challenges[0], challenges[1]);
+
+        // --------------------------------------------------------------------
+        // Second round: commit to the permutation polynomial.
+
+        let shifts = coset_shifts::<TargetField>();
+        let elements = domain.elements().collect::<Vec<_>>();
+        let sigma_evaluations = circuit_proving_key
+            .circuit
+            .permutation_polynomials
+            .iter()
+            .map(|sigma| domain.fft(sigma.coeffs()))
+            .collect::<Vec<_>>();
+
+        let mut numerators = Vec::with_capacity(n);
+        let mut denominators = Vec::with_capacity(n);
+        for (i, element) in elements.iter().enumerate() {
+            let mut numerator = TargetField::one();
+            let mut denominator = TargetField::one();
+            for column in 0..WIRE_LABELS.len() {
+                let wire = wire_evaluations[column][i];
+                numerator *= wire + beta * shifts[column] * element + gamma;
+                denominator *= wire + beta * sigma_evaluations[column][i] + gamma;
+            }
+            numerators.push(numerator);
+            denominators.push(denominator);
+        }
+        batch_inversion(&mut denominators);
+
+        let mut z_evaluations = Vec::with_capacity(n);
+        let mut accumulator = TargetField::one();
+        for (numerator, denominator) in numerators.iter().zip(&denominators) {
+            z_evaluations.push(accumulator);
+            accumulator *= *numerator * denominator;
+        }
+
+        let z_polynomial = LabeledPolynomial::new(
+            PERMUTATION_LABEL.to_string(),
+            Self::blind(&domain, &z_evaluations, 3, zk_rng),
+            None,
+            None,
+        );
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(PlonkError::Terminated);
+        }
+
+        let second_round_comm_time = start_timer!(|| "Committing to permutation polynomial");
+        let (z_commitments, z_randomness) =
+            PC::commit(&circuit_proving_key.committer_key, iter::once(&z_polynomial), None)?;
+        end_timer!(second_round_comm_time);
+
+        let second_commitments = z_commitments.iter().map(|c| c.commitment().clone()).collect::<Vec<_>>();
+        fs_rng.absorb_bytes(&to_bytes_le![second_commitments].unwrap());
+
+        let alpha = fs_rng.squeeze_nonnative_field_elements(1, OptimizationType::Weight)?[0];
+
+        // --------------------------------------------------------------------
+        // Third round: commit to the chunks of the quotient polynomial.
+
+        let quotient_time = start_timer!(|| "Computing quotient polynomial");
+        let quotient_domain =
+            EvaluationDomain::<TargetField>::new(8 * n).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let coset_evaluations =
+            |polynomial: &DensePolynomial<TargetField>| quotient_domain.coset_fft(polynomial.coeffs());
+
+        let wires = wire_polynomials
+            .iter()
+            .map(|p| coset_evaluations(p))
+            .collect::<Vec<_>>();
+        let selectors = circuit_proving_key
+            .circuit
+            .selector_polynomials
+            .iter()
+            .map(|p| coset_evaluations(p))
+            .collect::<Vec<_>>();
+        let sigmas = circuit_proving_key
+            .circuit
+            .permutation_polynomials
+            .iter()
+            .map(|p| coset_evaluations(p))
+            .collect::<Vec<_>>();
+        let z = coset_evaluations(&z_polynomial);
+        let z_shifted = {
+            let powers = iter::successors(Some(TargetField::one()), |power| Some(*power * domain.group_gen));
+            let coeffs = z_polynomial
+                .coeffs()
+                .iter()
+                .zip(powers)
+                .map(|(coeff, power)| *coeff * power)
+                .collect();
+            coset_evaluations(&DensePolynomial::from_coefficients_vec(coeffs))
+        };
+        let public_input_evaluations = {
+            let mut evaluations = vec![TargetField::zero(); n];
+            for (evaluation, input) in evaluations.iter_mut().zip(&public_input) {
+                *evaluation = -*input;
+            }
+            coset_evaluations(&DensePolynomial::from_coefficients_vec(domain.ifft(&evaluations)))
+        };
+        let first_lagrange = coset_evaluations(&DensePolynomial::from_coefficients_vec(vec![domain.size_inv; n]));
+
+        // The vanishing polynomial of the domain takes `quotient_domain.size() / n` distinct values over the coset.
+        let ratio = quotient_domain.size() / n;
+        let coset_shift_to_n = TargetField::multiplicative_generator().pow([n as u64]);
+        let root_to_n = quotient_domain.group_gen.pow([n as u64]);
+        let mut vanishing_inverses = iter::successors(Some(coset_shift_to_n), |value| Some(*value * root_to_n))
+            .take(ratio)
+            .map(|value| value - TargetField::one())
+            .collect::<Vec<_>>();
+        batch_inversion(&mut vanishing_inverses);
+
+        let alpha_squared = alpha.square();
+        let mut quotient_evaluations = Vec::with_capacity(quotient_domain.size());
+        for (i, element) in quotient_domain.elements().enumerate() {
+            let x = TargetField::multiplicative_generator() * element;
+            let (a, b, c) = (wires[0][i], wires[1][i], wires[2][i]);
+
+            let gate = selectors[0][i] * a * b
+                + selectors[1][i] * a
+                + selectors[2][i] * b
+                + selectors[3][i] * c
+                + selectors[4][i]
+                + selectors[5][i] * a.square().square() * a
+                + public_input_evaluations[i];
+            let permutation =
+                (a + beta * x + gamma) * (b + beta * shifts[1] * x + gamma) * (c + beta * shifts[2] * x + gamma) * z[i]
+                    - (a + beta * sigmas[0][i] + gamma)
+                        * (b + beta * sigmas[1][i] + gamma)
+                        * (c + beta * sigmas[2][i] + gamma)
+                        * z_shifted[i];
+            let first_row = (z[i] - TargetField::one()) * first_lagrange[i];
+
+            quotient_evaluations
+                .push((gate + alpha * permutation + alpha_squared * first_row) * vanishing_inverses[i % ratio]);
+        }
+
+        let quotient_coeffs = quotient_domain.coset_ifft(&quotient_evaluations);
+        if !quotient_coeffs[NUM_QUOTIENT_CHUNKS * n..]
+            .iter()
+            .all(|coeff| coeff.is_zero())
+        {
+            return Err(PlonkError::R1CSError(SynthesisError::Unsatisfiable));
+        }
+
+        // Blind the chunks so that `t(X) = sum_i X^{i n} t_i(X)` is preserved.
+        let mut chunks = quotient_coeffs[..NUM_QUOTIENT_CHUNKS * n]
+            .chunks(n)
+            .map(|chunk| chunk.to_vec())
+            .collect::<Vec<_>>();
+        for i in 0..NUM_QUOTIENT_CHUNKS - 1 {
+            let carry = TargetField::rand(zk_rng);
+            chunks[i].push(carry);
+            chunks[i + 1][0] -= carry;
+        }
+        let quotient_polynomials = chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, chunk)| {
+                LabeledPolynomial::new(
+                    format!("t_{}", i),
+                    DensePolynomial::from_coefficients_vec(chunk),
+                    None,
+                    None,
+                )
+            })
+            .collect::<Vec<_>>();
+        end_timer!(quotient_time);
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(PlonkError::Terminated);
+        }
+
+        let third_round_comm_time = start_timer!(|| "Committing to quotient polynomials");
+        let (quotient_commitments, quotient_randomness) =
+            PC::commit(&circuit_proving_key.committer_key, &quotient_polynomials, None)?;
+        end_timer!(third_round_comm_time);
+
+        let third_commitments = quotient_commitments
+            .iter()
+            .map(|c| c.commitment().clone())
+            .collect::<Vec<_>>();
+        fs_rng.absorb_bytes(&to_bytes_le![third_commitments].unwrap());
+
+        let zeta = fs_rng.squeeze_nonnative_field_elements(1, OptimizationType::Weight)?[0];
+        let zeta_omega = zeta * domain.group_gen;
+
+        // --------------------------------------------------------------------
+        // Evaluate the polynomials and open the commitments.
+
+        let polynomials = wire_polynomials
+            .iter()
+            .chain(circuit_proving_key.circuit.iter())
+            .chain(iter::once(&z_polynomial))
+            .chain(&quotient_polynomials)
+            .collect::<Vec<_>>();
+
+        let eval_time = start_timer!(|| "Evaluating polynomials over query set");
+        let mut evaluations = polynomials
+            .iter()
+            .map(|polynomial| polynomial.evaluate(zeta))
+            .collect::<Vec<_>>();
+        evaluations.push(z_polynomial.evaluate(zeta_omega));
+        end_timer!(eval_time);
+
+        fs_rng.absorb_bytes(&to_bytes_le![&evaluations].unwrap());
+        let opening_challenge = fs_rng.squeeze_128_bits_nonnative_field_elements(1)?[0];
+
+        let commitments = vec![first_commitments, second_commitments, third_commitments];
+        let labeled_commitments = Self::labeled_commitments(&circuit_proving_key.circuit_verifying_key, &commitments);
+        let randomness = wire_randomness
+            .iter()
+            .chain(&circuit_proving_key.circuit_commitment_randomness)
+            .chain(&z_randomness)
+            .chain(&quotient_randomness);
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(PlonkError::Terminated);
+        }
+
+        let pc_proof = PC::batch_open(
+            &circuit_proving_key.committer_key,
+            polynomials,
+            &labeled_commitments,
+            &Self::query_set(zeta, zeta_omega),
+            opening_challenge,
+            randomness,
+            Some(zk_rng),
+        )?;
+
+        let proof = Proof::new(commitments, evaluations, pc_proof);
+        end_timer!(prover_time);
+
+        Ok(proof)
+    }
+
+    /// Verify that a proof for the circuit asserts that all gates are satisfied.
+    pub fn verify(
+        circuit_verifying_key: &CircuitVerifyingKey<TargetField, BaseField, PC>,
+        public_input: &[TargetField],
+        proof: &Proof<TargetField, BaseField, PC>,
+    ) -> Result<bool, PlonkError> {
+        let verifier_time = start_timer!(|| "Plonk::Verify");
+
+        let circuit_info = circuit_verifying_key.circuit_info;
+        let pc_proofs: Vec<PC::Proof> = proof.pc_proof.clone().into();
+        let proof_has_correct_shape = proof.commitments.len() == 3
+            && proof.commitments[0].len() == WIRE_LABELS.len()
+            && proof.commitments[1].len() == 1
+            && proof.commitments[2].len() == NUM_QUOTIENT_CHUNKS
+            && proof.evaluations.len() == NUM_EVALUATIONS
+            && pc_proofs.len() == 2;
+        if !proof_has_correct_shape || public_input.len() != circuit_info.num_public_inputs {
+            eprintln!(
+                "Proof has an incorrect shape, or the public input has an incorrect length ({})",
+                public_input.len()
+            );
+            return Ok(false);
+        }
+
+        let domain = circuit_info.domain::<TargetField>()?;
+        let n = domain.size();
+
+        let mut fs_rng = FS::new();
+        fs_rng.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME, circuit_verifying_key, public_input].unwrap());
+
+        fs_rng.absorb_bytes(&to_bytes_le![proof.commitments[0]].unwrap());
+        let challenges = fs_rng.squeeze_nonnative_field_elements(2, OptimizationType::Weight)?;
+        let (beta, gamma) = (challenges[0], challenges[1]);
+
+        fs_rng.absorb_bytes(&to_bytes_le![proof.commitments[1]].unwrap());
+        let alpha = fs_rng.squeeze_nonnative_field_elements(1, OptimizationType::Weight)?[0];
+
+        fs_rng.absorb_bytes(&to_bytes_le![proof.commitments[2]].unwrap());
+        let zeta = fs_rng.squeeze_nonnative_field_elements(1, OptimizationType::Weight)?[0];
+        let zeta_omega = zeta * domain.group_gen;
+
+        fs_rng.absorb_bytes(&to_bytes_le![&proof.evaluations].unwrap());
+        let opening_challenge = fs_rng.squeeze_128_bits_nonnative_field_elements(1)?[0];
+
+        // Check the gate, permutation and quotient identity at the challenge point.
+        let vanishing = domain.evaluate_vanishing_polynomial(zeta);
+        if vanishing.is_zero() {
+            return Ok(false);
+        }
+        let lagrange = Self::evaluate_lagrange_coefficients(&domain, zeta, core::cmp::max(public_input.len(), 1));
+        let public_input_evaluation = -public_input
+            .iter()
+            .zip(&lagrange)
+            .map(|(input, lagrange)| *input * lagrange)
+            .sum::<TargetField>();
+
+        let e = &proof.evaluations;
+        let (a, b, c) = (e[0], e[1], e[2]);
+        let (q_m, q_l, q_r, q_o, q_c, q_h) = (e[3], e[4], e[5], e[6], e[7], e[8]);
+        let (sigma_a, sigma_b, sigma_c) = (e[9], e[10], e[11]);
+        let (z, z_shifted) = (e[12], e[NUM_EVALUATIONS - 1]);
+        let zeta_to_n = zeta.pow([n as u64]);
+        let quotient = e[13..13 + NUM_QUOTIENT_CHUNKS]
+            .iter()
+            .rev()
+            .fold(TargetField::zero(), |sum, chunk| sum * zeta_to_n + chunk);
+
+        let shifts = coset_shifts::<TargetField>();
+        let gate =
+            q_m * a * b + q_l * a + q_r * b + q_o * c + q_c + q_h * a.square().square() * a + public_input_evaluation;
+        let permutation = (a + beta * zeta + gamma)
+            * (b + beta * shifts[1] * zeta + gamma)
+            * (c + beta * shifts[2] * zeta + gamma)
+            * z
+            - (a + beta * sigma_a + gamma) * (b + beta * sigma_b + gamma) * (c + beta * sigma_c + gamma) * z_shifted;
+        let first_row = (z - TargetField::one()) * lagrange[0];
+
+        let identity_holds = gate + alpha * permutation + alpha.square() * first_row == quotient * vanishing;
+        if !identity_holds {
+            #[cfg(debug_assertions)]
+            eprintln!("Plonk identity check failed");
+            end_timer!(verifier_time);
+            return Ok(false);
+        }
+
+        // Check the evaluations against the commitments.
+        let mut evaluations = Evaluations::new();
+        for (label, evaluation) in Self::polynomial_labels().zip(e) {
+            evaluations.insert((label, zeta), *evaluation);
+        }
+        evaluations.insert((PERMUTATION_LABEL.to_string(), zeta_omega), z_shifted);
+
+        let evaluations_are_correct = PC::batch_check(
+            &circuit_verifying_key.verifier_key,
+            &Self::labeled_commitments(circuit_verifying_key, &proof.commitments),
+            &Self::query_set(zeta, zeta_omega),
+            &evaluations,
+            &proof.pc_proof,
+            opening_challenge,
+            &mut fs_rng,
+        )?;
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("PC::Check failed");
+        }
+        end_timer!(verifier_time, || format!(
+            " PC::Check for Plonk evaluations: {}",
+            evaluations_are_correct
+        ));
+        Ok(evaluations_are_correct)
+    }
+
+    /// Verify that a proof for the circuit asserts that all gates are satisfied, using a prepared verifying key.
+    pub fn prepared_verify(
+        prepared_verifying_key: &PreparedCircuitVerifyingKey<TargetField, BaseField, PC>,
+        public_input: &[TargetField],
+        proof: &Proof<TargetField, BaseField, PC>,
+    ) -> Result<bool, PlonkError> {
+        Self::verify(&prepared_verifying_key.orig_vk, public_input, proof)
+    }
+
+    /// Interpolates `evaluations` over `domain`, and adds a random multiple of the vanishing polynomial
+    /// with `num_blinders` coefficients, so that the result reveals nothing when opened fewer than
+    /// `num_blinders` times outside of the domain.
+    fn blind<R: RngCore>(
+        domain: &EvaluationDomain<TargetField>,
+        evaluations: &[TargetField],
+        num_blinders: usize,
+        rng: &mut R,
+    ) -> DensePolynomial<TargetField> {
+        let mut coeffs = domain.ifft(evaluations);
+        coeffs.resize(domain.size() + num_blinders, TargetField::zero());
+        for i in 0..num_blinders {
+            let blinder = TargetField::rand(rng);
+            coeffs[i] -= blinder;
+            coeffs[domain.size() + i] += blinder;
+        }
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+
+    /// Returns the first `num_coefficients` Lagrange basis polynomials of `domain`, evaluated at `point`.
+    fn evaluate_lagrange_coefficients(
+        domain: &EvaluationDomain<TargetField>,
+        point: TargetField,
+        num_coefficients: usize,
+    ) -> Vec<TargetField> {
+        let vanishing = domain.evaluate_vanishing_polynomial(point);
+        let mut denominators = domain
+            .elements()
+            .take(num_coefficients)
+            .map(|element| domain.size_as_field_element * (point - element))
+            .collect::<Vec<_>>();
+        batch_inversion(&mut denominators);
+        domain
+            .elements()
+            .zip(denominators)
+            .map(|(element, denominator)| vanishing * element * denominator)
+            .collect()
+    }
+
+    /// Returns the labels of the committed polynomials, in the order of the proof evaluations.
+    fn polynomial_labels() -> impl Iterator<Item = String> {
+        WIRE_LABELS
+            .iter()
+            .map(|label| label.to_string())
+            .chain(circuit_polynomial_labels())
+            .chain(iter::once(PERMUTATION_LABEL.to_string()))
+            .chain((0..NUM_QUOTIENT_CHUNKS).map(|i| format!("t_{}", i)))
+    }
+
+    /// Labels the circuit commitments and the commitments of the proof rounds,
+    /// in the order of the proof evaluations.
+    fn labeled_commitments(
+        circuit_verifying_key: &CircuitVerifyingKey<TargetField, BaseField, PC>,
+        commitments: &[Vec<PC::Commitment>],
+    ) -> Vec<LabeledCommitment<PC::Commitment>> {
+        let commitments = commitments[0]
+            .iter()
+            .chain(&circuit_verifying_key.circuit_commitments)
+            .chain(&commitments[1])
+            .chain(&commitments[2]);
+        Self::polynomial_labels()
+            .zip(commitments)
+            .map(|(label, commitment)| LabeledCommitment::new(label, commitment.clone(), None))
+            .collect()
+    }
+
+    /// Returns the query set, which opens every polynomial at `zeta`,
+    /// and the permutation polynomial at `zeta_omega`.
+    fn query_set(zeta: TargetField, zeta_omega: TargetField) -> QuerySet<'static, TargetField> {
+        let mut query_set: QuerySet<TargetField> = Self::polynomial_labels()
+            .map(|label| (label, ("zeta".to_string(), zeta)))
+            .collect();
+        query_set.insert((PERMUTATION_LABEL.to_string(), ("zeta_omega".to_string(), zeta_omega)));
+        query_set
+    }
+}
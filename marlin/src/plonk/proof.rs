@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Vec;
+use snarkvm_fields::PrimeField;
+use snarkvm_polycommit::PolynomialCommitment;
+use snarkvm_utilities::{error, errors::SerializationError, serialize::*, FromBytes, ToBytes};
+
+use crate::{Read, Write};
+
+/// A zkSNARK proof.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> {
+    /// Commitments to the wire polynomials, the permutation polynomial and the quotient chunks, by round.
+    pub commitments: Vec<Vec<PC::Commitment>>,
+    /// Evaluations of the committed polynomials at the challenge point.
+    pub evaluations: Vec<F>,
+    /// An evaluation proof from the polynomial commitment.
+    pub pc_proof: PC::BatchProof,
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> Proof<F, CF, PC> {
+    /// Construct a new proof.
+    pub fn new(commitments: Vec<Vec<PC::Commitment>>, evaluations: Vec<F>, pc_proof: PC::BatchProof) -> Self {
+        Self {
+            commitments,
+            evaluations,
+            pc_proof,
+        }
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> ToBytes for Proof<F, CF, PC> {
+    fn write_le<W: Write>(&self, mut w: W) -> crate::io::Result<()> {
+        CanonicalSerialize::serialize(self, &mut w).map_err(|_| error("could not serialize Proof"))
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> FromBytes for Proof<F, CF, PC> {
+    fn read_le<R: Read>(mut r: R) -> crate::io::Result<Self> {
+        CanonicalDeserialize::deserialize(&mut r).map_err(|_| error("could not deserialize Proof"))
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use rand::{CryptoRng, Rng};
+
+use crate::{
+    fiat_shamir::FiatShamirRng,
+    marlin::UniversalSRS,
+    plonk::{CircuitProvingKey, CircuitVerifyingKey, PlonkSNARK, PreparedCircuitVerifyingKey, Proof, R1CSCircuit},
+    PolynomialCommitment,
+};
+use core::sync::atomic::AtomicBool;
+use snarkvm_algorithms::{SNARKError, SNARK, SRS};
+use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_r1cs::ConstraintSynthesizer;
+
+impl<TargetField, BaseField, PC, FS, V> SNARK for PlonkSNARK<TargetField, BaseField, PC, FS, V>
+where
+    TargetField: PrimeField,
+    BaseField: PrimeField,
+    PC: PolynomialCommitment<TargetField, BaseField>,
+    FS: FiatShamirRng<TargetField, BaseField>,
+    V: ToConstraintField<TargetField> + Clone,
+{
+    type BaseField = BaseField;
+    type PreparedVerifyingKey = PreparedCircuitVerifyingKey<TargetField, BaseField, PC>;
+    type Proof = Proof<TargetField, BaseField, PC>;
+    type ProvingKey = CircuitProvingKey<TargetField, BaseField, PC>;
+    type ScalarField = TargetField;
+    type UniversalSetupConfig = usize;
+    type UniversalSetupParameters = UniversalSRS<TargetField, BaseField, PC>;
+    type VerifierInput = V;
+    type VerifyingKey = CircuitVerifyingKey<TargetField, BaseField, PC>;
+
+    fn universal_setup<R: Rng + CryptoRng>(
+        max_degree: &Self::UniversalSetupConfig,
+        rng: &mut R,
+    ) -> Result<Self::UniversalSetupParameters, SNARKError> {
+        let setup_time = start_timer!(|| "{Plonk}::Setup");
+        let srs = Self::universal_setup(*max_degree, rng)?;
+        end_timer!(setup_time);
+
+        Ok(srs)
+    }
+
+    fn setup<C: ConstraintSynthesizer<TargetField>, R: Rng + CryptoRng>(
+        circuit: &C,
+        srs: &mut SRS<R, Self::UniversalSetupParameters>,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
+        let (pk, vk) = match srs {
+            SRS::CircuitSpecific(rng) => Self::circuit_specific_setup(&R1CSCircuit(circuit), rng)?,
+            SRS::Universal(srs) => Self::circuit_setup(srs, &R1CSCircuit(circuit))?,
+        };
+        Ok((pk, vk))
+    }
+
+    fn prove_with_terminator<C: ConstraintSynthesizer<TargetField>, R: Rng + CryptoRng>(
+        parameters: &Self::ProvingKey,
+        circuit: &C,
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        match Self::prove_with_terminator(parameters, &R1CSCircuit(circuit), terminator, rng) {
+            Ok(res) => Ok(res),
+            Err(e) => Err(SNARKError::from(e)),
+        }
+    }
+
+    fn verify_prepared(
+        prepared_verifying_key: &Self::PreparedVerifyingKey,
+        input: &Self::VerifierInput,
+        proof: &Self::Proof,
+    ) -> Result<bool, SNARKError> {
+        match Self::prepared_verify(prepared_verifying_key, &input.to_field_elements()?, proof) {
+            Ok(res) => Ok(res),
+            Err(e) => Err(SNARKError::from(e)),
+        }
+    }
+}
@@ -0,0 +1,396 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    fiat_shamir::FiatShamirChaChaRng,
+    marlin::tests::Circuit,
+    plonk::{Gate, GateSynthesizer, GateSystem, PlonkSNARK, R1CSCircuit, Witness},
+};
+use snarkvm_algorithms::{crh::PedersenCRH, CRH, SNARK, SRS};
+use snarkvm_curves::{
+    bls12_377::{Bls12_377, Fq, Fr},
+    edwards_bls12::{EdwardsAffine, EdwardsProjective},
+};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_gadgets::{
+    algorithms::crh::PedersenCRHGadget,
+    curves::edwards_bls12::EdwardsBls12Gadget,
+    integers::uint::UInt8,
+    traits::{algorithms::CRHGadget, alloc::AllocGadget, eq::EqGadget},
+};
+use snarkvm_polycommit::sonic_pc::SonicKZG10;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+use snarkvm_utilities::{
+    rand::{test_rng, UniformRand},
+    FromBytes,
+    ToBytes,
+};
+
+use blake2::Blake2s;
+use core::ops::MulAssign;
+use rand::Rng;
+
+type PC = SonicKZG10<Bls12_377>;
+type PlonkInst = PlonkSNARK<Fr, Fq, PC, FiatShamirChaChaRng<Fr, Fq, Blake2s>, Vec<Fr>>;
+
+const PEDERSEN_NUM_WINDOWS: usize = 2;
+const PEDERSEN_WINDOW_SIZE: usize = 16;
+
+type TestCRH = PedersenCRH<EdwardsProjective, PEDERSEN_NUM_WINDOWS, PEDERSEN_WINDOW_SIZE>;
+type TestCRHGadget =
+    PedersenCRHGadget<EdwardsProjective, Fr, EdwardsBls12Gadget, PEDERSEN_NUM_WINDOWS, PEDERSEN_WINDOW_SIZE>;
+
+/// Enforces `(a + 2b + 3) * (a - b + a) = c - 5`, exercising constants and repeated variables.
+#[derive(Copy, Clone)]
+struct LinearCombinationCircuit<F: Field> {
+    a: Option<F>,
+    b: Option<F>,
+    c: Option<F>,
+}
+
+impl<F: Field> ConstraintSynthesizer<F> for LinearCombinationCircuit<F> {
+    fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let a = cs.alloc(|| "a", || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        let b = cs.alloc(|| "b", || self.b.ok_or(SynthesisError::AssignmentMissing))?;
+        let c = cs.alloc_input(|| "c", || self.c.ok_or(SynthesisError::AssignmentMissing))?;
+
+        let two = F::one().double();
+        let three = two + F::one();
+        let five = three + two;
+        cs.enforce(
+            || "constraint",
+            |lc| lc + a + (two, b) + (three, CS::one()),
+            |lc| lc + a - b + a,
+            |lc| lc + c - (five, CS::one()),
+        );
+
+        Ok(())
+    }
+}
+
+impl<F: Field> LinearCombinationCircuit<F> {
+    fn new(a: F, b: F) -> Self {
+        let two = F::one().double();
+        let three = two + F::one();
+        let c = (a + two * b + three) * (a.double() - b) + three + two;
+        Self {
+            a: Some(a),
+            b: Some(b),
+            c: Some(c),
+        }
+    }
+}
+
+/// Computes `y = (...((x^5 + k)^5 + k)...)^5` over a number of rounds with the custom fifth-power gate.
+#[derive(Copy, Clone)]
+struct PowerCircuit<F: PrimeField> {
+    x: Option<F>,
+    y: Option<F>,
+    constant: F,
+    num_rounds: usize,
+}
+
+impl<F: PrimeField> PowerCircuit<F> {
+    fn new(x: F, constant: F, num_rounds: usize) -> Self {
+        let y = (0..num_rounds).fold(x, |state, _| state.pow([5]) + constant);
+        Self {
+            x: Some(x),
+            y: Some(y),
+            constant,
+            num_rounds,
+        }
+    }
+}
+
+impl<F: PrimeField> GateSynthesizer<F> for PowerCircuit<F> {
+    fn synthesize(&self, gate_system: &mut GateSystem<F>) -> Result<(), SynthesisError> {
+        let mut state = gate_system.alloc_witness(|| self.x.ok_or(SynthesisError::AssignmentMissing))?;
+
+        for _ in 0..self.num_rounds {
+            let value = gate_system.value(state).map(|value| value.pow([5]));
+            let power = gate_system.alloc_witness(|| value.ok_or(SynthesisError::AssignmentMissing))?;
+            gate_system.enforce_pow5(state, power);
+
+            let value = value.map(|value| value + self.constant);
+            let next_state = gate_system.alloc_witness(|| value.ok_or(SynthesisError::AssignmentMissing))?;
+            gate_system.add_gate(Gate {
+                a: power,
+                c: next_state,
+                q_l: F::one(),
+                q_o: -F::one(),
+                q_c: self.constant,
+                ..Gate::zero()
+            });
+            state = next_state;
+        }
+
+        let y = gate_system.alloc_public_input(|| self.y.ok_or(SynthesisError::AssignmentMissing))?;
+        gate_system.enforce_equal(state, y);
+
+        Ok(())
+    }
+}
+
+/// Proves knowledge of a preimage of a public Pedersen hash with the CRH gadget.
+#[derive(Clone)]
+struct PedersenPreimageCircuit {
+    crh: TestCRH,
+    input: [u8; 4],
+    hash: EdwardsAffine,
+}
+
+impl PedersenPreimageCircuit {
+    fn new(crh: TestCRH, input: [u8; 4]) -> Self {
+        let hash = crh.hash(&input).unwrap();
+        Self { crh, input, hash }
+    }
+}
+
+impl ConstraintSynthesizer<Fr> for PedersenPreimageCircuit {
+    fn generate_constraints<CS: ConstraintSystem<Fr>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let input = UInt8::alloc_vec(cs.ns(|| "input"), &self.input)?;
+        let crh = TestCRHGadget::alloc_constant(cs.ns(|| "parameters"), || Ok(self.crh.clone()))?;
+        let hash = crh.check_evaluation_gadget(cs.ns(|| "evaluation"), input)?;
+
+        let expected_hash =
+            <TestCRHGadget as CRHGadget<_, _>>::OutputGadget::alloc_input(cs.ns(|| "hash"), || Ok(self.hash))?;
+        hash.enforce_equal(cs.ns(|| "enforce hash"), &expected_hash)
+    }
+}
+
+#[test]
+fn prove_and_verify_r1cs_circuit() {
+    let rng = &mut test_rng();
+
+    let max_degree = PlonkInst::max_degree(300).unwrap();
+    let universal_srs = PlonkInst::universal_setup(max_degree, rng).unwrap();
+
+    for _ in 0..5 {
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+
+        let circuit = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 100,
+            num_variables: 25,
+        };
+
+        let (pk, vk) = PlonkInst::circuit_setup(&universal_srs, &R1CSCircuit(&circuit)).unwrap();
+        let proof = PlonkInst::prove(&pk, &R1CSCircuit(&circuit), rng).unwrap();
+
+        assert!(PlonkInst::verify(&vk, &[c, d], &proof).unwrap());
+        assert!(!PlonkInst::verify(&vk, &[a, a], &proof).unwrap());
+        assert!(!PlonkInst::verify(&vk, &[c], &proof).unwrap());
+    }
+}
+
+#[test]
+fn prove_and_verify_linear_combinations() {
+    let rng = &mut test_rng();
+
+    let max_degree = PlonkInst::max_degree(16).unwrap();
+    let universal_srs = PlonkInst::universal_setup(max_degree, rng).unwrap();
+
+    let circuit = LinearCombinationCircuit::new(Fr::rand(rng), Fr::rand(rng));
+
+    let mut gate_system = GateSystem::new_proving_mode();
+    circuit.generate_constraints(&mut gate_system).unwrap();
+    assert_eq!(gate_system.is_satisfied(), Some(true));
+
+    let (pk, vk) = PlonkInst::circuit_setup(&universal_srs, &R1CSCircuit(&circuit)).unwrap();
+    let proof = PlonkInst::prove(&pk, &R1CSCircuit(&circuit), rng).unwrap();
+
+    assert!(PlonkInst::verify(&vk, &[circuit.c.unwrap()], &proof).unwrap());
+    assert!(!PlonkInst::verify(&vk, &[circuit.c.unwrap() + Fr::one()], &proof).unwrap());
+}
+
+#[test]
+fn prove_and_verify_gadget_circuit() {
+    let rng = &mut test_rng();
+
+    let circuit = PedersenPreimageCircuit::new(TestCRH::setup("prove_and_verify_gadget_circuit"), rng.gen());
+
+    let mut gate_system = GateSystem::new_proving_mode();
+    circuit.generate_constraints(&mut gate_system).unwrap();
+    assert_eq!(gate_system.is_satisfied(), Some(true));
+
+    let max_degree = PlonkInst::max_degree(gate_system.num_rows()).unwrap();
+    let universal_srs = PlonkInst::universal_setup(max_degree, rng).unwrap();
+
+    let (pk, vk) = PlonkInst::circuit_setup(&universal_srs, &R1CSCircuit(&circuit)).unwrap();
+    let proof = PlonkInst::prove(&pk, &R1CSCircuit(&circuit), rng).unwrap();
+
+    assert!(PlonkInst::verify(&vk, &[circuit.hash.x, circuit.hash.y], &proof).unwrap());
+    assert!(!PlonkInst::verify(&vk, &[circuit.hash.y, circuit.hash.x], &proof).unwrap());
+
+    // A preimage of a different hash does not satisfy the circuit.
+    let mut other_circuit = PedersenPreimageCircuit::new(circuit.crh.clone(), rng.gen());
+    other_circuit.hash = circuit.hash;
+    assert!(PlonkInst::prove(&pk, &R1CSCircuit(&other_circuit), rng).is_err());
+}
+
+#[test]
+fn prove_unsatisfied_circuit_fails() {
+    let rng = &mut test_rng();
+
+    let max_degree = PlonkInst::max_degree(16).unwrap();
+    let universal_srs = PlonkInst::universal_setup(max_degree, rng).unwrap();
+
+    let mut circuit = LinearCombinationCircuit::new(Fr::rand(rng), Fr::rand(rng));
+    let (pk, _) = PlonkInst::circuit_setup(&universal_srs, &R1CSCircuit(&circuit)).unwrap();
+
+    circuit.c = Some(circuit.c.unwrap() + Fr::one());
+
+    let mut gate_system = GateSystem::new_proving_mode();
+    circuit.generate_constraints(&mut gate_system).unwrap();
+    assert_eq!(gate_system.is_satisfied(), Some(false));
+
+    assert!(PlonkInst::prove(&pk, &R1CSCircuit(&circuit), rng).is_err());
+}
+
+#[test]
+fn prove_and_verify_custom_gates() {
+    let rng = &mut test_rng();
+
+    let max_degree = PlonkInst::max_degree(64).unwrap();
+    let universal_srs = PlonkInst::universal_setup(max_degree, rng).unwrap();
+
+    let circuit = PowerCircuit::new(Fr::rand(rng), Fr::rand(rng), 20);
+
+    // Each round takes one fifth-power gate and one addition gate.
+    let mut gate_system = GateSystem::new_proving_mode();
+    circuit.synthesize(&mut gate_system).unwrap();
+    assert_eq!(gate_system.num_rows(), 2 * 20 + 2);
+    assert_eq!(gate_system.is_satisfied(), Some(true));
+    assert_eq!(gate_system.value(Witness::ZERO), Some(Fr::zero()));
+
+    let (pk, vk) = PlonkInst::circuit_setup(&universal_srs, &circuit).unwrap();
+    let proof = PlonkInst::prove(&pk, &circuit, rng).unwrap();
+
+    assert!(PlonkInst::verify(&vk, &[circuit.y.unwrap()], &proof).unwrap());
+    assert!(!PlonkInst::verify(&vk, &[circuit.x.unwrap()], &proof).unwrap());
+}
+
+#[test]
+fn verify_tampered_evaluations_fails() {
+    let rng = &mut test_rng();
+
+    let max_degree = PlonkInst::max_degree(64).unwrap();
+    let universal_srs = PlonkInst::universal_setup(max_degree, rng).unwrap();
+
+    let circuit = PowerCircuit::new(Fr::rand(rng), Fr::rand(rng), 20);
+    let (pk, vk) = PlonkInst::circuit_setup(&universal_srs, &circuit).unwrap();
+    let proof = PlonkInst::prove(&pk, &circuit, rng).unwrap();
+    let public_input = [circuit.y.unwrap()];
+    assert!(PlonkInst::verify(&vk, &public_input, &proof).unwrap());
+
+    // Changing the evaluation of the first quotient chunk breaks the identity at the challenge point.
+    let mut tampered_proof = proof.clone();
+    tampered_proof.evaluations[13] += Fr::one();
+    assert!(!PlonkInst::verify(&vk, &public_input, &tampered_proof).unwrap());
+
+    // So does changing any other evaluation.
+    for i in 0..proof.evaluations.len() {
+        let mut tampered_proof = proof.clone();
+        tampered_proof.evaluations[i] += Fr::one();
+        assert!(!PlonkInst::verify(&vk, &public_input, &tampered_proof).unwrap());
+    }
+}
+
+#[test]
+fn verify_swapped_commitments_fails() {
+    let rng = &mut test_rng();
+
+    let max_degree = PlonkInst::max_degree(64).unwrap();
+    let universal_srs = PlonkInst::universal_setup(max_degree, rng).unwrap();
+
+    let circuit = PowerCircuit::new(Fr::rand(rng), Fr::rand(rng), 20);
+    let (pk, vk) = PlonkInst::circuit_setup(&universal_srs, &circuit).unwrap();
+    let proof = PlonkInst::prove(&pk, &circuit, rng).unwrap();
+    let other_proof = PlonkInst::prove(&pk, &circuit, rng).unwrap();
+    let public_input = [circuit.y.unwrap()];
+    assert!(PlonkInst::verify(&vk, &public_input, &proof).unwrap());
+    assert!(PlonkInst::verify(&vk, &public_input, &other_proof).unwrap());
+
+    // Swap the commitments to two wire polynomials, and to two quotient chunks.
+    for (round, i, j) in [(0, 0, 1), (0, 1, 2), (2, 0, 1)] {
+        let mut tampered_proof = proof.clone();
+        tampered_proof.commitments[round].swap(i, j);
+        assert!(!PlonkInst::verify(&vk, &public_input, &tampered_proof).unwrap());
+    }
+
+    // Swap in the commitments of each round from another valid proof.
+    for round in 0..proof.commitments.len() {
+        let mut tampered_proof = proof.clone();
+        tampered_proof.commitments[round] = other_proof.commitments[round].clone();
+        assert!(!PlonkInst::verify(&vk, &public_input, &tampered_proof).unwrap());
+    }
+}
+
+#[test]
+fn circuit_too_large_for_srs() {
+    let rng = &mut test_rng();
+
+    let universal_srs = PlonkInst::universal_setup(PlonkInst::max_degree(8).unwrap(), rng).unwrap();
+    let circuit = PowerCircuit::new(Fr::rand(rng), Fr::rand(rng), 20);
+
+    assert!(PlonkInst::circuit_setup(&universal_srs, &circuit).is_err());
+}
+
+#[test]
+fn snark_trait() {
+    let rng = &mut test_rng();
+
+    let a = Fr::rand(rng);
+    let b = Fr::rand(rng);
+    let c = a * b;
+    let d = c * b;
+
+    let circuit = Circuit {
+        a: Some(a),
+        b: Some(b),
+        num_constraints: 50,
+        num_variables: 10,
+    };
+
+    let universal_srs = <PlonkInst as SNARK>::universal_setup(&PlonkInst::max_degree(100).unwrap(), rng).unwrap();
+
+    for srs in &mut [SRS::CircuitSpecific(&mut test_rng()), SRS::Universal(&universal_srs)] {
+        let (pk, vk) = <PlonkInst as SNARK>::setup(&circuit, srs).unwrap();
+        let proof = <PlonkInst as SNARK>::prove(&pk, &circuit, rng).unwrap();
+
+        assert!(<PlonkInst as SNARK>::verify(&vk, &vec![c, d], &proof).unwrap());
+        assert!(!<PlonkInst as SNARK>::verify(&vk, &vec![d, c], &proof).unwrap());
+
+        let other_proof = <PlonkInst as SNARK>::prove(&pk, &circuit, rng).unwrap();
+        let inputs = vec![c, d];
+        assert!(<PlonkInst as SNARK>::batch_verify(&vk, &[(&inputs, &proof), (&inputs, &other_proof)], rng).unwrap());
+
+        // Serialize and deserialize the keys and the proof.
+        let pk = <PlonkInst as SNARK>::ProvingKey::read_le(&pk.to_bytes_le().unwrap()[..]).unwrap();
+        let vk = <PlonkInst as SNARK>::VerifyingKey::read_le(&vk.to_bytes_le().unwrap()[..]).unwrap();
+        let proof = <PlonkInst as SNARK>::Proof::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+
+        let new_proof = <PlonkInst as SNARK>::prove(&pk, &circuit, rng).unwrap();
+        assert!(<PlonkInst as SNARK>::verify(&vk, &vec![c, d], &proof).unwrap());
+        assert!(<PlonkInst as SNARK>::verify(&vk, &vec![c, d], &new_proof).unwrap());
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_algorithms::{crh::sha256::sha256, CRH, SNARK, SRS};
+use snarkvm_algorithms::{CRH, SNARK, SRS};
 use snarkvm_dpc::{
     Execution,
     Function,
@@ -29,6 +29,7 @@ use snarkvm_dpc::{
     SynthesizedCircuit,
 };
 use snarkvm_marlin::{ahp::AHPForR1CS, marlin::MarlinTestnet1Mode};
+use snarkvm_parameters::{checksum, versioned_filename};
 use snarkvm_utilities::{FromBytes, ToBytes, ToMinimalBits};
 
 use anyhow::Result;
@@ -40,17 +41,6 @@ use std::{
     path::PathBuf,
 };
 
-fn checksum(bytes: &[u8]) -> String {
-    hex::encode(sha256(bytes))
-}
-
-fn versioned_filename(filename: &str, checksum: &str) -> String {
-    match checksum.get(0..7) {
-        Some(sum) => format!("{}.{}", filename, sum),
-        _ => filename.to_string(),
-    }
-}
-
 /// Writes the given bytes to the given versioned filename.
 fn write_remote(filename: &str, version: &str, bytes: &[u8]) -> Result<()> {
     let mut file = BufWriter::new(File::create(PathBuf::from(&versioned_filename(filename, version)))?);
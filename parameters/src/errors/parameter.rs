@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::fmt::Debug;
+use std::{fmt::Debug, path::PathBuf};
 
 #[derive(Debug, Error)]
 pub enum ParameterError {
@@ -27,6 +27,9 @@ pub enum ParameterError {
     #[error("{}", _0)]
     Message(String),
 
+    #[error("parameter file \"{}\" was not found in {:?}", _0, _1)]
+    MissingParameter(String, PathBuf),
+
     #[error("Remote fetch is disabled, enable compiler flag for feature")]
     RemoteFetchDisabled,
 
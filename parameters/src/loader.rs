@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use once_cell::sync::Lazy;
+use std::{
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+/// The environment variable naming a local directory to load remote parameters from.
+pub const PARAMETERS_DIR_ENV: &str = "SNARKVM_PARAMETERS_DIR";
+
+/// The local directory set with `set_parameters_dir`, if any.
+static PARAMETERS_DIR: Lazy<RwLock<Option<PathBuf>>> = Lazy::new(|| RwLock::new(None));
+
+///
+/// Sets a local directory to load remote parameters from, overriding `SNARKVM_PARAMETERS_DIR`.
+///
+/// The directory is expected to mirror the remote parameter store, holding each file under its
+/// versioned filename (for example, `inner.proving.<checksum prefix>`). While a directory is set,
+/// parameters are never downloaded, and a missing file is reported as an error.
+///
+pub fn set_parameters_dir<P: AsRef<Path>>(directory: P) {
+    *PARAMETERS_DIR.write().expect("Failed to set the parameters directory") = Some(directory.as_ref().to_path_buf());
+}
+
+/// Clears the local directory set with `set_parameters_dir`.
+pub fn clear_parameters_dir() {
+    *PARAMETERS_DIR
+        .write()
+        .expect("Failed to clear the parameters directory") = None;
+}
+
+///
+/// Returns the local directory to load remote parameters from, if one is configured.
+///
+/// A directory set with `set_parameters_dir` takes precedence over `SNARKVM_PARAMETERS_DIR`.
+///
+pub fn parameters_dir() -> Option<PathBuf> {
+    match &*PARAMETERS_DIR.read().expect("Failed to read the parameters directory") {
+        Some(directory) => Some(directory.clone()),
+        None => std::env::var_os(PARAMETERS_DIR_ENV)
+            .filter(|directory| !directory.is_empty())
+            .map(PathBuf::from),
+    }
+}
+
+/// Returns the checksum of the given parameter bytes, as recorded in the parameter metadata.
+pub fn checksum(bytes: &[u8]) -> String {
+    hex::encode(snarkvm_algorithms::crh::sha256::sha256(bytes))
+}
+
+/// Returns the versioned filename of a parameter file, suffixed with a prefix of its checksum.
+pub fn versioned_filename(filename: &str, checksum: &str) -> String {
+    match checksum.get(0..7) {
+        Some(sum) => format!("{}.{}", filename, sum),
+        _ => filename.to_string(),
+    }
+}
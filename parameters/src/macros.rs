@@ -87,17 +87,12 @@ macro_rules! impl_remote {
 
         impl $name {
             pub fn load_bytes() -> Result<Vec<u8>, crate::errors::ParameterError> {
-                const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
-
-                let metadata: serde_json::Value = serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
-                let expected_checksum: String = metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
-                let expected_size: usize = metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
+                // If a local mirror is configured, load the parameter file from it without attempting a download.
+                if let Some(directory) = crate::parameters_dir() {
+                    return Self::load_bytes_from(&directory);
+                }
 
-                // Construct the versioned filename.
-                let filename = match expected_checksum.get(0..7) {
-                    Some(sum) => format!("{}.{}.{}", $fname, $ftype, sum),
-                    _ => concat!($fname, $ftype).to_string()
-                };
+                let (expected_checksum, _, filename) = Self::metadata();
 
                 // Compose the correct file path for the parameter file.
                 let mut file_path = aleo_std::aleo_dir();
@@ -172,7 +167,45 @@ macro_rules! impl_remote {
                     }
                 };
 
-                 // Ensure the size matches.
+                Self::verify_bytes(buffer)
+            }
+
+            /// Loads the parameter file from the given directory, without attempting a download.
+            pub fn load_bytes_from(directory: &std::path::Path) -> Result<Vec<u8>, crate::errors::ParameterError> {
+                let (_, _, filename) = Self::metadata();
+
+                let file_path = directory.join(&filename);
+                if !file_path.exists() {
+                    return Err(crate::errors::ParameterError::MissingParameter(filename, directory.to_path_buf()));
+                }
+
+                Self::verify_bytes(std::fs::read(file_path)?)
+            }
+
+            /// Returns the versioned filename of the parameter file.
+            pub fn filename() -> String {
+                Self::metadata().2
+            }
+
+            /// Returns the expected checksum, expected size, and versioned filename of the parameter file.
+            fn metadata() -> (String, usize, String) {
+                const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
+
+                let metadata: serde_json::Value = serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String = metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize = metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
+
+                // Construct the versioned filename.
+                let filename = crate::versioned_filename(concat!($fname, ".", $ftype), &expected_checksum);
+
+                (expected_checksum, expected_size, filename)
+            }
+
+            /// Ensures the given bytes match the expected size and checksum of the parameter file.
+            fn verify_bytes(buffer: Vec<u8>) -> Result<Vec<u8>, crate::errors::ParameterError> {
+                let (expected_checksum, expected_size, _) = Self::metadata();
+
+                // Ensure the size matches.
                 if expected_size != buffer.len() {
                     return Err(crate::errors::ParameterError::SizeMismatch(expected_size, buffer.len()));
                 }
@@ -183,7 +216,7 @@ macro_rules! impl_remote {
                     return checksum_error!(expected_checksum, candidate_checksum)
                 }
 
-                return Ok(buffer)
+                Ok(buffer)
             }
 
             #[cfg(not(feature = "wasm"))]
@@ -265,6 +298,26 @@ macro_rules! impl_remote {
             fn [< test_ $fname _ $ftype >]() {
                 assert!($name::load_bytes().is_ok());
             }
+
+            #[cfg(test)]
+            #[test]
+            fn [< test_ $fname _ $ftype _from_directory >]() {
+                let directory = std::env::temp_dir().join(concat!("snarkvm_parameters_", module_path!(), "_", $fname, "_", $ftype).replace("::", "_"));
+                let _ = std::fs::remove_dir_all(&directory);
+                std::fs::create_dir_all(&directory).unwrap();
+
+                // Ensure a missing file is reported by name.
+                match $name::load_bytes_from(&directory) {
+                    Err(crate::errors::ParameterError::MissingParameter(filename, _)) => assert_eq!(filename, $name::filename()),
+                    _ => panic!("Expected a missing parameter error"),
+                }
+
+                // Ensure a corrupted file is rejected.
+                std::fs::write(directory.join($name::filename()), b"corrupted").unwrap();
+                assert!($name::load_bytes_from(&directory).is_err());
+
+                std::fs::remove_dir_all(&directory).unwrap();
+            }
         }
     }
 }
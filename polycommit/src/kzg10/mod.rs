@@ -502,7 +502,7 @@ impl<E: PairingEngine> KZG10<E> {
 
 fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &Polynomial<F>) -> (usize, Vec<F::BigInteger>) {
     let mut num_leading_zeros = 0;
-    while p.coeffs[num_leading_zeros].is_zero() && num_leading_zeros < p.coeffs.len() {
+    while num_leading_zeros < p.coeffs.len() && p.coeffs[num_leading_zeros].is_zero() {
         num_leading_zeros += 1;
     }
     let coeffs = convert_to_bigints(&p.coeffs[num_leading_zeros..]);
@@ -136,6 +136,34 @@ pub struct BatchLCProof<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<
     pub evaluations: Option<Vec<F>>,
 }
 
+/// The inputs to `PolynomialCommitment::check_combinations`, used to check
+/// the evaluations of many sets of linear combinations at once.
+#[derive(Clone, Debug)]
+pub struct LinearCombinationsCheck<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> {
+    /// The linear combinations of the committed polynomials.
+    pub linear_combinations: Vec<LinearCombination<F>>,
+    /// The commitments to the polynomials.
+    pub commitments: Vec<LabeledCommitment<PC::Commitment>>,
+    /// The points at which the linear combinations are queried.
+    pub query_set: QuerySet<'static, F>,
+    /// The claimed evaluations of the linear combinations.
+    pub evaluations: Evaluations<'static, F>,
+    /// The proof of the claimed evaluations.
+    pub proof: BatchLCProof<F, CF, PC>,
+    /// The opening challenges.
+    pub opening_challenges: OpeningChallenges<F>,
+}
+
+/// The opening challenges used to check a set of linear combinations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OpeningChallenges<F: PrimeField> {
+    /// A single opening challenge, as used by `PolynomialCommitment::check_combinations`.
+    Single(F),
+    /// The individual opening challenges, as used by
+    /// `PolynomialCommitment::check_combinations_individual_opening_challenges`.
+    Individual(Vec<F>),
+}
+
 impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> PCProof for BatchLCProof<F, CF, PC> {
     fn is_hiding(&self) -> bool {
         self.proof.is_hiding()
@@ -537,6 +565,45 @@ pub trait PolynomialCommitment<F: PrimeField, CF: PrimeField>: Sized + Clone + D
     ) -> Result<bool, Error>
     where
         Self::Commitment: 'a;
+
+    /// Checks many sets of combinations, returning `true` if every set is valid.
+    /// By default, each set is checked separately.
+    fn batch_check_combinations<R: RngCore>(
+        vk: &Self::VerifierKey,
+        checks: &[LinearCombinationsCheck<F, CF, Self>],
+        rng: &mut R,
+    ) -> Result<bool, Error> {
+        for check in checks {
+            let is_valid = match &check.opening_challenges {
+                OpeningChallenges::Single(opening_challenge) => Self::check_combinations(
+                    vk,
+                    &check.linear_combinations,
+                    &check.commitments,
+                    &check.query_set,
+                    &check.evaluations,
+                    &check.proof,
+                    *opening_challenge,
+                    rng,
+                )?,
+                OpeningChallenges::Individual(opening_challenges) => {
+                    Self::check_combinations_individual_opening_challenges(
+                        vk,
+                        &check.linear_combinations,
+                        &check.commitments,
+                        &check.query_set,
+                        &check.evaluations,
+                        &check.proof,
+                        &|i| opening_challenges[i as usize],
+                        rng,
+                    )?
+                }
+            };
+            if !is_valid {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
 
 /// Evaluate the given polynomials at `query_set`.
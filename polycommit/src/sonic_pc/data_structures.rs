@@ -452,3 +452,27 @@ impl<E: PairingEngine> Prepare<PreparedVerifierKey<E>> for VerifierKey<E> {
     Eq(bound = "")
 )]
 pub struct BatchProof<E: PairingEngine>(pub(crate) Vec<kzg10::Proof<E>>);
+
+/// `Accumulator` is a deferred pairing check, accumulated from the opening checks of
+/// one or more sets of combinations, and from other accumulators.
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = ""),
+    Hash(bound = ""),
+    Clone(bound = ""),
+    Debug(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = "")
+)]
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct Accumulator<E: PairingEngine> {
+    /// The combined commitments, grouped by their degree bound.
+    pub combined_commitments: BTreeMap<Option<usize>, E::G1Affine>,
+
+    /// The combined evaluation proofs.
+    pub combined_witness: E::G1Affine,
+
+    /// The combined evaluations, adjusted by the evaluation proofs at their points.
+    pub combined_adjusted_witness: E::G1Affine,
+}
+impl_bytes!(Accumulator);
@@ -0,0 +1,1027 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use core::borrow::Borrow;
+
+use snarkvm_curves::{
+    traits::{AffineCurve, Group, ProjectiveCurve},
+    PairingEngine,
+};
+use snarkvm_fields::Zero;
+use snarkvm_gadgets::{
+    bits::{Boolean, ToBitsLEGadget},
+    fields::FpGadget,
+    nonnative::{NonNativeFieldMulResultVar, NonNativeFieldVar},
+    traits::{
+        alloc::AllocGadget,
+        curves::{GroupGadget, PairingGadget},
+        eq::EqGadget,
+        fields::FieldGadget,
+        select::CondSelectGadget,
+    },
+};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+use crate::{
+    sonic_pc::{
+        gadgets::sonic_kzg10::{LCInfoEntry, SonicKZG10Gadget},
+        prepared_labeled_commitment::PreparedLabeledCommitmentVar,
+        proof::batch_lc_proof::BatchLCProofVar,
+        verifier_key::prepared_verifier_key::PreparedVerifierKeyVar,
+        Accumulator,
+    },
+    BTreeMap,
+    BTreeSet,
+    EvaluationsVar,
+    LabeledPointVar,
+    LinearCombinationVar,
+    PCCheckRandomDataVar,
+    QuerySetVar,
+    String,
+    Vec,
+};
+
+/// Gadget for an accumulator of deferred Sonic-KZG10 opening checks.
+///
+/// The combined commitments have a fixed layout, with one entry without a degree bound and one entry
+/// for each supported degree bound, so that the circuit does not depend on which entries are zero.
+pub struct AccumulatorVar<
+    TargetCurve: PairingEngine,
+    BaseCurve: PairingEngine,
+    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+> {
+    /// The combined commitments, for each degree bound.
+    pub combined_commitments: BTreeMap<Option<usize>, PG::G1Gadget>,
+    /// The combined evaluation proofs.
+    pub combined_witness: PG::G1Gadget,
+    /// The combined evaluations and evaluation proofs, adjusted by the query points.
+    pub combined_adjusted_witness: PG::G1Gadget,
+}
+
+impl<TargetCurve, BaseCurve, PG> Clone for AccumulatorVar<TargetCurve, BaseCurve, PG>
+where
+    TargetCurve: PairingEngine,
+    BaseCurve: PairingEngine,
+    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            combined_commitments: self.combined_commitments.clone(),
+            combined_witness: self.combined_witness.clone(),
+            combined_adjusted_witness: self.combined_adjusted_witness.clone(),
+        }
+    }
+}
+
+impl<TargetCurve, BaseCurve, PG> AccumulatorVar<TargetCurve, BaseCurve, PG>
+where
+    TargetCurve: PairingEngine,
+    BaseCurve: PairingEngine,
+    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+{
+    /// Allocates an accumulator with an entry for each of the given degree bounds.
+    /// Entries that are missing from the accumulator are allocated as zero.
+    pub fn alloc_with_degree_bounds<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Accumulator<TargetCurve>>,
+        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+    >(
+        cs: CS,
+        degree_bounds: &[usize],
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Self::alloc_internal(cs, degree_bounds, value_gen, false)
+    }
+
+    /// Allocates an accumulator, as a public input, with an entry for each of the given degree bounds.
+    /// Entries that are missing from the accumulator are allocated as zero.
+    pub fn alloc_input_with_degree_bounds<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Accumulator<TargetCurve>>,
+        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+    >(
+        cs: CS,
+        degree_bounds: &[usize],
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Self::alloc_internal(cs, degree_bounds, value_gen, true)
+    }
+
+    fn alloc_internal<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Accumulator<TargetCurve>>,
+        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+    >(
+        mut cs: CS,
+        degree_bounds: &[usize],
+        value_gen: Fn,
+        is_input: bool,
+    ) -> Result<Self, SynthesisError> {
+        let accumulator = value_gen().map(|accumulator| accumulator.borrow().clone()).ok();
+
+        let layout: Vec<Option<usize>> = core::iter::once(None)
+            .chain(degree_bounds.iter().map(|degree_bound| Some(*degree_bound)))
+            .collect();
+
+        // An accumulator with an entry outside of the layout cannot be represented.
+        if let Some(accumulator) = &accumulator {
+            if accumulator
+                .combined_commitments
+                .keys()
+                .any(|degree_bound| !layout.contains(degree_bound))
+            {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+        }
+
+        let alloc_point = |cs: &mut CS, name: String, point: Option<TargetCurve::G1Affine>| {
+            let value_gen = || {
+                point
+                    .map(|point| point.into_projective())
+                    .ok_or(SynthesisError::AssignmentMissing)
+            };
+            match is_input {
+                true => PG::G1Gadget::alloc_input(cs.ns(|| name), value_gen),
+                false => PG::G1Gadget::alloc(cs.ns(|| name), value_gen),
+            }
+        };
+
+        let mut combined_commitments = BTreeMap::new();
+        for (i, degree_bound) in layout.into_iter().enumerate() {
+            let comm = accumulator.as_ref().map(|accumulator| {
+                accumulator
+                    .combined_commitments
+                    .get(&degree_bound)
+                    .copied()
+                    .unwrap_or_else(TargetCurve::G1Affine::zero)
+            });
+            let comm = alloc_point(&mut cs, format!("combined_commitment_{}", i), comm)?;
+            combined_commitments.insert(degree_bound, comm);
+        }
+
+        let combined_witness = alloc_point(
+            &mut cs,
+            "combined_witness".into(),
+            accumulator.as_ref().map(|accumulator| accumulator.combined_witness),
+        )?;
+        let combined_adjusted_witness = alloc_point(
+            &mut cs,
+            "combined_adjusted_witness".into(),
+            accumulator
+                .as_ref()
+                .map(|accumulator| accumulator.combined_adjusted_witness),
+        )?;
+
+        Ok(Self {
+            combined_commitments,
+            combined_witness,
+            combined_adjusted_witness,
+        })
+    }
+
+    /// Enforces that the two accumulators are equal. Both accumulators must have the same layout.
+    pub fn enforce_equal<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+    ) -> Result<(), SynthesisError> {
+        if !self.combined_commitments.keys().eq(other.combined_commitments.keys()) {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        for (i, (comm, other_comm)) in self
+            .combined_commitments
+            .values()
+            .zip(other.combined_commitments.values())
+            .enumerate()
+        {
+            comm.enforce_equal(cs.ns(|| format!("combined_commitment_enforce_equal_{}", i)), other_comm)?;
+        }
+        self.combined_witness
+            .enforce_equal(cs.ns(|| "combined_witness_enforce_equal"), &other.combined_witness)?;
+        self.combined_adjusted_witness.enforce_equal(
+            cs.ns(|| "combined_adjusted_witness_enforce_equal"),
+            &other.combined_adjusted_witness,
+        )
+    }
+}
+
+/// Group operations on points of G1 that may be zero.
+///
+/// The addition of the G1 gadget is incomplete, and its zero is the point `(0, 1)`, which is a point of
+/// order three on the curve. Offsetting a scalar multiplication by this point keeps every intermediate
+/// sum outside of the prime-order subgroup, so that none of the additions are degenerate.
+impl<TargetCurve, BaseCurve, PG> AccumulatorVar<TargetCurve, BaseCurve, PG>
+where
+    TargetCurve: PairingEngine,
+    BaseCurve: PairingEngine,
+    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+{
+    /// Returns the zero of G1, and the generator of G1 to substitute for zero inputs.
+    fn zero_and_generator<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+        mut cs: CS,
+    ) -> Result<(PG::G1Gadget, PG::G1Gadget), SynthesisError> {
+        let zero = PG::G1Gadget::zero(cs.ns(|| "zero"))?;
+        let generator = PG::G1Gadget::alloc_constant(cs.ns(|| "generator"), || {
+            Ok(TargetCurve::G1Projective::prime_subgroup_generator())
+        })?;
+        Ok((zero, generator))
+    }
+
+    /// Returns `a + b`, where either point may be zero.
+    fn add<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+        mut cs: CS,
+        a: &PG::G1Gadget,
+        b: &PG::G1Gadget,
+    ) -> Result<PG::G1Gadget, SynthesisError> {
+        let (zero, generator) = Self::zero_and_generator(cs.ns(|| "zero_and_generator"))?;
+        let double_generator = PG::G1Gadget::alloc_constant(cs.ns(|| "double_generator"), || {
+            Ok(TargetCurve::G1Projective::prime_subgroup_generator().double())
+        })?;
+
+        let a_is_zero = a.is_eq(cs.ns(|| "a_is_zero"), &zero)?;
+        let b_is_zero = b.is_eq(cs.ns(|| "b_is_zero"), &zero)?;
+
+        let safe_a = PG::G1Gadget::conditionally_select(cs.ns(|| "safe_a"), &a_is_zero, &generator, a)?;
+        let safe_b = PG::G1Gadget::conditionally_select(cs.ns(|| "safe_b"), &b_is_zero, &double_generator, b)?;
+        let sum = safe_a.add(cs.ns(|| "safe_a_plus_safe_b"), &safe_b)?;
+
+        let sum = PG::G1Gadget::conditionally_select(cs.ns(|| "sum_or_a"), &b_is_zero, a, &sum)?;
+        PG::G1Gadget::conditionally_select(cs.ns(|| "sum_or_b"), &a_is_zero, b, &sum)
+    }
+
+    /// Returns `point - zero` for a point that was offset by zero.
+    fn remove_offset<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+        mut cs: CS,
+        point: &PG::G1Gadget,
+    ) -> Result<PG::G1Gadget, SynthesisError> {
+        let (zero, generator) = Self::zero_and_generator(cs.ns(|| "zero_and_generator"))?;
+
+        let is_offset = point.is_eq(cs.ns(|| "is_offset"), &zero)?;
+        let safe_point = PG::G1Gadget::conditionally_select(cs.ns(|| "safe_point"), &is_offset, &generator, point)?;
+        let difference = safe_point.sub(cs.ns(|| "safe_point_minus_zero"), &zero)?;
+
+        PG::G1Gadget::conditionally_select(cs.ns(|| "difference_or_zero"), &is_offset, &zero, &difference)
+    }
+
+    /// Returns `scalar * base`, where the base may be zero, and the scalar is given in little-endian bits.
+    fn mul_bits<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+        mut cs: CS,
+        base: &PG::G1Gadget,
+        bits: &[Boolean],
+    ) -> Result<PG::G1Gadget, SynthesisError> {
+        let (zero, generator) = Self::zero_and_generator(cs.ns(|| "zero_and_generator"))?;
+
+        let base_is_zero = base.is_eq(cs.ns(|| "base_is_zero"), &zero)?;
+        let safe_base = PG::G1Gadget::conditionally_select(cs.ns(|| "safe_base"), &base_is_zero, &generator, base)?;
+
+        let offset_product = safe_base.mul_bits(cs.ns(|| "safe_base_mul_bits"), &zero, bits.iter().copied())?;
+        let product = Self::remove_offset(cs.ns(|| "remove_offset"), &offset_product)?;
+
+        PG::G1Gadget::conditionally_select(cs.ns(|| "product_or_zero"), &base_is_zero, &zero, &product)
+    }
+
+    /// Returns `scalar * base`, for a base given by its powers of two, and the scalar given in little-endian bits.
+    fn mul_prepared_bits<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+        mut cs: CS,
+        base_powers: &[PG::G1Gadget],
+        bits: &[Boolean],
+    ) -> Result<PG::G1Gadget, SynthesisError> {
+        let mut offset_product = PG::G1Gadget::zero(cs.ns(|| "zero"))?;
+        for (i, (bit, base_power)) in bits.iter().zip(base_powers).enumerate() {
+            let new_encoded =
+                offset_product.add(cs.ns(|| format!("offset_product_plus_base_power_{}", i)), base_power)?;
+            offset_product = PG::G1Gadget::conditionally_select(
+                cs.ns(|| format!("offset_product_cond_select_{}", i)),
+                bit,
+                &new_encoded,
+                &offset_product,
+            )?;
+        }
+        Self::remove_offset(cs.ns(|| "remove_offset"), &offset_product)
+    }
+}
+
+impl<TargetCurve, BaseCurve, PG> SonicKZG10Gadget<TargetCurve, BaseCurve, PG>
+where
+    TargetCurve: PairingEngine<Fq = <BaseCurve as PairingEngine>::Fr>,
+    BaseCurve: PairingEngine,
+    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+{
+    /// Accumulates the opening checks of the given linear combinations, and the given accumulators,
+    /// into a single accumulator, in place of the pairing check of `prepared_check_combinations`.
+    ///
+    /// This is the counterpart of `SonicKZG10::accumulate_combinations`. The batching randomizers
+    /// are used in the same order: one for each accumulator, and then one for each query point.
+    /// The resulting accumulator has an entry for each degree bound of the verifier key.
+    #[allow(clippy::type_complexity, clippy::too_many_arguments)]
+    pub fn prepared_accumulate_combinations<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+        mut cs: CS,
+        prepared_verification_key: &PreparedVerifierKeyVar<TargetCurve, BaseCurve, PG>,
+        accumulators: &[AccumulatorVar<TargetCurve, BaseCurve, PG>],
+        linear_combinations: &[LinearCombinationVar<
+            <TargetCurve as PairingEngine>::Fr,
+            <BaseCurve as PairingEngine>::Fr,
+        >],
+        prepared_commitments: &[PreparedLabeledCommitmentVar<TargetCurve, BaseCurve, PG>],
+        query_set: &QuerySetVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+        evaluations: &EvaluationsVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+        proof: &BatchLCProofVar<TargetCurve, BaseCurve, PG>,
+        rand_data: &PCCheckRandomDataVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+    ) -> Result<AccumulatorVar<TargetCurve, BaseCurve, PG>, SynthesisError> {
+        let BatchLCProofVar { proofs, .. } = proof;
+
+        let (lc_info, evaluations) =
+            Self::prepare_linear_combinations(&mut cs, linear_combinations, prepared_commitments, evaluations)?;
+        let commitment_lcs: BTreeMap<String, Vec<LCInfoEntry<TargetCurve, BaseCurve, PG>>> =
+            lc_info.into_iter().collect();
+
+        // The degree bounds of the verifier key determine the layout of the accumulator.
+        let degree_bounds: Vec<(usize, FpGadget<<BaseCurve as PairingEngine>::Fr>)> = prepared_verification_key
+            .origin_vk
+            .as_ref()
+            .and_then(|vk| vk.degree_bounds_and_neg_powers_of_h.as_ref())
+            .map(|degree_bounds| {
+                degree_bounds
+                    .iter()
+                    .map(|(bound, bound_gadget, _)| (*bound, bound_gadget.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let zero = PG::G1Gadget::zero(cs.ns(|| "g1_zero"))?;
+        let mut combined_comms: BTreeMap<Option<usize>, PG::G1Gadget> = core::iter::once(None)
+            .chain(degree_bounds.iter().map(|(bound, _)| Some(*bound)))
+            .map(|degree_bound| (degree_bound, zero.clone()))
+            .collect();
+        let mut combined_witness = zero.clone();
+        let mut combined_adjusted_witness = zero;
+
+        let mut batching_rands = rand_data.batching_rands.iter().zip(&rand_data.batching_rands_bits);
+
+        // Fold in the given accumulators.
+        for (i, accumulator) in accumulators.iter().enumerate() {
+            let (_, randomizer_bits) = batching_rands.next().ok_or(SynthesisError::Unsatisfiable)?;
+
+            for (j, (degree_bound, comm)) in accumulator.combined_commitments.iter().enumerate() {
+                let comm_times_randomizer = AccumulatorVar::<TargetCurve, BaseCurve, PG>::mul_bits(
+                    cs.ns(|| format!("accumulator_comm_times_randomizer_{}_{}", i, j)),
+                    comm,
+                    randomizer_bits,
+                )?;
+                let combined_comm = combined_comms
+                    .get_mut(degree_bound)
+                    .ok_or(SynthesisError::Unsatisfiable)?;
+                *combined_comm = AccumulatorVar::<TargetCurve, BaseCurve, PG>::add(
+                    cs.ns(|| format!("combined_comm_plus_accumulator_comm_{}_{}", i, j)),
+                    combined_comm,
+                    &comm_times_randomizer,
+                )?;
+            }
+
+            let witness_times_randomizer = AccumulatorVar::<TargetCurve, BaseCurve, PG>::mul_bits(
+                cs.ns(|| format!("accumulator_witness_times_randomizer_{}", i)),
+                &accumulator.combined_witness,
+                randomizer_bits,
+            )?;
+            combined_witness = AccumulatorVar::<TargetCurve, BaseCurve, PG>::add(
+                cs.ns(|| format!("combined_witness_plus_accumulator_witness_{}", i)),
+                &combined_witness,
+                &witness_times_randomizer,
+            )?;
+
+            let adjusted_witness_times_randomizer = AccumulatorVar::<TargetCurve, BaseCurve, PG>::mul_bits(
+                cs.ns(|| format!("accumulator_adjusted_witness_times_randomizer_{}", i)),
+                &accumulator.combined_adjusted_witness,
+                randomizer_bits,
+            )?;
+            combined_adjusted_witness = AccumulatorVar::<TargetCurve, BaseCurve, PG>::add(
+                cs.ns(|| format!("combined_adjusted_witness_plus_accumulator_adjusted_witness_{}", i)),
+                &combined_adjusted_witness,
+                &adjusted_witness_times_randomizer,
+            )?;
+        }
+
+        // Organize a map from queries to linear combinations (referenced by names).
+        let mut query_to_labels_map: BTreeMap<
+            String,
+            (
+                NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+                BTreeSet<&String>,
+            ),
+        > = BTreeMap::new();
+        for (label, point) in query_set.0.iter() {
+            let labels = query_to_labels_map
+                .entry(point.name.clone())
+                .or_insert((point.value.clone(), BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        if proofs.len() != query_to_labels_map.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut g_multiplier =
+            NonNativeFieldMulResultVar::<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>::zero();
+        let mut gamma_g_multiplier =
+            NonNativeFieldMulResultVar::<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>::zero();
+
+        // Accumulate the opening check of each query point.
+        for (i, ((_, (point, labels)), proof)) in query_to_labels_map.into_iter().zip(proofs).enumerate() {
+            let (randomizer, randomizer_bits) = batching_rands.next().ok_or(SynthesisError::Unsatisfiable)?;
+
+            let mut combined_eval = NonNativeFieldMulResultVar::<
+                <TargetCurve as PairingEngine>::Fr,
+                <BaseCurve as PairingEngine>::Fr,
+            >::zero();
+
+            for (j, label) in labels.into_iter().enumerate() {
+                let challenge = rand_data
+                    .opening_challenges
+                    .get(j)
+                    .ok_or(SynthesisError::Unsatisfiable)?;
+                let commitment_lc = commitment_lcs.get(label).ok_or(SynthesisError::Unsatisfiable)?;
+                let value = evaluations
+                    .0
+                    .get(&LabeledPointVar {
+                        name: label.clone(),
+                        value: point.clone(),
+                    })
+                    .ok_or(SynthesisError::Unsatisfiable)?;
+
+                let value_times_challenge =
+                    value.mul_without_reduce(cs.ns(|| format!("value_mul_without_reduce_{}_{}", i, j)), challenge)?;
+                combined_eval = combined_eval.add(
+                    &mut cs.ns(|| format!("combined_eval_add_value_times_challenge_{}_{}", i, j)),
+                    &value_times_challenge,
+                )?;
+
+                let challenge_times_randomizer =
+                    challenge.mul(cs.ns(|| format!("challenge_times_randomizer_{}_{}", i, j)), randomizer)?;
+
+                for (k, entry) in commitment_lc.iter().enumerate() {
+                    let LCInfoEntry {
+                        coeff,
+                        degree_bound,
+                        prepared_comm,
+                        negate,
+                    } = entry;
+
+                    let mut scalar = match coeff {
+                        Some(coeff) => challenge_times_randomizer.mul(
+                            cs.ns(|| format!("challenge_times_randomizer_times_coeff_{}_{}_{}", i, j, k)),
+                            coeff,
+                        )?,
+                        None => challenge_times_randomizer.clone(),
+                    };
+                    if *negate {
+                        scalar = scalar.negate(cs.ns(|| format!("negate_scalar_{}_{}_{}", i, j, k)))?;
+                    }
+                    let scalar_bits = scalar.to_bits_le(cs.ns(|| format!("scalar_to_bits_le_{}_{}_{}", i, j, k)))?;
+
+                    let comm = prepared_comm
+                        .prepared_comm
+                        .first()
+                        .ok_or(SynthesisError::Unsatisfiable)?;
+                    let comm_times_scalar = AccumulatorVar::<TargetCurve, BaseCurve, PG>::mul_bits(
+                        cs.ns(|| format!("comm_times_scalar_{}_{}_{}", i, j, k)),
+                        comm,
+                        &scalar_bits,
+                    )?;
+
+                    match degree_bound {
+                        Some(degree_bound) => {
+                            // Add the commitment to the entry of its degree bound, which must be supported.
+                            let mut num_matches = FpGadget::<<BaseCurve as PairingEngine>::Fr>::zero(
+                                cs.ns(|| format!("num_matches_zero_{}_{}_{}", i, j, k)),
+                            )?;
+                            for (l, (bound, bound_gadget)) in degree_bounds.iter().enumerate() {
+                                let is_match = degree_bound.is_eq(
+                                    cs.ns(|| format!("degree_bound_is_eq_{}_{}_{}_{}", i, j, k, l)),
+                                    bound_gadget,
+                                )?;
+                                let is_match_fp = FpGadget::<<BaseCurve as PairingEngine>::Fr>::from_boolean(
+                                    cs.ns(|| format!("degree_bound_is_eq_from_boolean_{}_{}_{}_{}", i, j, k, l)),
+                                    is_match,
+                                )?;
+                                num_matches = num_matches.add(
+                                    cs.ns(|| format!("num_matches_add_{}_{}_{}_{}", i, j, k, l)),
+                                    &is_match_fp,
+                                )?;
+
+                                let combined_comm = combined_comms
+                                    .get_mut(&Some(*bound))
+                                    .ok_or(SynthesisError::Unsatisfiable)?;
+                                let new_combined_comm = AccumulatorVar::<TargetCurve, BaseCurve, PG>::add(
+                                    cs.ns(|| format!("combined_comm_plus_comm_times_scalar_{}_{}_{}_{}", i, j, k, l)),
+                                    combined_comm,
+                                    &comm_times_scalar,
+                                )?;
+                                *combined_comm = PG::G1Gadget::conditionally_select(
+                                    cs.ns(|| format!("combined_comm_cond_select_{}_{}_{}_{}", i, j, k, l)),
+                                    &is_match,
+                                    &new_combined_comm,
+                                    combined_comm,
+                                )?;
+                            }
+                            let one = FpGadget::<<BaseCurve as PairingEngine>::Fr>::one(
+                                cs.ns(|| format!("num_matches_one_{}_{}_{}", i, j, k)),
+                            )?;
+                            num_matches.enforce_equal(
+                                cs.ns(|| format!("num_matches_enforce_equal_{}_{}_{}", i, j, k)),
+                                &one,
+                            )?;
+                        }
+                        None => {
+                            let combined_comm = combined_comms.get_mut(&None).ok_or(SynthesisError::Unsatisfiable)?;
+                            *combined_comm = AccumulatorVar::<TargetCurve, BaseCurve, PG>::add(
+                                cs.ns(|| format!("combined_comm_plus_comm_times_scalar_{}_{}_{}", i, j, k)),
+                                combined_comm,
+                                &comm_times_scalar,
+                            )?;
+                        }
+                    }
+                }
+            }
+
+            // Add the randomized evaluations to the multipliers of `g` and `gamma_g`.
+            let combined_eval = combined_eval.reduce(&mut cs.ns(|| format!("combined_eval_reduce_{}", i)))?;
+            let randomizer_times_combined_eval = randomizer.mul_without_reduce(
+                cs.ns(|| format!("randomizer_times_combined_eval_{}", i)),
+                &combined_eval,
+            )?;
+            g_multiplier = g_multiplier.add(
+                &mut cs.ns(|| format!("g_multiplier_plus_randomizer_times_combined_eval_{}", i)),
+                &randomizer_times_combined_eval,
+            )?;
+
+            if let Some(random_v) = &proof.random_v {
+                let randomizer_times_random_v =
+                    randomizer.mul_without_reduce(cs.ns(|| format!("randomizer_times_random_v_{}", i)), random_v)?;
+                gamma_g_multiplier = gamma_g_multiplier.add(
+                    &mut cs.ns(|| format!("gamma_g_multiplier_plus_randomizer_times_random_v_{}", i)),
+                    &randomizer_times_random_v,
+                )?;
+            }
+
+            // Add the randomized evaluation proof to the combined witness.
+            let w_times_randomizer = AccumulatorVar::<TargetCurve, BaseCurve, PG>::mul_bits(
+                cs.ns(|| format!("w_times_randomizer_{}", i)),
+                &proof.w,
+                randomizer_bits,
+            )?;
+            combined_witness = AccumulatorVar::<TargetCurve, BaseCurve, PG>::add(
+                cs.ns(|| format!("combined_witness_plus_w_times_randomizer_{}", i)),
+                &combined_witness,
+                &w_times_randomizer,
+            )?;
+
+            // Subtract the randomized evaluation proof, times the point, from the combined adjusted witness.
+            let neg_randomizer_times_point = randomizer
+                .mul(cs.ns(|| format!("randomizer_times_point_{}", i)), &point)?
+                .negate(cs.ns(|| format!("negate_randomizer_times_point_{}", i)))?;
+            let neg_randomizer_times_point_bits = neg_randomizer_times_point
+                .to_bits_le(cs.ns(|| format!("neg_randomizer_times_point_to_bits_le_{}", i)))?;
+            let w_times_neg_randomizer_times_point = AccumulatorVar::<TargetCurve, BaseCurve, PG>::mul_bits(
+                cs.ns(|| format!("w_times_neg_randomizer_times_point_{}", i)),
+                &proof.w,
+                &neg_randomizer_times_point_bits,
+            )?;
+            combined_adjusted_witness = AccumulatorVar::<TargetCurve, BaseCurve, PG>::add(
+                cs.ns(|| format!("combined_adjusted_witness_plus_w_times_point_{}", i)),
+                &combined_adjusted_witness,
+                &w_times_neg_randomizer_times_point,
+            )?;
+        }
+
+        if batching_rands.next().is_some() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // Add the multiples of `g` and `gamma_g` to the combined adjusted witness.
+        let g_multiplier = g_multiplier.reduce(&mut cs.ns(|| "g_multiplier_reduce"))?;
+        let g_multiplier_bits = g_multiplier.to_bits_le(cs.ns(|| "g_multiplier_to_bits_le"))?;
+        let g_times_multiplier = AccumulatorVar::<TargetCurve, BaseCurve, PG>::mul_prepared_bits(
+            cs.ns(|| "g_times_multiplier"),
+            &prepared_verification_key.prepared_g,
+            &g_multiplier_bits,
+        )?;
+        combined_adjusted_witness = AccumulatorVar::<TargetCurve, BaseCurve, PG>::add(
+            cs.ns(|| "combined_adjusted_witness_plus_g_times_multiplier"),
+            &combined_adjusted_witness,
+            &g_times_multiplier,
+        )?;
+
+        let gamma_g_multiplier = gamma_g_multiplier.reduce(&mut cs.ns(|| "gamma_g_multiplier_reduce"))?;
+        let gamma_g_multiplier_bits = gamma_g_multiplier.to_bits_le(cs.ns(|| "gamma_g_multiplier_to_bits_le"))?;
+        let gamma_g_times_multiplier = AccumulatorVar::<TargetCurve, BaseCurve, PG>::mul_prepared_bits(
+            cs.ns(|| "gamma_g_times_multiplier"),
+            &prepared_verification_key.prepared_gamma_g,
+            &gamma_g_multiplier_bits,
+        )?;
+        combined_adjusted_witness = AccumulatorVar::<TargetCurve, BaseCurve, PG>::add(
+            cs.ns(|| "combined_adjusted_witness_plus_gamma_g_times_multiplier"),
+            &combined_adjusted_witness,
+            &gamma_g_times_multiplier,
+        )?;
+
+        Ok(AccumulatorVar {
+            combined_commitments: combined_comms,
+            combined_witness,
+            combined_adjusted_witness,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377, Fq, Fr},
+        bw6_761::BW6_761,
+    };
+    use snarkvm_fields::One;
+    use snarkvm_gadgets::{curves::bls12_377::PairingGadget as Bls12_377PairingGadget, PrepareGadget};
+    use snarkvm_r1cs::TestConstraintSystem;
+    use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+    use crate::{
+        sonic_pc::{gadgets::verifier_key::VerifierKeyVar, Commitment, LabeledCommitmentVar, SonicKZG10, VerifierKey},
+        BatchLCProof,
+        Evaluations,
+        LCTerm,
+        LabeledCommitment,
+        LabeledPolynomial,
+        LinearCombination,
+        LinearCombinationCoeffVar,
+        LinearCombinationsCheck,
+        OpeningChallenges,
+        PCUniversalParams,
+        Polynomial,
+        PolynomialCommitment,
+        QuerySet,
+    };
+
+    use super::*;
+
+    type PC = SonicKZG10<Bls12_377>;
+    type PG = Bls12_377PairingGadget;
+    type BaseCurve = BW6_761;
+
+    const MAX_DEGREE: usize = 383;
+    const SUPPORTED_DEGREE: usize = 300;
+    const SUPPORTED_HIDING_BOUND: usize = 1;
+    const ACCUMULATION_SEED: u64 = 12345;
+
+    /// Returns the batching randomizers that `SonicKZG10::accumulate_combinations` samples from the given seed.
+    fn batching_rands(seed: u64, num_rands: usize) -> Vec<Fr> {
+        let rng = &mut StdRng::seed_from_u64(seed);
+        (0..num_rands).map(|_| u128::rand(rng).into()).collect()
+    }
+
+    /// Accumulates the given check and accumulator in the circuit, and returns whether
+    /// the circuit is satisfied with the result equal to the expected accumulator.
+    #[allow(clippy::too_many_arguments)]
+    fn accumulate_in_circuit(
+        vk: &VerifierKey<Bls12_377>,
+        accumulator: &Accumulator<Bls12_377>,
+        check: &LinearCombinationsCheck<Fr, Fq, PC>,
+        gadget_linear_combinations: &dyn Fn(&mut TestConstraintSystem<Fq>) -> Vec<LinearCombinationVar<Fr, Fq>>,
+        opening_challenges: &[Fr],
+        batching_rands: &[Fr],
+        expected_accumulator: &Accumulator<Bls12_377>,
+    ) -> bool {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        // The accumulators have an entry for each degree bound of the verifier key.
+        let degree_bounds: Vec<usize> = vk
+            .degree_bounds_and_neg_powers_of_h
+            .iter()
+            .flatten()
+            .map(|(degree_bound, _)| *degree_bound)
+            .collect();
+
+        let vk_gadget = VerifierKeyVar::<_, BaseCurve, PG>::alloc(cs.ns(|| "alloc_vk"), || Ok(vk.clone())).unwrap();
+        let prepared_vk_gadget = vk_gadget.prepare(cs.ns(|| "prepare_vk")).unwrap();
+
+        let accumulator_gadget = AccumulatorVar::<_, BaseCurve, PG>::alloc_with_degree_bounds(
+            cs.ns(|| "alloc_accumulator"),
+            &degree_bounds,
+            || Ok(accumulator.clone()),
+        )
+        .unwrap();
+
+        let linear_combinations = gadget_linear_combinations(&mut cs);
+
+        let mut prepared_commitments = Vec::new();
+        for (i, commitment) in check.commitments.iter().enumerate() {
+            let commitment_gadget =
+                LabeledCommitmentVar::<_, BaseCurve, PG>::alloc(cs.ns(|| format!("alloc_commitment_{}", i)), || {
+                    Ok(commitment.clone())
+                })
+                .unwrap();
+            prepared_commitments.push(
+                commitment_gadget
+                    .prepare(cs.ns(|| format!("prepare_commitment_{}", i)))
+                    .unwrap(),
+            );
+        }
+
+        let mut points = BTreeMap::new();
+        let mut query_set = QuerySetVar(Default::default());
+        for (i, (label, (point_name, point))) in check.query_set.iter().enumerate() {
+            let point_gadget = points
+                .entry(point_name.clone())
+                .or_insert_with(|| {
+                    NonNativeFieldVar::alloc(cs.ns(|| format!("alloc_point_{}", i)), || Ok(*point)).unwrap()
+                })
+                .clone();
+            query_set.0.insert((label.clone(), LabeledPointVar {
+                name: point_name.clone(),
+                value: point_gadget,
+            }));
+        }
+
+        let mut evaluations = EvaluationsVar(Default::default());
+        for (i, ((label, point), evaluation)) in check.evaluations.iter().enumerate() {
+            let (point_name, _) = check
+                .query_set
+                .iter()
+                .find(|(query_label, (_, query_point))| query_label == label && query_point == point)
+                .map(|(_, point)| point)
+                .unwrap();
+            let evaluation_gadget =
+                NonNativeFieldVar::alloc(cs.ns(|| format!("alloc_evaluation_{}", i)), || Ok(*evaluation)).unwrap();
+            evaluations.0.insert(
+                LabeledPointVar {
+                    name: label.clone(),
+                    value: points[point_name].clone(),
+                },
+                evaluation_gadget,
+            );
+        }
+
+        let proof_gadget =
+            BatchLCProofVar::<_, BaseCurve, PG>::alloc(cs.ns(|| "alloc_proof"), || Ok(check.proof.clone())).unwrap();
+
+        let mut rand_data = PCCheckRandomDataVar {
+            opening_challenges: Vec::new(),
+            opening_challenges_bits: Vec::new(),
+            batching_rands: Vec::new(),
+            batching_rands_bits: Vec::new(),
+        };
+        for (i, opening_challenge) in opening_challenges.iter().enumerate() {
+            let opening_challenge =
+                NonNativeFieldVar::alloc(cs.ns(|| format!("alloc_opening_challenge_{}", i)), || {
+                    Ok(*opening_challenge)
+                })
+                .unwrap();
+            let opening_challenge_bits = opening_challenge
+                .to_bits_le(cs.ns(|| format!("opening_challenge_to_bits_le_{}", i)))
+                .unwrap();
+            rand_data.opening_challenges.push(opening_challenge);
+            rand_data.opening_challenges_bits.push(opening_challenge_bits);
+        }
+        for (i, batching_rand) in batching_rands.iter().enumerate() {
+            let batching_rand =
+                NonNativeFieldVar::alloc(cs.ns(|| format!("alloc_batching_rand_{}", i)), || Ok(*batching_rand))
+                    .unwrap();
+            let batching_rand_bits = batching_rand
+                .to_bits_le(cs.ns(|| format!("batching_rand_to_bits_le_{}", i)))
+                .unwrap();
+            rand_data.batching_rands.push(batching_rand);
+            rand_data.batching_rands_bits.push(batching_rand_bits);
+        }
+
+        let accumulator_gadget = SonicKZG10Gadget::<_, BaseCurve, PG>::prepared_accumulate_combinations(
+            cs.ns(|| "accumulate_combinations"),
+            &prepared_vk_gadget,
+            &[accumulator_gadget],
+            &linear_combinations,
+            &prepared_commitments,
+            &query_set,
+            &evaluations,
+            &proof_gadget,
+            &rand_data,
+        )
+        .unwrap();
+
+        let expected_accumulator_gadget = AccumulatorVar::<_, BaseCurve, PG>::alloc_with_degree_bounds(
+            cs.ns(|| "alloc_expected_accumulator"),
+            &degree_bounds,
+            || Ok(expected_accumulator.clone()),
+        )
+        .unwrap();
+        accumulator_gadget
+            .enforce_equal(cs.ns(|| "enforce_equal_accumulator"), &expected_accumulator_gadget)
+            .unwrap();
+
+        cs.is_satisfied()
+    }
+
+    /// Returns a verifier key and a valid check of two linear combinations, one with a degree bound,
+    /// along with the coefficient and constant of the second linear combination.
+    fn sample_check<R: Rng>(rng: &mut R) -> (VerifierKey<Bls12_377>, LinearCombinationsCheck<Fr, Fq, PC>, Fr, Fr) {
+        // Construct the universal params.
+        let pp = PC::setup(MAX_DEGREE, rng).unwrap();
+
+        // Establish the bound.
+        let degree_bound = *pp
+            .supported_degree_bounds()
+            .iter()
+            .find(|bound| **bound >= 10 && **bound < SUPPORTED_DEGREE)
+            .unwrap();
+
+        // Construct the keys.
+        let (ck, vk) = PC::trim(&pp, SUPPORTED_DEGREE, SUPPORTED_HIDING_BOUND, Some(&[degree_bound])).unwrap();
+
+        // Commit to one polynomial with a degree bound, and two without.
+        let polynomials = vec![
+            LabeledPolynomial::new("a".into(), Polynomial::rand(10, rng), Some(degree_bound), Some(1)),
+            LabeledPolynomial::new("b".into(), Polynomial::rand(20, rng), None, Some(1)),
+            LabeledPolynomial::new("c".into(), Polynomial::rand(20, rng), None, Some(1)),
+        ];
+        let (commitments, rands) = PC::commit(&ck, &polynomials, Some(rng)).unwrap();
+
+        // Query the polynomial with the degree bound, and a linear combination of the others.
+        let coeff = Fr::rand(rng);
+        let constant = Fr::rand(rng);
+        let linear_combinations = vec![
+            LinearCombination::new("a", vec![(Fr::one(), "a")]),
+            LinearCombination::new("bc", vec![
+                (coeff, LCTerm::from("b")),
+                (-Fr::one(), LCTerm::from("c")),
+                (constant, LCTerm::One),
+            ]),
+        ];
+
+        let (first_point, second_point) = (Fr::rand(rng), Fr::rand(rng));
+        let mut query_set = QuerySet::new();
+        query_set.insert(("a".into(), ("first".into(), first_point)));
+        query_set.insert(("bc".into(), ("first".into(), first_point)));
+        query_set.insert(("bc".into(), ("second".into(), second_point)));
+
+        let mut evaluations = Evaluations::new();
+        for (label, (_, point)) in query_set.iter() {
+            let evaluation = match label.as_str() {
+                "a" => polynomials[0].evaluate(*point),
+                _ => coeff * polynomials[1].evaluate(*point) - polynomials[2].evaluate(*point) + constant,
+            };
+            evaluations.insert((label.clone(), *point), evaluation);
+        }
+
+        let opening_challenges = vec![Fr::rand(rng), Fr::rand(rng)];
+        let proof: BatchLCProof<Fr, Fq, PC> = PC::open_combinations_individual_opening_challenges(
+            &ck,
+            &linear_combinations,
+            &polynomials,
+            &commitments,
+            &query_set,
+            &|i| opening_challenges[i as usize],
+            &rands,
+        )
+        .unwrap();
+
+        let check = LinearCombinationsCheck {
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            opening_challenges: OpeningChallenges::Individual(opening_challenges),
+        };
+        (vk, check, coeff, constant)
+    }
+
+    #[test]
+    fn test_prepared_accumulate_combinations() {
+        let rng = &mut test_rng();
+        let (vk, check, coeff, constant) = sample_check(rng);
+        let opening_challenges = match &check.opening_challenges {
+            OpeningChallenges::Individual(opening_challenges) => opening_challenges.clone(),
+            OpeningChallenges::Single(_) => unreachable!("The check uses individual opening challenges"),
+        };
+
+        // Accumulate the check natively, once into a fresh accumulator, and once more into that accumulator.
+        let accumulator = PC::accumulate_combinations(&vk, &[], core::slice::from_ref(&check), rng)
+            .unwrap()
+            .unwrap();
+        let expected_accumulator = PC::accumulate_combinations(
+            &vk,
+            core::slice::from_ref(&accumulator),
+            core::slice::from_ref(&check),
+            &mut StdRng::seed_from_u64(ACCUMULATION_SEED),
+        )
+        .unwrap()
+        .unwrap();
+        assert!(PC::check_accumulator(&vk, &expected_accumulator).unwrap());
+
+        // The gadget uses one batching randomizer for the accumulator, and one for each query point.
+        let batching_rands = batching_rands(ACCUMULATION_SEED, 3);
+
+        let label_commitment = |label: &str| -> &LabeledCommitment<Commitment<Bls12_377>> {
+            check.commitments.iter().find(|c| c.label() == label).unwrap()
+        };
+        assert!(label_commitment("a").degree_bound().is_some());
+
+        let gadget_linear_combinations = |cs: &mut TestConstraintSystem<Fq>| {
+            let coeff = NonNativeFieldVar::alloc(cs.ns(|| "alloc_coeff"), || Ok(coeff)).unwrap();
+            let constant = NonNativeFieldVar::alloc(cs.ns(|| "alloc_constant"), || Ok(constant)).unwrap();
+            vec![
+                LinearCombinationVar {
+                    label: "a".into(),
+                    terms: vec![(LinearCombinationCoeffVar::One, "a".into())],
+                },
+                LinearCombinationVar {
+                    label: "bc".into(),
+                    terms: vec![
+                        (LinearCombinationCoeffVar::Var(coeff), "b".into()),
+                        (LinearCombinationCoeffVar::MinusOne, "c".into()),
+                        (LinearCombinationCoeffVar::Var(constant), LCTerm::One),
+                    ],
+                },
+            ]
+        };
+
+        assert!(accumulate_in_circuit(
+            &vk,
+            &accumulator,
+            &check,
+            &gadget_linear_combinations,
+            &opening_challenges,
+            &batching_rands,
+            &expected_accumulator,
+        ));
+
+        // The circuit is not satisfied with an accumulator that does not include the check.
+        assert!(!accumulate_in_circuit(
+            &vk,
+            &accumulator,
+            &check,
+            &gadget_linear_combinations,
+            &opening_challenges,
+            &batching_rands,
+            &accumulator,
+        ));
+    }
+    #[test]
+    fn test_accumulate_invalid_combinations() {
+        let rng = &mut test_rng();
+        let (vk, check, _, _) = sample_check(rng);
+
+        // Accumulate the valid check.
+        let accumulator = PC::accumulate_combinations(
+            &vk,
+            &[],
+            core::slice::from_ref(&check),
+            &mut StdRng::seed_from_u64(ACCUMULATION_SEED),
+        )
+        .unwrap()
+        .unwrap();
+        assert!(PC::check_accumulator(&vk, &accumulator).unwrap());
+
+        // Change one evaluation of the check.
+        let mut invalid_evaluation = check.clone();
+        let evaluation = invalid_evaluation.evaluations.values_mut().next().unwrap();
+        *evaluation += Fr::one();
+
+        // Change the witness of one opening proof of the check.
+        let mut invalid_proof = check.clone();
+        let proof = &mut invalid_proof.proof.proof[0];
+        proof.w = (proof.w.into_projective() + vk.vk.g.into_projective()).into_affine();
+
+        for invalid_check in [invalid_evaluation, invalid_proof] {
+            // Ensure the deferred pairing check of the accumulated invalid check fails.
+            let invalid_accumulator = PC::accumulate_combinations(&vk, &[], core::slice::from_ref(&invalid_check), rng)
+                .unwrap()
+                .unwrap();
+            assert!(!PC::check_accumulator(&vk, &invalid_accumulator).unwrap());
+
+            // Ensure the accumulator of the valid check is not the accumulation of the invalid check.
+            assert!(
+                !PC::verify_accumulation(
+                    &vk,
+                    &[],
+                    core::slice::from_ref(&invalid_check),
+                    &accumulator,
+                    &mut StdRng::seed_from_u64(ACCUMULATION_SEED),
+                )
+                .unwrap()
+            );
+        }
+    }
+}
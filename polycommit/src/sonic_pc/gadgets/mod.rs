@@ -14,6 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+/// Gadgets for accumulating Sonic-KZG10 opening checks.
+pub mod accumulator;
+pub use accumulator::*;
+
 /// Gadgets for Sonic-KZG10 commitments.
 pub mod commitment;
 pub(crate) use commitment::*;
@@ -124,6 +124,103 @@ where
     BaseCurve: PairingEngine,
     PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
 {
+    /// Returns the commitments of each linear combination, with their coefficients,
+    /// and the evaluations adjusted for the constant terms of the linear combinations.
+    #[allow(clippy::type_complexity)]
+    pub(super) fn prepare_linear_combinations<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+        mut cs: CS,
+        linear_combinations: &[LinearCombinationVar<
+            <TargetCurve as PairingEngine>::Fr,
+            <BaseCurve as PairingEngine>::Fr,
+        >],
+        prepared_commitments: &[PreparedLabeledCommitmentVar<TargetCurve, BaseCurve, PG>],
+        evaluations: &EvaluationsVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+    ) -> Result<
+        (
+            Vec<(String, Vec<LCInfoEntry<TargetCurve, BaseCurve, PG>>)>,
+            EvaluationsVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+        ),
+        SynthesisError,
+    > {
+        let label_comm_map = prepared_commitments
+            .iter()
+            .map(|c| (c.label.clone(), c))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut lc_info = Vec::new();
+        let mut evaluations = evaluations.clone();
+
+        // For each linear combination, we sum up the relevant commitments, multiplied
+        // with their corresponding coefficients; these combined commitments are then
+        // the inputs to the normal batch check.
+        for (i, lc) in linear_combinations.iter().enumerate() {
+            let lc_label = lc.label.clone();
+            let num_polys = lc.terms.len();
+
+            let mut coeffs_and_comms = Vec::new();
+
+            for (j, (coeff, label)) in lc.terms.iter().enumerate() {
+                if label.is_one() {
+                    for (k, (label, ref mut eval)) in evaluations.0.iter_mut().enumerate() {
+                        if label.name == lc_label {
+                            match coeff.clone() {
+                                LinearCombinationCoeffVar::One => {
+                                    let one = NonNativeFieldVar::one(cs.ns(|| format!("coeff_one_{}_{}_{}", i, j, k)))?;
+                                    **eval =
+                                        (**eval).sub(cs.ns(|| format!("eval_minus_one_{}_{}_{}", i, j, k)), &one)?;
+                                }
+                                LinearCombinationCoeffVar::MinusOne => {
+                                    let one = NonNativeFieldVar::one(cs.ns(|| format!("coeff_one_{}_{}_{}", i, j, k)))?;
+                                    **eval = (**eval).add(cs.ns(|| format!("eval_add_one_{}_{}_{}", i, j, k)), &one)?;
+                                }
+                                LinearCombinationCoeffVar::Var(variable) => {
+                                    **eval = (**eval)
+                                        .sub(cs.ns(|| format!("eval_minus_variable_{}_{}_{}", i, j, k)), &variable)?
+                                }
+                            };
+                        }
+                    }
+                } else {
+                    let label: &String = label.try_into().unwrap();
+                    let &cur_comm = label_comm_map.get(label).unwrap();
+                    let negate = match coeff {
+                        LinearCombinationCoeffVar::One | LinearCombinationCoeffVar::Var(_) => false,
+                        LinearCombinationCoeffVar::MinusOne => true,
+                    };
+
+                    if num_polys == 1 && cur_comm.degree_bound.is_some() {
+                        assert!(
+                            *coeff == LinearCombinationCoeffVar::One,
+                            "Coefficient must be one for degree-bounded equations"
+                        );
+                    } else if cur_comm.degree_bound.is_some() {
+                        eprintln!(
+                            "A commitment with a degree bound cannot be linearly combined with any other commitment."
+                        );
+                        return Err(SynthesisError::Unsatisfiable);
+                    }
+
+                    let coeff = match coeff {
+                        LinearCombinationCoeffVar::One => None,
+                        LinearCombinationCoeffVar::MinusOne => None,
+                        LinearCombinationCoeffVar::Var(variable) => Some(variable.clone()),
+                    };
+
+                    coeffs_and_comms.push(LCInfoEntry::new(
+                        &coeff,
+                        &cur_comm.degree_bound,
+                        &cur_comm.prepared_commitment,
+                        negate,
+                    ));
+                }
+            }
+
+            lc_info.push((lc_label, coeffs_and_comms));
+        }
+
+        Ok((lc_info, evaluations))
+    }
+
     #[allow(clippy::type_complexity, clippy::too_many_arguments)]
     fn prepared_batch_check_evaluations<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
         mut cs: CS,
@@ -594,80 +691,8 @@ where
     ) -> Result<Boolean, SynthesisError> {
         let BatchLCProofVar { proofs, .. } = proof;
 
-        let label_comm_map = prepared_commitments
-            .iter()
-            .map(|c| (c.label.clone(), c))
-            .collect::<BTreeMap<_, _>>();
-
-        let mut lc_info = Vec::new();
-        let mut evaluations = evaluations.clone();
-
-        // For each linear combination, we sum up the relevant commitments, multiplied
-        // with their corresponding coefficients; these combined commitments are then
-        // the inputs to the normal batch check.
-        for (i, lc) in linear_combinations.iter().enumerate() {
-            let lc_label = lc.label.clone();
-            let num_polys = lc.terms.len();
-
-            let mut coeffs_and_comms = Vec::new();
-
-            for (j, (coeff, label)) in lc.terms.iter().enumerate() {
-                if label.is_one() {
-                    for (label, ref mut eval) in evaluations.0.iter_mut() {
-                        if label.name == lc_label {
-                            match coeff.clone() {
-                                LinearCombinationCoeffVar::One => {
-                                    let one = NonNativeFieldVar::one(cs.ns(|| format!("coeff_one_{}_{}", i, j)))?;
-                                    **eval = (**eval).sub(cs.ns(|| format!("eval_minus_one_{}_{}", i, j)), &one)?;
-                                }
-                                LinearCombinationCoeffVar::MinusOne => {
-                                    let one = NonNativeFieldVar::one(cs.ns(|| format!("coeff_one_{}_{}", i, j)))?;
-                                    **eval = (**eval).add(cs.ns(|| format!("eval_add_one_{}_{}", i, j)), &one)?;
-                                }
-                                LinearCombinationCoeffVar::Var(variable) => {
-                                    **eval =
-                                        (**eval).sub(cs.ns(|| format!("eval_minus_variable_{}_{}", i, j)), &variable)?
-                                }
-                            };
-                        }
-                    }
-                } else {
-                    let label: &String = label.try_into().unwrap();
-                    let &cur_comm = label_comm_map.get(label).unwrap();
-                    let negate = match coeff {
-                        LinearCombinationCoeffVar::One | LinearCombinationCoeffVar::Var(_) => false,
-                        LinearCombinationCoeffVar::MinusOne => true,
-                    };
-
-                    if num_polys == 1 && cur_comm.degree_bound.is_some() {
-                        assert!(
-                            *coeff == LinearCombinationCoeffVar::One,
-                            "Coefficient must be one for degree-bounded equations"
-                        );
-                    } else if cur_comm.degree_bound.is_some() {
-                        eprintln!(
-                            "A commitment with a degree bound cannot be linearly combined with any other commitment."
-                        );
-                        return Err(SynthesisError::Unsatisfiable);
-                    }
-
-                    let coeff = match coeff {
-                        LinearCombinationCoeffVar::One => None,
-                        LinearCombinationCoeffVar::MinusOne => None,
-                        LinearCombinationCoeffVar::Var(variable) => Some(variable.clone()),
-                    };
-
-                    coeffs_and_comms.push(LCInfoEntry::new(
-                        &coeff,
-                        &cur_comm.degree_bound,
-                        &cur_comm.prepared_commitment,
-                        negate,
-                    ));
-                }
-            }
-
-            lc_info.push((lc_label, coeffs_and_comms));
-        }
+        let (lc_info, evaluations) =
+            Self::prepare_linear_combinations(&mut cs, linear_combinations, prepared_commitments, evaluations)?;
 
         Self::prepared_batch_check_evaluations(
             cs,
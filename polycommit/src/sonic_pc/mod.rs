@@ -24,6 +24,8 @@ use crate::{
     LabeledCommitment,
     LabeledPolynomial,
     LinearCombination,
+    LinearCombinationsCheck,
+    OpeningChallenges,
     PCCommitterKey,
     PCRandomness,
     PCUniversalParams,
@@ -36,7 +38,7 @@ use crate::{
     Vec,
 };
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{One, Zero};
+use snarkvm_fields::{Field, One, Zero};
 use snarkvm_utilities::rand::UniformRand;
 
 use core::{
@@ -697,6 +699,200 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
         Self::Commitment: 'a,
     {
         let BatchLCProof { proof, .. } = proof;
+        let (lc_commitments, evaluations) =
+            Self::combine_linear_combinations(linear_combinations, commitments, evaluations)?;
+
+        Self::batch_check_individual_opening_challenges(
+            vk,
+            &lc_commitments,
+            query_set,
+            &evaluations,
+            proof,
+            opening_challenges,
+            rng,
+        )
+    }
+
+    /// Checks many sets of combinations, using a single pairing product over
+    /// a random linear combination of all of their opening checks.
+    fn batch_check_combinations<R: RngCore>(
+        vk: &Self::VerifierKey,
+        checks: &[LinearCombinationsCheck<E::Fr, E::Fq, Self>],
+        rng: &mut R,
+    ) -> Result<bool, Error> {
+        let batch_check_time = start_timer!(|| format!("Batch checking {} sets of combinations", checks.len()));
+        let result = match Self::accumulate_combinations(vk, &[], checks, rng)? {
+            Some(accumulator) => Self::check_accumulator(vk, &accumulator),
+            None => Ok(false),
+        };
+        end_timer!(batch_check_time);
+        result
+    }
+}
+
+impl<E: PairingEngine> SonicKZG10<E> {
+    /// Accumulates the opening checks of the given sets of combinations, and the given accumulators,
+    /// into a single accumulator, using a random linear combination. No pairings are computed; the
+    /// pairing check is deferred to [`check_accumulator`](Self::check_accumulator).
+    ///
+    /// Returns `None` if a set of combinations has the wrong number of evaluation proofs.
+    pub fn accumulate_combinations<R: RngCore>(
+        vk: &VerifierKey<E>,
+        accumulators: &[Accumulator<E>],
+        checks: &[LinearCombinationsCheck<E::Fr, E::Fq, Self>],
+        rng: &mut R,
+    ) -> Result<Option<Accumulator<E>>, Error> {
+        let accumulate_time = start_timer!(|| format!(
+            "Accumulating {} accumulators and {} sets of combinations",
+            accumulators.len(),
+            checks.len()
+        ));
+
+        let mut combined_comms = BTreeMap::new();
+        let mut combined_witness = E::G1Projective::zero();
+        let mut combined_adjusted_witness = E::G1Projective::zero();
+
+        for accumulator in accumulators {
+            let randomizer: E::Fr = u128::rand(rng).into();
+            for (degree_bound, comm) in &accumulator.combined_commitments {
+                *combined_comms
+                    .entry(*degree_bound)
+                    .or_insert_with(E::G1Projective::zero) += &comm.into_projective().mul(randomizer);
+            }
+            combined_witness += &accumulator.combined_witness.into_projective().mul(randomizer);
+            combined_adjusted_witness += &accumulator.combined_adjusted_witness.into_projective().mul(randomizer);
+        }
+
+        for check in checks {
+            let (lc_commitments, evaluations) =
+                Self::combine_linear_combinations(&check.linear_combinations, &check.commitments, &check.evaluations)?;
+            let commitments: BTreeMap<_, _> = lc_commitments.iter().map(|c| (c.label(), c)).collect();
+
+            let mut query_to_labels_map = BTreeMap::new();
+            for (label, (point_name, point)) in check.query_set.iter() {
+                let labels = query_to_labels_map
+                    .entry(point_name)
+                    .or_insert((point, BTreeSet::new()));
+                labels.1.insert(label);
+            }
+
+            // Implicit assumption: proofs are order in same manner as queries in
+            // `query_to_labels_map`.
+            let proofs = &check.proof.proof;
+            if proofs.len() != query_to_labels_map.len() {
+                end_timer!(accumulate_time);
+                return Ok(None);
+            }
+
+            // A single opening challenge is applied in increasing powers, starting from the challenge itself.
+            let opening_challenges = |i: u64| match &check.opening_challenges {
+                OpeningChallenges::Single(opening_challenge) => opening_challenge.pow([i + 1]),
+                OpeningChallenges::Individual(opening_challenges) => opening_challenges[i as usize],
+            };
+            for ((_point_name, (point, labels)), proof) in query_to_labels_map.into_iter().zip(proofs) {
+                let mut comms: Vec<&'_ LabeledCommitment<_>> = Vec::new();
+                let mut values = Vec::new();
+                for label in labels {
+                    let commitment = commitments.get(label).ok_or(Error::MissingPolynomial {
+                        label: label.to_string(),
+                    })?;
+
+                    let v_i = evaluations
+                        .get(&(label.clone(), *point))
+                        .ok_or(Error::MissingEvaluation {
+                            label: label.to_string(),
+                        })?;
+
+                    comms.push(*commitment);
+                    values.push(*v_i);
+                }
+
+                Self::accumulate_elems_individual_opening_challenges(
+                    &mut combined_comms,
+                    &mut combined_witness,
+                    &mut combined_adjusted_witness,
+                    vk,
+                    comms,
+                    *point,
+                    values,
+                    proof,
+                    &opening_challenges,
+                    Some(u128::rand(rng).into()),
+                );
+            }
+        }
+
+        let accumulator = Self::normalize_accumulator(combined_comms, combined_witness, combined_adjusted_witness);
+        end_timer!(accumulate_time);
+        Ok(Some(accumulator))
+    }
+
+    /// Verifies that the given accumulator is the accumulation of the given sets of combinations and
+    /// accumulators, using the same randomness as [`accumulate_combinations`](Self::accumulate_combinations).
+    /// No pairings are computed.
+    pub fn verify_accumulation<R: RngCore>(
+        vk: &VerifierKey<E>,
+        accumulators: &[Accumulator<E>],
+        checks: &[LinearCombinationsCheck<E::Fr, E::Fq, Self>],
+        accumulator: &Accumulator<E>,
+        rng: &mut R,
+    ) -> Result<bool, Error> {
+        let expected_accumulator = Self::accumulate_combinations(vk, accumulators, checks, rng)?;
+        Ok(expected_accumulator.as_ref() == Some(accumulator))
+    }
+
+    /// Checks the deferred pairing check of the given accumulator,
+    /// using one pairing per degree bound in the accumulator, and two more.
+    pub fn check_accumulator(vk: &VerifierKey<E>, accumulator: &Accumulator<E>) -> Result<bool, Error> {
+        let combined_comms = accumulator
+            .combined_commitments
+            .iter()
+            .map(|(degree_bound, comm)| (*degree_bound, comm.into_projective()))
+            .collect();
+
+        Self::check_elems(
+            combined_comms,
+            accumulator.combined_witness.into_projective(),
+            accumulator.combined_adjusted_witness.into_projective(),
+            vk,
+        )
+    }
+
+    /// Returns the accumulator with the given combined elements, in affine form.
+    /// Combined commitments that are zero are omitted, so that equal accumulators have equal representations.
+    fn normalize_accumulator(
+        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+        combined_witness: E::G1Projective,
+        combined_adjusted_witness: E::G1Projective,
+    ) -> Accumulator<E> {
+        let (degree_bounds, mut elems): (Vec<_>, Vec<_>) = combined_comms.into_iter().unzip();
+        elems.push(combined_witness);
+        elems.push(combined_adjusted_witness);
+
+        let mut elems = E::G1Projective::batch_normalization_into_affine(elems);
+        let combined_adjusted_witness = elems.pop().unwrap();
+        let combined_witness = elems.pop().unwrap();
+
+        Accumulator {
+            combined_commitments: degree_bounds
+                .into_iter()
+                .zip(elems)
+                .filter(|(_, comm)| !comm.is_zero())
+                .collect(),
+            combined_witness,
+            combined_adjusted_witness,
+        }
+    }
+}
+
+impl<E: PairingEngine> SonicKZG10<E> {
+    /// Returns the commitments to the given linear combinations, and the evaluations
+    /// adjusted for the constant terms of the linear combinations.
+    fn combine_linear_combinations<'a>(
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        evaluations: &Evaluations<E::Fr>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Evaluations<'static, E::Fr>), Error> {
         let label_comm_map = commitments
             .into_iter()
             .map(|c| (c.label(), c))
@@ -751,15 +947,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check_individual_opening_challenges(
-            vk,
-            &lc_commitments,
-            query_set,
-            &evaluations,
-            proof,
-            opening_challenges,
-            rng,
-        )
+        Ok((lc_commitments, evaluations))
     }
 }
 
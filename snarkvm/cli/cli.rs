@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use std::path::PathBuf;
 use structopt::StructOpt;
 
 #[derive(StructOpt, Debug)]
@@ -33,6 +34,18 @@ pub struct CLI {
 
 #[derive(StructOpt, Debug)]
 pub enum Command {
+    /// Create or decrypt a password-encrypted account keystore
+    Keystore {
+        #[structopt(subcommand)]
+        command: KeystoreCommand,
+    },
+
+    /// Generate or verify a parameter bundle
+    Parameters {
+        #[structopt(subcommand)]
+        command: ParametersCommand,
+    },
+
     /// Update snarkVM to the latest version
     Update {
         /// Lists all available versions of snarkVM
@@ -44,3 +57,68 @@ pub enum Command {
         quiet: bool,
     },
 }
+
+#[derive(StructOpt, Debug)]
+pub enum KeystoreCommand {
+    /// Encrypt a new or given account key into a keystore
+    Create {
+        /// The network of the account (testnet1 or testnet2)
+        #[structopt(short = "n", long, default_value = "testnet2")]
+        network: String,
+
+        /// Encrypt the private key read from the given file, or from standard input if the path is "-",
+        /// instead of sampling a new one
+        #[structopt(long, parse(from_os_str))]
+        private_key_file: Option<PathBuf>,
+
+        /// Encrypt only the view key of the given private key
+        #[structopt(long, requires = "private-key-file")]
+        view_key: bool,
+
+        /// The path to write the keystore to
+        #[structopt(short = "o", long, parse(from_os_str), default_value = "account.keystore")]
+        output: PathBuf,
+
+        /// Read the password from the given file, instead of from standard input
+        #[structopt(long, parse(from_os_str))]
+        password_file: Option<PathBuf>,
+    },
+    /// Decrypt a keystore and print its account keys
+    Decrypt {
+        /// The path of the keystore to decrypt
+        #[structopt(parse(from_os_str))]
+        path: PathBuf,
+
+        /// Read the password from the given file, instead of from standard input
+        #[structopt(long, parse(from_os_str))]
+        password_file: Option<PathBuf>,
+    },
+}
+
+#[derive(StructOpt, Debug)]
+pub enum ParametersCommand {
+    /// Generate the full parameter set for a network, with a manifest of checksums and sizes
+    Generate {
+        /// The network to generate parameters for (testnet1, testnet2, or devnet with the `devnet` feature)
+        #[structopt(short = "n", long, default_value = "testnet2")]
+        network: String,
+
+        /// The directory to write the parameters to
+        #[structopt(short = "o", long, parse(from_os_str), default_value = ".")]
+        output: PathBuf,
+
+        /// Derive the parameters from the given seed (insecure, for reproducible test parameters only)
+        #[structopt(long)]
+        seed: Option<u64>,
+    },
+    /// Verify a parameter directory against its manifest
+    Verify {
+        /// The network to verify parameters for (testnet1, testnet2, or devnet with the `devnet` feature)
+        #[structopt(short = "n", long, default_value = "testnet2")]
+        network: String,
+
+        /// The directory of the parameters to verify
+        #[structopt(parse(from_os_str), default_value = ".")]
+        directory: PathBuf,
+    },
+}
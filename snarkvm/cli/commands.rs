@@ -14,10 +14,77 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::cli::{Command, Updater};
+use crate::cli::{Command, KeystoreCommand, ParameterBundle, ParametersCommand, Updater};
+#[cfg(feature = "devnet")]
+use snarkvm_dpc::devnet::Devnet;
+use snarkvm_dpc::{
+    testnet1::Testnet1,
+    testnet2::Testnet2,
+    Address,
+    Keystore,
+    KeystoreKind,
+    Network,
+    PrivateKey,
+    ViewKey,
+};
+
+use anyhow::anyhow;
+use rand::{thread_rng, CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+    str::FromStr,
+};
 
 pub fn parse(command: Command) -> anyhow::Result<String> {
     match command {
+        Command::Keystore { command } => match command {
+            KeystoreCommand::Create {
+                network,
+                private_key_file,
+                view_key,
+                output,
+                password_file,
+            } => {
+                let private_key = private_key_file.as_deref().map(read_private_key).transpose()?;
+                let password = read_new_password(password_file.as_deref())?;
+                let address = match network.as_str() {
+                    Testnet1::NETWORK_NAME => create_keystore::<Testnet1>(private_key, view_key, &password, &output)?,
+                    Testnet2::NETWORK_NAME => create_keystore::<Testnet2>(private_key, view_key, &password, &output)?,
+                    _ => return Err(anyhow!("Unknown network \"{}\"", network)),
+                };
+                Ok(format!("\nSaved the keystore of {} to {:?}", address, output))
+            }
+            KeystoreCommand::Decrypt { path, password_file } => {
+                let keystore = Keystore::load(&path)?;
+                let password = read_password(password_file.as_deref())?;
+                match keystore.network() {
+                    Testnet1::NETWORK_ID => decrypt_keystore::<Testnet1>(&keystore, &password),
+                    Testnet2::NETWORK_ID => decrypt_keystore::<Testnet2>(&keystore, &password),
+                    network_id => Err(anyhow!("Unknown network ID {}", network_id)),
+                }
+            }
+        },
+        Command::Parameters { command } => match command {
+            ParametersCommand::Generate { network, output, seed } => {
+                let manifest = match seed {
+                    Some(seed) => generate(&network, &output, &mut ChaChaRng::seed_from_u64(seed))?,
+                    None => generate(&network, &output, &mut thread_rng())?,
+                };
+                Ok(format!(
+                    "\nGenerated the {} parameters in {:?}\n{}",
+                    network,
+                    output,
+                    serde_json::to_string_pretty(&manifest)?
+                ))
+            }
+            ParametersCommand::Verify { network, directory } => {
+                let num_files = verify(&network, &directory)?;
+                Ok(format!("\nVerified {} parameter files in {:?}", num_files, directory))
+            }
+        },
         Command::Update { list, quiet } => match list {
             true => match Updater::show_available_releases() {
                 Ok(output) => Ok(output),
@@ -45,3 +112,126 @@ pub fn parse(command: Command) -> anyhow::Result<String> {
         }, // _ => Err(anyhow!("\nUnknown command\n")),
     }
 }
+
+/// Generates the parameter bundle for the network with the given name.
+fn generate<R: Rng + CryptoRng>(network: &str, directory: &Path, rng: &mut R) -> anyhow::Result<serde_json::Value> {
+    match network {
+        Testnet1::NETWORK_NAME => ParameterBundle::generate::<Testnet1, R>(directory, false, rng),
+        Testnet2::NETWORK_NAME => ParameterBundle::generate::<Testnet2, R>(directory, true, rng),
+        #[cfg(feature = "devnet")]
+        Devnet::NETWORK_NAME => ParameterBundle::generate::<Devnet, R>(directory, false, rng),
+        #[cfg(not(feature = "devnet"))]
+        DEVNET_NETWORK_NAME => Err(devnet_disabled_error()),
+        _ => Err(anyhow!("Unknown network \"{}\"", network)),
+    }
+}
+
+/// Verifies the parameter bundle for the network with the given name, returning the number of files verified.
+fn verify(network: &str, directory: &Path) -> anyhow::Result<usize> {
+    match network {
+        Testnet1::NETWORK_NAME => ParameterBundle::verify::<Testnet1>(directory, false),
+        Testnet2::NETWORK_NAME => ParameterBundle::verify::<Testnet2>(directory, true),
+        #[cfg(feature = "devnet")]
+        Devnet::NETWORK_NAME => ParameterBundle::verify::<Devnet>(directory, false),
+        #[cfg(not(feature = "devnet"))]
+        DEVNET_NETWORK_NAME => Err(devnet_disabled_error()),
+        _ => Err(anyhow!("Unknown network \"{}\"", network)),
+    }
+}
+
+/// The name of the devnet network, which is only available with the `devnet` feature.
+#[cfg(not(feature = "devnet"))]
+const DEVNET_NETWORK_NAME: &str = "devnet";
+
+/// Returns the error for a devnet command in a build without the `devnet` feature.
+#[cfg(not(feature = "devnet"))]
+fn devnet_disabled_error() -> anyhow::Error {
+    anyhow!("The devnet network requires snarkVM to be built with the \"devnet\" feature")
+}
+
+/// Encrypts the given (or a new) account key into a keystore at the given path, returning the account address.
+fn create_keystore<N: Network>(
+    private_key: Option<String>,
+    view_key: bool,
+    password: &str,
+    path: &Path,
+) -> anyhow::Result<String> {
+    let private_key = match private_key {
+        Some(private_key) => PrivateKey::<N>::from_str(&private_key)?,
+        None => PrivateKey::<N>::new(&mut thread_rng()),
+    };
+
+    let keystore = match view_key {
+        true => Keystore::from_view_key(&ViewKey::from_private_key(&private_key), password, &mut thread_rng())?,
+        false => Keystore::from_private_key(&private_key, password, &mut thread_rng())?,
+    };
+    keystore.save(path)?;
+
+    Ok(private_key.to_address().to_string())
+}
+
+/// Decrypts the keystore, returning its account keys for display.
+fn decrypt_keystore<N: Network>(keystore: &Keystore, password: &str) -> anyhow::Result<String> {
+    match keystore.kind() {
+        KeystoreKind::PrivateKey => {
+            let private_key = keystore.to_private_key::<N>(password)?;
+            Ok(format!(
+                "\n  Private Key  {}\n     View Key  {}\n      Address  {}\n",
+                private_key,
+                ViewKey::from_private_key(&private_key),
+                private_key.to_address()
+            ))
+        }
+        KeystoreKind::ViewKey => {
+            let view_key = keystore.to_view_key::<N>(password)?;
+            let address = Address::from_view_key(&view_key);
+            Ok(format!("\n     View Key  {}\n      Address  {}\n", view_key, address))
+        }
+    }
+}
+
+/// Reads the private key from the given file, or from standard input if the path is "-".
+fn read_private_key(private_key_file: &Path) -> anyhow::Result<String> {
+    let private_key = match private_key_file == Path::new("-") {
+        true => {
+            let mut private_key = String::new();
+            io::stdin().read_to_string(&mut private_key)?;
+            private_key
+        }
+        false => fs::read_to_string(private_key_file)?,
+    };
+    Ok(private_key.trim().to_string())
+}
+
+/// Reads the keystore password from the given file, or otherwise from the terminal without echoing it.
+fn read_password(password_file: Option<&Path>) -> anyhow::Result<String> {
+    let password = match password_file {
+        Some(path) => fs::read_to_string(path)?,
+        None => rpassword::prompt_password_stderr("Enter the keystore password: ")?,
+    };
+    trim_password(password)
+}
+
+/// Reads a new keystore password from the given file, or otherwise from the terminal without echoing it,
+/// asking for the password twice to ensure it was entered as intended.
+fn read_new_password(password_file: Option<&Path>) -> anyhow::Result<String> {
+    if password_file.is_some() {
+        return read_password(password_file);
+    }
+
+    let password = trim_password(rpassword::prompt_password_stderr("Enter the keystore password: ")?)?;
+    let confirmation = trim_password(rpassword::prompt_password_stderr("Confirm the keystore password: ")?)?;
+    match password == confirmation {
+        true => Ok(password),
+        false => Err(anyhow!("The keystore passwords do not match")),
+    }
+}
+
+/// Removes the trailing line break from the given password, and ensures it is not empty.
+fn trim_password(password: String) -> anyhow::Result<String> {
+    let password = password.trim_end_matches(&['\r', '\n'][..]).to_string();
+    match password.is_empty() {
+        true => Err(anyhow!("The keystore password must not be empty")),
+        false => Ok(password),
+    }
+}
@@ -23,5 +23,8 @@ pub use commands::*;
 pub mod errors;
 pub use errors::*;
 
+pub mod parameters;
+pub use parameters::*;
+
 pub mod updater;
 pub use updater::*;
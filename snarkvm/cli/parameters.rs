@@ -0,0 +1,655 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_algorithms::{merkle_tree::MerkleTree, CRH, SNARK, SRS};
+use snarkvm_dpc::{
+    Execution,
+    InnerCircuit,
+    Network,
+    OuterCircuit,
+    PoSWScheme,
+    ProgramPublicVariables,
+    SynthesizedCircuit,
+};
+use snarkvm_marlin::{ahp::AHPForR1CS, marlin::MarlinTestnet1Mode};
+use snarkvm_parameters::{checksum, versioned_filename};
+use snarkvm_utilities::{ToBytes, ToMinimalBits};
+
+use anyhow::{anyhow, Result};
+use rand::{CryptoRng, Rng};
+use serde_json::{json, Map, Value};
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+/// A parameter bundle, consisting of the parameter files of a network and a manifest of their checksums and sizes.
+pub struct ParameterBundle {
+    /// The directory of the parameter files.
+    directory: PathBuf,
+    /// The checksum and size of each parameter file, keyed by filename.
+    files: Map<String, Value>,
+}
+
+impl ParameterBundle {
+    /// The filename of the manifest in a parameter directory.
+    pub const MANIFEST: &'static str = "manifest.json";
+
+    ///
+    /// Generates the full parameter set for the given network into the given directory,
+    /// and writes a manifest of their checksums and sizes. Returns the manifest.
+    ///
+    /// If `is_universal` is set, a universal SRS is generated, and the noop circuit is set up from it.
+    ///
+    pub fn generate<N: Network, R: Rng + CryptoRng>(
+        directory: &Path,
+        is_universal: bool,
+        rng: &mut R,
+    ) -> Result<Value> {
+        fs::create_dir_all(directory)?;
+
+        let mut bundle = Self {
+            directory: directory.to_path_buf(),
+            files: Map::new(),
+        };
+
+        // Universal SRS
+        let universal_srs = match is_universal {
+            true => Some(bundle.universal_setup::<N, R>(rng)?),
+            false => None,
+        };
+
+        // Noop Circuit
+        let (noop_proving_key, noop_verifying_key) = bundle.noop_setup::<N, R>(universal_srs.as_ref(), rng)?;
+        let noop_execution = Self::noop_execution::<N, R>(&noop_proving_key, noop_verifying_key, rng)?;
+
+        // Inner & Outer Circuit
+        let (inner_proving_key, inner_verifying_key) = bundle.inner_setup::<N, R>(noop_execution.program_id, rng)?;
+        bundle.outer_setup::<N, R>(&inner_proving_key, inner_verifying_key, noop_execution, rng)?;
+
+        // PoSW Circuit
+        bundle.posw_setup::<N, R>(rng)?;
+
+        // Manifest
+        bundle.write_manifest(N::NETWORK_NAME)
+    }
+
+    ///
+    /// Verifies the parameter files of the given network in the given directory against its manifest,
+    /// returning the number of files verified. The manifest must list exactly the files that `generate`
+    /// writes for the network, with a universal SRS if `is_universal` is set.
+    ///
+    pub fn verify<N: Network>(directory: &Path, is_universal: bool) -> Result<usize> {
+        let manifest_path = directory.join(Self::MANIFEST);
+        let manifest: Value = serde_json::from_slice(
+            &fs::read(&manifest_path).map_err(|error| anyhow!("Failed to read {:?}: {}", manifest_path, error))?,
+        )?;
+
+        // Ensure the manifest is for the given network.
+        if manifest["network"] != N::NETWORK_NAME {
+            return Err(anyhow!(
+                "Expected a manifest for {}, found a manifest for {}",
+                N::NETWORK_NAME,
+                manifest["network"]
+            ));
+        }
+
+        let files = manifest["files"]
+            .as_object()
+            .ok_or_else(|| anyhow!("The manifest in {:?} is missing its list of files", directory))?;
+
+        // Ensure every filename in the manifest is a plain file name in the directory.
+        if let Some(filename) = files.keys().find(|filename| !Self::is_plain_filename(filename)) {
+            return Err(anyhow!(
+                "The manifest in {:?} lists an invalid filename {:?}",
+                directory,
+                filename
+            ));
+        }
+
+        // Ensure the manifest lists exactly the parameter files of the network.
+        let expected_files = Self::filenames(is_universal);
+        let mut found_files = Vec::with_capacity(files.len());
+        for (filename, expected) in files {
+            let expected_checksum = expected["checksum"].as_str().unwrap_or_default();
+            match expected_files.iter().find(|(name, is_versioned)| match is_versioned {
+                true => *filename == versioned_filename(name, expected_checksum),
+                false => filename == name,
+            }) {
+                Some((name, _)) if !found_files.contains(name) => found_files.push(*name),
+                _ => {
+                    return Err(anyhow!(
+                        "The manifest in {:?} lists an unexpected file {}",
+                        directory,
+                        filename
+                    ))
+                }
+            }
+        }
+        if let Some((name, _)) = expected_files.iter().find(|(name, _)| !found_files.contains(name)) {
+            return Err(anyhow!("The manifest in {:?} is missing the file {}", directory, name));
+        }
+
+        let mut errors = Vec::new();
+        for (filename, expected) in files {
+            let expected_checksum = expected["checksum"].as_str().unwrap_or_default();
+            let expected_size = expected["size"].as_u64().unwrap_or_default();
+
+            match fs::read(directory.join(filename)) {
+                Ok(bytes) => {
+                    // Ensure the size matches.
+                    if bytes.len() as u64 != expected_size {
+                        errors.push(format!(
+                            "{} - expected size of {}, found size of {}",
+                            filename,
+                            expected_size,
+                            bytes.len()
+                        ));
+                        continue;
+                    }
+
+                    // Ensure the checksum matches.
+                    let candidate_checksum = checksum(&bytes);
+                    if candidate_checksum != expected_checksum {
+                        errors.push(format!(
+                            "{} - expected checksum of {}, found checksum of {}",
+                            filename, expected_checksum, candidate_checksum
+                        ));
+                    }
+                }
+                Err(error) => errors.push(format!("{} - {}", filename, error)),
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(files.len()),
+            false => Err(anyhow!(
+                "Failed to verify {} of {} parameter files in {:?}\n  {}",
+                errors.len(),
+                files.len(),
+                directory,
+                errors.join("\n  ")
+            )),
+        }
+    }
+
+    ///
+    /// Returns the filenames that `generate` writes, with whether each filename is versioned by its checksum.
+    /// The universal SRS files are included if `is_universal` is set.
+    ///
+    fn filenames(is_universal: bool) -> Vec<(&'static str, bool)> {
+        let universal_files = [("universal.metadata", false), ("universal.srs", true)];
+        let circuit_files = [
+            ("noop.metadata", false),
+            ("noop.proving", false),
+            ("noop.verifying", false),
+            ("inner.metadata", false),
+            ("inner.proving", true),
+            ("inner.verifying", false),
+            ("outer.metadata", false),
+            ("outer.proving", true),
+            ("outer.verifying", false),
+            ("posw.metadata", false),
+            ("posw.proving", true),
+            ("posw.verifying", false),
+        ];
+
+        match is_universal {
+            true => universal_files.iter().chain(circuit_files.iter()).copied().collect(),
+            false => circuit_files.to_vec(),
+        }
+    }
+
+    /// Returns `true` if the given filename names a file in the parameter directory itself.
+    fn is_plain_filename(filename: &str) -> bool {
+        let mut components = Path::new(filename).components();
+        matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(name)), None) if name == filename
+        )
+    }
+
+    /// Runs a universal SRS setup.
+    fn universal_setup<N: Network, R: Rng + CryptoRng>(
+        &mut self,
+        rng: &mut R,
+    ) -> Result<<N::ProgramSNARK as SNARK>::UniversalSetupParameters> {
+        let max_degree = AHPForR1CS::<N::InnerScalarField, MarlinTestnet1Mode>::max_degree(2000000, 4000000, 8000000)
+            .map_err(|error| anyhow!("{:?}", error))?;
+        let universal_srs = <N::ProgramSNARK as SNARK>::universal_setup(&max_degree, rng)?;
+        let universal_srs_bytes = universal_srs.to_bytes_le()?;
+
+        let universal_checksum = checksum(&universal_srs_bytes);
+        self.write_metadata(
+            "universal.metadata",
+            json!({
+                "srs_checksum": universal_checksum,
+                "srs_size": universal_srs_bytes.len()
+            }),
+        )?;
+        self.write(
+            &versioned_filename("universal.srs", &universal_checksum),
+            &universal_srs_bytes,
+        )?;
+
+        Ok(universal_srs)
+    }
+
+    /// Runs the noop circuit setup.
+    #[allow(clippy::type_complexity)]
+    fn noop_setup<N: Network, R: Rng + CryptoRng>(
+        &mut self,
+        universal_srs: Option<&<N::ProgramSNARK as SNARK>::UniversalSetupParameters>,
+        rng: &mut R,
+    ) -> Result<(
+        <N::ProgramSNARK as SNARK>::ProvingKey,
+        <N::ProgramSNARK as SNARK>::VerifyingKey,
+    )> {
+        let circuit = SynthesizedCircuit::<N>::Noop(Default::default());
+        let (proving_key, verifying_key) = match universal_srs {
+            Some(universal_srs) => {
+                <N::ProgramSNARK as SNARK>::setup(&circuit, &mut SRS::<R, _>::Universal(universal_srs))?
+            }
+            None => <N::ProgramSNARK as SNARK>::setup(&circuit, &mut SRS::CircuitSpecific(rng))?,
+        };
+
+        let noop_function_id = hex::encode(N::function_id(&verifying_key)?.to_bytes_le()?);
+        let noop_proving_key = proving_key.to_bytes_le()?;
+        let noop_verifying_key = verifying_key.to_bytes_le()?;
+
+        self.write_metadata(
+            "noop.metadata",
+            json!({
+                "proving_checksum": checksum(&noop_proving_key),
+                "proving_size": noop_proving_key.len(),
+                "verifying_checksum": checksum(&noop_verifying_key),
+                "verifying_size": noop_verifying_key.len(),
+                "circuit_id": noop_function_id,
+            }),
+        )?;
+        self.write("noop.proving", &noop_proving_key)?;
+        self.write("noop.verifying", &noop_verifying_key)?;
+
+        Ok((proving_key, verifying_key))
+    }
+
+    ///
+    /// Returns a blank execution of the noop program, for the given noop circuit keys.
+    ///
+    /// The noop program ID and program path are derived from the given verifying key, rather than
+    /// loaded from the network, so that the inner and outer circuits are set up for the generated keys.
+    ///
+    fn noop_execution<N: Network, R: Rng + CryptoRng>(
+        noop_proving_key: &<N::ProgramSNARK as SNARK>::ProvingKey,
+        noop_verifying_key: <N::ProgramSNARK as SNARK>::VerifyingKey,
+        rng: &mut R,
+    ) -> Result<Execution<N>> {
+        // Construct the noop program, as a tree with the noop function as its only leaf.
+        let noop_function_id = N::function_id(&noop_verifying_key)?;
+        let program_tree = MerkleTree::<N::ProgramIDParameters>::new::<N::FunctionID>(
+            Arc::new(N::program_id_parameters().clone()),
+            &[],
+        )?
+        .rebuild(0, &[noop_function_id])?;
+
+        let proof = <N::ProgramSNARK as SNARK>::prove(
+            noop_proving_key,
+            &SynthesizedCircuit::<N>::Noop(ProgramPublicVariables::blank()),
+            rng,
+        )?;
+
+        Ok(Execution {
+            program_id: (*program_tree.root()).into(),
+            program_path: program_tree.generate_proof(0, &noop_function_id)?,
+            verifying_key: noop_verifying_key,
+            proof: proof.into(),
+        })
+    }
+
+    /// Runs the inner circuit setup, for the given noop program ID.
+    #[allow(clippy::type_complexity)]
+    fn inner_setup<N: Network, R: Rng + CryptoRng>(
+        &mut self,
+        noop_program_id: N::ProgramID,
+        rng: &mut R,
+    ) -> Result<(
+        <N::InnerSNARK as SNARK>::ProvingKey,
+        <N::InnerSNARK as SNARK>::VerifyingKey,
+    )> {
+        let (proving_key, verifying_key) = N::InnerSNARK::setup(
+            &InnerCircuit::<N>::blank_with_noop_program_id(noop_program_id),
+            &mut SRS::CircuitSpecific(rng),
+        )?;
+
+        let inner_circuit_id = hex::encode(
+            N::inner_circuit_id_crh()
+                .hash_bits(&verifying_key.to_minimal_bits())?
+                .to_bytes_le()?,
+        );
+        let inner_proving_key = proving_key.to_bytes_le()?;
+        let inner_proving_checksum = checksum(&inner_proving_key);
+        let inner_verifying_key = verifying_key.to_bytes_le()?;
+
+        self.write_metadata(
+            "inner.metadata",
+            json!({
+                "proving_checksum": inner_proving_checksum,
+                "proving_size": inner_proving_key.len(),
+                "verifying_checksum": checksum(&inner_verifying_key),
+                "verifying_size": inner_verifying_key.len(),
+                "circuit_id": inner_circuit_id
+            }),
+        )?;
+        self.write(
+            &versioned_filename("inner.proving", &inner_proving_checksum),
+            &inner_proving_key,
+        )?;
+        self.write("inner.verifying", &inner_verifying_key)?;
+
+        Ok((proving_key, verifying_key))
+    }
+
+    /// Runs the outer circuit setup, over a proof of the blank inner circuit and the given noop execution.
+    fn outer_setup<N: Network, R: Rng + CryptoRng>(
+        &mut self,
+        inner_proving_key: &<N::InnerSNARK as SNARK>::ProvingKey,
+        inner_verifying_key: <N::InnerSNARK as SNARK>::VerifyingKey,
+        noop_execution: Execution<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let inner_circuit = InnerCircuit::<N>::blank_with_noop_program_id(noop_execution.program_id);
+        let inner_proof = N::InnerSNARK::prove(inner_proving_key, &inner_circuit, rng)?;
+
+        let (proving_key, verifying_key) = N::OuterSNARK::setup(
+            &OuterCircuit::<N>::blank(inner_verifying_key, inner_proof.into(), noop_execution),
+            &mut SRS::CircuitSpecific(rng),
+        )?;
+
+        let outer_proving_key = proving_key.to_bytes_le()?;
+        let outer_proving_checksum = checksum(&outer_proving_key);
+        let outer_verifying_key = verifying_key.to_bytes_le()?;
+
+        self.write_metadata(
+            "outer.metadata",
+            json!({
+                "proving_checksum": outer_proving_checksum,
+                "proving_size": outer_proving_key.len(),
+                "verifying_checksum": checksum(&outer_verifying_key),
+                "verifying_size": outer_verifying_key.len(),
+            }),
+        )?;
+        self.write(
+            &versioned_filename("outer.proving", &outer_proving_checksum),
+            &outer_proving_key,
+        )?;
+        self.write("outer.verifying", &outer_verifying_key)?;
+
+        Ok(())
+    }
+
+    /// Runs the PoSW circuit setup.
+    fn posw_setup<N: Network, R: Rng + CryptoRng>(&mut self, rng: &mut R) -> Result<()> {
+        let max_degree = AHPForR1CS::<N::InnerScalarField, MarlinTestnet1Mode>::max_degree(40000, 40000, 60000)
+            .map_err(|error| anyhow!("{:?}", error))?;
+        let universal_srs = <N::PoSWSNARK as SNARK>::universal_setup(&max_degree, rng)?;
+        let posw = <N::PoSW as PoSWScheme<N>>::setup::<R>(&mut SRS::<R, _>::Universal(&universal_srs))?;
+
+        let posw_proving_key = posw
+            .proving_key()
+            .as_ref()
+            .ok_or_else(|| anyhow!("The PoSW proving key is missing"))?
+            .to_bytes_le()?;
+        let posw_proving_checksum = checksum(&posw_proving_key);
+        let posw_verifying_key = posw.verifying_key().to_bytes_le()?;
+
+        self.write_metadata(
+            "posw.metadata",
+            json!({
+                "proving_checksum": posw_proving_checksum,
+                "proving_size": posw_proving_key.len(),
+                "verifying_checksum": checksum(&posw_verifying_key),
+                "verifying_size": posw_verifying_key.len(),
+            }),
+        )?;
+        self.write(
+            &versioned_filename("posw.proving", &posw_proving_checksum),
+            &posw_proving_key,
+        )?;
+        self.write("posw.verifying", &posw_verifying_key)?;
+
+        Ok(())
+    }
+
+    /// Writes the manifest of all files written so far for the given network, and returns it.
+    fn write_manifest(&self, network: &str) -> Result<Value> {
+        let manifest = json!({
+            "network": network,
+            "files": self.files,
+        });
+        fs::write(
+            self.directory.join(Self::MANIFEST),
+            serde_json::to_vec_pretty(&manifest)?,
+        )?;
+        Ok(manifest)
+    }
+
+    /// Writes the given metadata as JSON to the given filename.
+    fn write_metadata(&mut self, filename: &str, metadata: Value) -> Result<()> {
+        self.write(filename, &serde_json::to_vec_pretty(&metadata)?)
+    }
+
+    /// Writes the given bytes to the given filename, and records its checksum and size in the manifest.
+    fn write(&mut self, filename: &str, bytes: &[u8]) -> Result<()> {
+        fs::write(self.directory.join(filename), bytes)?;
+        self.files.insert(
+            filename.to_string(),
+            json!({
+                "checksum": checksum(bytes),
+                "size": bytes.len(),
+            }),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "devnet"))]
+mod tests {
+    use super::*;
+    use snarkvm_dpc::{devnet::Devnet, testnet2::Testnet2};
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    #[test]
+    fn test_noop_execution_matches_network() {
+        // Ensure the noop program derived from the noop keys matches the noop program of the network.
+        let noop_execution = ParameterBundle::noop_execution::<Devnet, _>(
+            Devnet::noop_circuit_proving_key(),
+            Devnet::noop_circuit_verifying_key().clone(),
+            &mut ChaChaRng::seed_from_u64(0),
+        )
+        .unwrap();
+        assert_eq!(*Devnet::noop_program_id(), noop_execution.program_id);
+        assert_eq!(
+            Devnet::noop_program_path().to_bytes_le().unwrap(),
+            noop_execution.program_path.to_bytes_le().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_generate_and_verify() {
+        let directory = tempfile::tempdir().unwrap();
+
+        let manifest =
+            ParameterBundle::generate::<Devnet, _>(directory.path(), false, &mut ChaChaRng::seed_from_u64(0)).unwrap();
+        assert_eq!(Devnet::NETWORK_NAME, manifest["network"]);
+
+        // Ensure every file in the manifest is verified.
+        let num_files = manifest["files"].as_object().unwrap().len();
+        assert_eq!(
+            num_files,
+            ParameterBundle::verify::<Devnet>(directory.path(), false).unwrap()
+        );
+
+        // Ensure the bundle is rejected for another network, or as a bundle with a universal SRS.
+        assert!(ParameterBundle::verify::<Testnet2>(directory.path(), false).is_err());
+        assert!(ParameterBundle::verify::<Devnet>(directory.path(), true).is_err());
+
+        // Ensure a modified parameter file is rejected.
+        let verifying_key = directory.path().join("outer.verifying");
+        let mut bytes = fs::read(&verifying_key).unwrap();
+        bytes[0] ^= 1;
+        fs::write(&verifying_key, &bytes).unwrap();
+        assert!(ParameterBundle::verify::<Devnet>(directory.path(), false).is_err());
+    }
+
+    /// Writes a bundle of placeholder files for every parameter file into the given directory, and returns its manifest.
+    fn placeholder_bundle(directory: &Path) -> Value {
+        let mut bundle = ParameterBundle {
+            directory: directory.to_path_buf(),
+            files: Map::new(),
+        };
+        for (index, (name, is_versioned)) in ParameterBundle::filenames(false).into_iter().enumerate() {
+            let bytes = vec![index as u8; 32];
+            let filename = match is_versioned {
+                true => versioned_filename(name, &checksum(&bytes)),
+                false => name.to_string(),
+            };
+            bundle.write(&filename, &bytes).unwrap();
+        }
+        bundle.write_manifest(Devnet::NETWORK_NAME).unwrap()
+    }
+
+    /// Writes the given manifest into the given directory.
+    fn write_manifest(directory: &Path, manifest: &Value) {
+        fs::write(
+            directory.join(ParameterBundle::MANIFEST),
+            serde_json::to_vec_pretty(manifest).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_verify_tampered_manifest() {
+        let directory = tempfile::tempdir().unwrap();
+
+        let mut manifest = placeholder_bundle(directory.path());
+        let num_files = ParameterBundle::filenames(false).len();
+        assert_eq!(
+            num_files,
+            ParameterBundle::verify::<Devnet>(directory.path(), false).unwrap()
+        );
+
+        // Ensure a manifest with a wrong checksum is rejected.
+        let checksum = manifest["files"]["noop.proving"]["checksum"].clone();
+        manifest["files"]["noop.proving"]["checksum"] = json!(super::checksum(&[0u8; 32]));
+        write_manifest(directory.path(), &manifest);
+        assert!(ParameterBundle::verify::<Devnet>(directory.path(), false).is_err());
+        manifest["files"]["noop.proving"]["checksum"] = checksum;
+
+        // Ensure a manifest with a wrong size is rejected.
+        manifest["files"]["noop.verifying"]["size"] = json!(33);
+        write_manifest(directory.path(), &manifest);
+        assert!(ParameterBundle::verify::<Devnet>(directory.path(), false).is_err());
+        manifest["files"]["noop.verifying"]["size"] = json!(32);
+
+        // Ensure a manifest for another network is rejected.
+        manifest["network"] = json!(Testnet2::NETWORK_NAME);
+        write_manifest(directory.path(), &manifest);
+        assert!(ParameterBundle::verify::<Devnet>(directory.path(), false).is_err());
+        manifest["network"] = json!(Devnet::NETWORK_NAME);
+
+        // Ensure a manifest listing an unexpected file is rejected.
+        let mut extended_manifest = manifest.clone();
+        extended_manifest["files"]["extra.verifying"] = json!({ "checksum": super::checksum(&[]), "size": 0 });
+        write_manifest(directory.path(), &extended_manifest);
+        assert!(ParameterBundle::verify::<Devnet>(directory.path(), false).is_err());
+
+        // Ensure a manifest without a list of files is rejected.
+        write_manifest(directory.path(), &json!({ "network": Devnet::NETWORK_NAME }));
+        assert!(ParameterBundle::verify::<Devnet>(directory.path(), false).is_err());
+
+        // Ensure the restored manifest is verified.
+        write_manifest(directory.path(), &manifest);
+        assert_eq!(
+            num_files,
+            ParameterBundle::verify::<Devnet>(directory.path(), false).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_truncated_manifest() {
+        let directory = tempfile::tempdir().unwrap();
+
+        let manifest = placeholder_bundle(directory.path());
+
+        // Ensure a manifest missing one of the parameter files is rejected.
+        let mut truncated_manifest = manifest.clone();
+        truncated_manifest["files"]
+            .as_object_mut()
+            .unwrap()
+            .remove("noop.verifying");
+        write_manifest(directory.path(), &truncated_manifest);
+        assert!(ParameterBundle::verify::<Devnet>(directory.path(), false).is_err());
+
+        // Ensure a manifest with an empty list of files is rejected.
+        write_manifest(
+            directory.path(),
+            &json!({ "network": Devnet::NETWORK_NAME, "files": {} }),
+        );
+        assert!(ParameterBundle::verify::<Devnet>(directory.path(), false).is_err());
+    }
+
+    #[test]
+    fn test_verify_path_traversal() {
+        let parent = tempfile::tempdir().unwrap();
+        let directory = parent.path().join("parameters");
+        fs::create_dir_all(&directory).unwrap();
+
+        let manifest = placeholder_bundle(&directory);
+
+        // Write a file outside of the parameter directory.
+        let bytes = [7u8; 32];
+        let outside = parent.path().join("outside");
+        fs::write(&outside, bytes).unwrap();
+        let entry = json!({ "checksum": checksum(&bytes), "size": bytes.len() });
+
+        // Ensure a manifest listing a file outside of the parameter directory is rejected.
+        for filename in ["../outside", outside.to_str().unwrap(), "./noop.proving"] {
+            let mut traversal_manifest = manifest.clone();
+            traversal_manifest["files"][filename] = entry.clone();
+            write_manifest(&directory, &traversal_manifest);
+            assert!(ParameterBundle::verify::<Devnet>(&directory, false).is_err());
+        }
+
+        // Ensure a versioned filename may not traverse out of the parameter directory through its checksum.
+        let mut traversal_manifest = manifest;
+        let files = traversal_manifest["files"].as_object_mut().unwrap();
+        let filename = files
+            .keys()
+            .find(|filename| filename.starts_with("inner.proving"))
+            .unwrap()
+            .clone();
+        files.remove(&filename);
+        files.insert(
+            "inner.proving./../..".to_string(),
+            json!({ "checksum": "/../../", "size": bytes.len() }),
+        );
+        write_manifest(&directory, &traversal_manifest);
+        assert!(ParameterBundle::verify::<Devnet>(&directory, false).is_err());
+    }
+}